@@ -0,0 +1,9 @@
+-- Drop triggers
+DROP TRIGGER IF EXISTS update_user_ratings_updated_at ON user_ratings;
+
+-- Drop tables
+DROP TABLE IF EXISTS user_ratings;
+
+-- Drop types
+DROP TYPE IF EXISTS recommendation_preference;
+DROP TYPE IF EXISTS overall_feeling;
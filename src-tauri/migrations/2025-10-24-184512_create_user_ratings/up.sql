@@ -0,0 +1,77 @@
+-- ============================================================================
+-- CUSTOM TYPES
+-- ============================================================================
+
+CREATE TYPE overall_feeling AS ENUM (
+    'loved',
+    'enjoyed',
+    'liked',
+    'okay',
+    'disliked',
+    'hated'
+);
+
+CREATE TYPE recommendation_preference AS ENUM (
+    'recommend',
+    'neutral',
+    'avoid'
+);
+
+-- ============================================================================
+-- USER RATINGS TABLE
+-- ============================================================================
+
+CREATE TABLE user_ratings (
+    -- Primary Key
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+
+    -- Foreign Key
+    anime_id UUID NOT NULL REFERENCES anime(id) ON DELETE CASCADE,
+    user_id VARCHAR(255) NOT NULL,
+
+    -- Core Rating
+    overall_feeling overall_feeling NOT NULL,
+    recommendation_preference recommendation_preference,
+
+    -- Extensible Rating Data
+    rating_criteria JSONB NOT NULL DEFAULT '{}'::jsonb,
+    emotional_tags JSONB NOT NULL DEFAULT '[]'::jsonb,
+    standout_aspects JSONB NOT NULL DEFAULT '[]'::jsonb,
+    personal_notes TEXT,
+    would_rewatch BOOLEAN,
+
+    -- Derived Score (kept in sync by the application)
+    composite_score REAL NOT NULL CHECK (composite_score >= 0 AND composite_score <= 10),
+
+    -- Timestamps
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    -- Constraints
+    CONSTRAINT unique_user_rating_per_anime UNIQUE(anime_id, user_id)
+);
+
+-- ============================================================================
+-- INDEXES
+-- ============================================================================
+
+CREATE INDEX idx_user_ratings_user ON user_ratings(user_id);
+CREATE INDEX idx_user_ratings_feeling ON user_ratings(user_id, overall_feeling);
+CREATE INDEX idx_user_ratings_composite ON user_ratings(composite_score DESC);
+
+-- ============================================================================
+-- TRIGGERS
+-- ============================================================================
+
+CREATE TRIGGER update_user_ratings_updated_at
+    BEFORE UPDATE ON user_ratings
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- ============================================================================
+-- COMMENTS (Documentation)
+-- ============================================================================
+
+COMMENT ON TABLE user_ratings IS 'Detailed personal ratings (feeling, criteria, tags) per anime';
+COMMENT ON COLUMN user_ratings.rating_criteria IS 'Map of criterion key to RatingCriterion (type, value, weight)';
+COMMENT ON COLUMN user_ratings.composite_score IS 'Weighted 0-10 score computed from overall feeling and numeric criteria';
@@ -0,0 +1,19 @@
+-- Postgres cannot drop enum values, so rebuild the type without 'cancelled'
+
+DROP INDEX IF EXISTS idx_jobs_pending;
+
+UPDATE background_jobs SET status = 'failed' WHERE status = 'cancelled';
+
+ALTER TABLE background_jobs ALTER COLUMN status DROP DEFAULT;
+ALTER TYPE job_status RENAME TO job_status_old;
+
+CREATE TYPE job_status AS ENUM ('pending', 'running', 'completed', 'failed');
+
+ALTER TABLE background_jobs
+    ALTER COLUMN status TYPE job_status USING status::text::job_status;
+ALTER TABLE background_jobs ALTER COLUMN status SET DEFAULT 'pending';
+
+DROP TYPE job_status_old;
+
+CREATE INDEX idx_jobs_pending ON background_jobs(status, priority DESC, created_at ASC)
+WHERE status = 'pending';
@@ -0,0 +1,2 @@
+-- Allow pending jobs to be cancelled from the UI
+ALTER TYPE job_status ADD VALUE IF NOT EXISTS 'cancelled';
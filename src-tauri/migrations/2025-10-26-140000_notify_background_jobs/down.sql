@@ -0,0 +1,2 @@
+DROP TRIGGER IF EXISTS background_jobs_notify ON background_jobs;
+DROP FUNCTION IF EXISTS notify_background_job();
@@ -0,0 +1,14 @@
+-- Wake the worker pool as soon as a job becomes pending instead of waiting for the next poll
+
+CREATE OR REPLACE FUNCTION notify_background_job() RETURNS trigger AS $$
+BEGIN
+    PERFORM pg_notify('background_jobs', NEW.job_type);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER background_jobs_notify
+AFTER INSERT OR UPDATE OF status ON background_jobs
+FOR EACH ROW
+WHEN (NEW.status = 'pending')
+EXECUTE FUNCTION notify_background_job();
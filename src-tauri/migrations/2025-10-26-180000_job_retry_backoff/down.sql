@@ -0,0 +1,28 @@
+-- Postgres cannot drop enum values, so rebuild the type without 'dead_letter'
+
+DROP TRIGGER IF EXISTS background_jobs_notify ON background_jobs;
+DROP INDEX IF EXISTS idx_jobs_pending;
+
+ALTER TABLE background_jobs DROP COLUMN scheduled_at;
+
+UPDATE background_jobs SET status = 'failed' WHERE status = 'dead_letter';
+
+ALTER TABLE background_jobs ALTER COLUMN status DROP DEFAULT;
+ALTER TYPE job_status RENAME TO job_status_old;
+
+CREATE TYPE job_status AS ENUM ('pending', 'running', 'completed', 'failed', 'cancelled');
+
+ALTER TABLE background_jobs
+    ALTER COLUMN status TYPE job_status USING status::text::job_status;
+ALTER TABLE background_jobs ALTER COLUMN status SET DEFAULT 'pending';
+
+DROP TYPE job_status_old;
+
+CREATE INDEX idx_jobs_pending ON background_jobs(status, priority DESC, created_at ASC)
+WHERE status = 'pending';
+
+CREATE TRIGGER background_jobs_notify
+AFTER INSERT OR UPDATE OF status ON background_jobs
+FOR EACH ROW
+WHEN (NEW.status = 'pending')
+EXECUTE FUNCTION notify_background_job();
@@ -0,0 +1,18 @@
+-- Failed jobs wait out an exponential backoff (or the provider's Retry-After) before
+-- the next attempt; jobs that use up every attempt move to a dead-letter state
+
+ALTER TYPE job_status ADD VALUE IF NOT EXISTS 'dead_letter';
+
+ALTER TABLE background_jobs ADD COLUMN scheduled_at TIMESTAMPTZ NOT NULL DEFAULT NOW();
+
+DROP INDEX IF EXISTS idx_jobs_pending;
+CREATE INDEX idx_jobs_pending ON background_jobs(priority ASC, scheduled_at ASC)
+WHERE status = 'pending';
+
+-- Rescheduled jobs are not due yet, the worker's poll picks them up once they are
+DROP TRIGGER IF EXISTS background_jobs_notify ON background_jobs;
+CREATE TRIGGER background_jobs_notify
+AFTER INSERT OR UPDATE OF status ON background_jobs
+FOR EACH ROW
+WHEN (NEW.status = 'pending' AND NEW.scheduled_at <= NOW())
+EXECUTE FUNCTION notify_background_job();
@@ -0,0 +1,4 @@
+DROP TABLE IF EXISTS anime_characters;
+DROP TABLE IF EXISTS people;
+DROP TABLE IF EXISTS characters;
+DROP TYPE IF EXISTS character_role;
@@ -0,0 +1,110 @@
+-- ============================================================================
+-- CUSTOM TYPES
+-- ============================================================================
+
+CREATE TYPE character_role AS ENUM (
+    'main',
+    'supporting',
+    'background'
+);
+
+-- ============================================================================
+-- CHARACTERS TABLE
+-- ============================================================================
+
+CREATE TABLE characters (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+
+    -- Provider identities used to merge cast lists
+    mal_id INTEGER UNIQUE,
+    anilist_id INTEGER UNIQUE,
+
+    name VARCHAR(255) NOT NULL,
+    name_native VARCHAR(255),
+    image_url TEXT,
+    description TEXT,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT characters_provider_id CHECK (mal_id IS NOT NULL OR anilist_id IS NOT NULL)
+);
+
+-- ============================================================================
+-- PEOPLE TABLE (voice actors, later staff)
+-- ============================================================================
+
+CREATE TABLE people (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+
+    mal_id INTEGER UNIQUE,
+    anilist_id INTEGER UNIQUE,
+
+    name VARCHAR(255) NOT NULL,
+    name_native VARCHAR(255),
+    image_url TEXT,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT people_provider_id CHECK (mal_id IS NOT NULL OR anilist_id IS NOT NULL)
+);
+
+-- ============================================================================
+-- ANIME CAST TABLE
+-- ============================================================================
+
+-- One row per (character, voice actor) pair; characters without a credited
+-- voice actor get a single row with a NULL voice_actor_id
+CREATE TABLE anime_characters (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+
+    anime_id UUID NOT NULL REFERENCES anime(id) ON DELETE CASCADE,
+    character_id UUID NOT NULL REFERENCES characters(id) ON DELETE CASCADE,
+    voice_actor_id UUID REFERENCES people(id) ON DELETE CASCADE,
+
+    role character_role NOT NULL,
+    language VARCHAR(50),
+    position INTEGER NOT NULL DEFAULT 0,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- ============================================================================
+-- INDEXES
+-- ============================================================================
+
+CREATE UNIQUE INDEX idx_anime_characters_unique ON anime_characters(
+    anime_id,
+    character_id,
+    COALESCE(voice_actor_id, '00000000-0000-0000-0000-000000000000'::uuid)
+);
+CREATE INDEX idx_anime_characters_anime ON anime_characters(anime_id, position);
+CREATE INDEX idx_anime_characters_character ON anime_characters(character_id);
+CREATE INDEX idx_anime_characters_voice_actor ON anime_characters(voice_actor_id)
+WHERE voice_actor_id IS NOT NULL;
+
+CREATE INDEX idx_characters_name ON characters(LOWER(name));
+CREATE INDEX idx_people_name ON people(LOWER(name));
+
+-- ============================================================================
+-- TRIGGERS
+-- ============================================================================
+
+CREATE TRIGGER update_characters_updated_at
+    BEFORE UPDATE ON characters
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER update_people_updated_at
+    BEFORE UPDATE ON people
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- ============================================================================
+-- COMMENTS (Documentation)
+-- ============================================================================
+
+COMMENT ON TABLE characters IS 'Anime characters merged from MyAnimeList and AniList cast lists';
+COMMENT ON TABLE people IS 'Real people credited on anime (voice actors)';
+COMMENT ON TABLE anime_characters IS 'Cast of an anime: character, role and voice actor per language';
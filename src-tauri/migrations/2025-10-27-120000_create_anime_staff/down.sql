@@ -0,0 +1,4 @@
+COMMENT ON TABLE people IS 'Real people credited on anime (voice actors)';
+
+DROP TABLE IF EXISTS anime_staff;
+DROP TYPE IF EXISTS staff_role;
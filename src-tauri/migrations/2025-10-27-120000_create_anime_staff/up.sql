@@ -0,0 +1,55 @@
+-- ============================================================================
+-- CUSTOM TYPES
+-- ============================================================================
+
+CREATE TYPE staff_role AS ENUM (
+    'director',
+    'series_composition',
+    'script',
+    'original_creator',
+    'character_design',
+    'chief_animation_director',
+    'art_director',
+    'sound_director',
+    'music',
+    'episode_director',
+    'storyboard',
+    'animation_director',
+    'producer',
+    'other'
+);
+
+-- ============================================================================
+-- ANIME STAFF TABLE
+-- ============================================================================
+
+-- One row per (person, position); untracked positions are stored as 'other'
+-- and told apart by the provider's label
+CREATE TABLE anime_staff (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+
+    anime_id UUID NOT NULL REFERENCES anime(id) ON DELETE CASCADE,
+    person_id UUID NOT NULL REFERENCES people(id) ON DELETE CASCADE,
+
+    role staff_role NOT NULL,
+    role_label VARCHAR(255) NOT NULL,
+    position INTEGER NOT NULL DEFAULT 0,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- ============================================================================
+-- INDEXES
+-- ============================================================================
+
+CREATE UNIQUE INDEX idx_anime_staff_unique ON anime_staff(anime_id, person_id, role, role_label);
+CREATE INDEX idx_anime_staff_anime ON anime_staff(anime_id, position);
+CREATE INDEX idx_anime_staff_person_role ON anime_staff(person_id, role);
+
+-- ============================================================================
+-- COMMENTS (Documentation)
+-- ============================================================================
+
+COMMENT ON TABLE anime_staff IS 'Staff credits of an anime with positions normalised across providers';
+COMMENT ON COLUMN anime_staff.role_label IS 'Position as spelled by the provider, without episode notes';
+COMMENT ON TABLE people IS 'Real people credited on anime (voice actors and staff)';
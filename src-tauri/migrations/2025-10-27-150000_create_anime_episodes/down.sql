@@ -0,0 +1,3 @@
+COMMENT ON COLUMN background_jobs.job_type IS 'Job type: enrichment, relations_discovery';
+
+DROP TABLE IF EXISTS anime_episodes;
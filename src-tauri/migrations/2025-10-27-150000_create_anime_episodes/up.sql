@@ -0,0 +1,50 @@
+-- ============================================================================
+-- ANIME EPISODES TABLE
+-- ============================================================================
+
+CREATE TABLE anime_episodes (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+
+    anime_id UUID NOT NULL REFERENCES anime(id) ON DELETE CASCADE,
+    number INTEGER NOT NULL,
+
+    title VARCHAR(500),
+    title_japanese VARCHAR(500),
+    title_romaji VARCHAR(500),
+    aired_at TIMESTAMPTZ,
+    score REAL,
+
+    is_filler BOOLEAN NOT NULL DEFAULT FALSE,
+    is_recap BOOLEAN NOT NULL DEFAULT FALSE,
+
+    thumbnail_url TEXT,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT anime_episodes_positive_number CHECK (number > 0)
+);
+
+-- ============================================================================
+-- INDEXES
+-- ============================================================================
+
+CREATE UNIQUE INDEX idx_anime_episodes_unique ON anime_episodes(anime_id, number);
+
+-- ============================================================================
+-- TRIGGERS
+-- ============================================================================
+
+CREATE TRIGGER update_anime_episodes_updated_at
+    BEFORE UPDATE ON anime_episodes
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- ============================================================================
+-- COMMENTS (Documentation)
+-- ============================================================================
+
+COMMENT ON TABLE anime_episodes IS 'Episode list of an anime merged from MyAnimeList and AniList';
+COMMENT ON COLUMN anime_episodes.is_filler IS 'Episode is not adapted from the source material (MyAnimeList flag)';
+COMMENT ON COLUMN anime_episodes.is_recap IS 'Episode mostly recaps earlier episodes (MyAnimeList flag)';
+COMMENT ON COLUMN background_jobs.job_type IS 'Job type: enrichment, relations_discovery, episode_sync';
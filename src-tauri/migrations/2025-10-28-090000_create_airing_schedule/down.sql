@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS anime_broadcasts;
+DROP TABLE IF EXISTS anime_airing_schedule;
@@ -0,0 +1,61 @@
+-- ============================================================================
+-- AIRING SCHEDULE TABLE
+-- ============================================================================
+
+-- Airing time of each announced episode, as published by AniList
+CREATE TABLE anime_airing_schedule (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+
+    anime_id UUID NOT NULL REFERENCES anime(id) ON DELETE CASCADE,
+    episode INTEGER NOT NULL,
+    airs_at TIMESTAMPTZ NOT NULL,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- ============================================================================
+-- BROADCAST SLOTS TABLE
+-- ============================================================================
+
+-- Weekly TV slot in the broadcaster's timezone (MyAnimeList), used when no
+-- per-episode schedule is known
+CREATE TABLE anime_broadcasts (
+    anime_id UUID PRIMARY KEY REFERENCES anime(id) ON DELETE CASCADE,
+
+    day VARCHAR(10) NOT NULL,
+    start_time VARCHAR(5) NOT NULL,
+    timezone VARCHAR(64) NOT NULL DEFAULT 'Asia/Tokyo',
+
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- ============================================================================
+-- INDEXES
+-- ============================================================================
+
+CREATE UNIQUE INDEX idx_anime_airing_schedule_unique ON anime_airing_schedule(anime_id, episode);
+CREATE INDEX idx_anime_airing_schedule_airs_at ON anime_airing_schedule(airs_at);
+
+-- ============================================================================
+-- TRIGGERS
+-- ============================================================================
+
+CREATE TRIGGER update_anime_airing_schedule_updated_at
+    BEFORE UPDATE ON anime_airing_schedule
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER update_anime_broadcasts_updated_at
+    BEFORE UPDATE ON anime_broadcasts
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- ============================================================================
+-- COMMENTS (Documentation)
+-- ============================================================================
+
+COMMENT ON TABLE anime_airing_schedule IS 'Per-episode airing times of airing and upcoming anime';
+COMMENT ON TABLE anime_broadcasts IS 'Weekly broadcast slot of an anime, e.g. Saturday 23:30 Asia/Tokyo';
+COMMENT ON COLUMN anime_broadcasts.day IS 'English weekday name, e.g. Saturday';
+COMMENT ON COLUMN anime_broadcasts.start_time IS 'Local start time as HH:MM in the broadcast timezone';
@@ -0,0 +1,52 @@
+-- ============================================================================
+-- ANIME RECOMMENDATIONS TABLE
+-- ============================================================================
+
+CREATE TABLE anime_recommendations (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+
+    source_anime_id UUID NOT NULL REFERENCES anime(id) ON DELETE CASCADE,
+
+    -- Recommended anime, usually not in the library yet
+    target_mal_id INTEGER,
+    target_anilist_id INTEGER,
+    title VARCHAR(500) NOT NULL,
+    image_url TEXT,
+    genres JSONB NOT NULL DEFAULT '[]',
+    studios JSONB NOT NULL DEFAULT '[]',
+
+    votes INTEGER NOT NULL DEFAULT 0,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT anime_recommendations_has_target CHECK (
+        target_mal_id IS NOT NULL OR target_anilist_id IS NOT NULL
+    )
+);
+
+-- ============================================================================
+-- INDEXES
+-- ============================================================================
+
+CREATE INDEX idx_anime_recommendations_source ON anime_recommendations(source_anime_id);
+CREATE INDEX idx_anime_recommendations_target_mal ON anime_recommendations(target_mal_id);
+CREATE INDEX idx_anime_recommendations_target_anilist ON anime_recommendations(target_anilist_id);
+
+-- ============================================================================
+-- TRIGGERS
+-- ============================================================================
+
+CREATE TRIGGER update_anime_recommendations_updated_at
+    BEFORE UPDATE ON anime_recommendations
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- ============================================================================
+-- COMMENTS (Documentation)
+-- ============================================================================
+
+COMMENT ON TABLE anime_recommendations IS '"Users also liked" edges from a library anime, merged from MyAnimeList and AniList';
+COMMENT ON COLUMN anime_recommendations.votes IS 'Users backing the recommendation, summed across providers';
+COMMENT ON COLUMN anime_recommendations.genres IS 'Genres of the recommended anime as a JSON array (AniList only)';
+COMMENT ON COLUMN anime_recommendations.studios IS 'Main studios of the recommended anime as a JSON array (AniList only)';
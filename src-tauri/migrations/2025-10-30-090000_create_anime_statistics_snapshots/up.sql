@@ -0,0 +1,43 @@
+-- ============================================================================
+-- ANIME STATISTICS SNAPSHOTS TABLE
+-- ============================================================================
+
+CREATE TABLE anime_statistics_snapshots (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+
+    anime_id UUID NOT NULL REFERENCES anime(id) ON DELETE CASCADE,
+    provider media_provider NOT NULL,
+
+    -- List status counts
+    watching INTEGER NOT NULL DEFAULT 0,
+    completed INTEGER NOT NULL DEFAULT 0,
+    on_hold INTEGER NOT NULL DEFAULT 0,
+    dropped INTEGER NOT NULL DEFAULT 0,
+    plan_to_watch INTEGER NOT NULL DEFAULT 0,
+    total INTEGER NOT NULL DEFAULT 0,
+    favorites INTEGER,
+
+    -- User scores
+    score_distribution JSONB NOT NULL DEFAULT '[]',
+    mean_score REAL,
+
+    captured_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- ============================================================================
+-- INDEXES
+-- ============================================================================
+
+CREATE INDEX idx_anime_statistics_snapshots_series
+    ON anime_statistics_snapshots(anime_id, provider, captured_at);
+CREATE INDEX idx_anime_statistics_snapshots_captured
+    ON anime_statistics_snapshots(captured_at);
+
+-- ============================================================================
+-- COMMENTS (Documentation)
+-- ============================================================================
+
+COMMENT ON TABLE anime_statistics_snapshots IS 'Time series of provider list statistics per anime; rows are never updated';
+COMMENT ON COLUMN anime_statistics_snapshots.total IS 'Users with the anime on their list in any status';
+COMMENT ON COLUMN anime_statistics_snapshots.score_distribution IS 'Votes per score on a 1-10 scale as a JSON array of {score, votes}';
+COMMENT ON COLUMN anime_statistics_snapshots.mean_score IS 'Vote-weighted mean of score_distribution';
@@ -0,0 +1,4 @@
+COMMENT ON COLUMN background_jobs.job_type IS 'Job type: enrichment, relations_discovery, episode_sync';
+
+DROP TABLE IF EXISTS anime_news;
+DROP TABLE IF EXISTS news_items;
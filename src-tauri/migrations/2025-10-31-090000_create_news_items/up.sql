@@ -0,0 +1,57 @@
+-- ============================================================================
+-- NEWS ITEMS TABLE
+-- ============================================================================
+
+CREATE TABLE news_items (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+
+    provider media_provider NOT NULL,
+    url TEXT NOT NULL UNIQUE,
+    title VARCHAR(500) NOT NULL,
+    excerpt TEXT,
+    image_url TEXT,
+    author VARCHAR(255),
+    forum_url TEXT,
+    comments INTEGER NOT NULL DEFAULT 0,
+    published_at TIMESTAMPTZ,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- ============================================================================
+-- ANIME NEWS TABLE (news item <-> anime)
+-- ============================================================================
+
+CREATE TABLE anime_news (
+    anime_id UUID NOT NULL REFERENCES anime(id) ON DELETE CASCADE,
+    news_item_id UUID NOT NULL REFERENCES news_items(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (anime_id, news_item_id)
+);
+
+-- ============================================================================
+-- INDEXES
+-- ============================================================================
+
+CREATE INDEX idx_news_items_published ON news_items(published_at DESC NULLS LAST);
+CREATE INDEX idx_anime_news_item ON anime_news(news_item_id);
+
+-- ============================================================================
+-- TRIGGERS
+-- ============================================================================
+
+CREATE TRIGGER update_news_items_updated_at
+    BEFORE UPDATE ON news_items
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- ============================================================================
+-- COMMENTS (Documentation)
+-- ============================================================================
+
+COMMENT ON TABLE news_items IS 'Provider news articles, deduplicated by URL';
+COMMENT ON TABLE anime_news IS 'Anime a news item was listed under; one article can cover several anime';
+COMMENT ON COLUMN news_items.comments IS 'Forum comment count at the last sync';
+COMMENT ON COLUMN background_jobs.job_type IS 'Job type: enrichment, relations_discovery, episode_sync, news_sync';
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS anime_links;
+DROP TYPE IF EXISTS link_category;
@@ -0,0 +1,48 @@
+-- ============================================================================
+-- CUSTOM TYPES
+-- ============================================================================
+
+CREATE TYPE link_category AS ENUM (
+    'official',
+    'streaming',
+    'social',
+    'info'
+);
+
+-- ============================================================================
+-- ANIME LINKS TABLE
+-- ============================================================================
+
+-- External links merged across providers, one row per host (and language for
+-- regional streaming sites)
+CREATE TABLE anime_links (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+
+    anime_id UUID NOT NULL REFERENCES anime(id) ON DELETE CASCADE,
+
+    category link_category NOT NULL,
+    site VARCHAR(255) NOT NULL,
+    host VARCHAR(255) NOT NULL,
+    url TEXT NOT NULL,
+    language VARCHAR(50),
+    provider media_provider NOT NULL,
+    position INTEGER NOT NULL DEFAULT 0,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- ============================================================================
+-- INDEXES
+-- ============================================================================
+
+CREATE UNIQUE INDEX idx_anime_links_unique ON anime_links(anime_id, host, COALESCE(language, ''));
+CREATE INDEX idx_anime_links_anime ON anime_links(anime_id, category, position);
+CREATE INDEX idx_anime_links_host ON anime_links(host) WHERE category = 'streaming';
+
+-- ============================================================================
+-- COMMENTS (Documentation)
+-- ============================================================================
+
+COMMENT ON TABLE anime_links IS 'Official, streaming and social links of an anime merged across providers';
+COMMENT ON COLUMN anime_links.host IS 'Normalised host used for deduplication (no www., x.com stored as twitter.com)';
+COMMENT ON COLUMN anime_links.provider IS 'Provider the link was first seen on';
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS anime_themes;
+DROP TYPE IF EXISTS theme_kind;
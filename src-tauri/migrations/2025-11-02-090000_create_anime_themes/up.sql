@@ -0,0 +1,49 @@
+-- ============================================================================
+-- CUSTOM TYPES
+-- ============================================================================
+
+CREATE TYPE theme_kind AS ENUM (
+    'opening',
+    'ending'
+);
+
+-- ============================================================================
+-- ANIME THEMES TABLE
+-- ============================================================================
+
+CREATE TABLE anime_themes (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+
+    anime_id UUID NOT NULL REFERENCES anime(id) ON DELETE CASCADE,
+    video_id UUID REFERENCES anime_videos(id) ON DELETE SET NULL,
+
+    kind theme_kind NOT NULL,
+    sequence INTEGER NOT NULL,
+    title VARCHAR(500) NOT NULL,
+    title_native VARCHAR(500),
+    artist VARCHAR(500),
+    episodes VARCHAR(255),
+    episode_start INTEGER,
+    episode_end INTEGER,
+    raw_text TEXT NOT NULL,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- ============================================================================
+-- INDEXES
+-- ============================================================================
+
+CREATE UNIQUE INDEX idx_anime_themes_unique ON anime_themes(anime_id, kind, sequence);
+CREATE INDEX idx_anime_themes_artist ON anime_themes(LOWER(artist));
+CREATE INDEX idx_anime_themes_video ON anime_themes(video_id) WHERE video_id IS NOT NULL;
+
+-- ============================================================================
+-- COMMENTS (Documentation)
+-- ============================================================================
+
+COMMENT ON TABLE anime_themes IS 'Opening and ending songs parsed from provider theme lists';
+COMMENT ON COLUMN anime_themes.episodes IS 'Episodes the song plays in as listed by the provider, e.g. 1-11, 13-24';
+COMMENT ON COLUMN anime_themes.episode_start IS 'First episode of the listed episodes';
+COMMENT ON COLUMN anime_themes.episode_end IS 'Last episode of the listed episodes';
+COMMENT ON COLUMN anime_themes.raw_text IS 'Provider text the record was parsed from';
@@ -0,0 +1,2 @@
+DELETE FROM anime_external_ids WHERE provider_code = 'tmdb';
+DELETE FROM providers WHERE code = 'tmdb';
@@ -0,0 +1,8 @@
+-- ============================================================================
+-- TMDB PROVIDER
+-- ============================================================================
+
+-- TMDB ids are stored in anime_external_ids, which references providers(code)
+INSERT INTO providers (code, display_name, api_base_url) VALUES
+    ('tmdb', 'The Movie Database', 'https://api.themoviedb.org/3')
+ON CONFLICT (code) DO NOTHING;
@@ -0,0 +1,2 @@
+DELETE FROM anime_external_ids WHERE provider_code = 'anidb';
+DELETE FROM providers WHERE code = 'anidb';
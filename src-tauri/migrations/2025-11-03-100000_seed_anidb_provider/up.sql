@@ -0,0 +1,8 @@
+-- ============================================================================
+-- ANIDB PROVIDER
+-- ============================================================================
+
+-- AniDB ids are stored in anime_external_ids, which references providers(code)
+INSERT INTO providers (code, display_name, api_base_url) VALUES
+    ('anidb', 'AniDB', 'http://api.anidb.net:9001/httpapi')
+ON CONFLICT (code) DO NOTHING;
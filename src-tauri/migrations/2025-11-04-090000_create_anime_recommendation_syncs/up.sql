@@ -0,0 +1,22 @@
+-- ============================================================================
+-- ANIME RECOMMENDATION SYNCS TABLE
+-- ============================================================================
+
+-- Anime whose providers list no recommendations leave no edges behind, so the
+-- sync time is kept apart from anime_recommendations
+CREATE TABLE anime_recommendation_syncs (
+    anime_id UUID PRIMARY KEY REFERENCES anime(id) ON DELETE CASCADE,
+    synced_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Anime synced before this table existed keep their place in the refresh cycle
+INSERT INTO anime_recommendation_syncs (anime_id, synced_at)
+SELECT source_anime_id, MAX(created_at)
+FROM anime_recommendations
+GROUP BY source_anime_id;
+
+-- ============================================================================
+-- COMMENTS (Documentation)
+-- ============================================================================
+
+COMMENT ON TABLE anime_recommendation_syncs IS 'Last time provider recommendations were fetched for an anime, even when none were found';
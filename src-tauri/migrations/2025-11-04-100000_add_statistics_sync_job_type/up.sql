@@ -0,0 +1 @@
+COMMENT ON COLUMN background_jobs.job_type IS 'Job type: enrichment, relations_discovery, episode_sync, news_sync, statistics_sync';
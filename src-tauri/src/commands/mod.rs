@@ -1,3 +1,3 @@
 pub mod registry;
 
-pub use registry::get_all_commands;
+pub use registry::{get_all_commands, get_all_events};
@@ -1,9 +1,12 @@
-use tauri_specta::collect_commands;
+use tauri_specta::{collect_commands, collect_events};
 
 // Import all command modules
 use crate::modules::{
-    anime::commands::*, collection::commands::*, data_import::commands::*, media::commands::*,
-    provider::commands::*,
+    anime::commands::*, backup::commands::*, character::commands::*, collection::commands::*,
+    data_export::commands::*, data_import::commands::*, discovery::commands::*,
+    episode::commands::*, jobs::commands::*, links::commands::*, media::commands::*,
+    news::commands::*, provider::commands::*, recommendation::commands::*, season::commands::*,
+    statistics::commands::*, themes::commands::*, tracking::commands::*,
 };
 
 /// Single source of truth for all Tauri commands
@@ -13,6 +16,7 @@ pub fn get_all_commands() -> tauri_specta::Commands<tauri::Wry> {
     collect_commands![
         // Anime commands
         search_anime,
+        browse_library,
         get_anime_by_id,
         get_top_anime,
         get_seasonal_anime,
@@ -33,10 +37,80 @@ pub fn get_all_commands() -> tauri_specta::Commands<tauri::Wry> {
         remove_anime_from_collection,
         get_collection_anime,
         update_anime_in_collection,
+        // User rating commands
+        create_user_rating,
+        update_user_rating,
+        get_user_rating,
+        delete_user_rating,
+        get_rating_aggregates,
+        // Watch tracking commands
+        get_watch_entry,
+        get_watch_list,
+        get_watch_list_summary,
+        set_watch_status,
+        update_watch_progress,
+        increment_watch_progress,
+        update_watch_entry,
+        remove_from_watch_list,
+        // Character, voice actor and staff commands
+        get_anime_cast,
+        sync_anime_cast,
+        get_anime_staff,
+        sync_anime_staff,
+        get_anime_by_staff,
+        search_voice_actors,
+        get_voice_actor_filmography,
+        // Episode commands
+        get_anime_episodes,
+        sync_anime_episodes,
+        // Airing schedule commands
+        get_weekly_schedule,
+        get_next_episodes,
+        sync_airing_schedule,
+        // Recommendation commands
+        get_recommendations,
+        sync_recommendations,
+        // Statistics commands
+        get_anime_statistics_trend,
+        sync_anime_statistics,
+        // News commands
+        get_news_feed,
+        sync_news,
+        // Link commands
+        get_anime_links,
+        sync_anime_links,
+        get_where_to_watch,
+        // Theme commands
+        get_anime_themes,
+        sync_anime_themes,
+        search_theme_artists,
+        get_themes_by_artist,
+        // Discovery commands
+        get_discovery_feed,
+        // Export commands
+        export_library,
+        // Backup commands
+        create_backup,
+        restore_backup,
         // Import commands
         import_anime_batch,
         validate_anime_titles,
         import_validated_anime,
+        import_mal_list,
+        import_anilist_list,
+        preview_text_list,
+        import_text_list,
+        // Job queue commands
+        list_jobs,
+        get_job_statistics,
+        get_worker_status,
+        retry_job,
+        retry_failed_jobs,
+        cancel_job,
+        cancel_pending_jobs,
+        pause_worker,
+        resume_worker,
+        purge_old_jobs,
         // Media commands
         get_anime_media,
         get_anime_images,
@@ -59,18 +133,37 @@ pub fn get_all_commands() -> tauri_specta::Commands<tauri::Wry> {
     ]
 }
 
+/// Typed events pushed to the frontend
+pub fn get_all_events() -> tauri_specta::Events {
+    use crate::modules::anime::domain::events::{
+        AnimeCreatedEvent, AnimeEnrichedEvent, AnimeScoreUpdatedEvent, RelationsDiscoveredEvent,
+    };
+
+    collect_events![
+        AnimeCreatedEvent,
+        AnimeScoreUpdatedEvent,
+        RelationsDiscoveredEvent,
+        AnimeEnrichedEvent,
+    ]
+}
+
 /// Generate the handler list with all commands imported
 #[macro_export]
 macro_rules! generate_handler_list {
     () => {{
         use crate::modules::{
-            anime::commands::*, collection::commands::*, data_import::commands::*,
-            media::commands::*, provider::commands::*,
+            anime::commands::*, backup::commands::*, character::commands::*,
+            collection::commands::*, data_export::commands::*, data_import::commands::*,
+            discovery::commands::*, episode::commands::*, jobs::commands::*, links::commands::*,
+            media::commands::*, news::commands::*, provider::commands::*,
+            recommendation::commands::*, season::commands::*, statistics::commands::*,
+            themes::commands::*, tracking::commands::*,
         };
 
         tauri::generate_handler![
             // Anime commands
             search_anime,
+            browse_library,
             get_anime_by_id,
             get_top_anime,
             get_seasonal_anime,
@@ -91,10 +184,80 @@ macro_rules! generate_handler_list {
             remove_anime_from_collection,
             get_collection_anime,
             update_anime_in_collection,
+            // User rating commands
+            create_user_rating,
+            update_user_rating,
+            get_user_rating,
+            delete_user_rating,
+            get_rating_aggregates,
+            // Watch tracking commands
+            get_watch_entry,
+            get_watch_list,
+            get_watch_list_summary,
+            set_watch_status,
+            update_watch_progress,
+            increment_watch_progress,
+            update_watch_entry,
+            remove_from_watch_list,
+            // Character, voice actor and staff commands
+            get_anime_cast,
+            sync_anime_cast,
+            get_anime_staff,
+            sync_anime_staff,
+            get_anime_by_staff,
+            search_voice_actors,
+            get_voice_actor_filmography,
+            // Episode commands
+            get_anime_episodes,
+            sync_anime_episodes,
+            // Airing schedule commands
+            get_weekly_schedule,
+            get_next_episodes,
+            sync_airing_schedule,
+            // Recommendation commands
+            get_recommendations,
+            sync_recommendations,
+            // Statistics commands
+            get_anime_statistics_trend,
+            sync_anime_statistics,
+            // News commands
+            get_news_feed,
+            sync_news,
+            // Link commands
+            get_anime_links,
+            sync_anime_links,
+            get_where_to_watch,
+            // Theme commands
+            get_anime_themes,
+            sync_anime_themes,
+            search_theme_artists,
+            get_themes_by_artist,
+            // Discovery commands
+            get_discovery_feed,
+            // Export commands
+            export_library,
+            // Backup commands
+            create_backup,
+            restore_backup,
             // Import commands
             import_anime_batch,
             validate_anime_titles,
             import_validated_anime,
+            import_mal_list,
+            import_anilist_list,
+            preview_text_list,
+            import_text_list,
+            // Job queue commands
+            list_jobs,
+            get_job_statistics,
+            get_worker_status,
+            retry_job,
+            retry_failed_jobs,
+            cancel_job,
+            cancel_pending_jobs,
+            pause_worker,
+            resume_worker,
+            purge_old_jobs,
             // Media commands
             get_anime_media,
             get_anime_images,
@@ -3,41 +3,83 @@ pub mod modules;
 mod schema;
 pub mod shared;
 
-use commands::get_all_commands;
+use commands::{get_all_commands, get_all_events};
 use modules::{
     anime::{
         application::{ingestion_service::AnimeIngestionService, service::AnimeService},
-        domain::services::anime_relations_service::{AnimeRelationsService, RelationsCache},
-        infrastructure::persistence::{AnimeRelationsRepositoryImpl, AnimeRepositoryImpl},
-        AnimeRepository,
+        domain::{
+            events::{
+                AnimeCreatedEvent, AnimeEnrichedEvent, AnimeScoreUpdatedEvent,
+                RelationsDiscoveredEvent,
+            },
+            services::anime_relations_service::{AnimeRelationsService, RelationsCache},
+        },
+        infrastructure::{
+            events::InProcessEventBus,
+            persistence::{
+                AnimeQueryRepositoryImpl, AnimeRelationsRepositoryImpl, AnimeRepositoryImpl,
+            },
+        },
+        AnimeQueryRepository, AnimeRepository, BrowseLibraryHandler, EventPublisher,
     },
+    backup::{infrastructure::BackupRepositoryImpl, BackupRepository, BackupService},
+    character::{infrastructure::CharacterRepositoryImpl, CharacterRepository, CharacterService},
     collection::{
-        application::service::CollectionService,
-        infrastructure::persistence::CollectionRepositoryImpl, CollectionRepository,
+        application::{rating_service::UserRatingService, service::CollectionService},
+        infrastructure::persistence::{CollectionRepositoryImpl, UserRatingRepositoryImpl},
+        CollectionRepository, UserRatingRepository,
     },
+    data_export::{infrastructure::ExportRepositoryImpl, ExportRepository, ExportService},
     data_import::{
-        application::service::ImportService,
+        application::{list_import_service::ListImportService, service::ImportService},
         domain::services::import_components::{
             data_enhancement_service::DataEnhancementService, validation_service::ValidationService,
         },
     },
-    jobs::{infrastructure::JobRepositoryImpl, worker::BackgroundWorker},
+    discovery::{infrastructure::DiscoveryRepositoryImpl, DiscoveryRepository, DiscoveryService},
+    episode::{infrastructure::EpisodeRepositoryImpl, EpisodeRepository, EpisodeService},
+    jobs::{
+        infrastructure::{JobRepositoryImpl, PgJobListener},
+        worker::BackgroundWorker,
+        JobRepository,
+    },
+    links::{infrastructure::AnimeLinkRepositoryImpl, AnimeLinkRepository, LinkService},
     media::{
         application::{MediaService, MediaSyncService},
         infrastructure::{AnimeImageRepositoryImpl, AnimeVideoRepositoryImpl},
         AnimeImageRepository, AnimeVideoRepository,
     },
+    news::{infrastructure::NewsRepositoryImpl, NewsRepository, NewsService},
     provider::{
         application::service::ProviderService,
         domain::repositories::{
-            AnimeProviderRepository, CacheRepository, MediaProviderRepository,
-            RelationshipProviderRepository,
+            AnimeProviderRepository, CacheRepository, CastProviderRepository,
+            CatalogProviderRepository, DiscoveryProviderRepository, EpisodeProviderRepository,
+            LinkProviderRepository, MediaProviderRepository, NewsProviderRepository,
+            RecommendationProviderRepository, RelationshipProviderRepository,
+            ScheduleProviderRepository, StatisticsProviderRepository, ThemeProviderRepository,
         },
         infrastructure::{
             adapters::{CacheAdapter, ProviderRepositoryAdapter},
             CachingRepositoryDecorator,
         },
     },
+    recommendation::{
+        infrastructure::RecommendationRepositoryImpl, RecommendationRepository,
+        RecommendationService,
+    },
+    season::{
+        infrastructure::AiringScheduleRepositoryImpl, AiringScheduleRepository, ScheduleService,
+    },
+    statistics::{
+        infrastructure::StatisticsRepositoryImpl, SnapshotMomentumSource, StatisticsRepository,
+        StatisticsService,
+    },
+    themes::{infrastructure::ThemeRepositoryImpl, ThemeRepository, ThemeService},
+    tracking::{
+        application::service::WatchTrackingService, infrastructure::WatchEntryRepositoryImpl,
+        WatchEntryRepository,
+    },
 };
 use shared::{DatabaseHealthMonitor, DatabaseState};
 use std::sync::Arc;
@@ -54,7 +96,9 @@ pub fn run() {
     // Load environment variables
     dotenvy::dotenv().ok();
 
-    let specta_builder = SpectaBuilder::<tauri::Wry>::new().commands(get_all_commands());
+    let specta_builder = SpectaBuilder::<tauri::Wry>::new()
+        .commands(get_all_commands())
+        .events(get_all_events());
 
     #[cfg(debug_assertions)]
     if let Err(e) = specta_builder.export(Typescript::default(), "../src/types/bindings.ts") {
@@ -136,8 +180,8 @@ pub fn run() {
 
             // Run migrations if database is available, otherwise continue with degraded functionality
             {
-                use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
-                const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
+                use crate::shared::infrastructure::MIGRATIONS;
+                use diesel_migrations::MigrationHarness;
 
                 match db_state_read.get_database() {
                     Ok(database) => {
@@ -174,6 +218,16 @@ pub fn run() {
             // This makes caching transparent - business logic doesn't need manual cache checks
             let cache_repo_trait: Arc<dyn CacheRepository> = cache_repo.clone();
             let relationship_provider_repo: Arc<dyn RelationshipProviderRepository> = provider_repo.clone();
+            let catalog_provider_repo: Arc<dyn CatalogProviderRepository> = provider_repo.clone();
+            let cast_provider_repo: Arc<dyn CastProviderRepository> = provider_repo.clone();
+            let episode_provider_repo: Arc<dyn EpisodeProviderRepository> = provider_repo.clone();
+            let schedule_provider_repo: Arc<dyn ScheduleProviderRepository> = provider_repo.clone();
+            let recommendation_provider_repo: Arc<dyn RecommendationProviderRepository> = provider_repo.clone();
+            let statistics_provider_repo: Arc<dyn StatisticsProviderRepository> = provider_repo.clone();
+            let news_provider_repo: Arc<dyn NewsProviderRepository> = provider_repo.clone();
+            let link_provider_repo: Arc<dyn LinkProviderRepository> = provider_repo.clone();
+            let theme_provider_repo: Arc<dyn ThemeProviderRepository> = provider_repo.clone();
+            let discovery_provider_repo: Arc<dyn DiscoveryProviderRepository> = provider_repo.clone();
             let anime_provider_repo: Arc<dyn AnimeProviderRepository> = Arc::new(
                 CachingRepositoryDecorator::new(provider_repo, cache_repo_trait)
             );
@@ -182,6 +236,16 @@ pub fn run() {
                 anime_provider_repo,
                 media_provider_repo,
                 relationship_provider_repo,
+                catalog_provider_repo,
+                cast_provider_repo,
+                episode_provider_repo,
+                schedule_provider_repo,
+                recommendation_provider_repo,
+                statistics_provider_repo,
+                news_provider_repo,
+                link_provider_repo,
+                theme_provider_repo,
+                discovery_provider_repo,
             ));
 
 
@@ -201,6 +265,23 @@ pub fn run() {
             let anime_repo_impl = Arc::new(AnimeRepositoryImpl::new(Arc::clone(&database)));
             let anime_repo: Arc<dyn AnimeRepository> = anime_repo_impl.clone();
             let collection_repo: Arc<dyn CollectionRepository> = Arc::new(CollectionRepositoryImpl::new(Arc::clone(&database)));
+            let user_rating_repo: Arc<dyn UserRatingRepository> = Arc::new(UserRatingRepositoryImpl::new(Arc::clone(&database)));
+            let watch_entry_repo: Arc<dyn WatchEntryRepository> = Arc::new(WatchEntryRepositoryImpl::new(Arc::clone(&database)));
+            let character_repo: Arc<dyn CharacterRepository> = Arc::new(CharacterRepositoryImpl::new(Arc::clone(&database)));
+            let episode_repo: Arc<dyn EpisodeRepository> = Arc::new(EpisodeRepositoryImpl::new(Arc::clone(&database)));
+            let schedule_repo: Arc<dyn AiringScheduleRepository> = Arc::new(AiringScheduleRepositoryImpl::new(Arc::clone(&database)));
+            let recommendation_repo: Arc<dyn RecommendationRepository> = Arc::new(RecommendationRepositoryImpl::new(Arc::clone(&database)));
+            let statistics_repo: Arc<dyn StatisticsRepository> = Arc::new(StatisticsRepositoryImpl::new(Arc::clone(&database)));
+            let news_repo: Arc<dyn NewsRepository> = Arc::new(NewsRepositoryImpl::new(Arc::clone(&database)));
+            let link_repo: Arc<dyn AnimeLinkRepository> = Arc::new(AnimeLinkRepositoryImpl::new(Arc::clone(&database)));
+            let theme_repo: Arc<dyn ThemeRepository> = Arc::new(ThemeRepositoryImpl::new(Arc::clone(&database)));
+            let discovery_repo: Arc<dyn DiscoveryRepository> = Arc::new(DiscoveryRepositoryImpl::new(Arc::clone(&database)));
+            let export_repo: Arc<dyn ExportRepository> = Arc::new(ExportRepositoryImpl::new(Arc::clone(&database)));
+            let backup_repo: Arc<dyn BackupRepository> = Arc::new(BackupRepositoryImpl::new(Arc::clone(&database)));
+
+            let anime_query_repo: Arc<dyn AnimeQueryRepository> = Arc::new(
+                AnimeQueryRepositoryImpl::new(Arc::clone(&database), anime_repo_impl.clone())
+            );
 
             // Initialize anime relations repository
             let anime_relations_repo = Arc::new(
@@ -213,20 +294,110 @@ pub fn run() {
             let anime_video_repo: Arc<dyn AnimeVideoRepository> =
                 Arc::new(AnimeVideoRepositoryImpl::new(Arc::clone(&database)));
 
+            // Initialize in-process event bus and forward domain events to the UI
+            let event_bus = Arc::new(InProcessEventBus::new());
+            event_bus.forward_to_frontend::<AnimeCreatedEvent>(app.handle().clone());
+            event_bus.forward_to_frontend::<AnimeScoreUpdatedEvent>(app.handle().clone());
+            event_bus.forward_to_frontend::<RelationsDiscoveredEvent>(app.handle().clone());
+            event_bus.forward_to_frontend::<AnimeEnrichedEvent>(app.handle().clone());
+            let event_publisher: Arc<dyn EventPublisher> = event_bus.clone();
+
             // Initialize core services
             let anime_service = Arc::new(AnimeService::new(
                 Arc::clone(&anime_repo),
                 Arc::clone(&provider_service),
+                Arc::clone(&event_publisher),
+                Arc::new(SnapshotMomentumSource::new(Arc::clone(&statistics_repo))),
             ));
 
+            let browse_library_handler = Arc::new(BrowseLibraryHandler::new(Arc::clone(&anime_query_repo)));
+
             let collection_service = Arc::new(CollectionService::new(
                 Arc::clone(&collection_repo),
                 Arc::clone(&anime_repo),
             ));
 
+            let user_rating_service = Arc::new(UserRatingService::new(
+                Arc::clone(&user_rating_repo),
+                Arc::clone(&anime_repo),
+            ));
+
+            let watch_tracking_service = Arc::new(WatchTrackingService::new(
+                Arc::clone(&watch_entry_repo),
+                Arc::clone(&anime_repo),
+            ));
+
+            let character_service = Arc::new(CharacterService::new(
+                Arc::clone(&character_repo),
+                Arc::clone(&anime_repo),
+                Arc::clone(&anime_query_repo),
+                Arc::clone(&provider_service),
+            ));
+
+            let episode_service = Arc::new(EpisodeService::new(
+                Arc::clone(&episode_repo),
+                Arc::clone(&anime_repo),
+                Arc::clone(&provider_service),
+            ));
+
+            let schedule_service = Arc::new(ScheduleService::new(
+                Arc::clone(&schedule_repo),
+                Arc::clone(&anime_query_repo),
+                Arc::clone(&provider_service),
+            ));
+
+            let recommendation_service = Arc::new(RecommendationService::new(
+                Arc::clone(&recommendation_repo),
+                Arc::clone(&user_rating_repo),
+                Arc::clone(&collection_repo),
+                Arc::clone(&anime_repo),
+                Arc::clone(&provider_service),
+            ));
+
+            let statistics_service = Arc::new(StatisticsService::new(
+                Arc::clone(&statistics_repo),
+                Arc::clone(&anime_repo),
+                Arc::clone(&anime_query_repo),
+                Arc::clone(&provider_service),
+            ));
+
+            let news_service = Arc::new(NewsService::new(
+                Arc::clone(&news_repo),
+                Arc::clone(&anime_repo),
+                Arc::clone(&provider_service),
+            ));
+
+            let link_service = Arc::new(LinkService::new(
+                Arc::clone(&link_repo),
+                Arc::clone(&anime_repo),
+                Arc::clone(&provider_service),
+            ));
+
+            let theme_service = Arc::new(ThemeService::new(
+                Arc::clone(&theme_repo),
+                Arc::clone(&anime_video_repo),
+                Arc::clone(&anime_repo),
+                Arc::clone(&provider_service),
+            ));
+
+            let discovery_service = Arc::new(DiscoveryService::new(
+                Arc::clone(&discovery_repo),
+                Arc::clone(&collection_repo),
+                Arc::clone(&provider_service),
+            ));
+
+            let export_service = Arc::new(ExportService::new(
+                Arc::clone(&export_repo),
+                Arc::clone(&collection_repo),
+                Arc::clone(&watch_entry_repo),
+            ));
+
+            let backup_service = Arc::new(BackupService::new(Arc::clone(&backup_repo)));
+
             let import_service = Arc::new(ImportService::new(
                 Arc::clone(&anime_repo),
                 Arc::clone(&provider_service),
+                Arc::clone(&anime_service),
             ));
 
             // Initialize media services
@@ -242,7 +413,8 @@ pub fn run() {
             ));
 
             // Initialize background jobs system
-            let job_repository = Arc::new(JobRepositoryImpl::new(database.pool().clone()));
+            let job_repository: Arc<dyn JobRepository> =
+                Arc::new(JobRepositoryImpl::new(database.pool().clone()));
 
             // Initialize ingestion service (unified anime creation pipeline)
             let validation_service = Arc::new(ValidationService::new(
@@ -260,6 +432,13 @@ pub fn run() {
                 job_repository.clone(),
             ));
 
+            let list_import_service = Arc::new(ListImportService::new(
+                Arc::clone(&anime_repo),
+                Arc::clone(&watch_entry_repo),
+                Arc::clone(&provider_service),
+                Arc::clone(&ingestion_service),
+            ));
+
             // Initialize progressive relations service (new architecture)
             let relations_cache = Arc::new(RelationsCache::new());
             let anime_relations_service = Arc::new(
@@ -278,6 +457,10 @@ pub fn run() {
                 Arc::clone(&anime_service),
                 Arc::clone(&provider_service),
                 Arc::clone(&anime_relations_service),
+                Arc::clone(&episode_service),
+                Arc::clone(&news_service),
+                Arc::clone(&statistics_service),
+                event_publisher,
             ));
 
             // Start background worker using Tauri's async runtime
@@ -286,7 +469,18 @@ pub fn run() {
             let worker_handle = spawn(async move {
                 worker.run().await;
             });
-            log::info!("Background worker initialized for anime enrichment and relations discovery");
+            log::info!("Background worker initialized for anime enrichment, relations discovery, episode sync and news sync");
+
+            // Wake the worker pool on enqueue instead of waiting for the next poll
+            match PgJobListener::from_env() {
+                Some(listener) => {
+                    let wakeup = background_worker.wakeup_handle();
+                    spawn(async move {
+                        listener.run(wakeup).await;
+                    });
+                }
+                None => log::warn!("Job listener disabled: DATABASE_URL not set, worker will poll"),
+            }
 
             // Store worker handle for graceful shutdown
             app.manage(worker_handle);
@@ -294,20 +488,44 @@ pub fn run() {
 
             // Manage state so commands can access services via `State<T>`
             app.manage(anime_service);
+            app.manage(browse_library_handler);
             app.manage(collection_service);
+            app.manage(user_rating_service);
+            app.manage(watch_tracking_service);
+            app.manage(character_service);
+            app.manage(episode_service);
+            app.manage(schedule_service);
+            app.manage(recommendation_service);
+            app.manage(statistics_service);
+            app.manage(news_service);
+            app.manage(link_service);
+            app.manage(theme_service);
+            app.manage(discovery_service);
+            app.manage(export_service);
+            app.manage(backup_service);
             app.manage(import_service);
+            app.manage(list_import_service);
             app.manage(anime_relations_service);
             app.manage(provider_service);
             app.manage(media_service);
             app.manage(media_sync_service);
             app.manage(job_repository);
+            app.manage(event_bus);
 
             Ok(())
         })
-        .run(tauri::generate_context!())
+        .build(tauri::generate_context!())
         .unwrap_or_else(|e| {
             eprintln!("Failed to run Tauri application: {}", e);
             eprintln!("Application startup failed. Please check system requirements and permissions.");
             std::process::exit(1);
+        })
+        .run(|app_handle, event| {
+            // Let in-flight jobs finish (or return to the queue) before the process exits
+            if let tauri::RunEvent::Exit = event {
+                if let Some(worker) = app_handle.try_state::<Arc<BackgroundWorker>>() {
+                    block_on(worker.shutdown());
+                }
+            }
         });
 }
@@ -373,7 +373,7 @@ impl AnimeIngestionService {
 
         match self
             .validation_service
-            .validate_single_title_enhanced(title)
+            .validate_single_title_enhanced(title, Default::default())
             .await
         {
             EnhancedValidationSingleResult::Found(enhanced) => Ok(enhanced.anime_data),
@@ -0,0 +1,38 @@
+//! Library walks shared by the syncs that follow running shows
+
+use super::ports::{AnimeQueryRepository, AnimeSearchSpecification};
+use crate::modules::anime::{AnimeDetailed, AnimeStatus};
+use crate::shared::application::pagination::PaginationParams;
+use crate::shared::errors::AppResult;
+
+/// Page size used when walking the library
+const LIBRARY_PAGE_SIZE: u32 = 100;
+
+/// Library anime that are airing or haven't started yet
+pub async fn airing_library_anime(
+    anime_query_repo: &dyn AnimeQueryRepository,
+) -> AppResult<Vec<AnimeDetailed>> {
+    let specification = AnimeSearchSpecification {
+        statuses: Some(vec![AnimeStatus::Airing, AnimeStatus::NotYetAired]),
+        ..Default::default()
+    };
+
+    let mut anime = Vec::new();
+    let mut page = 1;
+    loop {
+        let result = anime_query_repo
+            .find_by_criteria(
+                specification.clone(),
+                PaginationParams::new(page, LIBRARY_PAGE_SIZE),
+            )
+            .await?;
+
+        anime.extend(result.items);
+        if page >= result.total_pages {
+            break;
+        }
+        page += 1;
+    }
+
+    Ok(anime)
+}
@@ -1,4 +1,5 @@
 pub mod ingestion_service;
+pub mod library;
 pub mod ports;
 pub mod service;
 pub mod use_cases;
@@ -6,11 +7,14 @@ pub mod use_cases;
 // Re-export commonly used types
 pub use ports::{
     AnimeQueryRepository, AnimeRelationsRepository, AnimeRepository, AnimeSearchSpecification,
-    EventPublisher, ProviderClient,
+    AnimeSortOrder, EventPublisher, EventSubscriber, MomentumSource, ProviderClient,
 };
 
+pub use library::airing_library_anime;
+
 pub use use_cases::{
-    CreateAnimeCommand, CreateAnimeHandler, CreateAnimeResult, DiscoverRelationsCommand,
-    DiscoverRelationsHandler, DiscoverRelationsResult, SearchAnimeHandler, SearchAnimeQuery,
-    SearchAnimeResult, UpdateAnimeScoreCommand, UpdateAnimeScoreHandler, UpdateAnimeScoreResult,
+    BrowseLibraryHandler, BrowseLibraryQuery, BrowseLibraryResult, CreateAnimeCommand,
+    CreateAnimeHandler, CreateAnimeResult, DiscoverRelationsCommand, DiscoverRelationsHandler,
+    DiscoverRelationsResult, SearchAnimeHandler, SearchAnimeQuery, SearchAnimeResult,
+    UpdateAnimeScoreCommand, UpdateAnimeScoreHandler, UpdateAnimeScoreResult,
 };
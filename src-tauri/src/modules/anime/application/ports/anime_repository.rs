@@ -1,10 +1,15 @@
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use specta::Type;
 use uuid::Uuid;
 
-use crate::modules::anime::domain::{AnimeAggregate, AnimeDetailed, AnimeRelation};
+use crate::modules::anime::domain::{
+    value_objects::{AnimeStatus, AnimeTier, AnimeType},
+    AnimeAggregate, AnimeDetailed, AnimeRelation,
+};
 use crate::shared::{
     application::pagination::{PaginatedResult, PaginationParams},
-    domain::value_objects::AnimeProvider,
+    domain::value_objects::{AnimeProvider, StaffRole, UnifiedAgeRestriction},
     errors::AppResult,
 };
 
@@ -79,13 +84,43 @@ pub trait AnimeQueryRepository: Send + Sync {
 }
 
 /// Specification for complex anime searches
-#[derive(Debug, Clone, Default)]
+///
+/// Every field is optional; list filters match any of the given values except
+/// `genres`, which requires all of them.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(default)]
 pub struct AnimeSearchSpecification {
     pub title_contains: Option<String>,
     pub min_score: Option<f32>,
     pub max_score: Option<f32>,
     pub providers: Option<Vec<AnimeProvider>>,
     pub genres: Option<Vec<String>>,
+    pub studios: Option<Vec<String>>,
+    /// Credited to any of these people; narrowed by `staff_roles` when set
+    pub staff_ids: Option<Vec<Uuid>>,
+    /// Staff positions to consider, e.g. `[Director]` for "directed by"
+    pub staff_roles: Option<Vec<StaffRole>>,
+    /// Exact start year; use `year_from`/`year_to` for ranges
     pub year: Option<i32>,
-    pub status: Option<String>,
+    pub year_from: Option<i32>,
+    pub year_to: Option<i32>,
+    pub statuses: Option<Vec<AnimeStatus>>,
+    pub anime_types: Option<Vec<AnimeType>>,
+    pub tiers: Option<Vec<AnimeTier>>,
+    pub age_restrictions: Option<Vec<UnifiedAgeRestriction>>,
+    pub sort: AnimeSortOrder,
+}
+
+/// Ordering for library browsing
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub enum AnimeSortOrder {
+    #[default]
+    CompositeScore,
+    Score,
+    Favorites,
+    Title,
+    AiredNewest,
+    AiredOldest,
+    RecentlyAdded,
+    RecentlyUpdated,
 }
@@ -13,3 +13,10 @@ pub trait EventPublisher: Send + Sync {
     /// Publish multiple domain events
     async fn publish_all(&self, events: Vec<Box<dyn DomainEvent>>) -> AppResult<()>;
 }
+
+/// Port (interface) for reacting to one concrete domain event type
+#[async_trait]
+pub trait EventSubscriber<E: DomainEvent + 'static>: Send + Sync {
+    /// Handle a published event; failures are reported but never undo the publish
+    async fn handle(&self, event: &E) -> AppResult<()>;
+}
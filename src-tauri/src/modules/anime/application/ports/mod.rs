@@ -1,9 +1,12 @@
 pub mod anime_repository;
 pub mod event_publisher;
+pub mod momentum_source;
 pub mod provider_client;
 
 pub use anime_repository::{
     AnimeQueryRepository, AnimeRelationsRepository, AnimeRepository, AnimeSearchSpecification,
+    AnimeSortOrder,
 };
-pub use event_publisher::EventPublisher;
+pub use event_publisher::{EventPublisher, EventSubscriber};
+pub use momentum_source::MomentumSource;
 pub use provider_client::ProviderClient;
@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::modules::anime::domain::value_objects::AudienceMomentum;
+use crate::shared::errors::AppResult;
+
+/// Port (interface) for audience momentum measured from tracked statistics
+/// The statistics module implements this from its snapshot history
+#[async_trait]
+pub trait MomentumSource: Send + Sync {
+    /// Momentum measured for the anime; `None` until its history spans a usable period
+    async fn measured_momentum(&self, anime_id: &Uuid) -> AppResult<Option<AudienceMomentum>>;
+}
@@ -1,12 +1,16 @@
 use super::super::domain::{
-    entities::anime_detailed::AnimeDetailed, repositories::anime_repository::AnimeRepository,
+    entities::anime_detailed::AnimeDetailed,
+    events::{AnimeCreatedEvent, AnimeScoreUpdatedEvent, DomainEvent},
+    repositories::anime_repository::AnimeRepository,
     services::score_calculator::ScoreCalculator,
 };
+use super::ports::{EventPublisher, MomentumSource};
 use crate::modules::provider::ProviderService;
+use crate::modules::season::SeasonPeriod;
 use crate::shared::domain::value_objects::AnimeProvider;
 use crate::shared::errors::AppResult;
 use crate::shared::utils::logger::LogContext;
-use crate::{log_debug, log_info};
+use crate::{log_debug, log_info, log_warn};
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -15,17 +19,23 @@ pub struct AnimeService {
     provider_service: Arc<ProviderService>,
     #[allow(dead_code)]
     score_calculator: Arc<ScoreCalculator>,
+    event_publisher: Arc<dyn EventPublisher>,
+    momentum_source: Arc<dyn MomentumSource>,
 }
 
 impl AnimeService {
     pub fn new(
         anime_repo: Arc<dyn AnimeRepository>,
         provider_service: Arc<ProviderService>,
+        event_publisher: Arc<dyn EventPublisher>,
+        momentum_source: Arc<dyn MomentumSource>,
     ) -> Self {
         Self {
             anime_repo,
             provider_service,
             score_calculator: Arc::new(ScoreCalculator::new()),
+            event_publisher,
+            momentum_source,
         }
     }
 
@@ -99,29 +109,20 @@ impl AnimeService {
         Ok(saved_anime)
     }
 
-    pub async fn get_seasonal_anime(
-        &self,
-        year: i32,
-        season: &str,
-        limit: usize,
-    ) -> AppResult<Vec<AnimeDetailed>> {
-        // Fetch via provider service
-        let query = format!("{} {} anime", season, year);
-        let anime_list = self
-            .provider_service
-            .search_anime_internal(&query, limit)
-            .await?;
+    /// Fetch a season lineup from the providers and persist every title
+    pub async fn get_seasonal_anime(&self, period: SeasonPeriod) -> AppResult<Vec<AnimeDetailed>> {
+        let lineup = self.provider_service.get_season_lineup(period).await?;
 
-        // Save to database (handling duplicates)
-        let mut saved_anime = Vec::new();
-        for anime in anime_list {
+        // Save the full lineup (handling duplicates)
+        let mut saved_anime = Vec::with_capacity(lineup.len());
+        for anime in lineup {
             match self.anime_repo.save(&anime).await {
                 Ok(saved) => saved_anime.push(saved),
                 Err(e) => {
                     // Log but don't fail the entire operation
                     LogContext::error_with_context(
                         &e,
-                        &format!("Failed to save anime {}", anime.title),
+                        &format!("Failed to save seasonal anime {}", anime.title),
                     );
                     // Still include the anime in results even if save failed
                     saved_anime.push(anime);
@@ -129,25 +130,94 @@ impl AnimeService {
             }
         }
 
+        log_info!(
+            "Persisted {} anime for {}",
+            saved_anime.len(),
+            period.label()
+        );
         Ok(saved_anime)
     }
 
     /// Create new anime with proper score calculation
+    ///
+    /// Publishes `AnimeCreatedEvent` once the anime is stored.
     pub async fn create_anime(&self, anime: &AnimeDetailed) -> AppResult<AnimeDetailed> {
         // Calculate scores before saving
         let mut new_anime = anime.clone();
-        new_anime.update_scores(&self.score_calculator);
-
-        self.anime_repo.save(&new_anime).await
+        self.rescore(&mut new_anime).await;
+
+        let saved = self.anime_repo.save(&new_anime).await?;
+
+        let provider = saved.provider_metadata.primary_provider;
+        let external_id = saved
+            .provider_metadata
+            .get_external_id(&provider)
+            .cloned()
+            .unwrap_or_default();
+        self.publish(AnimeCreatedEvent::new(
+            saved.id,
+            saved.title.main.clone(),
+            format!("{:?}", provider),
+            external_id,
+        ))
+        .await;
+
+        Ok(saved)
     }
 
+    /// Recalculate scores and store the anime
+    ///
+    /// Publishes `AnimeScoreUpdatedEvent` when the provider score changed.
     #[allow(dead_code)]
     pub async fn update_anime(&self, anime: &AnimeDetailed) -> AppResult<AnimeDetailed> {
+        let old_score = self
+            .anime_repo
+            .find_by_id(&anime.id)
+            .await?
+            .and_then(|stored| stored.score);
+
         // Recalculate scores before saving
         let mut updated_anime = anime.clone();
-        updated_anime.update_scores(&self.score_calculator);
+        self.rescore(&mut updated_anime).await;
+
+        let saved = self.anime_repo.update(&updated_anime).await?;
+
+        if let Some(new_score) = saved.score.filter(|score| Some(*score) != old_score) {
+            self.publish(AnimeScoreUpdatedEvent::new(saved.id, old_score, new_score))
+                .await;
+        }
+
+        Ok(saved)
+    }
 
-        self.anime_repo.update(&updated_anime).await
+    /// Recalculate scores, using measured momentum once statistics snapshots provide it
+    ///
+    /// Without a measurement, momentum is estimated from favorites.
+    async fn rescore(&self, anime: &mut AnimeDetailed) {
+        match self.momentum_source.measured_momentum(&anime.id).await {
+            Ok(Some(momentum)) => {
+                anime.update_scores_with_momentum(&self.score_calculator, momentum)
+            }
+            Ok(None) => anime.update_scores(&self.score_calculator),
+            Err(e) => {
+                log_warn!(
+                    "Failed to read measured momentum for '{}', estimating it: {}",
+                    anime.title.main,
+                    e
+                );
+                anime.update_scores(&self.score_calculator);
+            }
+        }
+    }
+
+    /// Publish an event for a change that is already stored
+    ///
+    /// The change stands even if nobody hears about it, so failures are only logged.
+    async fn publish(&self, event: impl DomainEvent + 'static) {
+        let event_type = event.event_type();
+        if let Err(e) = self.event_publisher.publish(Box::new(event)).await {
+            log_warn!("Failed to publish {}: {}", event_type, e);
+        }
     }
 
     #[allow(dead_code)]
@@ -0,0 +1,115 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::modules::anime::application::ports::AnimeQueryRepository;
+use crate::shared::{
+    application::use_case::Query,
+    errors::{AppError, AppResult},
+};
+
+use super::{query::BrowseLibraryQuery, result::BrowseLibraryResult};
+
+/// Query handler for filtered, paginated library browsing
+pub struct BrowseLibraryHandler {
+    query_repository: Arc<dyn AnimeQueryRepository>,
+}
+
+impl BrowseLibraryHandler {
+    pub fn new(query_repository: Arc<dyn AnimeQueryRepository>) -> Self {
+        Self { query_repository }
+    }
+}
+
+#[async_trait]
+impl Query<BrowseLibraryQuery, BrowseLibraryResult> for BrowseLibraryHandler {
+    async fn execute(&self, query: BrowseLibraryQuery) -> AppResult<BrowseLibraryResult> {
+        query.pagination.validate()?;
+
+        let spec = &query.specification;
+        if let (Some(min), Some(max)) = (spec.min_score, spec.max_score) {
+            if min > max {
+                return Err(AppError::ValidationError(
+                    "Minimum score cannot exceed maximum score".to_string(),
+                ));
+            }
+        }
+        if let (Some(from), Some(to)) = (spec.year_from, spec.year_to) {
+            if from > to {
+                return Err(AppError::ValidationError(
+                    "Start year cannot be after end year".to_string(),
+                ));
+            }
+        }
+
+        self.query_repository
+            .find_by_criteria(query.specification, query.pagination)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::anime::application::ports::AnimeSearchSpecification;
+    use crate::modules::anime::domain::AnimeDetailed;
+    use crate::shared::application::pagination::{PaginatedResult, PaginationParams};
+
+    struct EmptyQueryRepository;
+
+    #[async_trait]
+    impl AnimeQueryRepository for EmptyQueryRepository {
+        async fn find_by_criteria(
+            &self,
+            _specification: AnimeSearchSpecification,
+            pagination: PaginationParams,
+        ) -> AppResult<PaginatedResult<AnimeDetailed>> {
+            Ok(PaginatedResult::new(Vec::new(), 0, &pagination))
+        }
+
+        async fn count_by_criteria(
+            &self,
+            _specification: AnimeSearchSpecification,
+        ) -> AppResult<u64> {
+            Ok(0)
+        }
+    }
+
+    fn handler() -> BrowseLibraryHandler {
+        BrowseLibraryHandler::new(Arc::new(EmptyQueryRepository))
+    }
+
+    #[tokio::test]
+    async fn test_rejects_page_zero() {
+        let query = BrowseLibraryQuery::new(
+            AnimeSearchSpecification::default(),
+            PaginationParams::new(0, 20),
+        );
+
+        assert!(handler().execute(query).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rejects_inverted_year_range() {
+        let specification = AnimeSearchSpecification {
+            year_from: Some(2020),
+            year_to: Some(2010),
+            ..Default::default()
+        };
+        let query = BrowseLibraryQuery::new(specification, PaginationParams::default());
+
+        assert!(handler().execute(query).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_delegates_to_repository() {
+        let query = BrowseLibraryQuery::new(
+            AnimeSearchSpecification::default(),
+            PaginationParams::new(2, 50),
+        );
+        let result = handler().execute(query).await.unwrap();
+
+        assert_eq!(result.page, 2);
+        assert_eq!(result.page_size, 50);
+        assert_eq!(result.total_count, 0);
+    }
+}
@@ -0,0 +1,7 @@
+mod handler;
+mod query;
+mod result;
+
+pub use handler::BrowseLibraryHandler;
+pub use query::BrowseLibraryQuery;
+pub use result::BrowseLibraryResult;
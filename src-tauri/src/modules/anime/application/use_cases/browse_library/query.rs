@@ -0,0 +1,18 @@
+use crate::modules::anime::application::ports::AnimeSearchSpecification;
+use crate::shared::application::pagination::PaginationParams;
+
+/// Query for browsing the local library with filters and sorting
+#[derive(Debug, Clone)]
+pub struct BrowseLibraryQuery {
+    pub specification: AnimeSearchSpecification,
+    pub pagination: PaginationParams,
+}
+
+impl BrowseLibraryQuery {
+    pub fn new(specification: AnimeSearchSpecification, pagination: PaginationParams) -> Self {
+        Self {
+            specification,
+            pagination,
+        }
+    }
+}
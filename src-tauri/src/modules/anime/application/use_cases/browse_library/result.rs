@@ -0,0 +1,5 @@
+use crate::modules::anime::domain::entities::anime_detailed::AnimeDetailed;
+use crate::shared::application::pagination::PaginatedResult;
+
+/// Result of browsing the library (uses PaginatedResult from shared)
+pub type BrowseLibraryResult = PaginatedResult<AnimeDetailed>;
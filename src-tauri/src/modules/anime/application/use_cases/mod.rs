@@ -1,8 +1,10 @@
+pub mod browse_library;
 pub mod create_anime;
 pub mod discover_relations;
 pub mod search_anime;
 pub mod update_anime_score;
 
+pub use browse_library::{BrowseLibraryHandler, BrowseLibraryQuery, BrowseLibraryResult};
 pub use create_anime::{CreateAnimeCommand, CreateAnimeHandler, CreateAnimeResult};
 pub use discover_relations::{
     DiscoverRelationsCommand, DiscoverRelationsHandler, DiscoverRelationsResult,
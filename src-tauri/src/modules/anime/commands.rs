@@ -1,6 +1,11 @@
+use super::application::ports::AnimeSearchSpecification;
 use super::application::service::AnimeService;
+use super::application::use_cases::{BrowseLibraryHandler, BrowseLibraryQuery};
 use super::domain::entities::anime_detailed::AnimeDetailed;
 use crate::modules::provider::AnimeProvider;
+use crate::modules::season::SeasonPeriod;
+use crate::shared::application::pagination::{PaginatedResult, PaginationParams};
+use crate::shared::application::use_case::Query;
 use serde::{Deserialize, Serialize};
 use specta::Type;
 use std::sync::Arc;
@@ -12,6 +17,13 @@ pub struct SearchAnimeRequest {
     pub query: String,
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(default)]
+pub struct BrowseLibraryRequest {
+    pub specification: AnimeSearchSpecification,
+    pub pagination: PaginationParams,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 pub struct GetAnimeByIdRequest {
     pub id: String,
@@ -35,9 +47,7 @@ pub struct GetTopAnimeRequest {
 
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 pub struct GetSeasonalAnimeRequest {
-    pub year: i32,
-    pub season: String,
-    pub page: i32,
+    pub period: SeasonPeriod,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
@@ -75,6 +85,21 @@ pub async fn search_anime(
         .map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub async fn browse_library(
+    request: BrowseLibraryRequest,
+    handler: State<'_, Arc<BrowseLibraryHandler>>,
+) -> Result<PaginatedResult<AnimeDetailed>, String> {
+    handler
+        .execute(BrowseLibraryQuery::new(
+            request.specification,
+            request.pagination,
+        ))
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn get_anime_by_id(
@@ -129,7 +154,7 @@ pub async fn get_seasonal_anime(
     anime_service: State<'_, Arc<AnimeService>>,
 ) -> Result<Vec<AnimeDetailed>, String> {
     anime_service
-        .get_seasonal_anime(request.year, &request.season, request.page as usize)
+        .get_seasonal_anime(request.period)
         .await
         .map_err(|e| e.to_string())
 }
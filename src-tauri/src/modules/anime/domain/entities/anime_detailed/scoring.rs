@@ -2,6 +2,7 @@ use chrono::Utc;
 
 use super::AnimeDetailed;
 use crate::modules::anime::domain::traits::Scoreable;
+use crate::modules::anime::domain::value_objects::AudienceMomentum;
 
 // Scoring and quality methods for AnimeDetailed
 impl AnimeDetailed {
@@ -15,6 +16,61 @@ impl AnimeDetailed {
         self.tier = calculator.determine_tier(self.composite_score);
         self.updated_at = Utc::now();
     }
+
+    /// Update scores with momentum measured from statistics snapshots instead of estimated
+    pub fn update_scores_with_momentum(
+        &mut self,
+        calculator: &crate::modules::anime::domain::services::score_calculator::ScoreCalculator,
+        momentum: AudienceMomentum,
+    ) {
+        let measured = MeasuredAnime {
+            anime: self,
+            momentum,
+        };
+        let composite_score = calculator.calculate_composite_score(&measured);
+        let quality_metrics = calculator.calculate_quality_metrics(&measured);
+
+        self.composite_score = composite_score;
+        self.quality_metrics = quality_metrics;
+        self.tier = calculator.determine_tier(composite_score);
+        self.updated_at = Utc::now();
+    }
+}
+
+/// Scores an anime as usual but with measured audience momentum
+struct MeasuredAnime<'a> {
+    anime: &'a AnimeDetailed,
+    momentum: AudienceMomentum,
+}
+
+impl Scoreable for MeasuredAnime<'_> {
+    fn score(&self) -> Option<f32> {
+        self.anime.score()
+    }
+
+    fn scored_by(&self) -> Option<i32> {
+        self.anime.scored_by()
+    }
+
+    fn popularity(&self) -> Option<i32> {
+        self.anime.popularity()
+    }
+
+    fn members(&self) -> Option<i32> {
+        self.anime.members()
+    }
+
+    fn favorites(&self) -> Option<i32> {
+        self.anime.favorites()
+    }
+
+    fn aired_from(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.anime.aired_from()
+    }
+
+    fn audience_momentum(&self) -> Option<AudienceMomentum> {
+        Some(self.momentum)
+    }
 }
 
 // Implement Scoreable trait for AnimeDetailed
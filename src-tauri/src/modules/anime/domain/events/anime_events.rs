@@ -8,6 +8,8 @@
 /// - Auditing
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::any::Any;
 use uuid::Uuid;
 
 /// Base trait for all domain events
@@ -20,10 +22,13 @@ pub trait DomainEvent: Send + Sync {
 
     /// Type of event (for serialization/routing)
     fn event_type(&self) -> &'static str;
+
+    /// Concrete event, used by typed subscribers to downcast
+    fn as_any(&self) -> &dyn Any;
 }
 
 /// Anime was created in the system
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type, tauri_specta::Event)]
 pub struct AnimeCreatedEvent {
     pub event_id: Uuid,
     pub occurred_at: DateTime<Utc>,
@@ -58,10 +63,14 @@ impl DomainEvent for AnimeCreatedEvent {
     fn event_type(&self) -> &'static str {
         "AnimeCreated"
     }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
 }
 
 /// Anime score was updated
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type, tauri_specta::Event)]
 pub struct AnimeScoreUpdatedEvent {
     pub event_id: Uuid,
     pub occurred_at: DateTime<Utc>,
@@ -94,14 +103,19 @@ impl DomainEvent for AnimeScoreUpdatedEvent {
     fn event_type(&self) -> &'static str {
         "AnimeScoreUpdated"
     }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
 }
 
 /// Relations were discovered for an anime
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type, tauri_specta::Event)]
 pub struct RelationsDiscoveredEvent {
     pub event_id: Uuid,
     pub occurred_at: DateTime<Utc>,
     pub anime_id: Uuid,
+    #[specta(type = u32)]
     pub relations_count: usize,
     pub source: String, // "AniList", "Jikan", etc.
 }
@@ -130,10 +144,14 @@ impl DomainEvent for RelationsDiscoveredEvent {
     fn event_type(&self) -> &'static str {
         "RelationsDiscovered"
     }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
 }
 
 /// Anime was enriched with additional data
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type, tauri_specta::Event)]
 pub struct AnimeEnrichedEvent {
     pub event_id: Uuid,
     pub occurred_at: DateTime<Utc>,
@@ -166,4 +184,8 @@ impl DomainEvent for AnimeEnrichedEvent {
     fn event_type(&self) -> &'static str {
         "AnimeEnriched"
     }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
 }
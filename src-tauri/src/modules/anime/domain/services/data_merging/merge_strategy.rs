@@ -80,6 +80,23 @@ impl DefaultMergeStrategy {
         all_providers.extend(context.sources.iter().map(|s| s.source.primary_provider));
         merged.source.providers_used = all_providers;
 
+        // Keep every provider's id and page, not only the base's, so the merged
+        // anime can still be matched against each provider it came from
+        let metadata = &mut merged.anime.provider_metadata;
+        for source in &context.sources {
+            let source_metadata = &source.anime.provider_metadata;
+            for (provider, id) in &source_metadata.external_ids {
+                if !metadata.has_provider(provider) {
+                    metadata.add_external_id(*provider, id.clone());
+                }
+            }
+            for (provider, url) in &source_metadata.provider_urls {
+                if metadata.get_provider_url(provider).is_none() {
+                    metadata.add_provider_url(*provider, url.clone());
+                }
+            }
+        }
+
         // Calculate merged confidence
         let total_sources = 1 + context.sources.len();
         let avg_quality = (context.base.quality.score
@@ -1,5 +1,7 @@
 use super::super::traits::Scoreable;
-use super::super::value_objects::{anime_tier::AnimeTier, quality_metrics::QualityMetrics};
+use super::super::value_objects::{
+    anime_tier::AnimeTier, quality_metrics::QualityMetrics, AudienceMomentum,
+};
 
 #[derive(Debug, Clone)]
 pub struct ScoreCalculator {
@@ -23,6 +25,7 @@ struct NormalizationContext {
     global_rating_mean: f32,
     vote_prior: f32,
     max_favorites_per_day: f32,
+    max_members_per_day: f32,
     recency_half_life_days: f32,
     vote_reliability_threshold: f32,
 }
@@ -43,6 +46,7 @@ impl Default for ScoreCalculator {
                 global_rating_mean: 7.1,
                 vote_prior: 10_000.0,
                 max_favorites_per_day: 5_000.0,
+                max_members_per_day: 20_000.0,
                 recency_half_life_days: 90.0,
                 vote_reliability_threshold: 25_000.0,
             },
@@ -234,13 +238,18 @@ impl ScoreCalculator {
             return None;
         }
 
+        let score = anime.score().unwrap_or(5.0);
+        let quality_factor = score / 10.0;
+
+        if let Some(momentum) = anime.audience_momentum() {
+            return Some(self.measured_momentum(&momentum, quality_factor));
+        }
+
         let days = days.max(1.0);
         let f = anime.favorites().unwrap_or(0) as f32;
-        let score = anime.score().unwrap_or(5.0);
 
-        // Calculate momentum based on favorites per day and quality
+        // Estimate momentum from favorites per day and quality
         let favorites_per_day = f / days;
-        let quality_factor = score / 10.0;
 
         let fr = self.normalize_log(favorites_per_day, self.context.max_favorites_per_day);
 
@@ -249,6 +258,18 @@ impl ScoreCalculator {
         Some(self.clamp(momentum * 10.0, 0.0, 10.0))
     }
 
+    /// Momentum from snapshot deltas: list growth, held back by drops and falling scores
+    fn measured_momentum(&self, momentum: &AudienceMomentum, quality_factor: f32) -> f32 {
+        // A shrinking list contributes no growth at all
+        let growth = self.normalize_log(momentum.members_per_day, self.context.max_members_per_day);
+        let retention = 1.0 - self.clamp(momentum.drop_rate, 0.0, 1.0);
+        // Half a point of mean score change either way saturates
+        let reception = self.clamp(0.5 + momentum.score_change, 0.0, 1.0);
+
+        let momentum = 0.5 * growth + 0.2 * retention + 0.15 * reception + 0.15 * quality_factor;
+        self.clamp(momentum * 10.0, 0.0, 10.0)
+    }
+
     fn momentum_weight(&self, anime: &dyn Scoreable) -> f32 {
         let from = match anime.aired_from() {
             Some(date) => date,
@@ -294,3 +315,74 @@ impl ScoreCalculator {
         x.max(min).min(max)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Duration, Utc};
+
+    struct AiringAnime {
+        momentum: Option<AudienceMomentum>,
+    }
+
+    impl Scoreable for AiringAnime {
+        fn score(&self) -> Option<f32> {
+            Some(7.5)
+        }
+        fn scored_by(&self) -> Option<i32> {
+            Some(200)
+        }
+        fn popularity(&self) -> Option<i32> {
+            Some(200)
+        }
+        fn members(&self) -> Option<i32> {
+            Some(200)
+        }
+        fn favorites(&self) -> Option<i32> {
+            Some(200)
+        }
+        fn aired_from(&self) -> Option<DateTime<Utc>> {
+            Some(Utc::now() - Duration::days(30))
+        }
+        fn audience_momentum(&self) -> Option<AudienceMomentum> {
+            self.momentum
+        }
+    }
+
+    #[test]
+    fn test_measured_momentum_separates_gaining_and_bleeding_shows() {
+        let calculator = ScoreCalculator::new();
+        let gaining = AiringAnime {
+            momentum: Some(AudienceMomentum {
+                members_per_day: 5_000.0,
+                drop_rate: 0.02,
+                score_change: 0.1,
+            }),
+        };
+        let bleeding = AiringAnime {
+            momentum: Some(AudienceMomentum {
+                members_per_day: -300.0,
+                drop_rate: 0.6,
+                score_change: -0.3,
+            }),
+        };
+
+        let gaining_momentum = calculator.momentum_score(&gaining).unwrap();
+        let bleeding_momentum = calculator.momentum_score(&bleeding).unwrap();
+
+        assert!(gaining_momentum > 7.0);
+        assert!(bleeding_momentum < 3.0);
+        assert!(
+            calculator.calculate_composite_score(&gaining)
+                > calculator.calculate_composite_score(&bleeding)
+        );
+    }
+
+    #[test]
+    fn test_momentum_is_estimated_without_snapshots() {
+        let calculator = ScoreCalculator::new();
+        let anime = AiringAnime { momentum: None };
+
+        assert!(calculator.momentum_score(&anime).is_some());
+    }
+}
@@ -1,5 +1,7 @@
 use chrono::{DateTime, Utc};
 
+use crate::modules::anime::domain::value_objects::AudienceMomentum;
+
 /// Trait for entities that can be scored by the ScoreCalculator
 pub trait Scoreable {
     fn score(&self) -> Option<f32>;
@@ -8,4 +10,9 @@ pub trait Scoreable {
     fn members(&self) -> Option<i32>;
     fn favorites(&self) -> Option<i32>;
     fn aired_from(&self) -> Option<DateTime<Utc>>;
+
+    /// Measured audience movement; the momentum term is estimated when absent
+    fn audience_momentum(&self) -> Option<AudienceMomentum> {
+        None
+    }
 }
@@ -0,0 +1,13 @@
+/// Audience movement measured between two provider statistics snapshots
+///
+/// Fed to `ScoreCalculator` in place of the favorites-per-day estimate once an
+/// anime has been snapshotted at least twice.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AudienceMomentum {
+    /// Net list entries added per day, across every list status
+    pub members_per_day: f32,
+    /// Share of the watching audience that dropped the show over the period, 0.0-1.0
+    pub drop_rate: f32,
+    /// Change of the mean user score over the same period
+    pub score_change: f32,
+}
@@ -5,6 +5,7 @@ pub mod anime_status;
 pub mod anime_tier;
 pub mod anime_title;
 pub mod anime_type;
+pub mod audience_momentum;
 pub mod quality_metrics;
 
 pub use anime_relation_type::AnimeRelationType;
@@ -12,4 +13,5 @@ pub use anime_status::AnimeStatus;
 pub use anime_tier::AnimeTier;
 pub use anime_title::AnimeTitle;
 pub use anime_type::AnimeType;
+pub use audience_momentum::AudienceMomentum;
 pub use quality_metrics::QualityMetrics;
@@ -0,0 +1,220 @@
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use serde::Serialize;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::{Arc, RwLock};
+use tauri::AppHandle;
+
+use crate::modules::anime::application::ports::{EventPublisher, EventSubscriber};
+use crate::modules::anime::domain::events::DomainEvent;
+use crate::shared::errors::{AppError, AppResult};
+
+type ErasedSubscriber =
+    Arc<dyn Fn(Arc<dyn DomainEvent>) -> BoxFuture<'static, AppResult<()>> + Send + Sync>;
+
+/// In-process event bus dispatching published domain events to typed subscribers
+///
+/// Subscribers run sequentially in registration order. A failing subscriber is
+/// logged and skipped so one broken listener never fails the publishing use case.
+#[derive(Default)]
+pub struct InProcessEventBus {
+    subscribers: RwLock<HashMap<TypeId, Vec<ErasedSubscriber>>>,
+}
+
+impl InProcessEventBus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a subscriber for events of type `E`
+    pub fn subscribe<E>(&self, subscriber: Arc<dyn EventSubscriber<E>>)
+    where
+        E: DomainEvent + 'static,
+    {
+        let erased: ErasedSubscriber = Arc::new(move |event: Arc<dyn DomainEvent>| {
+            let subscriber = Arc::clone(&subscriber);
+            Box::pin(async move {
+                match event.as_any().downcast_ref::<E>() {
+                    Some(typed) => subscriber.handle(typed).await,
+                    None => Ok(()),
+                }
+            })
+        });
+
+        self.subscribers
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .entry(TypeId::of::<E>())
+            .or_default()
+            .push(erased);
+    }
+
+    /// Emit every `E` to the frontend as a typed specta event
+    pub fn forward_to_frontend<E>(&self, app: AppHandle)
+    where
+        E: DomainEvent + tauri_specta::Event + Serialize + Clone + 'static,
+    {
+        self.subscribe::<E>(Arc::new(FrontendForwarder {
+            app,
+            _event: PhantomData,
+        }));
+    }
+
+    /// Number of subscribers registered for events of type `E`
+    pub fn subscriber_count<E: 'static>(&self) -> usize {
+        self.subscribers
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(&TypeId::of::<E>())
+            .map_or(0, Vec::len)
+    }
+
+    fn subscribers_for(&self, event: &dyn DomainEvent) -> Vec<ErasedSubscriber> {
+        self.subscribers
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(&Any::type_id(event.as_any()))
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl EventPublisher for InProcessEventBus {
+    async fn publish(&self, event: Box<dyn DomainEvent>) -> AppResult<()> {
+        let event: Arc<dyn DomainEvent> = Arc::from(event);
+        let subscribers = self.subscribers_for(event.as_ref());
+
+        log::debug!(
+            "Publishing {} event {} to {} subscriber(s)",
+            event.event_type(),
+            event.event_id(),
+            subscribers.len()
+        );
+
+        for subscriber in subscribers {
+            if let Err(e) = subscriber(Arc::clone(&event)).await {
+                log::warn!(
+                    "Subscriber failed to handle {} event {}: {}",
+                    event.event_type(),
+                    event.event_id(),
+                    e
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn publish_all(&self, events: Vec<Box<dyn DomainEvent>>) -> AppResult<()> {
+        for event in events {
+            self.publish(event).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Subscriber that re-emits domain events to the webview
+struct FrontendForwarder<E> {
+    app: AppHandle,
+    _event: PhantomData<fn(E)>,
+}
+
+#[async_trait]
+impl<E> EventSubscriber<E> for FrontendForwarder<E>
+where
+    E: DomainEvent + tauri_specta::Event + Serialize + Clone + 'static,
+{
+    async fn handle(&self, event: &E) -> AppResult<()> {
+        tauri_specta::Event::emit(event, &self.app).map_err(|e| {
+            AppError::InternalError(format!(
+                "Failed to emit {} to frontend: {}",
+                event.event_type(),
+                e
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::anime::domain::events::{AnimeEnrichedEvent, AnimeScoreUpdatedEvent};
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    #[derive(Default)]
+    struct RecordingSubscriber {
+        seen: Mutex<Vec<Uuid>>,
+    }
+
+    #[async_trait]
+    impl EventSubscriber<AnimeEnrichedEvent> for RecordingSubscriber {
+        async fn handle(&self, event: &AnimeEnrichedEvent) -> AppResult<()> {
+            self.seen.lock().unwrap().push(event.anime_id);
+            Ok(())
+        }
+    }
+
+    struct FailingSubscriber;
+
+    #[async_trait]
+    impl EventSubscriber<AnimeEnrichedEvent> for FailingSubscriber {
+        async fn handle(&self, _event: &AnimeEnrichedEvent) -> AppResult<()> {
+            Err(AppError::InternalError("boom".to_string()))
+        }
+    }
+
+    fn enriched(anime_id: Uuid) -> Box<dyn DomainEvent> {
+        Box::new(AnimeEnrichedEvent::new(
+            anime_id,
+            "AniList".to_string(),
+            vec!["synopsis".to_string()],
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_subscriber_receives_only_its_event_type() {
+        let bus = InProcessEventBus::new();
+        let subscriber = Arc::new(RecordingSubscriber::default());
+        bus.subscribe::<AnimeEnrichedEvent>(subscriber.clone());
+
+        let anime_id = Uuid::new_v4();
+        bus.publish(enriched(anime_id)).await.unwrap();
+        bus.publish(Box::new(AnimeScoreUpdatedEvent::new(anime_id, None, 8.0)))
+            .await
+            .unwrap();
+
+        assert_eq!(*subscriber.seen.lock().unwrap(), vec![anime_id]);
+        assert_eq!(bus.subscriber_count::<AnimeEnrichedEvent>(), 1);
+        assert_eq!(bus.subscriber_count::<AnimeScoreUpdatedEvent>(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_failing_subscriber_does_not_block_others() {
+        let bus = InProcessEventBus::new();
+        let subscriber = Arc::new(RecordingSubscriber::default());
+        bus.subscribe::<AnimeEnrichedEvent>(Arc::new(FailingSubscriber));
+        bus.subscribe::<AnimeEnrichedEvent>(subscriber.clone());
+
+        let anime_id = Uuid::new_v4();
+        assert!(bus.publish(enriched(anime_id)).await.is_ok());
+        assert_eq!(subscriber.seen.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_publish_all_preserves_order() {
+        let bus = InProcessEventBus::new();
+        let subscriber = Arc::new(RecordingSubscriber::default());
+        bus.subscribe::<AnimeEnrichedEvent>(subscriber.clone());
+
+        let ids = vec![Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+        bus.publish_all(ids.iter().map(|id| enriched(*id)).collect())
+            .await
+            .unwrap();
+
+        assert_eq!(*subscriber.seen.lock().unwrap(), ids);
+    }
+}
@@ -7,5 +7,5 @@ pub mod mapper;
 // Re-export repository implementations
 pub use repositories::{
     inverse_relation_type, AnimeQueryRepositoryImpl, AnimeRelationsRepositoryImpl,
-    AnimeRepositoryImpl, AnimeSearchSpecification,
+    AnimeRepositoryImpl,
 };
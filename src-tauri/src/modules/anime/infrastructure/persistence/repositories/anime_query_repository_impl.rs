@@ -1,29 +1,24 @@
+use async_trait::async_trait;
+use diesel::dsl::sql;
+use diesel::pg::Pg;
 use diesel::prelude::*;
+use diesel::sql_types::{Bool, Integer};
 use std::sync::Arc;
 use tokio::task;
 
+use crate::modules::anime::application::ports::{
+    AnimeQueryRepository, AnimeSearchSpecification, AnimeSortOrder,
+};
 use crate::modules::anime::domain::entities::anime_detailed::AnimeDetailed;
 use crate::modules::anime::infrastructure::models::Anime;
 use crate::schema::anime;
-use crate::shared::domain::value_objects::AnimeProvider;
+use crate::shared::application::pagination::{PaginatedResult, PaginationParams};
 use crate::shared::errors::{AppError, AppResult};
 use crate::shared::utils::Validator;
 use crate::shared::Database;
 
 use super::anime_repository_impl::AnimeRepositoryImpl;
 
-/// Specification for complex anime searches (following Specification Pattern)
-#[derive(Debug, Clone, Default)]
-pub struct AnimeSearchSpecification {
-    pub title_contains: Option<String>,
-    pub min_score: Option<f32>,
-    pub max_score: Option<f32>,
-    pub providers: Option<Vec<AnimeProvider>>,
-    pub genres: Option<Vec<String>>,
-    pub year: Option<i32>,
-    pub status: Option<String>,
-}
-
 pub struct AnimeQueryRepositoryImpl {
     db: Arc<Database>,
     anime_repository: Arc<AnimeRepositoryImpl>,
@@ -37,139 +32,146 @@ impl AnimeQueryRepositoryImpl {
         }
     }
 
-    /// Find anime by multiple criteria (Specification Pattern)
-    pub async fn find_by_criteria(
-        &self,
-        specification: AnimeSearchSpecification,
-        offset: i64,
-        limit: i64,
-    ) -> AppResult<Vec<AnimeDetailed>> {
-        Validator::validate_pagination(offset, limit)?;
-
-        let db = Arc::clone(&self.db);
-
-        let models = task::spawn_blocking(move || -> AppResult<Vec<Anime>> {
-            let mut conn = db.get_connection()?;
-
-            // Start with base query
-            let mut query = anime::table.into_boxed();
+    /// Build the filtered base query for a specification (shared by find and count)
+    fn filtered_query(specification: &AnimeSearchSpecification) -> anime::BoxedQuery<'static, Pg> {
+        let mut query = anime::table.into_boxed();
+
+        // Apply title filter
+        if let Some(title) = &specification.title_contains {
+            let pattern = format!("%{}%", title.to_lowercase());
+            query = query.filter(
+                anime::title_main
+                    .ilike(pattern.clone())
+                    .or(anime::title_english.ilike(pattern.clone()))
+                    .or(anime::title_japanese.ilike(pattern.clone()))
+                    .or(anime::title_romaji.ilike(pattern)),
+            );
+        }
 
-            // Apply title filter
-            if let Some(title) = specification.title_contains {
-                let pattern = format!("%{}%", title.to_lowercase());
-                query = query.filter(
-                    anime::title_main
-                        .ilike(pattern.clone())
-                        .or(anime::title_english.ilike(pattern.clone()))
-                        .or(anime::title_japanese.ilike(pattern.clone()))
-                        .or(anime::title_romaji.ilike(pattern)),
-                );
-            }
+        // Apply score filters
+        if let Some(min_score) = specification.min_score {
+            query = query.filter(anime::score.ge(min_score));
+        }
+        if let Some(max_score) = specification.max_score {
+            query = query.filter(anime::score.le(max_score));
+        }
 
-            // Apply score filters
-            if let Some(min_score) = specification.min_score {
-                query = query.filter(anime::score.ge(min_score));
-            }
-            if let Some(max_score) = specification.max_score {
-                query = query.filter(anime::score.le(max_score));
-            }
+        // Apply enum filters (match any of the given values)
+        if let Some(statuses) = specification.statuses.clone().filter(|v| !v.is_empty()) {
+            query = query.filter(anime::status.eq_any(statuses));
+        }
+        if let Some(types) = specification.anime_types.clone().filter(|v| !v.is_empty()) {
+            query = query.filter(anime::anime_type.eq_any(types));
+        }
+        if let Some(tiers) = specification.tiers.clone().filter(|v| !v.is_empty()) {
+            query = query.filter(anime::tier.eq_any(tiers));
+        }
+        if let Some(restrictions) = specification
+            .age_restrictions
+            .clone()
+            .filter(|v| !v.is_empty())
+        {
+            query = query.filter(anime::age_restriction.eq_any(restrictions));
+        }
 
-            // Apply status filter
-            if let Some(status) = specification.status {
-                use crate::modules::anime::domain::value_objects::AnimeStatus;
-                // Parse string to AnimeStatus enum
-                if let Ok(status_enum) = status.parse::<AnimeStatus>() {
-                    query = query.filter(anime::status.eq(status_enum));
-                }
-            }
+        // Apply year filters (based on aired_from)
+        if let Some(year) = specification.year {
+            query = query
+                .filter(sql::<Bool>("EXTRACT(YEAR FROM aired_from) = ").bind::<Integer, _>(year));
+        }
+        if let Some(year_from) = specification.year_from {
+            query = query.filter(
+                sql::<Bool>("EXTRACT(YEAR FROM aired_from) >= ").bind::<Integer, _>(year_from),
+            );
+        }
+        if let Some(year_to) = specification.year_to {
+            query = query.filter(
+                sql::<Bool>("EXTRACT(YEAR FROM aired_from) <= ").bind::<Integer, _>(year_to),
+            );
+        }
 
-            // Apply year filter (based on aired_from)
-            if let Some(year) = specification.year {
-                use diesel::dsl::sql;
-                use diesel::sql_types::Integer;
+        // Every requested genre must be present
+        if let Some(genre_names) = &specification.genres {
+            use crate::schema::{anime_genres, genres};
 
+            for genre in genre_names {
                 query = query.filter(
-                    sql::<diesel::sql_types::Bool>("EXTRACT(YEAR FROM aired_from) = ")
-                        .bind::<Integer, _>(year),
+                    anime::id.eq_any(
+                        anime_genres::table
+                            .inner_join(genres::table)
+                            .filter(genres::name.eq(genre.clone()))
+                            .select(anime_genres::anime_id),
+                    ),
                 );
             }
+        }
 
-            // Execute query with pagination
-            let rows = query
-                .offset(offset)
-                .limit(limit)
-                .order(anime::composite_score.desc())
-                .load::<Anime>(&mut conn)?;
-
-            Ok(rows)
-        })
-        .await??;
-
-        // Load full anime details with relations
-        self.anime_repository
-            .load_anime_batch_with_relations(models)
-            .await
-    }
-
-    /// Count anime matching criteria
-    pub async fn count_by_criteria(
-        &self,
-        specification: AnimeSearchSpecification,
-    ) -> AppResult<u64> {
-        let db = Arc::clone(&self.db);
-
-        task::spawn_blocking(move || -> AppResult<u64> {
-            let mut conn = db.get_connection()?;
-
-            // Start with base query
-            let mut query = anime::table.into_boxed();
+        // Any of the requested studios
+        if let Some(studio_names) = specification.studios.clone().filter(|v| !v.is_empty()) {
+            use crate::schema::{anime_studios, studios};
 
-            // Apply title filter
-            if let Some(title) = specification.title_contains {
-                let pattern = format!("%{}%", title.to_lowercase());
-                query = query.filter(
-                    anime::title_main
-                        .ilike(pattern.clone())
-                        .or(anime::title_english.ilike(pattern.clone()))
-                        .or(anime::title_japanese.ilike(pattern.clone()))
-                        .or(anime::title_romaji.ilike(pattern)),
-                );
-            }
+            query = query.filter(
+                anime::id.eq_any(
+                    anime_studios::table
+                        .inner_join(studios::table)
+                        .filter(studios::name.eq_any(studio_names))
+                        .select(anime_studios::anime_id),
+                ),
+            );
+        }
 
-            // Apply score filters
-            if let Some(min_score) = specification.min_score {
-                query = query.filter(anime::score.ge(min_score));
-            }
-            if let Some(max_score) = specification.max_score {
-                query = query.filter(anime::score.le(max_score));
+        // Credited to any of the requested people, in any of the requested positions
+        let staff_ids = specification.staff_ids.clone().filter(|v| !v.is_empty());
+        let staff_roles = specification.staff_roles.clone().filter(|v| !v.is_empty());
+        if staff_ids.is_some() || staff_roles.is_some() {
+            use crate::schema::anime_staff;
+
+            let mut credits = anime_staff::table
+                .select(anime_staff::anime_id)
+                .into_boxed();
+            if let Some(person_ids) = staff_ids {
+                credits = credits.filter(anime_staff::person_id.eq_any(person_ids));
             }
-
-            // Apply status filter
-            if let Some(status) = specification.status {
-                use crate::modules::anime::domain::value_objects::AnimeStatus;
-                // Parse string to AnimeStatus enum
-                if let Ok(status_enum) = status.parse::<AnimeStatus>() {
-                    query = query.filter(anime::status.eq(status_enum));
-                }
+            if let Some(roles) = staff_roles {
+                credits = credits.filter(anime_staff::role.eq_any(roles));
             }
 
-            // Apply year filter (based on aired_from)
-            if let Some(year) = specification.year {
-                use diesel::dsl::sql;
-                use diesel::sql_types::Integer;
+            query = query.filter(anime::id.eq_any(credits));
+        }
 
-                query = query.filter(
-                    sql::<diesel::sql_types::Bool>("EXTRACT(YEAR FROM aired_from) = ")
-                        .bind::<Integer, _>(year),
-                );
-            }
+        // Known to any of the requested providers
+        if let Some(providers) = specification.providers.as_ref().filter(|v| !v.is_empty()) {
+            use crate::schema::anime_external_ids;
+
+            let codes: Vec<String> = providers.iter().map(|p| p.to_string()).collect();
+            query = query.filter(
+                anime::id.eq_any(
+                    anime_external_ids::table
+                        .filter(anime_external_ids::provider_code.eq_any(codes))
+                        .select(anime_external_ids::anime_id),
+                ),
+            );
+        }
 
-            // Count results
-            let count = query.count().get_result::<i64>(&mut conn)?;
+        query
+    }
 
-            Ok(count as u64)
-        })
-        .await?
+    /// Apply the requested ordering, with the id as a tie-breaker for stable pages
+    fn apply_sort(
+        query: anime::BoxedQuery<'static, Pg>,
+        sort: AnimeSortOrder,
+    ) -> anime::BoxedQuery<'static, Pg> {
+        let query = match sort {
+            AnimeSortOrder::CompositeScore => query.order(anime::composite_score.desc()),
+            AnimeSortOrder::Score => query.order(anime::score.desc().nulls_last()),
+            AnimeSortOrder::Favorites => query.order(anime::favorites.desc().nulls_last()),
+            AnimeSortOrder::Title => query.order(anime::title_main.asc()),
+            AnimeSortOrder::AiredNewest => query.order(anime::aired_from.desc().nulls_last()),
+            AnimeSortOrder::AiredOldest => query.order(anime::aired_from.asc().nulls_last()),
+            AnimeSortOrder::RecentlyAdded => query.order(anime::created_at.desc()),
+            AnimeSortOrder::RecentlyUpdated => query.order(anime::updated_at.desc()),
+        };
+        query.then_order_by(anime::id.asc())
     }
 
     /// Find anime by title variations (exact and fuzzy matching)
@@ -429,3 +431,60 @@ impl AnimeQueryRepositoryImpl {
             .await
     }
 }
+
+#[async_trait]
+impl AnimeQueryRepository for AnimeQueryRepositoryImpl {
+    async fn find_by_criteria(
+        &self,
+        specification: AnimeSearchSpecification,
+        pagination: PaginationParams,
+    ) -> AppResult<PaginatedResult<AnimeDetailed>> {
+        pagination.validate()?;
+        let offset = pagination.offset();
+        let limit = pagination.limit();
+        Validator::validate_pagination(offset, limit)?;
+
+        let db = Arc::clone(&self.db);
+
+        let (models, total_count) =
+            task::spawn_blocking(move || -> AppResult<(Vec<Anime>, i64)> {
+                let mut conn = db.get_connection()?;
+
+                let total_count = Self::filtered_query(&specification)
+                    .count()
+                    .get_result::<i64>(&mut conn)?;
+
+                let rows =
+                    Self::apply_sort(Self::filtered_query(&specification), specification.sort)
+                        .offset(offset)
+                        .limit(limit)
+                        .load::<Anime>(&mut conn)?;
+
+                Ok((rows, total_count))
+            })
+            .await??;
+
+        // Load full anime details with relations
+        let items = self
+            .anime_repository
+            .load_anime_batch_with_relations(models)
+            .await?;
+
+        Ok(PaginatedResult::new(items, total_count as u64, &pagination))
+    }
+
+    async fn count_by_criteria(&self, specification: AnimeSearchSpecification) -> AppResult<u64> {
+        let db = Arc::clone(&self.db);
+
+        task::spawn_blocking(move || -> AppResult<u64> {
+            let mut conn = db.get_connection()?;
+
+            let count = Self::filtered_query(&specification)
+                .count()
+                .get_result::<i64>(&mut conn)?;
+
+            Ok(count as u64)
+        })
+        .await?
+    }
+}
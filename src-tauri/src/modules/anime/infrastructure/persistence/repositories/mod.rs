@@ -22,6 +22,6 @@ pub mod anime_relations_repository_impl;
 pub mod anime_repository_impl;
 
 // Re-export the implementations for convenience
-pub use anime_query_repository_impl::{AnimeQueryRepositoryImpl, AnimeSearchSpecification};
+pub use anime_query_repository_impl::AnimeQueryRepositoryImpl;
 pub use anime_relations_repository_impl::{inverse_relation_type, AnimeRelationsRepositoryImpl};
 pub use anime_repository_impl::AnimeRepositoryImpl;
@@ -16,12 +16,15 @@ pub use domain::{AnimeAggregate, AnimeDetailed, AnimeRepository};
 pub use domain::value_objects::{AnimeStatus, AnimeTier, AnimeType};
 
 // Re-export infrastructure components
+pub use infrastructure::events::InProcessEventBus;
 
 // Re-export application layer use cases and ports
 pub use application::{
-    AnimeQueryRepository, AnimeRelationsRepository, AnimeRepository as IAnimeRepository,
-    AnimeSearchSpecification, CreateAnimeCommand, CreateAnimeHandler, CreateAnimeResult,
-    DiscoverRelationsCommand, DiscoverRelationsHandler, DiscoverRelationsResult, EventPublisher,
-    ProviderClient, SearchAnimeHandler, SearchAnimeQuery, SearchAnimeResult,
-    UpdateAnimeScoreCommand, UpdateAnimeScoreHandler, UpdateAnimeScoreResult,
+    airing_library_anime, AnimeQueryRepository, AnimeRelationsRepository,
+    AnimeRepository as IAnimeRepository, AnimeSearchSpecification, AnimeSortOrder,
+    BrowseLibraryHandler, BrowseLibraryQuery, BrowseLibraryResult, CreateAnimeCommand,
+    CreateAnimeHandler, CreateAnimeResult, DiscoverRelationsCommand, DiscoverRelationsHandler,
+    DiscoverRelationsResult, EventPublisher, EventSubscriber, MomentumSource, ProviderClient,
+    SearchAnimeHandler, SearchAnimeQuery, SearchAnimeResult, UpdateAnimeScoreCommand,
+    UpdateAnimeScoreHandler, UpdateAnimeScoreResult,
 };
@@ -0,0 +1,100 @@
+use super::super::domain::{
+    BackupArchive, BackupBundle, BackupRepository, BackupSummary, RestoreSummary, BACKUP_TABLES,
+};
+use crate::log_info;
+use crate::shared::errors::{AppError, AppResult};
+use std::path::Path;
+use std::sync::Arc;
+
+pub struct BackupService {
+    backup_repo: Arc<dyn BackupRepository>,
+}
+
+impl BackupService {
+    pub fn new(backup_repo: Arc<dyn BackupRepository>) -> Self {
+        Self { backup_repo }
+    }
+
+    /// Dump every app table into a compressed bundle at `path`
+    ///
+    /// The background job queue is only included when `include_jobs` is set.
+    pub async fn create_backup(&self, path: &Path, include_jobs: bool) -> AppResult<BackupSummary> {
+        let tables = BACKUP_TABLES
+            .iter()
+            .filter(|table| include_jobs || !table.optional)
+            .collect();
+
+        let schema_version = self.backup_repo.applied_schema_version().await?;
+        let dumped = self.backup_repo.dump_tables(tables).await?;
+        let bundle = BackupBundle::new(schema_version, include_jobs, dumped);
+
+        tokio::fs::write(path, BackupArchive::encode(&bundle)?)
+            .await
+            .map_err(|e| {
+                AppError::InternalError(format!(
+                    "Failed to write backup to {}: {}",
+                    path.display(),
+                    e
+                ))
+            })?;
+
+        log_info!(
+            "Backed up {} rows from {} tables to {}",
+            bundle.tables.values().map(Vec::len).sum::<usize>(),
+            bundle.tables.len(),
+            path.display()
+        );
+
+        Ok(BackupSummary {
+            path: path.display().to_string(),
+            manifest: bundle.manifest,
+        })
+    }
+
+    /// Restore a bundle written by [`Self::create_backup`]
+    ///
+    /// The manifest is checked before anything is written. Pending migrations
+    /// then run on their own and stay applied even if the restore fails. Rows
+    /// are written parents first in a single transaction, so a failed restore
+    /// writes none of them.
+    ///
+    /// With `replace_library` the library is emptied in the same transaction
+    /// and replaced by the backup. Otherwise rows are merged in by their table
+    /// key, and a row clashing with the library on a natural key such as an
+    /// external id, genre name or news url fails the restore.
+    pub async fn restore_backup(
+        &self,
+        path: &Path,
+        replace_library: bool,
+    ) -> AppResult<RestoreSummary> {
+        let bytes = tokio::fs::read(path).await.map_err(|e| {
+            AppError::InternalError(format!("Failed to read backup {}: {}", path.display(), e))
+        })?;
+
+        let bundle = BackupArchive::decode(&bytes)?;
+        let latest_schema_version = self.backup_repo.latest_schema_version()?;
+        bundle.validate(latest_schema_version.as_deref())?;
+
+        let migrations_applied = self.backup_repo.run_pending_migrations().await?;
+        let manifest = bundle.manifest.clone();
+        let rows_restored = self
+            .backup_repo
+            .upsert_tables(bundle.into_restore_order(), replace_library)
+            .await?;
+
+        log_info!(
+            "Restored {} rows from backup {} taken {} (schema {})",
+            rows_restored,
+            path.display(),
+            manifest.created_at,
+            manifest.schema_version.as_deref().unwrap_or("unknown")
+        );
+
+        Ok(RestoreSummary {
+            path: path.display().to_string(),
+            manifest,
+            migrations_applied,
+            rows_restored,
+        })
+    }
+}
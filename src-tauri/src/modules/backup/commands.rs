@@ -0,0 +1,49 @@
+use super::application::service::BackupService;
+use super::domain::{BackupSummary, RestoreSummary};
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::Path;
+use std::sync::Arc;
+use tauri::State;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct CreateBackupRequest {
+    /// File to write, replaced when it exists
+    pub path: String,
+    /// Also back up the background job queue
+    #[serde(default)]
+    pub include_jobs: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct RestoreBackupRequest {
+    pub path: String,
+    /// Empty the library first instead of merging the backup into it
+    #[serde(default)]
+    pub replace_library: bool,
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn create_backup(
+    request: CreateBackupRequest,
+    backup_service: State<'_, Arc<BackupService>>,
+) -> Result<BackupSummary, String> {
+    backup_service
+        .create_backup(Path::new(&request.path), request.include_jobs)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn restore_backup(
+    request: RestoreBackupRequest,
+    backup_service: State<'_, Arc<BackupService>>,
+) -> Result<RestoreSummary, String> {
+    backup_service
+        .restore_backup(Path::new(&request.path), request.replace_library)
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,215 @@
+use super::super::value_objects::{BackupTable, BACKUP_TABLES};
+use crate::shared::errors::{AppError, AppResult};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use specta::Type;
+use std::collections::BTreeMap;
+
+/// Marks a file as a miru backup
+pub const BACKUP_FORMAT: &str = "miru-backup";
+
+/// Version of the bundle layout, bumped on breaking changes
+pub const BACKUP_FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct TableCount {
+    pub table: String,
+    pub rows: u32,
+}
+
+/// Describes what a backup holds and which schema it was taken from
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupManifest {
+    pub format: String,
+    pub format_version: u32,
+    pub app_version: String,
+    /// Latest migration applied to the database the backup was taken from
+    pub schema_version: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub include_jobs: bool,
+    pub tables: Vec<TableCount>,
+}
+
+/// A manifest together with every backed up row, keyed by table name
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupBundle {
+    pub manifest: BackupManifest,
+    pub tables: BTreeMap<String, Vec<Value>>,
+}
+
+impl BackupBundle {
+    pub fn new(
+        schema_version: Option<String>,
+        include_jobs: bool,
+        tables: Vec<(String, Vec<Value>)>,
+    ) -> Self {
+        let counts = tables
+            .iter()
+            .map(|(table, rows)| TableCount {
+                table: table.clone(),
+                rows: rows.len() as u32,
+            })
+            .collect();
+
+        Self {
+            manifest: BackupManifest {
+                format: BACKUP_FORMAT.to_string(),
+                format_version: BACKUP_FORMAT_VERSION,
+                app_version: env!("CARGO_PKG_VERSION").to_string(),
+                schema_version,
+                created_at: Utc::now(),
+                include_jobs,
+                tables: counts,
+            },
+            tables: tables.into_iter().collect(),
+        }
+    }
+
+    /// Check the bundle can be restored by a build whose newest migration is
+    /// `latest_schema_version`
+    ///
+    /// Migration versions are timestamps, so they compare as strings.
+    pub fn validate(&self, latest_schema_version: Option<&str>) -> AppResult<()> {
+        let manifest = &self.manifest;
+
+        if manifest.format != BACKUP_FORMAT {
+            return Err(AppError::InvalidInput(format!(
+                "Not a miru backup (format '{}')",
+                manifest.format
+            )));
+        }
+
+        if manifest.format_version > BACKUP_FORMAT_VERSION {
+            return Err(AppError::InvalidInput(format!(
+                "Backup format version {} is newer than the supported version {}",
+                manifest.format_version, BACKUP_FORMAT_VERSION
+            )));
+        }
+
+        if let Some(schema_version) = manifest.schema_version.as_deref() {
+            if latest_schema_version.is_none_or(|latest| schema_version > latest) {
+                return Err(AppError::InvalidInput(format!(
+                    "Backup was taken with schema version {}, newer than this app knows ({}); update the app first",
+                    schema_version,
+                    latest_schema_version.unwrap_or("none")
+                )));
+            }
+        }
+
+        if manifest.tables.len() != self.tables.len() {
+            return Err(AppError::InvalidInput(format!(
+                "Backup manifest lists {} tables but the bundle holds {}",
+                manifest.tables.len(),
+                self.tables.len()
+            )));
+        }
+
+        for count in &manifest.tables {
+            if BackupTable::find(&count.table).is_none() {
+                return Err(AppError::InvalidInput(format!(
+                    "Backup contains unknown table '{}'",
+                    count.table
+                )));
+            }
+
+            let rows = self.tables.get(&count.table).map(Vec::len);
+            if rows != Some(count.rows as usize) {
+                return Err(AppError::InvalidInput(format!(
+                    "Backup table '{}' should hold {} rows but holds {}",
+                    count.table,
+                    count.rows,
+                    rows.unwrap_or(0)
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Backed up rows per table, parents before the tables referencing them
+    pub fn into_restore_order(mut self) -> Vec<(&'static BackupTable, Vec<Value>)> {
+        BACKUP_TABLES
+            .iter()
+            .filter_map(|table| self.tables.remove(table.name).map(|rows| (table, rows)))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupSummary {
+    pub path: String,
+    pub manifest: BackupManifest,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreSummary {
+    pub path: String,
+    pub manifest: BackupManifest,
+    /// Migrations applied before the rows were restored
+    pub migrations_applied: Vec<String>,
+    pub rows_restored: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn bundle(schema_version: &str) -> BackupBundle {
+        BackupBundle::new(
+            Some(schema_version.to_string()),
+            false,
+            vec![
+                (
+                    "collection_anime".to_string(),
+                    vec![json!({ "collection_id": "c", "anime_id": "a" })],
+                ),
+                ("anime".to_string(), vec![json!({ "id": "a" })]),
+                ("collections".to_string(), vec![json!({ "id": "c" })]),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_validate_rejects_newer_schema_and_tampered_bundles() {
+        assert!(bundle("20251102090000")
+            .validate(Some("20251102090000"))
+            .is_ok());
+        assert!(bundle("20251001000000")
+            .validate(Some("20251102090000"))
+            .is_ok());
+        assert!(bundle("20251201000000")
+            .validate(Some("20251102090000"))
+            .is_err());
+
+        let mut missing_rows = bundle("20251102090000");
+        missing_rows.tables.get_mut("anime").unwrap().clear();
+        assert!(missing_rows.validate(Some("20251102090000")).is_err());
+
+        let mut unknown_table = bundle("20251102090000");
+        unknown_table
+            .tables
+            .insert("secrets".to_string(), Vec::new());
+        unknown_table.manifest.tables.push(TableCount {
+            table: "secrets".to_string(),
+            rows: 0,
+        });
+        assert!(unknown_table.validate(Some("20251102090000")).is_err());
+    }
+
+    #[test]
+    fn test_restore_order_puts_parents_first() {
+        let order: Vec<&str> = bundle("20251102090000")
+            .into_restore_order()
+            .into_iter()
+            .map(|(table, _)| table.name)
+            .collect();
+
+        assert_eq!(order, vec!["anime", "collections", "collection_anime"]);
+    }
+}
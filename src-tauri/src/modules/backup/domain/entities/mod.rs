@@ -0,0 +1,3 @@
+pub mod backup_bundle;
+
+pub use backup_bundle::{BackupBundle, BackupManifest, BackupSummary, RestoreSummary, TableCount};
@@ -0,0 +1,10 @@
+pub mod entities;
+pub mod repositories;
+pub mod services;
+pub mod value_objects;
+
+// Re-exports for easy access
+pub use entities::{BackupBundle, BackupManifest, BackupSummary, RestoreSummary, TableCount};
+pub use repositories::BackupRepository;
+pub use services::BackupArchive;
+pub use value_objects::{BackupTable, BACKUP_TABLES};
@@ -0,0 +1,35 @@
+use super::super::value_objects::BackupTable;
+use crate::shared::errors::AppResult;
+use async_trait::async_trait;
+use serde_json::Value;
+
+#[async_trait]
+pub trait BackupRepository: Send + Sync {
+    /// Latest migration applied to the database
+    async fn applied_schema_version(&self) -> AppResult<Option<String>>;
+
+    /// Latest migration this build ships with
+    fn latest_schema_version(&self) -> AppResult<Option<String>>;
+
+    /// Every row of the given tables as JSON objects, read from one snapshot
+    async fn dump_tables(
+        &self,
+        tables: Vec<&'static BackupTable>,
+    ) -> AppResult<Vec<(String, Vec<Value>)>>;
+
+    /// Apply the migrations the database is missing, returning their versions
+    async fn run_pending_migrations(&self) -> AppResult<Vec<String>>;
+
+    /// Upsert rows table by table, in the given order, inside one transaction
+    ///
+    /// With `replace_library` every table except seeded ones is emptied first,
+    /// children before parents. Otherwise rows merge with the library on their
+    /// table key. Columns the current schema no longer has are dropped and
+    /// columns the backup lacks keep their defaults. Returns the number of
+    /// rows written.
+    async fn upsert_tables(
+        &self,
+        tables: Vec<(&'static BackupTable, Vec<Value>)>,
+        replace_library: bool,
+    ) -> AppResult<u32>;
+}
@@ -0,0 +1,3 @@
+pub mod backup_repository;
+
+pub use backup_repository::BackupRepository;
@@ -0,0 +1,61 @@
+use super::super::entities::BackupBundle;
+use crate::shared::errors::{AppError, AppResult};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::io::{Read, Write};
+
+/// Reads and writes backups as gzip-compressed JSON bundles
+pub struct BackupArchive;
+
+impl BackupArchive {
+    pub fn encode(bundle: &BackupBundle) -> AppResult<Vec<u8>> {
+        let json = serde_json::to_vec(bundle).map_err(|e| {
+            AppError::SerializationError(format!("Failed to serialise backup: {}", e))
+        })?;
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(&json)
+            .and_then(|_| encoder.finish())
+            .map_err(|e| AppError::InternalError(format!("Failed to compress backup: {}", e)))
+    }
+
+    pub fn decode(bytes: &[u8]) -> AppResult<BackupBundle> {
+        let mut json = Vec::new();
+        GzDecoder::new(bytes)
+            .read_to_end(&mut json)
+            .map_err(|e| AppError::InvalidInput(format!("Backup is not a gzip archive: {}", e)))?;
+
+        serde_json::from_slice(&json)
+            .map_err(|e| AppError::InvalidInput(format!("Backup contents are invalid: {}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_encoded_bundle_decodes_unchanged() {
+        let bundle = BackupBundle::new(
+            Some("20251102090000".to_string()),
+            true,
+            vec![(
+                "anime".to_string(),
+                vec![json!({ "id": "a", "title_main": "Cowboy Bebop", "episodes": 26 })],
+            )],
+        );
+
+        let bytes = BackupArchive::encode(&bundle).unwrap();
+        assert_eq!(&bytes[..2], &[0x1f, 0x8b]);
+
+        let decoded = BackupArchive::decode(&bytes).unwrap();
+        assert_eq!(decoded.manifest.tables, bundle.manifest.tables);
+        assert_eq!(decoded.tables, bundle.tables);
+        assert!(decoded.validate(Some("20251102090000")).is_ok());
+
+        assert!(BackupArchive::decode(b"not a backup").is_err());
+    }
+}
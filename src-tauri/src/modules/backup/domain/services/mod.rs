@@ -0,0 +1,3 @@
+pub mod backup_archive;
+
+pub use backup_archive::BackupArchive;
@@ -0,0 +1,72 @@
+/// A table copied into backups and the columns its rows are upserted on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BackupTable {
+    pub name: &'static str,
+    /// Primary key columns, used as the conflict target on restore
+    pub key_columns: &'static [&'static str],
+    /// Only backed up on request, like the background job queue
+    pub optional: bool,
+    /// Filled by migrations, so its rows are kept when the library is replaced
+    pub seeded: bool,
+}
+
+impl BackupTable {
+    const fn new(name: &'static str, key_columns: &'static [&'static str]) -> Self {
+        Self {
+            name,
+            key_columns,
+            optional: false,
+            seeded: false,
+        }
+    }
+
+    pub fn find(name: &str) -> Option<&'static BackupTable> {
+        BACKUP_TABLES.iter().find(|table| table.name == name)
+    }
+}
+
+const ID: &[&str] = &["id"];
+
+/// Every app table, each listed after the tables it references
+pub const BACKUP_TABLES: &[BackupTable] = &[
+    BackupTable {
+        name: "providers",
+        key_columns: &["code"],
+        optional: false,
+        seeded: true,
+    },
+    BackupTable::new("genres", ID),
+    BackupTable::new("studios", ID),
+    BackupTable::new("anime", ID),
+    BackupTable::new("anime_external_ids", &["anime_id", "provider_code"]),
+    BackupTable::new("anime_genres", &["anime_id", "genre_id"]),
+    BackupTable::new("anime_studios", &["anime_id", "studio_id"]),
+    BackupTable::new("anime_relations", ID),
+    BackupTable::new("quality_metrics", ID),
+    BackupTable::new("anime_images", ID),
+    BackupTable::new("anime_videos", ID),
+    BackupTable::new("anime_themes", ID),
+    BackupTable::new("anime_links", ID),
+    BackupTable::new("anime_episodes", ID),
+    BackupTable::new("anime_airing_schedule", ID),
+    BackupTable::new("anime_broadcasts", &["anime_id"]),
+    BackupTable::new("anime_recommendations", ID),
+    BackupTable::new("anime_recommendation_syncs", &["anime_id"]),
+    BackupTable::new("anime_statistics_snapshots", ID),
+    BackupTable::new("characters", ID),
+    BackupTable::new("people", ID),
+    BackupTable::new("anime_characters", ID),
+    BackupTable::new("anime_staff", ID),
+    BackupTable::new("news_items", ID),
+    BackupTable::new("anime_news", &["anime_id", "news_item_id"]),
+    BackupTable::new("collections", ID),
+    BackupTable::new("collection_anime", &["collection_id", "anime_id"]),
+    BackupTable::new("user_anime_data", &["anime_id", "user_id"]),
+    BackupTable::new("user_ratings", ID),
+    BackupTable {
+        name: "background_jobs",
+        key_columns: ID,
+        optional: true,
+        seeded: false,
+    },
+];
@@ -0,0 +1,3 @@
+pub mod backup_table;
+
+pub use backup_table::{BackupTable, BACKUP_TABLES};
@@ -0,0 +1,312 @@
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use diesel::migration::MigrationSource;
+use diesel::pg::Pg;
+use diesel::prelude::*;
+use diesel::sql_types::{Json, Text};
+use diesel_migrations::MigrationHarness;
+use serde_json::Value;
+use tokio::task;
+
+use crate::modules::backup::domain::{BackupRepository, BackupTable, BACKUP_TABLES};
+use crate::shared::errors::{AppError, AppResult};
+use crate::shared::infrastructure::MIGRATIONS;
+use crate::shared::Database;
+
+/// Rows sent to Postgres per upsert statement
+const UPSERT_BATCH_SIZE: usize = 500;
+
+/// Helper struct for a table dumped as one JSON array
+#[derive(QueryableByName)]
+struct TableRows {
+    #[diesel(sql_type = Json)]
+    table_rows: Value,
+}
+
+/// Helper struct for reading a table's column names
+#[derive(QueryableByName)]
+struct ColumnName {
+    #[diesel(sql_type = Text)]
+    column_name: String,
+}
+
+pub struct BackupRepositoryImpl {
+    db: Arc<Database>,
+}
+
+impl BackupRepositoryImpl {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// Tables emptied before a library replace, children before parents
+    ///
+    /// Seeded tables keep their rows, which the backup upserts over, and
+    /// optional tables are only emptied when the backup brings their rows.
+    fn replaced_tables(tables: &[(&'static BackupTable, Vec<Value>)]) -> Vec<&'static str> {
+        BACKUP_TABLES
+            .iter()
+            .rev()
+            .filter(|table| !table.seeded)
+            .filter(|table| {
+                !table.optional
+                    || tables
+                        .iter()
+                        .any(|(backed_up, _)| backed_up.name == table.name)
+            })
+            .map(|table| table.name)
+            .collect()
+    }
+
+    /// Columns present both in the backed up rows and in the current table
+    ///
+    /// Generated columns cannot be written and are always left out.
+    fn restorable_columns(
+        conn: &mut PgConnection,
+        table: &BackupTable,
+        rows: &[Value],
+    ) -> AppResult<Vec<String>> {
+        let backed_up: BTreeSet<&str> = rows
+            .iter()
+            .filter_map(Value::as_object)
+            .flat_map(|row| row.keys().map(String::as_str))
+            .collect();
+
+        let columns: Vec<ColumnName> = diesel::sql_query(
+            "SELECT column_name::text AS column_name
+             FROM information_schema.columns
+             WHERE table_schema = current_schema()
+               AND table_name = $1
+               AND is_generated = 'NEVER'
+             ORDER BY ordinal_position",
+        )
+        .bind::<Text, _>(table.name)
+        .load(conn)?;
+
+        let columns: Vec<String> = columns
+            .into_iter()
+            .map(|column| column.column_name)
+            .filter(|column| backed_up.contains(column.as_str()))
+            .collect();
+
+        if let Some(key) = table
+            .key_columns
+            .iter()
+            .find(|key| !columns.iter().any(|column| column == *key))
+        {
+            return Err(AppError::InvalidInput(format!(
+                "Backup rows for '{}' are missing key column '{}'",
+                table.name, key
+            )));
+        }
+
+        Ok(columns)
+    }
+
+    /// `INSERT ... ON CONFLICT DO UPDATE` reading rows from a JSON array in `$1`
+    fn upsert_statement(table: &BackupTable, columns: &[String]) -> String {
+        let quoted: Vec<String> = columns
+            .iter()
+            .map(|column| format!("\"{}\"", column))
+            .collect();
+        let keys: Vec<String> = table
+            .key_columns
+            .iter()
+            .map(|column| format!("\"{}\"", column))
+            .collect();
+        let updates: Vec<String> = columns
+            .iter()
+            .filter(|column| !table.key_columns.contains(&column.as_str()))
+            .map(|column| format!("\"{0}\" = EXCLUDED.\"{0}\"", column))
+            .collect();
+
+        let on_conflict = if updates.is_empty() {
+            "DO NOTHING".to_string()
+        } else {
+            format!("DO UPDATE SET {}", updates.join(", "))
+        };
+
+        format!(
+            "INSERT INTO {table} ({columns})
+             SELECT {columns} FROM json_populate_recordset(NULL::{table}, $1)
+             ON CONFLICT ({keys}) {on_conflict}",
+            table = table.name,
+            columns = quoted.join(", "),
+            keys = keys.join(", "),
+        )
+    }
+}
+
+#[async_trait]
+impl BackupRepository for BackupRepositoryImpl {
+    async fn applied_schema_version(&self) -> AppResult<Option<String>> {
+        let db = Arc::clone(&self.db);
+
+        task::spawn_blocking(move || -> AppResult<Option<String>> {
+            let mut conn = db.get_connection()?;
+            let applied = conn.applied_migrations().map_err(|e| {
+                AppError::DatabaseError(format!("Failed to read applied migrations: {}", e))
+            })?;
+            Ok(applied.iter().map(|version| version.to_string()).max())
+        })
+        .await?
+    }
+
+    fn latest_schema_version(&self) -> AppResult<Option<String>> {
+        let migrations = MigrationSource::<Pg>::migrations(&MIGRATIONS).map_err(|e| {
+            AppError::InternalError(format!("Failed to read bundled migrations: {}", e))
+        })?;
+
+        Ok(migrations
+            .iter()
+            .map(|migration| migration.name().version().to_string())
+            .max())
+    }
+
+    async fn dump_tables(
+        &self,
+        tables: Vec<&'static BackupTable>,
+    ) -> AppResult<Vec<(String, Vec<Value>)>> {
+        let db = Arc::clone(&self.db);
+
+        task::spawn_blocking(move || -> AppResult<Vec<(String, Vec<Value>)>> {
+            let mut conn = db.get_connection()?;
+
+            conn.build_transaction()
+                .read_only()
+                .repeatable_read()
+                .run::<_, AppError, _>(|conn| {
+                    tables
+                        .iter()
+                        .map(|table| {
+                            let dumped: TableRows = diesel::sql_query(format!(
+                                "SELECT COALESCE(json_agg(t), '[]'::json) AS table_rows FROM {} t",
+                                table.name
+                            ))
+                            .get_result(conn)?;
+
+                            let rows = match dumped.table_rows {
+                                Value::Array(rows) => rows,
+                                _ => Vec::new(),
+                            };
+                            Ok((table.name.to_string(), rows))
+                        })
+                        .collect()
+                })
+        })
+        .await?
+    }
+
+    async fn run_pending_migrations(&self) -> AppResult<Vec<String>> {
+        let db = Arc::clone(&self.db);
+
+        task::spawn_blocking(move || -> AppResult<Vec<String>> {
+            let mut conn = db.get_connection()?;
+            let applied = conn.run_pending_migrations(MIGRATIONS).map_err(|e| {
+                AppError::DatabaseError(format!("Failed to run database migrations: {}", e))
+            })?;
+            Ok(applied.iter().map(|version| version.to_string()).collect())
+        })
+        .await?
+    }
+
+    async fn upsert_tables(
+        &self,
+        tables: Vec<(&'static BackupTable, Vec<Value>)>,
+        replace_library: bool,
+    ) -> AppResult<u32> {
+        let db = Arc::clone(&self.db);
+
+        task::spawn_blocking(move || -> AppResult<u32> {
+            let mut conn = db.get_connection()?;
+
+            conn.transaction::<_, AppError, _>(|conn| {
+                if replace_library {
+                    for table in Self::replaced_tables(&tables) {
+                        diesel::sql_query(format!("DELETE FROM {}", table)).execute(conn)?;
+                    }
+                }
+
+                let mut written = 0;
+
+                for (table, rows) in &tables {
+                    if rows.is_empty() {
+                        continue;
+                    }
+
+                    let columns = Self::restorable_columns(conn, table, rows)?;
+                    let statement = Self::upsert_statement(table, &columns);
+
+                    for batch in rows.chunks(UPSERT_BATCH_SIZE) {
+                        written += diesel::sql_query(&statement)
+                            .bind::<Json, _>(Value::Array(batch.to_vec()))
+                            .execute(conn)?;
+                    }
+                }
+
+                Ok(written as u32)
+            })
+        })
+        .await?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn columns(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_upsert_statement_updates_non_key_columns() {
+        let table = BackupTable::find("anime_external_ids").unwrap();
+        let statement = BackupRepositoryImpl::upsert_statement(
+            table,
+            &columns(&["anime_id", "provider_code", "external_id", "is_primary"]),
+        );
+
+        let statement: Vec<&str> = statement.lines().map(str::trim).collect();
+        assert_eq!(
+            statement,
+            vec![
+                r#"INSERT INTO anime_external_ids ("anime_id", "provider_code", "external_id", "is_primary")"#,
+                r#"SELECT "anime_id", "provider_code", "external_id", "is_primary" FROM json_populate_recordset(NULL::anime_external_ids, $1)"#,
+                r#"ON CONFLICT ("anime_id", "provider_code") DO UPDATE SET "external_id" = EXCLUDED."external_id", "is_primary" = EXCLUDED."is_primary""#,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_upsert_statement_skips_rows_with_only_key_columns() {
+        let table = BackupTable::find("anime_genres").unwrap();
+        let statement =
+            BackupRepositoryImpl::upsert_statement(table, &columns(&["anime_id", "genre_id"]));
+
+        assert!(statement.ends_with(r#"ON CONFLICT ("anime_id", "genre_id") DO NOTHING"#));
+    }
+
+    #[test]
+    fn test_replaced_tables_empties_children_first_and_keeps_seeded_rows() {
+        let replaced = BackupRepositoryImpl::replaced_tables(&[]);
+
+        assert!(!replaced.contains(&"providers"));
+        assert!(!replaced.contains(&"background_jobs"));
+
+        let position = |name: &str| replaced.iter().position(|table| *table == name).unwrap();
+        assert!(position("anime_external_ids") < position("anime"));
+        assert!(position("collection_anime") < position("collections"));
+        assert!(position("anime_genres") < position("genres"));
+    }
+
+    #[test]
+    fn test_replaced_tables_empties_jobs_only_when_backed_up() {
+        let jobs = BackupTable::find("background_jobs").unwrap();
+        let replaced = BackupRepositoryImpl::replaced_tables(&[(jobs, Vec::new())]);
+
+        assert_eq!(replaced.first(), Some(&"background_jobs"));
+    }
+}
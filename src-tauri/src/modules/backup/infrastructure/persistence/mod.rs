@@ -0,0 +1,3 @@
+pub mod backup_repository_impl;
+
+pub use backup_repository_impl::BackupRepositoryImpl;
@@ -0,0 +1,169 @@
+use super::super::domain::{
+    CastMember, CastMerger, CharacterRepository, Person, StaffCredit, StaffMerger, StaffRole,
+    VoiceActorRole,
+};
+use crate::modules::anime::domain::repositories::anime_repository::AnimeRepository;
+use crate::modules::anime::{
+    AnimeDetailed, AnimeQueryRepository, AnimeSearchSpecification, AnimeSortOrder,
+};
+use crate::modules::provider::application::service::ProviderService;
+use crate::shared::application::pagination::{PaginatedResult, PaginationParams};
+use crate::shared::errors::{AppError, AppResult};
+use crate::{log_debug, log_info};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Default number of people returned by a voice actor search
+const DEFAULT_SEARCH_LIMIT: i64 = 20;
+
+/// A voice actor together with every role they have in the library
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct VoiceActorFilmography {
+    pub person: Person,
+    pub roles: Vec<VoiceActorRole>,
+}
+
+pub struct CharacterService {
+    character_repo: Arc<dyn CharacterRepository>,
+    anime_repo: Arc<dyn AnimeRepository>,
+    anime_query_repo: Arc<dyn AnimeQueryRepository>,
+    provider_service: Arc<ProviderService>,
+}
+
+impl CharacterService {
+    pub fn new(
+        character_repo: Arc<dyn CharacterRepository>,
+        anime_repo: Arc<dyn AnimeRepository>,
+        anime_query_repo: Arc<dyn AnimeQueryRepository>,
+        provider_service: Arc<ProviderService>,
+    ) -> Self {
+        Self {
+            character_repo,
+            anime_repo,
+            anime_query_repo,
+            provider_service,
+        }
+    }
+
+    /// Stored cast of an anime, main characters first
+    pub async fn get_cast(&self, anime_id: &Uuid) -> AppResult<Vec<CastMember>> {
+        self.character_repo.find_cast(anime_id).await
+    }
+
+    /// Fetch the cast from MyAnimeList and AniList, merge it and replace the stored one
+    pub async fn sync_cast(&self, anime_id: &Uuid) -> AppResult<Vec<CastMember>> {
+        let anime = self.require_anime(anime_id).await?;
+
+        let provider_cast = self
+            .provider_service
+            .fetch_anime_cast(&anime.provider_metadata)
+            .await?;
+
+        let merged = CastMerger::merge(provider_cast);
+        if merged.is_empty() {
+            // Keep whatever we had rather than wiping it on an empty answer
+            log_debug!("No cast returned by providers for '{}'", anime.title.main);
+            return self.character_repo.find_cast(anime_id).await;
+        }
+
+        let credits = self.character_repo.replace_cast(anime_id, &merged).await?;
+        log_info!(
+            "Synced {} characters ({} credits) for '{}'",
+            merged.len(),
+            credits,
+            anime.title.main
+        );
+
+        self.character_repo.find_cast(anime_id).await
+    }
+
+    /// Stored staff of an anime, directors first
+    pub async fn get_staff(&self, anime_id: &Uuid) -> AppResult<Vec<StaffCredit>> {
+        self.character_repo.find_staff(anime_id).await
+    }
+
+    /// Fetch the staff from MyAnimeList and AniList, merge it and replace the stored one
+    pub async fn sync_staff(&self, anime_id: &Uuid) -> AppResult<Vec<StaffCredit>> {
+        let anime = self.require_anime(anime_id).await?;
+
+        let provider_staff = self
+            .provider_service
+            .fetch_anime_staff(&anime.provider_metadata)
+            .await?;
+
+        let merged = StaffMerger::merge(provider_staff);
+        if merged.is_empty() {
+            log_debug!("No staff returned by providers for '{}'", anime.title.main);
+            return self.character_repo.find_staff(anime_id).await;
+        }
+
+        let credits = self.character_repo.replace_staff(anime_id, &merged).await?;
+        log_info!(
+            "Synced {} staff credits for '{}'",
+            credits,
+            anime.title.main
+        );
+
+        self.character_repo.find_staff(anime_id).await
+    }
+
+    /// Library anime a person is credited on, e.g. everything they directed
+    ///
+    /// `roles` narrows the credits considered; `None` matches any position.
+    pub async fn get_anime_by_staff(
+        &self,
+        person_id: &Uuid,
+        roles: Option<Vec<StaffRole>>,
+        pagination: PaginationParams,
+    ) -> AppResult<PaginatedResult<AnimeDetailed>> {
+        let specification = AnimeSearchSpecification {
+            staff_ids: Some(vec![*person_id]),
+            staff_roles: roles,
+            sort: AnimeSortOrder::AiredNewest,
+            ..Default::default()
+        };
+
+        self.anime_query_repo
+            .find_by_criteria(specification, pagination)
+            .await
+    }
+
+    pub async fn search_voice_actors(
+        &self,
+        query: &str,
+        limit: Option<u32>,
+    ) -> AppResult<Vec<Person>> {
+        if query.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let limit = limit.map(i64::from).unwrap_or(DEFAULT_SEARCH_LIMIT);
+        self.character_repo.search_people(query, limit).await
+    }
+
+    /// Every role a voice actor has across the anime in the library
+    pub async fn get_voice_actor_filmography(
+        &self,
+        person_id: &Uuid,
+    ) -> AppResult<VoiceActorFilmography> {
+        let person = self
+            .character_repo
+            .find_person(person_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Person with ID {} not found", person_id)))?;
+
+        let roles = self.character_repo.find_voice_roles(person_id).await?;
+
+        Ok(VoiceActorFilmography { person, roles })
+    }
+
+    async fn require_anime(&self, anime_id: &Uuid) -> AppResult<AnimeDetailed> {
+        self.anime_repo
+            .find_by_id(anime_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Anime with ID {} not found", anime_id)))
+    }
+}
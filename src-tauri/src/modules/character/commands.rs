@@ -0,0 +1,134 @@
+use super::application::service::{CharacterService, VoiceActorFilmography};
+use super::domain::{CastMember, Person, StaffCredit, StaffRole};
+use crate::modules::anime::AnimeDetailed;
+use crate::shared::application::pagination::{PaginatedResult, PaginationParams};
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::sync::Arc;
+use tauri::State;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct GetAnimeCastRequest {
+    pub anime_id: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SyncAnimeCastRequest {
+    pub anime_id: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct GetAnimeStaffRequest {
+    pub anime_id: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SyncAnimeStaffRequest {
+    pub anime_id: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct GetAnimeByStaffRequest {
+    pub person_id: Uuid,
+    /// e.g. `["director"]`; omit to match any position
+    pub roles: Option<Vec<StaffRole>>,
+    #[serde(default)]
+    pub pagination: PaginationParams,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SearchVoiceActorsRequest {
+    pub query: String,
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct GetVoiceActorFilmographyRequest {
+    pub person_id: Uuid,
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_anime_cast(
+    request: GetAnimeCastRequest,
+    character_service: State<'_, Arc<CharacterService>>,
+) -> Result<Vec<CastMember>, String> {
+    character_service
+        .get_cast(&request.anime_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn sync_anime_cast(
+    request: SyncAnimeCastRequest,
+    character_service: State<'_, Arc<CharacterService>>,
+) -> Result<Vec<CastMember>, String> {
+    character_service
+        .sync_cast(&request.anime_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_anime_staff(
+    request: GetAnimeStaffRequest,
+    character_service: State<'_, Arc<CharacterService>>,
+) -> Result<Vec<StaffCredit>, String> {
+    character_service
+        .get_staff(&request.anime_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn sync_anime_staff(
+    request: SyncAnimeStaffRequest,
+    character_service: State<'_, Arc<CharacterService>>,
+) -> Result<Vec<StaffCredit>, String> {
+    character_service
+        .sync_staff(&request.anime_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_anime_by_staff(
+    request: GetAnimeByStaffRequest,
+    character_service: State<'_, Arc<CharacterService>>,
+) -> Result<PaginatedResult<AnimeDetailed>, String> {
+    character_service
+        .get_anime_by_staff(&request.person_id, request.roles, request.pagination)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn search_voice_actors(
+    request: SearchVoiceActorsRequest,
+    character_service: State<'_, Arc<CharacterService>>,
+) -> Result<Vec<Person>, String> {
+    character_service
+        .search_voice_actors(&request.query, request.limit)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_voice_actor_filmography(
+    request: GetVoiceActorFilmographyRequest,
+    character_service: State<'_, Arc<CharacterService>>,
+) -> Result<VoiceActorFilmography, String> {
+    character_service
+        .get_voice_actor_filmography(&request.person_id)
+        .await
+        .map_err(|e| e.to_string())
+}
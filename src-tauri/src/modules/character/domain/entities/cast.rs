@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use uuid::Uuid;
+
+use super::{Character, NewCharacter, NewPerson, Person};
+use crate::modules::character::domain::value_objects::CharacterRole;
+
+/// A character in an anime's cast with everyone who voiced them
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CastMember {
+    pub character: Character,
+    pub role: CharacterRole,
+    pub voice_actors: Vec<VoiceActorCredit>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct VoiceActorCredit {
+    pub person: Person,
+    pub language: Option<String>,
+}
+
+/// One role a voice actor played in an anime from the library
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct VoiceActorRole {
+    pub anime_id: Uuid,
+    pub anime_title: String,
+    pub anime_image_url: Option<String>,
+    pub character: Character,
+    pub role: CharacterRole,
+    pub language: Option<String>,
+}
+
+/// Merged cast entry ready to be stored for an anime
+#[derive(Debug, Clone, PartialEq)]
+pub struct CastEntry {
+    pub character: NewCharacter,
+    pub role: CharacterRole,
+    pub voice_actors: Vec<CastVoiceActor>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CastVoiceActor {
+    pub person: NewPerson,
+    pub language: Option<String>,
+}
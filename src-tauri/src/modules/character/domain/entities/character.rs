@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use uuid::Uuid;
+
+/// Anime character, shared across every anime it appears in
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct Character {
+    pub id: Uuid,
+    pub mal_id: Option<i32>,
+    pub anilist_id: Option<i32>,
+    pub name: String,
+    pub name_native: Option<String>,
+    pub image_url: Option<String>,
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Character data to insert or merge into an existing row with the same provider id
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewCharacter {
+    pub mal_id: Option<i32>,
+    pub anilist_id: Option<i32>,
+    pub name: String,
+    pub name_native: Option<String>,
+    pub image_url: Option<String>,
+    pub description: Option<String>,
+}
@@ -0,0 +1,13 @@
+pub mod cast;
+pub mod character;
+pub mod person;
+pub mod provider_cast;
+pub mod staff;
+
+pub use cast::{CastEntry, CastMember, CastVoiceActor, VoiceActorCredit, VoiceActorRole};
+pub use character::{Character, NewCharacter};
+pub use person::{NewPerson, Person};
+pub use provider_cast::{
+    ProviderCastMember, ProviderCharacter, ProviderStaffMember, ProviderVoiceActor,
+};
+pub use staff::{StaffCredit, StaffEntry};
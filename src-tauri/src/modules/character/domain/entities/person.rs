@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use uuid::Uuid;
+
+/// Real person credited on an anime (voice actor or staff)
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct Person {
+    pub id: Uuid,
+    pub mal_id: Option<i32>,
+    pub anilist_id: Option<i32>,
+    pub name: String,
+    pub name_native: Option<String>,
+    pub image_url: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Person data to insert or merge into an existing row with the same provider id
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewPerson {
+    pub mal_id: Option<i32>,
+    pub anilist_id: Option<i32>,
+    pub name: String,
+    pub name_native: Option<String>,
+    pub image_url: Option<String>,
+}
@@ -0,0 +1,42 @@
+use crate::modules::character::domain::value_objects::CharacterRole;
+use crate::shared::domain::value_objects::AnimeProvider;
+
+/// Cast entry as reported by a single provider, ids are that provider's
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProviderCastMember {
+    pub provider: AnimeProvider,
+    pub character: ProviderCharacter,
+    pub role: CharacterRole,
+    pub voice_actors: Vec<ProviderVoiceActor>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProviderCharacter {
+    pub external_id: i32,
+    pub name: String,
+    pub name_native: Option<String>,
+    pub image_url: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProviderVoiceActor {
+    pub external_id: i32,
+    pub name: String,
+    pub name_native: Option<String>,
+    pub image_url: Option<String>,
+    /// Dub language, e.g. `Japanese`
+    pub language: Option<String>,
+}
+
+/// Staff member as reported by a single provider with every position they held
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProviderStaffMember {
+    pub provider: AnimeProvider,
+    pub external_id: i32,
+    pub name: String,
+    pub name_native: Option<String>,
+    pub image_url: Option<String>,
+    /// Raw position labels, e.g. `Director` or `Storyboard (eps 1, 3)`
+    pub positions: Vec<String>,
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use super::{NewPerson, Person};
+use crate::modules::character::domain::value_objects::StaffRole;
+
+/// A staff position held by a person on an anime
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct StaffCredit {
+    pub person: Person,
+    pub role: StaffRole,
+    /// Position as the provider spelled it, e.g. `Key Animation` for `Other`
+    pub role_label: String,
+}
+
+/// Merged staff credit ready to be stored for an anime
+#[derive(Debug, Clone, PartialEq)]
+pub struct StaffEntry {
+    pub person: NewPerson,
+    pub role: StaffRole,
+    pub role_label: String,
+}
@@ -0,0 +1,14 @@
+pub mod entities;
+pub mod repositories;
+pub mod services;
+pub mod value_objects;
+
+// Re-exports for easy access
+pub use entities::{
+    CastEntry, CastMember, CastVoiceActor, Character, NewCharacter, NewPerson, Person,
+    ProviderCastMember, ProviderCharacter, ProviderStaffMember, ProviderVoiceActor, StaffCredit,
+    StaffEntry, VoiceActorCredit, VoiceActorRole,
+};
+pub use repositories::CharacterRepository;
+pub use services::{CastMerger, StaffMerger};
+pub use value_objects::{CharacterRole, StaffRole};
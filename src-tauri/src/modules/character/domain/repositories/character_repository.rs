@@ -0,0 +1,33 @@
+use super::super::entities::{
+    CastEntry, CastMember, Person, StaffCredit, StaffEntry, VoiceActorRole,
+};
+use crate::shared::errors::AppResult;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait CharacterRepository: Send + Sync {
+    /// Replace an anime's cast, upserting characters and people by provider id
+    ///
+    /// Returns the number of stored cast rows.
+    async fn replace_cast(&self, anime_id: &Uuid, cast: &[CastEntry]) -> AppResult<usize>;
+
+    /// Cast of an anime in stored order (main characters first)
+    async fn find_cast(&self, anime_id: &Uuid) -> AppResult<Vec<CastMember>>;
+
+    /// Replace an anime's staff credits, upserting people by provider id
+    ///
+    /// Returns the number of stored credits.
+    async fn replace_staff(&self, anime_id: &Uuid, staff: &[StaffEntry]) -> AppResult<usize>;
+
+    /// Staff credits of an anime in stored order (leads first)
+    async fn find_staff(&self, anime_id: &Uuid) -> AppResult<Vec<StaffCredit>>;
+
+    async fn find_person(&self, person_id: &Uuid) -> AppResult<Option<Person>>;
+
+    /// People whose name contains `query`, case-insensitive
+    async fn search_people(&self, query: &str, limit: i64) -> AppResult<Vec<Person>>;
+
+    /// Every role a person voiced in anime from the library, newest anime first
+    async fn find_voice_roles(&self, person_id: &Uuid) -> AppResult<Vec<VoiceActorRole>>;
+}
@@ -0,0 +1,3 @@
+pub mod character_repository;
+
+pub use character_repository::CharacterRepository;
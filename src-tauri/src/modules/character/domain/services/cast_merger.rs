@@ -0,0 +1,299 @@
+use crate::modules::character::domain::entities::{
+    CastEntry, CastVoiceActor, NewCharacter, NewPerson, ProviderCastMember, ProviderVoiceActor,
+};
+use crate::shared::domain::value_objects::AnimeProvider;
+
+/// Merges MyAnimeList and AniList cast lists into one entry per character
+///
+/// The providers share no character or person ids, so entries are matched on
+/// their own provider id first and on a word-order independent name key second
+/// (MAL lists `Spiegel, Spike`, AniList `Spike Spiegel`). Entries from other
+/// providers are ignored: only MAL and AniList ids identify stored characters.
+pub struct CastMerger;
+
+impl CastMerger {
+    pub fn merge(members: Vec<ProviderCastMember>) -> Vec<CastEntry> {
+        let mut entries: Vec<CastEntry> = Vec::new();
+
+        for member in members {
+            let Some((mal_id, anilist_id)) =
+                provider_ids(member.provider, member.character.external_id)
+            else {
+                continue;
+            };
+
+            let key = name_key(&member.character.name);
+            let existing = entries.iter_mut().find(|entry| {
+                let character = &entry.character;
+                let same_id = (mal_id.is_some() && character.mal_id == mal_id)
+                    || (anilist_id.is_some() && character.anilist_id == anilist_id);
+                let conflicting_id = (mal_id.is_some() && character.mal_id.is_some())
+                    || (anilist_id.is_some() && character.anilist_id.is_some());
+
+                same_id || (!conflicting_id && name_key(&character.name) == key)
+            });
+
+            let voice_actors: Vec<CastVoiceActor> = member
+                .voice_actors
+                .into_iter()
+                .filter_map(|actor| to_cast_voice_actor(member.provider, actor))
+                .collect();
+
+            match existing {
+                Some(entry) => {
+                    let character = &mut entry.character;
+                    character.mal_id = character.mal_id.or(mal_id);
+                    character.anilist_id = character.anilist_id.or(anilist_id);
+                    if member.provider == AnimeProvider::AniList {
+                        character.name = member.character.name;
+                    }
+                    fill(&mut character.name_native, member.character.name_native);
+                    fill(&mut character.image_url, member.character.image_url);
+                    fill(&mut character.description, member.character.description);
+
+                    if member.role.rank() < entry.role.rank() {
+                        entry.role = member.role;
+                    }
+
+                    for actor in voice_actors {
+                        merge_voice_actor(&mut entry.voice_actors, actor, member.provider);
+                    }
+                }
+                None => {
+                    let mut merged_actors = Vec::new();
+                    for actor in voice_actors {
+                        merge_voice_actor(&mut merged_actors, actor, member.provider);
+                    }
+
+                    entries.push(CastEntry {
+                        character: NewCharacter {
+                            mal_id,
+                            anilist_id,
+                            name: member.character.name,
+                            name_native: member.character.name_native,
+                            image_url: member.character.image_url,
+                            description: member.character.description,
+                        },
+                        role: member.role,
+                        voice_actors: merged_actors,
+                    });
+                }
+            }
+        }
+
+        // Stable: providers' own ordering is kept within each role
+        entries.sort_by_key(|entry| entry.role.rank());
+        entries
+    }
+}
+
+fn merge_voice_actor(
+    actors: &mut Vec<CastVoiceActor>,
+    actor: CastVoiceActor,
+    provider: AnimeProvider,
+) {
+    let key = name_key(&actor.person.name);
+    let language = language_key(&actor.language);
+
+    let existing = actors.iter_mut().find(|existing| {
+        let person = &existing.person;
+        let same_id = (actor.person.mal_id.is_some() && person.mal_id == actor.person.mal_id)
+            || (actor.person.anilist_id.is_some() && person.anilist_id == actor.person.anilist_id);
+        let conflicting_id = (actor.person.mal_id.is_some() && person.mal_id.is_some())
+            || (actor.person.anilist_id.is_some() && person.anilist_id.is_some());
+
+        language_key(&existing.language) == language
+            && (same_id || (!conflicting_id && name_key(&person.name) == key))
+    });
+
+    match existing {
+        Some(existing) => {
+            let person = &mut existing.person;
+            person.mal_id = person.mal_id.or(actor.person.mal_id);
+            person.anilist_id = person.anilist_id.or(actor.person.anilist_id);
+            if provider == AnimeProvider::AniList {
+                person.name = actor.person.name;
+            }
+            fill(&mut person.name_native, actor.person.name_native);
+            fill(&mut person.image_url, actor.person.image_url);
+            fill(&mut existing.language, actor.language);
+        }
+        None => actors.push(actor),
+    }
+}
+
+fn to_cast_voice_actor(
+    provider: AnimeProvider,
+    actor: ProviderVoiceActor,
+) -> Option<CastVoiceActor> {
+    let (mal_id, anilist_id) = provider_ids(provider, actor.external_id)?;
+
+    Some(CastVoiceActor {
+        person: NewPerson {
+            mal_id,
+            anilist_id,
+            name: actor.name,
+            name_native: actor.name_native,
+            image_url: actor.image_url,
+        },
+        language: actor.language,
+    })
+}
+
+/// Provider id split into the (mal_id, anilist_id) columns
+pub(super) fn provider_ids(
+    provider: AnimeProvider,
+    external_id: i32,
+) -> Option<(Option<i32>, Option<i32>)> {
+    match provider {
+        AnimeProvider::Jikan => Some((Some(external_id), None)),
+        AnimeProvider::AniList => Some((None, Some(external_id))),
+        _ => None,
+    }
+}
+
+pub(super) fn fill<T>(target: &mut Option<T>, value: Option<T>) {
+    if target.is_none() {
+        *target = value;
+    }
+}
+
+/// Lowercased name words in sorted order, so `Spiegel, Spike` == `Spike Spiegel`
+pub(super) fn name_key(name: &str) -> String {
+    let mut words: Vec<String> = name
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect();
+    words.sort();
+    words.join(" ")
+}
+
+fn language_key(language: &Option<String>) -> Option<String> {
+    language.as_ref().map(|l| l.trim().to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::character::domain::entities::ProviderCharacter;
+    use crate::modules::character::domain::value_objects::CharacterRole;
+
+    fn member(
+        provider: AnimeProvider,
+        id: i32,
+        name: &str,
+        role: CharacterRole,
+        actors: Vec<ProviderVoiceActor>,
+    ) -> ProviderCastMember {
+        ProviderCastMember {
+            provider,
+            character: ProviderCharacter {
+                external_id: id,
+                name: name.to_string(),
+                name_native: None,
+                image_url: None,
+                description: None,
+            },
+            role,
+            voice_actors: actors,
+        }
+    }
+
+    fn actor(id: i32, name: &str, language: &str) -> ProviderVoiceActor {
+        ProviderVoiceActor {
+            external_id: id,
+            name: name.to_string(),
+            name_native: None,
+            image_url: None,
+            language: Some(language.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_merges_same_character_across_providers() {
+        let cast = CastMerger::merge(vec![
+            member(
+                AnimeProvider::Jikan,
+                1,
+                "Spiegel, Spike",
+                CharacterRole::Main,
+                vec![
+                    actor(11, "Yamadera, Kouichi", "Japanese"),
+                    actor(12, "Blum, Steve", "English"),
+                ],
+            ),
+            member(
+                AnimeProvider::AniList,
+                1,
+                "Spike Spiegel",
+                CharacterRole::Main,
+                vec![actor(95011, "Kouichi Yamadera", "Japanese")],
+            ),
+        ]);
+
+        assert_eq!(cast.len(), 1);
+        let spike = &cast[0];
+        assert_eq!(spike.character.mal_id, Some(1));
+        assert_eq!(spike.character.anilist_id, Some(1));
+        assert_eq!(spike.character.name, "Spike Spiegel");
+
+        assert_eq!(spike.voice_actors.len(), 2);
+        let yamadera = &spike.voice_actors[0].person;
+        assert_eq!(yamadera.mal_id, Some(11));
+        assert_eq!(yamadera.anilist_id, Some(95011));
+    }
+
+    #[test]
+    fn test_same_name_with_different_ids_stays_separate() {
+        let cast = CastMerger::merge(vec![
+            member(
+                AnimeProvider::Jikan,
+                1,
+                "Narrator",
+                CharacterRole::Background,
+                vec![],
+            ),
+            member(
+                AnimeProvider::Jikan,
+                2,
+                "Narrator",
+                CharacterRole::Background,
+                vec![],
+            ),
+        ]);
+
+        assert_eq!(cast.len(), 2);
+    }
+
+    #[test]
+    fn test_main_cast_sorted_first_and_other_providers_ignored() {
+        let cast = CastMerger::merge(vec![
+            member(
+                AnimeProvider::Jikan,
+                3,
+                "Ein",
+                CharacterRole::Supporting,
+                vec![],
+            ),
+            member(
+                AnimeProvider::Kitsu,
+                9,
+                "Faye Valentine",
+                CharacterRole::Main,
+                vec![],
+            ),
+            member(
+                AnimeProvider::AniList,
+                2,
+                "Jet Black",
+                CharacterRole::Main,
+                vec![],
+            ),
+        ]);
+
+        let names: Vec<&str> = cast.iter().map(|c| c.character.name.as_str()).collect();
+        assert_eq!(names, vec!["Jet Black", "Ein"]);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod cast_merger;
+pub mod staff_merger;
+
+pub use cast_merger::CastMerger;
+pub use staff_merger::StaffMerger;
@@ -0,0 +1,204 @@
+use super::cast_merger::{fill, name_key, provider_ids};
+use crate::modules::character::domain::entities::{NewPerson, ProviderStaffMember, StaffEntry};
+use crate::modules::character::domain::value_objects::StaffRole;
+use crate::shared::domain::value_objects::AnimeProvider;
+
+/// Merges MyAnimeList and AniList staff lists into one credit per person and position
+///
+/// People are matched the same way as in `CastMerger`. Positions are normalised
+/// with `StaffRole::from_provider`, so `Director` on both providers is one credit,
+/// while untracked positions are kept apart by their cleaned label.
+pub struct StaffMerger;
+
+struct MergedPerson {
+    person: NewPerson,
+    credits: Vec<(StaffRole, String)>,
+}
+
+impl StaffMerger {
+    pub fn merge(members: Vec<ProviderStaffMember>) -> Vec<StaffEntry> {
+        let mut people: Vec<MergedPerson> = Vec::new();
+
+        for member in members {
+            let Some((mal_id, anilist_id)) = provider_ids(member.provider, member.external_id)
+            else {
+                continue;
+            };
+
+            let key = name_key(&member.name);
+            let index = people.iter().position(|merged| {
+                let person = &merged.person;
+                let same_id = (mal_id.is_some() && person.mal_id == mal_id)
+                    || (anilist_id.is_some() && person.anilist_id == anilist_id);
+                let conflicting_id = (mal_id.is_some() && person.mal_id.is_some())
+                    || (anilist_id.is_some() && person.anilist_id.is_some());
+
+                same_id || (!conflicting_id && name_key(&person.name) == key)
+            });
+
+            let merged = match index {
+                Some(index) => {
+                    let merged = &mut people[index];
+                    let person = &mut merged.person;
+                    person.mal_id = person.mal_id.or(mal_id);
+                    person.anilist_id = person.anilist_id.or(anilist_id);
+                    if member.provider == AnimeProvider::AniList {
+                        person.name = member.name;
+                    }
+                    fill(&mut person.name_native, member.name_native);
+                    fill(&mut person.image_url, member.image_url);
+                    merged
+                }
+                None => {
+                    people.push(MergedPerson {
+                        person: NewPerson {
+                            mal_id,
+                            anilist_id,
+                            name: member.name,
+                            name_native: member.name_native,
+                            image_url: member.image_url,
+                        },
+                        credits: Vec::new(),
+                    });
+                    people.last_mut().expect("just pushed")
+                }
+            };
+
+            for position in &member.positions {
+                let label = StaffRole::clean_label(position);
+                if label.is_empty() {
+                    continue;
+                }
+
+                let role = StaffRole::from_provider(label);
+                let duplicate = merged
+                    .credits
+                    .iter()
+                    .any(|(existing_role, existing_label)| {
+                        *existing_role == role
+                            && (role != StaffRole::Other
+                                || existing_label.eq_ignore_ascii_case(label))
+                    });
+                if !duplicate {
+                    merged.credits.push((role, label.to_string()));
+                }
+            }
+        }
+
+        let mut entries: Vec<StaffEntry> = people
+            .into_iter()
+            .flat_map(|merged| {
+                let person = merged.person;
+                merged
+                    .credits
+                    .into_iter()
+                    .map(move |(role, role_label)| StaffEntry {
+                        person: person.clone(),
+                        role,
+                        role_label,
+                    })
+            })
+            .collect();
+
+        // Stable: providers' own ordering is kept within each role
+        entries.sort_by_key(|entry| entry.role.rank());
+        entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(
+        provider: AnimeProvider,
+        id: i32,
+        name: &str,
+        positions: &[&str],
+    ) -> ProviderStaffMember {
+        ProviderStaffMember {
+            provider,
+            external_id: id,
+            name: name.to_string(),
+            name_native: None,
+            image_url: None,
+            positions: positions.iter().map(|p| p.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_merges_same_person_and_role_across_providers() {
+        let staff = StaffMerger::merge(vec![
+            member(
+                AnimeProvider::Jikan,
+                2009,
+                "Watanabe, Shinichiro",
+                &["Director", "Storyboard"],
+            ),
+            member(
+                AnimeProvider::AniList,
+                100185,
+                "Shinichiro Watanabe",
+                &["Director"],
+            ),
+            member(
+                AnimeProvider::AniList,
+                100185,
+                "Shinichiro Watanabe",
+                &["Storyboard (eps 1, 26)"],
+            ),
+        ]);
+
+        assert_eq!(staff.len(), 2);
+        assert_eq!(staff[0].role, StaffRole::Director);
+        assert_eq!(staff[0].person.mal_id, Some(2009));
+        assert_eq!(staff[0].person.anilist_id, Some(100185));
+        assert_eq!(staff[0].person.name, "Shinichiro Watanabe");
+        assert_eq!(staff[1].role, StaffRole::Storyboard);
+        assert_eq!(staff[1].role_label, "Storyboard");
+    }
+
+    #[test]
+    fn test_keeps_untracked_positions_apart_by_label() {
+        let staff = StaffMerger::merge(vec![member(
+            AnimeProvider::Jikan,
+            1,
+            "Kawamoto, Toshihiro",
+            &["Key Animation", "In-Between Animation", "key animation"],
+        )]);
+
+        assert_eq!(staff.len(), 2);
+        assert!(staff.iter().all(|entry| entry.role == StaffRole::Other));
+        assert_eq!(staff[0].role_label, "Key Animation");
+        assert_eq!(staff[1].role_label, "In-Between Animation");
+    }
+
+    #[test]
+    fn test_sorts_leads_first() {
+        let staff = StaffMerger::merge(vec![
+            member(AnimeProvider::Jikan, 1, "Kanno, Yoko", &["Music"]),
+            member(
+                AnimeProvider::Jikan,
+                2,
+                "Nobumoto, Keiko",
+                &["Series Composition"],
+            ),
+            member(
+                AnimeProvider::Jikan,
+                3,
+                "Watanabe, Shinichiro",
+                &["Director"],
+            ),
+        ]);
+
+        let roles: Vec<StaffRole> = staff.iter().map(|entry| entry.role).collect();
+        assert_eq!(
+            roles,
+            vec![
+                StaffRole::Director,
+                StaffRole::SeriesComposition,
+                StaffRole::Music
+            ]
+        );
+    }
+}
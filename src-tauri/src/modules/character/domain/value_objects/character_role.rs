@@ -0,0 +1,46 @@
+use diesel_derive_enum::DbEnum;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, DbEnum, Type)]
+#[ExistingTypePath = "crate::schema::sql_types::CharacterRole"]
+#[serde(rename_all = "snake_case")]
+pub enum CharacterRole {
+    Main,
+    Supporting,
+    Background,
+}
+
+impl CharacterRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CharacterRole::Main => "main",
+            CharacterRole::Supporting => "supporting",
+            CharacterRole::Background => "background",
+        }
+    }
+
+    /// Parse a provider role label (`Main` on MAL, `MAIN` on AniList)
+    pub fn from_provider(role: &str) -> Self {
+        match role.trim().to_lowercase().as_str() {
+            "main" => CharacterRole::Main,
+            "supporting" => CharacterRole::Supporting,
+            _ => CharacterRole::Background,
+        }
+    }
+
+    /// Sort key: main cast first
+    pub fn rank(&self) -> u8 {
+        match self {
+            CharacterRole::Main => 0,
+            CharacterRole::Supporting => 1,
+            CharacterRole::Background => 2,
+        }
+    }
+}
+
+impl std::fmt::Display for CharacterRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -0,0 +1,4 @@
+pub mod character_role;
+
+pub use crate::shared::domain::value_objects::StaffRole;
+pub use character_role::CharacterRole;
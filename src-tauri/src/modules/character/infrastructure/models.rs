@@ -0,0 +1,181 @@
+use crate::modules::character::domain::{
+    Character, CharacterRole, NewCharacter, NewPerson, Person, StaffRole,
+};
+use crate::schema::{anime_characters, anime_staff, characters, people};
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use uuid::Uuid;
+
+// For reading from database
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone)]
+#[diesel(table_name = characters)]
+pub struct CharacterModel {
+    pub id: Uuid,
+    pub mal_id: Option<i32>,
+    pub anilist_id: Option<i32>,
+    pub name: String,
+    pub name_native: Option<String>,
+    pub image_url: Option<String>,
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// For inserting new rows
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = characters)]
+pub struct NewCharacterModel {
+    pub mal_id: Option<i32>,
+    pub anilist_id: Option<i32>,
+    pub name: String,
+    pub name_native: Option<String>,
+    pub image_url: Option<String>,
+    pub description: Option<String>,
+}
+
+// For merging into existing rows (None keeps the stored value)
+#[derive(AsChangeset, Debug, Clone)]
+#[diesel(table_name = characters)]
+pub struct CharacterChangeset {
+    pub mal_id: Option<i32>,
+    pub anilist_id: Option<i32>,
+    pub name: String,
+    pub name_native: Option<String>,
+    pub image_url: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone)]
+#[diesel(table_name = people)]
+pub struct PersonModel {
+    pub id: Uuid,
+    pub mal_id: Option<i32>,
+    pub anilist_id: Option<i32>,
+    pub name: String,
+    pub name_native: Option<String>,
+    pub image_url: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = people)]
+pub struct NewPersonModel {
+    pub mal_id: Option<i32>,
+    pub anilist_id: Option<i32>,
+    pub name: String,
+    pub name_native: Option<String>,
+    pub image_url: Option<String>,
+}
+
+#[derive(AsChangeset, Debug, Clone)]
+#[diesel(table_name = people)]
+pub struct PersonChangeset {
+    pub mal_id: Option<i32>,
+    pub anilist_id: Option<i32>,
+    pub name: String,
+    pub name_native: Option<String>,
+    pub image_url: Option<String>,
+}
+
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone)]
+#[diesel(table_name = anime_characters)]
+pub struct AnimeCharacterModel {
+    pub id: Uuid,
+    pub anime_id: Uuid,
+    pub character_id: Uuid,
+    pub voice_actor_id: Option<Uuid>,
+    pub role: CharacterRole,
+    pub language: Option<String>,
+    pub position: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = anime_characters)]
+pub struct NewAnimeCharacterModel {
+    pub anime_id: Uuid,
+    pub character_id: Uuid,
+    pub voice_actor_id: Option<Uuid>,
+    pub role: CharacterRole,
+    pub language: Option<String>,
+    pub position: i32,
+}
+
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone)]
+#[diesel(table_name = anime_staff)]
+pub struct AnimeStaffModel {
+    pub id: Uuid,
+    pub anime_id: Uuid,
+    pub person_id: Uuid,
+    pub role: StaffRole,
+    pub role_label: String,
+    pub position: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = anime_staff)]
+pub struct NewAnimeStaffModel {
+    pub anime_id: Uuid,
+    pub person_id: Uuid,
+    pub role: StaffRole,
+    pub role_label: String,
+    pub position: i32,
+}
+
+impl From<CharacterModel> for Character {
+    fn from(model: CharacterModel) -> Self {
+        Self {
+            id: model.id,
+            mal_id: model.mal_id,
+            anilist_id: model.anilist_id,
+            name: model.name,
+            name_native: model.name_native,
+            image_url: model.image_url,
+            description: model.description,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+impl From<&NewCharacter> for NewCharacterModel {
+    fn from(character: &NewCharacter) -> Self {
+        Self {
+            mal_id: character.mal_id,
+            anilist_id: character.anilist_id,
+            name: character.name.clone(),
+            name_native: character.name_native.clone(),
+            image_url: character.image_url.clone(),
+            description: character.description.clone(),
+        }
+    }
+}
+
+impl From<PersonModel> for Person {
+    fn from(model: PersonModel) -> Self {
+        Self {
+            id: model.id,
+            mal_id: model.mal_id,
+            anilist_id: model.anilist_id,
+            name: model.name,
+            name_native: model.name_native,
+            image_url: model.image_url,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+impl From<&NewPerson> for NewPersonModel {
+    fn from(person: &NewPerson) -> Self {
+        Self {
+            mal_id: person.mal_id,
+            anilist_id: person.anilist_id,
+            name: person.name.clone(),
+            name_native: person.name_native.clone(),
+            image_url: person.image_url.clone(),
+        }
+    }
+}
@@ -0,0 +1,396 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+use tokio::task;
+use uuid::Uuid;
+
+use crate::modules::character::domain::{
+    CastEntry, CastMember, CharacterRepository, CharacterRole, NewCharacter, NewPerson, Person,
+    StaffCredit, StaffEntry, VoiceActorCredit, VoiceActorRole,
+};
+use crate::modules::character::infrastructure::models::{
+    AnimeCharacterModel, AnimeStaffModel, CharacterChangeset, CharacterModel,
+    NewAnimeCharacterModel, NewAnimeStaffModel, NewCharacterModel, NewPersonModel, PersonChangeset,
+    PersonModel,
+};
+use crate::schema::{anime, anime_characters, anime_staff, characters, people};
+use crate::shared::errors::AppResult;
+use crate::shared::infrastructure::escape_like;
+use crate::shared::Database;
+
+pub struct CharacterRepositoryImpl {
+    db: Arc<Database>,
+}
+
+impl CharacterRepositoryImpl {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// Find the stored character sharing a provider id with `character`, or insert it
+    ///
+    /// Missing provider ids are only filled in when a single row matched, otherwise
+    /// the ids already belong to two different rows and must stay where they are.
+    fn upsert_character(conn: &mut PgConnection, character: &NewCharacter) -> AppResult<Uuid> {
+        let matches: Vec<CharacterModel> = characters::table
+            .filter(
+                characters::mal_id
+                    .eq(character.mal_id)
+                    .or(characters::anilist_id.eq(character.anilist_id)),
+            )
+            .select(CharacterModel::as_select())
+            .load(conn)?;
+
+        let target = matches
+            .iter()
+            .find(|row| row.mal_id.is_some() && row.mal_id == character.mal_id)
+            .or(matches.first());
+
+        match target {
+            Some(row) => {
+                let fill_ids = matches.len() == 1;
+                let changeset = CharacterChangeset {
+                    mal_id: character
+                        .mal_id
+                        .filter(|_| fill_ids && row.mal_id.is_none()),
+                    anilist_id: character
+                        .anilist_id
+                        .filter(|_| fill_ids && row.anilist_id.is_none()),
+                    name: character.name.clone(),
+                    name_native: character.name_native.clone(),
+                    image_url: character.image_url.clone(),
+                    description: character.description.clone(),
+                };
+
+                diesel::update(characters::table.find(row.id))
+                    .set(&changeset)
+                    .execute(conn)?;
+                Ok(row.id)
+            }
+            None => {
+                let id = diesel::insert_into(characters::table)
+                    .values(NewCharacterModel::from(character))
+                    .returning(characters::id)
+                    .get_result(conn)?;
+                Ok(id)
+            }
+        }
+    }
+
+    /// Same as `upsert_character`, for people
+    fn upsert_person(conn: &mut PgConnection, person: &NewPerson) -> AppResult<Uuid> {
+        let matches: Vec<PersonModel> = people::table
+            .filter(
+                people::mal_id
+                    .eq(person.mal_id)
+                    .or(people::anilist_id.eq(person.anilist_id)),
+            )
+            .select(PersonModel::as_select())
+            .load(conn)?;
+
+        let target = matches
+            .iter()
+            .find(|row| row.mal_id.is_some() && row.mal_id == person.mal_id)
+            .or(matches.first());
+
+        match target {
+            Some(row) => {
+                let fill_ids = matches.len() == 1;
+                let changeset = PersonChangeset {
+                    mal_id: person.mal_id.filter(|_| fill_ids && row.mal_id.is_none()),
+                    anilist_id: person
+                        .anilist_id
+                        .filter(|_| fill_ids && row.anilist_id.is_none()),
+                    name: person.name.clone(),
+                    name_native: person.name_native.clone(),
+                    image_url: person.image_url.clone(),
+                };
+
+                diesel::update(people::table.find(row.id))
+                    .set(&changeset)
+                    .execute(conn)?;
+                Ok(row.id)
+            }
+            None => {
+                let id = diesel::insert_into(people::table)
+                    .values(NewPersonModel::from(person))
+                    .returning(people::id)
+                    .get_result(conn)?;
+                Ok(id)
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl CharacterRepository for CharacterRepositoryImpl {
+    async fn replace_cast(&self, anime_id: &Uuid, cast: &[CastEntry]) -> AppResult<usize> {
+        let db = Arc::clone(&self.db);
+        let anime_id = *anime_id;
+        let cast = cast.to_vec();
+
+        task::spawn_blocking(move || -> AppResult<usize> {
+            let mut conn = db.get_connection()?;
+
+            conn.transaction(|conn| {
+                diesel::delete(
+                    anime_characters::table.filter(anime_characters::anime_id.eq(anime_id)),
+                )
+                .execute(conn)?;
+
+                let mut rows = Vec::new();
+                let mut seen = HashSet::new();
+
+                for (position, entry) in cast.iter().enumerate() {
+                    let character_id = Self::upsert_character(conn, &entry.character)?;
+
+                    let mut credits = Vec::new();
+                    for actor in &entry.voice_actors {
+                        let person_id = Self::upsert_person(conn, &actor.person)?;
+                        credits.push((Some(person_id), actor.language.clone()));
+                    }
+                    if credits.is_empty() {
+                        credits.push((None, None));
+                    }
+
+                    for (voice_actor_id, language) in credits {
+                        // Two merged entries can resolve to the same stored character
+                        if !seen.insert((character_id, voice_actor_id)) {
+                            continue;
+                        }
+
+                        rows.push(NewAnimeCharacterModel {
+                            anime_id,
+                            character_id,
+                            voice_actor_id,
+                            role: entry.role,
+                            language,
+                            position: position as i32,
+                        });
+                    }
+                }
+
+                let inserted = diesel::insert_into(anime_characters::table)
+                    .values(&rows)
+                    .execute(conn)?;
+                Ok(inserted)
+            })
+        })
+        .await?
+    }
+
+    async fn find_cast(&self, anime_id: &Uuid) -> AppResult<Vec<CastMember>> {
+        let db = Arc::clone(&self.db);
+        let anime_id = *anime_id;
+
+        let rows = task::spawn_blocking(
+            move || -> AppResult<Vec<(AnimeCharacterModel, CharacterModel, Option<PersonModel>)>> {
+                let mut conn = db.get_connection()?;
+                let rows = anime_characters::table
+                    .inner_join(characters::table)
+                    .left_join(people::table)
+                    .filter(anime_characters::anime_id.eq(anime_id))
+                    .order((
+                        anime_characters::position.asc(),
+                        anime_characters::language.asc(),
+                    ))
+                    .select((
+                        AnimeCharacterModel::as_select(),
+                        CharacterModel::as_select(),
+                        Option::<PersonModel>::as_select(),
+                    ))
+                    .load(&mut conn)?;
+                Ok(rows)
+            },
+        )
+        .await??;
+
+        let mut cast: Vec<CastMember> = Vec::new();
+        for (link, character, person) in rows {
+            let member = match cast.last_mut() {
+                Some(member) if member.character.id == character.id => member,
+                _ => {
+                    cast.push(CastMember {
+                        character: character.into(),
+                        role: link.role,
+                        voice_actors: Vec::new(),
+                    });
+                    cast.last_mut().expect("just pushed")
+                }
+            };
+
+            if let Some(person) = person {
+                member.voice_actors.push(VoiceActorCredit {
+                    person: person.into(),
+                    language: link.language,
+                });
+            }
+        }
+
+        Ok(cast)
+    }
+
+    async fn replace_staff(&self, anime_id: &Uuid, staff: &[StaffEntry]) -> AppResult<usize> {
+        let db = Arc::clone(&self.db);
+        let anime_id = *anime_id;
+        let staff = staff.to_vec();
+
+        task::spawn_blocking(move || -> AppResult<usize> {
+            let mut conn = db.get_connection()?;
+
+            conn.transaction(|conn| {
+                diesel::delete(anime_staff::table.filter(anime_staff::anime_id.eq(anime_id)))
+                    .execute(conn)?;
+
+                let mut rows = Vec::new();
+                let mut seen = HashSet::new();
+
+                for (position, entry) in staff.iter().enumerate() {
+                    let person_id = Self::upsert_person(conn, &entry.person)?;
+
+                    // Two merged entries can resolve to the same stored person
+                    if !seen.insert((person_id, entry.role, entry.role_label.clone())) {
+                        continue;
+                    }
+
+                    rows.push(NewAnimeStaffModel {
+                        anime_id,
+                        person_id,
+                        role: entry.role,
+                        role_label: entry.role_label.clone(),
+                        position: position as i32,
+                    });
+                }
+
+                let inserted = diesel::insert_into(anime_staff::table)
+                    .values(&rows)
+                    .execute(conn)?;
+                Ok(inserted)
+            })
+        })
+        .await?
+    }
+
+    async fn find_staff(&self, anime_id: &Uuid) -> AppResult<Vec<StaffCredit>> {
+        let db = Arc::clone(&self.db);
+        let anime_id = *anime_id;
+
+        let rows =
+            task::spawn_blocking(move || -> AppResult<Vec<(AnimeStaffModel, PersonModel)>> {
+                let mut conn = db.get_connection()?;
+                let rows = anime_staff::table
+                    .inner_join(people::table)
+                    .filter(anime_staff::anime_id.eq(anime_id))
+                    .order(anime_staff::position.asc())
+                    .select((AnimeStaffModel::as_select(), PersonModel::as_select()))
+                    .load(&mut conn)?;
+                Ok(rows)
+            })
+            .await??;
+
+        Ok(rows
+            .into_iter()
+            .map(|(credit, person)| StaffCredit {
+                person: person.into(),
+                role: credit.role,
+                role_label: credit.role_label,
+            })
+            .collect())
+    }
+
+    async fn find_person(&self, person_id: &Uuid) -> AppResult<Option<Person>> {
+        let db = Arc::clone(&self.db);
+        let person_id = *person_id;
+
+        let model = task::spawn_blocking(move || -> AppResult<Option<PersonModel>> {
+            let mut conn = db.get_connection()?;
+            let m = people::table
+                .find(person_id)
+                .select(PersonModel::as_select())
+                .first(&mut conn)
+                .optional()?;
+            Ok(m)
+        })
+        .await??;
+
+        Ok(model.map(Into::into))
+    }
+
+    async fn search_people(&self, query: &str, limit: i64) -> AppResult<Vec<Person>> {
+        let db = Arc::clone(&self.db);
+        let pattern = format!("%{}%", escape_like(query));
+
+        let models = task::spawn_blocking(move || -> AppResult<Vec<PersonModel>> {
+            let mut conn = db.get_connection()?;
+            let rows = people::table
+                .filter(
+                    people::name
+                        .ilike(&pattern)
+                        .or(people::name_native.ilike(&pattern)),
+                )
+                .order(people::name.asc())
+                .limit(limit)
+                .select(PersonModel::as_select())
+                .load(&mut conn)?;
+            Ok(rows)
+        })
+        .await??;
+
+        Ok(models.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_voice_roles(&self, person_id: &Uuid) -> AppResult<Vec<VoiceActorRole>> {
+        type RoleRow = (
+            Uuid,
+            String,
+            Option<String>,
+            CharacterModel,
+            CharacterRole,
+            Option<String>,
+        );
+
+        let db = Arc::clone(&self.db);
+        let person_id = *person_id;
+
+        let rows = task::spawn_blocking(move || -> AppResult<Vec<RoleRow>> {
+            let mut conn = db.get_connection()?;
+            let rows = anime_characters::table
+                .inner_join(characters::table)
+                .inner_join(anime::table)
+                .filter(anime_characters::voice_actor_id.eq(person_id))
+                .order((
+                    anime::aired_from.desc().nulls_last(),
+                    anime::title_main.asc(),
+                ))
+                .select((
+                    anime::id,
+                    anime::title_main,
+                    anime::image_url,
+                    CharacterModel::as_select(),
+                    anime_characters::role,
+                    anime_characters::language,
+                ))
+                .load::<RoleRow>(&mut conn)?;
+            Ok(rows)
+        })
+        .await??;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(anime_id, anime_title, anime_image_url, character, role, language)| {
+                    VoiceActorRole {
+                        anime_id,
+                        anime_title,
+                        anime_image_url,
+                        character: character.into(),
+                        role,
+                        language,
+                    }
+                },
+            )
+            .collect())
+    }
+}
@@ -0,0 +1,3 @@
+pub mod character_repository_impl;
+
+pub use character_repository_impl::CharacterRepositoryImpl;
@@ -0,0 +1,10 @@
+pub mod application;
+pub mod commands;
+pub mod domain;
+pub mod infrastructure;
+
+// Re-exports for easy external access
+pub use application::service::CharacterService;
+pub use domain::{
+    CastMember, Character, CharacterRepository, CharacterRole, Person, StaffCredit, StaffRole,
+};
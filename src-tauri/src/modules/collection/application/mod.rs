@@ -1 +1,2 @@
+pub mod rating_service;
 pub mod service;
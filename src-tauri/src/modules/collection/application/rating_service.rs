@@ -0,0 +1,153 @@
+use super::super::domain::{
+    entities::user_rating::{
+        OverallFeeling, RatingCriterion, RatingValue, RecommendationPreference,
+    },
+    RatingAggregates, UserRating, UserRatingRepository,
+};
+use crate::log_info;
+use crate::modules::anime::domain::repositories::anime_repository::AnimeRepository;
+use crate::shared::domain::LOCAL_USER_ID;
+use crate::shared::errors::{AppError, AppResult};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Full set of user-editable rating fields
+#[derive(Debug, Clone)]
+pub struct UserRatingInput {
+    pub overall_feeling: OverallFeeling,
+    pub recommendation_preference: Option<RecommendationPreference>,
+    pub criteria: Vec<RatingCriterion>,
+    pub personal_notes: Option<String>,
+    pub emotional_tags: Vec<String>,
+    pub standout_aspects: Vec<String>,
+    pub would_rewatch: Option<bool>,
+}
+
+pub struct UserRatingService {
+    rating_repo: Arc<dyn UserRatingRepository>,
+    anime_repo: Arc<dyn AnimeRepository>,
+}
+
+impl UserRatingService {
+    pub fn new(
+        rating_repo: Arc<dyn UserRatingRepository>,
+        anime_repo: Arc<dyn AnimeRepository>,
+    ) -> Self {
+        Self {
+            rating_repo,
+            anime_repo,
+        }
+    }
+
+    pub async fn create_rating(
+        &self,
+        anime_id: &Uuid,
+        input: UserRatingInput,
+    ) -> AppResult<UserRating> {
+        if self.anime_repo.find_by_id(anime_id).await?.is_none() {
+            return Err(AppError::NotFound(format!(
+                "Anime with ID {} not found",
+                anime_id
+            )));
+        }
+
+        if self.rating_repo.find_by_anime(anime_id).await?.is_some() {
+            return Err(AppError::Duplicate(format!(
+                "Anime {} already has a rating",
+                anime_id
+            )));
+        }
+
+        let mut rating =
+            UserRating::new(*anime_id, LOCAL_USER_ID.to_string(), input.overall_feeling);
+        Self::apply_input(&mut rating, input)?;
+
+        let saved = self.rating_repo.save(&rating).await?;
+        log_info!("Created rating for anime {}", anime_id);
+        Ok(saved)
+    }
+
+    pub async fn update_rating(
+        &self,
+        anime_id: &Uuid,
+        input: UserRatingInput,
+    ) -> AppResult<UserRating> {
+        let mut rating = self
+            .rating_repo
+            .find_by_anime(anime_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("No rating found for anime {}", anime_id)))?;
+
+        Self::apply_input(&mut rating, input)?;
+
+        self.rating_repo.save(&rating).await
+    }
+
+    pub async fn get_rating(&self, anime_id: &Uuid) -> AppResult<Option<UserRating>> {
+        self.rating_repo.find_by_anime(anime_id).await
+    }
+
+    pub async fn delete_rating(&self, anime_id: &Uuid) -> AppResult<()> {
+        self.rating_repo.delete(anime_id).await
+    }
+
+    /// Average every rating criterion across the whole library
+    pub async fn get_aggregates(&self) -> AppResult<RatingAggregates> {
+        let ratings = self.rating_repo.find_all().await?;
+        Ok(RatingAggregates::from_ratings(&ratings))
+    }
+
+    fn apply_input(rating: &mut UserRating, input: UserRatingInput) -> AppResult<()> {
+        for criterion in &input.criteria {
+            Self::validate_criterion(criterion)?;
+        }
+
+        rating.set_overall_feeling(input.overall_feeling);
+        rating.set_recommendation_preference(input.recommendation_preference);
+
+        rating.clear_criteria();
+        for criterion in input.criteria {
+            rating.add_weighted_criterion(
+                criterion.criterion_type,
+                criterion.value,
+                criterion.weight,
+            );
+        }
+
+        rating.emotional_tags.clear();
+        for tag in input.emotional_tags {
+            rating.add_emotional_tag(tag);
+        }
+        rating.standout_aspects.clear();
+        for aspect in input.standout_aspects {
+            rating.add_standout_aspect(aspect);
+        }
+
+        rating.set_notes(input.personal_notes.filter(|n| !n.trim().is_empty()));
+        rating.set_rewatch_preference(input.would_rewatch);
+        Ok(())
+    }
+
+    fn validate_criterion(criterion: &RatingCriterion) -> AppResult<()> {
+        if let RatingValue::Numeric(score) = criterion.value {
+            if !(0.0..=100.0).contains(&score) {
+                return Err(AppError::ValidationError(format!(
+                    "{} must be between 0 and 100, got {}",
+                    criterion.criterion_type.display_name(),
+                    score
+                )));
+            }
+        }
+
+        if let Some(weight) = criterion.weight {
+            if weight <= 0.0 {
+                return Err(AppError::ValidationError(format!(
+                    "Weight for {} must be positive",
+                    criterion.criterion_type.display_name()
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,5 +1,10 @@
+use super::application::rating_service::{UserRatingInput, UserRatingService};
 use super::application::service::CollectionService;
 use super::domain::entities::collection::Collection;
+use super::domain::entities::user_rating::{
+    OverallFeeling, RatingCriterion, RecommendationPreference,
+};
+use super::domain::{RatingAggregates, UserRating};
 use crate::modules::anime::AnimeDetailed;
 
 use crate::{log_debug, log_error, log_info};
@@ -70,6 +75,48 @@ pub struct ImportAnimeBatchRequest {
     pub collection_id: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SaveUserRatingRequest {
+    pub anime_id: Uuid,
+    pub overall_feeling: OverallFeeling,
+    pub recommendation_preference: Option<RecommendationPreference>,
+    #[serde(default)]
+    pub criteria: Vec<RatingCriterion>,
+    pub personal_notes: Option<String>,
+    #[serde(default)]
+    pub emotional_tags: Vec<String>,
+    #[serde(default)]
+    pub standout_aspects: Vec<String>,
+    pub would_rewatch: Option<bool>,
+}
+
+impl SaveUserRatingRequest {
+    fn into_input(self) -> (Uuid, UserRatingInput) {
+        (
+            self.anime_id,
+            UserRatingInput {
+                overall_feeling: self.overall_feeling,
+                recommendation_preference: self.recommendation_preference,
+                criteria: self.criteria,
+                personal_notes: self.personal_notes,
+                emotional_tags: self.emotional_tags,
+                standout_aspects: self.standout_aspects,
+                would_rewatch: self.would_rewatch,
+            },
+        )
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct GetUserRatingRequest {
+    pub anime_id: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct DeleteUserRatingRequest {
+    pub anime_id: Uuid,
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn create_collection(
@@ -207,3 +254,64 @@ pub async fn update_anime_in_collection(
         .await
         .map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub async fn create_user_rating(
+    request: SaveUserRatingRequest,
+    rating_service: State<'_, Arc<UserRatingService>>,
+) -> Result<UserRating, String> {
+    let (anime_id, input) = request.into_input();
+    rating_service
+        .create_rating(&anime_id, input)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn update_user_rating(
+    request: SaveUserRatingRequest,
+    rating_service: State<'_, Arc<UserRatingService>>,
+) -> Result<UserRating, String> {
+    let (anime_id, input) = request.into_input();
+    rating_service
+        .update_rating(&anime_id, input)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_user_rating(
+    request: GetUserRatingRequest,
+    rating_service: State<'_, Arc<UserRatingService>>,
+) -> Result<Option<UserRating>, String> {
+    rating_service
+        .get_rating(&request.anime_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn delete_user_rating(
+    request: DeleteUserRatingRequest,
+    rating_service: State<'_, Arc<UserRatingService>>,
+) -> Result<(), String> {
+    rating_service
+        .delete_rating(&request.anime_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_rating_aggregates(
+    rating_service: State<'_, Arc<UserRatingService>>,
+) -> Result<RatingAggregates, String> {
+    rating_service
+        .get_aggregates()
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -1,2 +1,3 @@
 pub mod collection;
+pub mod rating_aggregate;
 pub mod user_rating;
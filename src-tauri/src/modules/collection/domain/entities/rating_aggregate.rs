@@ -0,0 +1,168 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashMap;
+
+use super::user_rating::{OverallFeeling, UserRating};
+
+/// Average score of one rating criterion across the library
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct CriterionAverage {
+    pub criterion_key: String,
+    pub display_name: String,
+    /// Average on the 0-10 scale
+    pub average_score: f32,
+    /// Number of ratings with a numeric value for this criterion
+    pub rating_count: u32,
+}
+
+/// Library-wide aggregation of user ratings
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+pub struct RatingAggregates {
+    pub total_ratings: u32,
+    pub average_overall_score: Option<f32>,
+    pub average_composite_score: Option<f32>,
+    pub would_rewatch_count: u32,
+    pub feeling_distribution: Vec<(OverallFeeling, u32)>,
+    /// Per-criterion averages, highest average first
+    pub criteria: Vec<CriterionAverage>,
+}
+
+impl RatingAggregates {
+    /// Aggregate ratings, averaging every criterion that has a numeric value
+    pub fn from_ratings(ratings: &[UserRating]) -> Self {
+        if ratings.is_empty() {
+            return Self::default();
+        }
+
+        let count = ratings.len() as f32;
+        let mut feelings: HashMap<OverallFeeling, u32> = HashMap::new();
+        // key -> (display name, sum, count)
+        let mut criteria: HashMap<String, (String, f32, u32)> = HashMap::new();
+
+        for rating in ratings {
+            *feelings.entry(rating.overall_feeling).or_insert(0) += 1;
+
+            for (key, criterion) in &rating.rating_criteria {
+                if let Some(score) = criterion.value.to_numeric_score() {
+                    let entry = criteria
+                        .entry(key.clone())
+                        .or_insert_with(|| (criterion.criterion_type.display_name(), 0.0, 0));
+                    entry.1 += score;
+                    entry.2 += 1;
+                }
+            }
+        }
+
+        let mut criteria: Vec<CriterionAverage> = criteria
+            .into_iter()
+            .map(|(criterion_key, (display_name, sum, n))| CriterionAverage {
+                criterion_key,
+                display_name,
+                average_score: sum / n as f32,
+                rating_count: n,
+            })
+            .collect();
+        criteria.sort_by(|a, b| {
+            b.average_score
+                .partial_cmp(&a.average_score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.criterion_key.cmp(&b.criterion_key))
+        });
+
+        let mut feeling_distribution: Vec<(OverallFeeling, u32)> = feelings.into_iter().collect();
+        feeling_distribution.sort_by(|a, b| {
+            b.0.to_score()
+                .partial_cmp(&a.0.to_score())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        Self {
+            total_ratings: ratings.len() as u32,
+            average_overall_score: Some(
+                ratings
+                    .iter()
+                    .map(|r| r.overall_feeling.to_score())
+                    .sum::<f32>()
+                    / count,
+            ),
+            average_composite_score: Some(
+                ratings
+                    .iter()
+                    .map(|r| r.calculate_composite_score())
+                    .sum::<f32>()
+                    / count,
+            ),
+            would_rewatch_count: ratings
+                .iter()
+                .filter(|r| r.would_rewatch == Some(true))
+                .count() as u32,
+            feeling_distribution,
+            criteria,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::collection::domain::entities::user_rating::{
+        RatingCriterionType, RatingValue,
+    };
+    use uuid::Uuid;
+
+    fn rating(feeling: OverallFeeling) -> UserRating {
+        UserRating::new(Uuid::new_v4(), "local".to_string(), feeling)
+    }
+
+    #[test]
+    fn test_empty_library_has_no_averages() {
+        let aggregates = RatingAggregates::from_ratings(&[]);
+        assert_eq!(aggregates.total_ratings, 0);
+        assert!(aggregates.average_overall_score.is_none());
+        assert!(aggregates.criteria.is_empty());
+    }
+
+    #[test]
+    fn test_criterion_average_across_ratings() {
+        let mut first = rating(OverallFeeling::Loved);
+        first.add_criterion(
+            RatingCriterionType::StoryQuality,
+            RatingValue::Numeric(90.0),
+        );
+        first.add_criterion(
+            RatingCriterionType::Pacing,
+            RatingValue::Text("slow".into()),
+        );
+
+        let mut second = rating(OverallFeeling::Okay);
+        second.add_criterion(
+            RatingCriterionType::StoryQuality,
+            RatingValue::Numeric(50.0),
+        );
+
+        let aggregates = RatingAggregates::from_ratings(&[first, second]);
+
+        assert_eq!(aggregates.total_ratings, 2);
+        assert_eq!(aggregates.average_overall_score, Some(7.0));
+        // Text values have no numeric score, so only story quality is averaged
+        assert_eq!(aggregates.criteria.len(), 1);
+        let story = &aggregates.criteria[0];
+        assert_eq!(story.criterion_key, "storyquality");
+        assert_eq!(story.rating_count, 2);
+        assert!((story.average_score - 7.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_feeling_distribution_is_ordered_by_score() {
+        let aggregates = RatingAggregates::from_ratings(&[
+            rating(OverallFeeling::Okay),
+            rating(OverallFeeling::Loved),
+            rating(OverallFeeling::Okay),
+        ]);
+
+        assert_eq!(
+            aggregates.feeling_distribution,
+            vec![(OverallFeeling::Loved, 1), (OverallFeeling::Okay, 2)]
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use chrono::{DateTime, Utc};
+use diesel_derive_enum::DbEnum;
 use serde::{Deserialize, Serialize};
 use specta::Type;
 use std::collections::HashMap;
@@ -37,7 +38,8 @@ pub struct UserRating {
 }
 
 /// Overall feeling about the anime (required field)
-#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq, Eq, Hash, DbEnum)]
+#[ExistingTypePath = "crate::schema::sql_types::OverallFeeling"]
 pub enum OverallFeeling {
     Loved,    // 5 stars equivalent
     Enjoyed,  // 4 stars
@@ -99,7 +101,8 @@ impl OverallFeeling {
 }
 
 /// How this rating should affect recommendations
-#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq, Eq, DbEnum)]
+#[ExistingTypePath = "crate::schema::sql_types::RecommendationPreference"]
 pub enum RecommendationPreference {
     /// Show me more like this
     Recommend,
@@ -155,7 +158,6 @@ pub enum RatingCriterionType {
 }
 
 impl RatingCriterionType {
-    #[allow(dead_code)]
     pub fn display_name(&self) -> String {
         match self {
             Self::StoryQuality => "Story Quality".to_string(),
@@ -169,6 +171,16 @@ impl RatingCriterionType {
         }
     }
 
+    /// Stable key used to store this criterion in `UserRating::rating_criteria`
+    pub fn storage_key(&self) -> String {
+        match self {
+            Self::Custom(name) => {
+                format!("custom_{}", name.trim().to_lowercase().replace(' ', "_"))
+            }
+            _ => format!("{:?}", self).to_lowercase(),
+        }
+    }
+
     /// Get default criteria that can be suggested to users
     pub fn get_default_criteria() -> Vec<Self> {
         vec![
@@ -244,24 +256,45 @@ impl UserRating {
 
     /// Add or update a rating criterion
     pub fn add_criterion(&mut self, criterion_type: RatingCriterionType, value: RatingValue) {
-        let key = match &criterion_type {
-            RatingCriterionType::Custom(name) => {
-                format!("custom_{}", name.to_lowercase().replace(' ', "_"))
-            }
-            _ => format!("{:?}", criterion_type).to_lowercase(),
-        };
+        self.add_weighted_criterion(criterion_type, value, None);
+    }
 
+    /// Add or update a rating criterion with an explicit weight
+    pub fn add_weighted_criterion(
+        &mut self,
+        criterion_type: RatingCriterionType,
+        value: RatingValue,
+        weight: Option<f32>,
+    ) {
         self.rating_criteria.insert(
-            key,
+            criterion_type.storage_key(),
             RatingCriterion {
                 criterion_type,
                 value,
-                weight: None,
+                weight,
             },
         );
         self.updated_at = Utc::now();
     }
 
+    /// Remove every rating criterion
+    pub fn clear_criteria(&mut self) {
+        self.rating_criteria.clear();
+        self.updated_at = Utc::now();
+    }
+
+    /// Change the overall feeling
+    pub fn set_overall_feeling(&mut self, overall_feeling: OverallFeeling) {
+        self.overall_feeling = overall_feeling;
+        self.updated_at = Utc::now();
+    }
+
+    /// Change how this rating affects recommendations
+    pub fn set_recommendation_preference(&mut self, preference: Option<RecommendationPreference>) {
+        self.recommendation_preference = preference;
+        self.updated_at = Utc::now();
+    }
+
     /// Add emotional tag
     pub fn add_emotional_tag(&mut self, tag: String) {
         let tag = tag.trim().to_lowercase();
@@ -307,13 +340,7 @@ impl UserRating {
 
     /// Get criteria of specific type
     pub fn get_criterion(&self, criterion_type: &RatingCriterionType) -> Option<&RatingCriterion> {
-        let key = match criterion_type {
-            RatingCriterionType::Custom(name) => {
-                format!("custom_{}", name.to_lowercase().replace(' ', "_"))
-            }
-            _ => format!("{:?}", criterion_type).to_lowercase(),
-        };
-        self.rating_criteria.get(&key)
+        self.rating_criteria.get(&criterion_type.storage_key())
     }
 
     /// Check if rating is positive (for recommendations)
@@ -3,4 +3,7 @@ pub mod repositories;
 
 // Re-exports for easy access
 pub use entities::collection::{Collection, CollectionAnime};
+pub use entities::rating_aggregate::{CriterionAverage, RatingAggregates};
+pub use entities::user_rating::UserRating;
 pub use repositories::collection_repository::CollectionRepository;
+pub use repositories::user_rating_repository::UserRatingRepository;
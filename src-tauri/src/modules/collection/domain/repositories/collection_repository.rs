@@ -37,4 +37,7 @@ pub trait CollectionRepository: Send + Sync {
     ) -> AppResult<Option<CollectionAnime>>;
 
     async fn update_collection_entry(&self, entry: &CollectionAnime) -> AppResult<()>;
+
+    /// Every anime that is in at least one collection
+    async fn find_collected_anime_ids(&self) -> AppResult<Vec<Uuid>>;
 }
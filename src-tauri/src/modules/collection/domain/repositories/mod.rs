@@ -1 +1,2 @@
 pub mod collection_repository;
+pub mod user_rating_repository;
@@ -0,0 +1,15 @@
+use super::super::entities::user_rating::UserRating;
+use crate::shared::errors::AppResult;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait UserRatingRepository: Send + Sync {
+    async fn find_by_anime(&self, anime_id: &Uuid) -> AppResult<Option<UserRating>>;
+    async fn find_all(&self) -> AppResult<Vec<UserRating>>;
+
+    /// Insert or update the rating for its anime
+    async fn save(&self, rating: &UserRating) -> AppResult<UserRating>;
+
+    async fn delete(&self, anime_id: &Uuid) -> AppResult<()>;
+}
@@ -1,4 +1,7 @@
-use crate::schema::{collection_anime, collections};
+use crate::modules::collection::domain::entities::user_rating::{
+    OverallFeeling, RecommendationPreference,
+};
+use crate::schema::{collection_anime, collections, user_ratings};
 use chrono::{DateTime, Utc};
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -70,3 +73,56 @@ pub struct CollectionAnimeChangeset {
     pub user_score: Option<f32>,
     pub notes: Option<String>,
 }
+
+// ============= USER RATINGS =============
+
+// For reading from database
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone)]
+#[diesel(table_name = user_ratings)]
+pub struct UserRatingModel {
+    pub id: Uuid,
+    pub anime_id: Uuid,
+    pub user_id: String,
+    pub overall_feeling: OverallFeeling,
+    pub recommendation_preference: Option<RecommendationPreference>,
+    pub rating_criteria: serde_json::Value,
+    pub emotional_tags: serde_json::Value,
+    pub standout_aspects: serde_json::Value,
+    pub personal_notes: Option<String>,
+    pub would_rewatch: Option<bool>,
+    pub composite_score: f32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// For inserting new ratings
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = user_ratings)]
+pub struct NewUserRating {
+    pub id: Uuid,
+    pub anime_id: Uuid,
+    pub user_id: String,
+    pub overall_feeling: OverallFeeling,
+    pub recommendation_preference: Option<RecommendationPreference>,
+    pub rating_criteria: serde_json::Value,
+    pub emotional_tags: serde_json::Value,
+    pub standout_aspects: serde_json::Value,
+    pub personal_notes: Option<String>,
+    pub would_rewatch: Option<bool>,
+    pub composite_score: f32,
+}
+
+// For updating existing ratings (excludes keys and created_at)
+#[derive(AsChangeset, Debug, Clone)]
+#[diesel(table_name = user_ratings)]
+#[diesel(treat_none_as_null = true)]
+pub struct UserRatingChangeset {
+    pub overall_feeling: OverallFeeling,
+    pub recommendation_preference: Option<RecommendationPreference>,
+    pub rating_criteria: serde_json::Value,
+    pub emotional_tags: serde_json::Value,
+    pub standout_aspects: serde_json::Value,
+    pub personal_notes: Option<String>,
+    pub would_rewatch: Option<bool>,
+    pub composite_score: f32,
+}
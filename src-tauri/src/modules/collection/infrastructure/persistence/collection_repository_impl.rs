@@ -353,6 +353,20 @@ impl CollectionRepository for CollectionRepositoryImpl {
         })
         .await?
     }
+
+    async fn find_collected_anime_ids(&self) -> AppResult<Vec<Uuid>> {
+        let db = Arc::clone(&self.db);
+
+        task::spawn_blocking(move || -> AppResult<Vec<Uuid>> {
+            let mut conn = db.get_connection()?;
+            let ids = collection_anime::table
+                .select(collection_anime::anime_id)
+                .distinct()
+                .load::<Uuid>(&mut conn)?;
+            Ok(ids)
+        })
+        .await?
+    }
 }
 
 // -----------------------------------------------------------------------------
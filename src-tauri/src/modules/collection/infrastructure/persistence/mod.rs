@@ -1,3 +1,5 @@
 pub mod collection_repository_impl;
+pub mod user_rating_repository_impl;
 
 pub use collection_repository_impl::CollectionRepositoryImpl;
+pub use user_rating_repository_impl::UserRatingRepositoryImpl;
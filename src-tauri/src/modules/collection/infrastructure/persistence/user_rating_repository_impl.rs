@@ -0,0 +1,152 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+use tokio::task;
+use uuid::Uuid;
+
+use crate::modules::collection::domain::{UserRating, UserRatingRepository};
+use crate::modules::collection::infrastructure::models::{
+    NewUserRating, UserRatingChangeset, UserRatingModel,
+};
+use crate::schema::user_ratings;
+use crate::shared::domain::LOCAL_USER_ID;
+use crate::shared::errors::{AppError, AppResult};
+use crate::shared::Database;
+
+pub struct UserRatingRepositoryImpl {
+    db: Arc<Database>,
+}
+
+impl UserRatingRepositoryImpl {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    fn model_to_entity(model: UserRatingModel) -> AppResult<UserRating> {
+        Ok(UserRating {
+            id: model.id,
+            anime_id: model.anime_id,
+            user_id: model.user_id,
+            overall_feeling: model.overall_feeling,
+            recommendation_preference: model.recommendation_preference,
+            rating_criteria: serde_json::from_value(model.rating_criteria)?,
+            personal_notes: model.personal_notes,
+            emotional_tags: serde_json::from_value(model.emotional_tags)?,
+            standout_aspects: serde_json::from_value(model.standout_aspects)?,
+            would_rewatch: model.would_rewatch,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        })
+    }
+
+    fn entity_to_changeset(rating: &UserRating) -> AppResult<UserRatingChangeset> {
+        Ok(UserRatingChangeset {
+            overall_feeling: rating.overall_feeling,
+            recommendation_preference: rating.recommendation_preference,
+            rating_criteria: serde_json::to_value(&rating.rating_criteria)?,
+            emotional_tags: serde_json::to_value(&rating.emotional_tags)?,
+            standout_aspects: serde_json::to_value(&rating.standout_aspects)?,
+            personal_notes: rating.personal_notes.clone(),
+            would_rewatch: rating.would_rewatch,
+            composite_score: rating.calculate_composite_score().clamp(0.0, 10.0),
+        })
+    }
+}
+
+#[async_trait]
+impl UserRatingRepository for UserRatingRepositoryImpl {
+    async fn find_by_anime(&self, anime_id: &Uuid) -> AppResult<Option<UserRating>> {
+        let db = Arc::clone(&self.db);
+        let anime_id = *anime_id;
+
+        let model = task::spawn_blocking(move || -> AppResult<Option<UserRatingModel>> {
+            let mut conn = db.get_connection()?;
+            let m = user_ratings::table
+                .filter(user_ratings::anime_id.eq(anime_id))
+                .filter(user_ratings::user_id.eq(LOCAL_USER_ID))
+                .select(UserRatingModel::as_select())
+                .first(&mut conn)
+                .optional()?;
+            Ok(m)
+        })
+        .await??;
+
+        model.map(Self::model_to_entity).transpose()
+    }
+
+    async fn find_all(&self) -> AppResult<Vec<UserRating>> {
+        let db = Arc::clone(&self.db);
+
+        let models = task::spawn_blocking(move || -> AppResult<Vec<UserRatingModel>> {
+            let mut conn = db.get_connection()?;
+            let rows = user_ratings::table
+                .filter(user_ratings::user_id.eq(LOCAL_USER_ID))
+                .order(user_ratings::updated_at.desc())
+                .select(UserRatingModel::as_select())
+                .load(&mut conn)?;
+            Ok(rows)
+        })
+        .await??;
+
+        models.into_iter().map(Self::model_to_entity).collect()
+    }
+
+    async fn save(&self, rating: &UserRating) -> AppResult<UserRating> {
+        let db = Arc::clone(&self.db);
+        let changeset = Self::entity_to_changeset(rating)?;
+        let new_row = NewUserRating {
+            id: rating.id,
+            anime_id: rating.anime_id,
+            user_id: LOCAL_USER_ID.to_string(),
+            overall_feeling: changeset.overall_feeling,
+            recommendation_preference: changeset.recommendation_preference,
+            rating_criteria: changeset.rating_criteria.clone(),
+            emotional_tags: changeset.emotional_tags.clone(),
+            standout_aspects: changeset.standout_aspects.clone(),
+            personal_notes: changeset.personal_notes.clone(),
+            would_rewatch: changeset.would_rewatch,
+            composite_score: changeset.composite_score,
+        };
+
+        let model = task::spawn_blocking(move || -> AppResult<UserRatingModel> {
+            let mut conn = db.get_connection()?;
+            let m = diesel::insert_into(user_ratings::table)
+                .values(&new_row)
+                .on_conflict((user_ratings::anime_id, user_ratings::user_id))
+                .do_update()
+                .set(&changeset)
+                .returning(UserRatingModel::as_returning())
+                .get_result(&mut conn)?;
+            Ok(m)
+        })
+        .await??;
+
+        Self::model_to_entity(model)
+    }
+
+    async fn delete(&self, anime_id: &Uuid) -> AppResult<()> {
+        let db = Arc::clone(&self.db);
+        let anime_id = *anime_id;
+
+        let deleted = task::spawn_blocking(move || -> AppResult<usize> {
+            let mut conn = db.get_connection()?;
+            let n = diesel::delete(
+                user_ratings::table
+                    .filter(user_ratings::anime_id.eq(anime_id))
+                    .filter(user_ratings::user_id.eq(LOCAL_USER_ID)),
+            )
+            .execute(&mut conn)?;
+            Ok(n)
+        })
+        .await??;
+
+        if deleted == 0 {
+            return Err(AppError::NotFound(format!(
+                "No rating found for anime {}",
+                anime_id
+            )));
+        }
+        Ok(())
+    }
+}
@@ -4,5 +4,9 @@ pub mod domain;
 pub mod infrastructure;
 
 // Re-exports for easy external access
+pub use application::rating_service::UserRatingService;
 pub use application::service::CollectionService;
-pub use domain::{Collection, CollectionAnime, CollectionRepository};
+pub use domain::{
+    Collection, CollectionAnime, CollectionRepository, RatingAggregates, UserRating,
+    UserRatingRepository,
+};
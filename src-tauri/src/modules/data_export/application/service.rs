@@ -0,0 +1,133 @@
+use super::super::domain::{
+    AniListJsonWriter, CollectionMembership, CsvWriter, ExportFormat, ExportRepository,
+    ExportSummary, LibraryExport, MalXmlWriter,
+};
+use crate::log_info;
+use crate::modules::collection::CollectionRepository;
+use crate::modules::tracking::WatchEntryRepository;
+use crate::shared::errors::{AppError, AppResult};
+use chrono::Utc;
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+use std::sync::Arc;
+
+pub struct ExportService {
+    export_repo: Arc<dyn ExportRepository>,
+    collection_repo: Arc<dyn CollectionRepository>,
+    watch_repo: Arc<dyn WatchEntryRepository>,
+}
+
+impl ExportService {
+    pub fn new(
+        export_repo: Arc<dyn ExportRepository>,
+        collection_repo: Arc<dyn CollectionRepository>,
+        watch_repo: Arc<dyn WatchEntryRepository>,
+    ) -> Self {
+        Self {
+            export_repo,
+            collection_repo,
+            watch_repo,
+        }
+    }
+
+    /// Write every tracked or collected anime to `path` in the given format
+    pub async fn export_library(
+        &self,
+        format: ExportFormat,
+        path: &Path,
+    ) -> AppResult<ExportSummary> {
+        let library = self.load_library().await?;
+
+        let (content, skipped_count) = match format {
+            ExportFormat::MalXml => (
+                MalXmlWriter::write(&library),
+                library
+                    .anime
+                    .iter()
+                    .filter(|anime| anime.mal_id.is_none())
+                    .count(),
+            ),
+            ExportFormat::Json => (AniListJsonWriter::write(&library)?, 0),
+            ExportFormat::Csv => (CsvWriter::write(&library), 0),
+        };
+
+        tokio::fs::write(path, content).await.map_err(|e| {
+            AppError::InternalError(format!(
+                "Failed to write export to {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+
+        let anime_count = library.anime.len() - skipped_count;
+        log_info!(
+            "Exported {} anime as {} to {} ({} without a usable id)",
+            anime_count,
+            format,
+            path.display(),
+            skipped_count
+        );
+
+        Ok(ExportSummary {
+            path: path.display().to_string(),
+            format,
+            anime_count: anime_count as u32,
+            collection_count: library.collections.len() as u32,
+            skipped_count: skipped_count as u32,
+        })
+    }
+
+    /// Gather collections, collection entries and watch progress per anime
+    async fn load_library(&self) -> AppResult<LibraryExport> {
+        let collections = self.collection_repo.get_all().await?;
+        let collection_names: HashMap<_, _> = collections
+            .iter()
+            .map(|collection| (collection.id, collection.name.clone()))
+            .collect();
+
+        let mut memberships: HashMap<_, Vec<CollectionMembership>> = HashMap::new();
+        for entry in self.export_repo.find_collection_entries().await? {
+            let Some(name) = collection_names.get(&entry.collection_id) else {
+                continue;
+            };
+            memberships
+                .entry(entry.anime_id)
+                .or_default()
+                .push(CollectionMembership {
+                    collection_id: entry.collection_id,
+                    collection_name: name.clone(),
+                    added_at: entry.added_at,
+                    user_score: entry.user_score,
+                    notes: entry.notes,
+                });
+        }
+
+        let mut watch_entries: HashMap<_, _> = self
+            .watch_repo
+            .find_all(None)
+            .await?
+            .into_iter()
+            .map(|entry| (entry.anime_id, entry))
+            .collect();
+
+        let anime_ids: Vec<_> = memberships
+            .keys()
+            .chain(watch_entries.keys())
+            .copied()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        let mut anime = self.export_repo.find_anime(&anime_ids).await?;
+        for exported in &mut anime {
+            exported.watch = watch_entries.remove(&exported.id);
+            exported.collections = memberships.remove(&exported.id).unwrap_or_default();
+        }
+
+        Ok(LibraryExport {
+            exported_at: Utc::now(),
+            anime,
+            collections,
+        })
+    }
+}
@@ -0,0 +1,27 @@
+use super::application::service::ExportService;
+use super::domain::{ExportFormat, ExportSummary};
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::Path;
+use std::sync::Arc;
+use tauri::State;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ExportLibraryRequest {
+    pub format: ExportFormat,
+    /// File to write, replaced when it exists
+    pub path: String,
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn export_library(
+    request: ExportLibraryRequest,
+    export_service: State<'_, Arc<ExportService>>,
+) -> Result<ExportSummary, String> {
+    export_service
+        .export_library(request.format, Path::new(&request.path))
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,101 @@
+use super::super::value_objects::ExportFormat;
+use crate::modules::anime::AnimeType;
+use crate::modules::collection::Collection;
+use crate::modules::tracking::WatchEntry;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// An anime's place in one collection
+#[derive(Debug, Clone, PartialEq)]
+pub struct CollectionMembership {
+    pub collection_id: Uuid,
+    pub collection_name: String,
+    pub added_at: DateTime<Utc>,
+    pub user_score: Option<f32>,
+    pub notes: Option<String>,
+}
+
+/// An anime as it is written to an export, together with the user's data on it
+#[derive(Debug, Clone)]
+pub struct ExportedAnime {
+    pub id: Uuid,
+    pub title: String,
+    pub title_english: Option<String>,
+    pub title_romaji: Option<String>,
+    pub anime_type: AnimeType,
+    pub episodes: Option<i32>,
+    pub mal_id: Option<String>,
+    pub anilist_id: Option<String>,
+    pub watch: Option<WatchEntry>,
+    pub collections: Vec<CollectionMembership>,
+}
+
+impl ExportedAnime {
+    /// The personal rating, else the highest score given in a collection
+    pub fn score(&self) -> Option<f32> {
+        self.watch
+            .as_ref()
+            .and_then(|watch| watch.personal_rating)
+            .or_else(|| {
+                self.collections
+                    .iter()
+                    .filter_map(|membership| membership.user_score)
+                    .reduce(f32::max)
+            })
+    }
+
+    /// Watch notes, else the notes left in collections
+    pub fn notes(&self) -> Option<String> {
+        if let Some(notes) = self.watch.as_ref().and_then(|watch| watch.notes.clone()) {
+            return Some(notes);
+        }
+
+        let notes: Vec<&str> = self
+            .collections
+            .iter()
+            .filter_map(|membership| membership.notes.as_deref())
+            .filter(|notes| !notes.trim().is_empty())
+            .collect();
+        (!notes.is_empty()).then(|| notes.join("\n"))
+    }
+
+    /// Watch tags followed by the names of the collections holding the anime
+    ///
+    /// Formats without collections keep them as tags.
+    pub fn tags(&self) -> Vec<String> {
+        let mut seen = HashSet::new();
+        self.watch
+            .iter()
+            .flat_map(|watch| watch.tags.iter().cloned())
+            .chain(
+                self.collections
+                    .iter()
+                    .map(|membership| membership.collection_name.clone()),
+            )
+            .filter(|tag| seen.insert(tag.to_lowercase()))
+            .collect()
+    }
+}
+
+/// Everything the user has put in the library, ready to be serialised
+#[derive(Debug, Clone)]
+pub struct LibraryExport {
+    pub exported_at: DateTime<Utc>,
+    pub anime: Vec<ExportedAnime>,
+    pub collections: Vec<Collection>,
+}
+
+/// What an export wrote and where
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportSummary {
+    pub path: String,
+    pub format: ExportFormat,
+    pub anime_count: u32,
+    pub collection_count: u32,
+    /// Anime left out because the format needs an id they lack
+    pub skipped_count: u32,
+}
@@ -0,0 +1,3 @@
+pub mod library_export;
+
+pub use library_export::{CollectionMembership, ExportSummary, ExportedAnime, LibraryExport};
@@ -0,0 +1,10 @@
+pub mod entities;
+pub mod repositories;
+pub mod services;
+pub mod value_objects;
+
+// Re-exports for easy access
+pub use entities::{CollectionMembership, ExportSummary, ExportedAnime, LibraryExport};
+pub use repositories::ExportRepository;
+pub use services::{AniListJsonWriter, CsvWriter, MalXmlWriter};
+pub use value_objects::ExportFormat;
@@ -0,0 +1,16 @@
+use super::super::entities::ExportedAnime;
+use crate::modules::collection::CollectionAnime;
+use crate::shared::errors::AppResult;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait ExportRepository: Send + Sync {
+    /// Every anime placed in a collection, with its score and notes there
+    async fn find_collection_entries(&self) -> AppResult<Vec<CollectionAnime>>;
+
+    /// Titles and MyAnimeList / AniList ids of the given anime
+    ///
+    /// Watch data and collections are left empty for the caller to attach.
+    async fn find_anime(&self, anime_ids: &[Uuid]) -> AppResult<Vec<ExportedAnime>>;
+}
@@ -0,0 +1,3 @@
+pub mod export_repository;
+
+pub use export_repository::ExportRepository;
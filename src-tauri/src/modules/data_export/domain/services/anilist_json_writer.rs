@@ -0,0 +1,298 @@
+use super::super::entities::{CollectionMembership, ExportedAnime, LibraryExport};
+use crate::modules::anime::AnimeType;
+use crate::modules::tracking::WatchingStatus;
+use crate::shared::errors::{AppError, AppResult};
+use chrono::{DateTime, Datelike, Utc};
+use serde::Serialize;
+
+/// Version of the JSON export layout, bumped on breaking changes
+const FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonExport {
+    format_version: u32,
+    exported_at: DateTime<Utc>,
+    #[serde(rename = "MediaListCollection")]
+    media_list_collection: JsonCollection,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonCollection {
+    user: JsonUser,
+    lists: Vec<JsonList>,
+}
+
+/// Scores are written on the 0-10 scale with decimals
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonUser {
+    media_list_options: JsonMediaListOptions,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonMediaListOptions {
+    score_format: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonList {
+    name: String,
+    is_custom_list: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    entries: Vec<JsonEntry>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonEntry {
+    media_id: Option<u32>,
+    status: Option<&'static str>,
+    score: f32,
+    progress: u32,
+    repeat: u32,
+    notes: Option<String>,
+    started_at: JsonDate,
+    completed_at: JsonDate,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    added_at: Option<DateTime<Utc>>,
+    media: JsonMedia,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonMedia {
+    id: Option<u32>,
+    id_mal: Option<u32>,
+    title: JsonTitle,
+    format: Option<&'static str>,
+    episodes: Option<i32>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonTitle {
+    user_preferred: String,
+    romaji: Option<String>,
+    english: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct JsonDate {
+    year: Option<i32>,
+    month: Option<u32>,
+    day: Option<u32>,
+}
+
+/// Statuses in the order AniList lists them
+const STATUS_LISTS: [(WatchingStatus, &str); 6] = [
+    (WatchingStatus::Watching, "Watching"),
+    (WatchingStatus::Rewatching, "Rewatching"),
+    (WatchingStatus::Completed, "Completed"),
+    (WatchingStatus::OnHold, "Paused"),
+    (WatchingStatus::Dropped, "Dropped"),
+    (WatchingStatus::PlanToWatch, "Planning"),
+];
+
+/// Writes the library in the shape of an AniList `MediaListCollection`
+///
+/// Tracked anime go into one list per status and every collection becomes a
+/// custom list carrying its own scores and notes, so the file reads back
+/// through the AniList list importer.
+pub struct AniListJsonWriter;
+
+impl AniListJsonWriter {
+    pub fn write(library: &LibraryExport) -> AppResult<String> {
+        let mut lists = Vec::new();
+
+        for (status, name) in STATUS_LISTS {
+            let entries: Vec<JsonEntry> = library
+                .anime
+                .iter()
+                .filter(|anime| Self::status(anime) == Some(status))
+                .map(|anime| Self::entry(anime, None))
+                .collect();
+
+            if !entries.is_empty() {
+                lists.push(JsonList {
+                    name: name.to_string(),
+                    is_custom_list: false,
+                    status: Some(Self::status_code(status)),
+                    description: None,
+                    entries,
+                });
+            }
+        }
+
+        for collection in &library.collections {
+            let entries = library
+                .anime
+                .iter()
+                .filter_map(|anime| {
+                    anime
+                        .collections
+                        .iter()
+                        .find(|membership| membership.collection_id == collection.id)
+                        .map(|membership| Self::entry(anime, Some(membership)))
+                })
+                .collect();
+
+            lists.push(JsonList {
+                name: collection.name.clone(),
+                is_custom_list: true,
+                status: None,
+                description: collection.description.clone(),
+                entries,
+            });
+        }
+
+        let export = JsonExport {
+            format_version: FORMAT_VERSION,
+            exported_at: library.exported_at,
+            media_list_collection: JsonCollection {
+                user: JsonUser {
+                    media_list_options: JsonMediaListOptions {
+                        score_format: "POINT_10_DECIMAL",
+                    },
+                },
+                lists,
+            },
+        };
+
+        serde_json::to_string_pretty(&export).map_err(|e| {
+            AppError::SerializationError(format!("Failed to serialise library export: {}", e))
+        })
+    }
+
+    /// Collection entries carry the score and notes given in that collection
+    fn entry(anime: &ExportedAnime, membership: Option<&CollectionMembership>) -> JsonEntry {
+        let watch = anime.watch.as_ref();
+        let anilist_id = anime.anilist_id.as_deref().and_then(|id| id.parse().ok());
+
+        let (score, notes) = match membership {
+            Some(membership) => (membership.user_score, membership.notes.clone()),
+            None => (anime.score(), watch.and_then(|watch| watch.notes.clone())),
+        };
+
+        JsonEntry {
+            media_id: anilist_id,
+            status: Self::status(anime).map(Self::status_code),
+            score: score.unwrap_or(0.0),
+            progress: watch.map(|watch| watch.episodes_watched).unwrap_or(0),
+            repeat: watch.map(|watch| watch.rewatched_count).unwrap_or(0),
+            notes,
+            started_at: Self::date(watch.and_then(|watch| watch.start_date)),
+            completed_at: Self::date(watch.and_then(|watch| watch.finish_date)),
+            added_at: membership.map(|membership| membership.added_at),
+            media: JsonMedia {
+                id: anilist_id,
+                id_mal: anime.mal_id.as_deref().and_then(|id| id.parse().ok()),
+                title: JsonTitle {
+                    user_preferred: anime.title.clone(),
+                    romaji: anime.title_romaji.clone(),
+                    english: anime.title_english.clone(),
+                },
+                format: Self::format(anime.anime_type),
+                episodes: anime.episodes,
+            },
+        }
+    }
+
+    fn status(anime: &ExportedAnime) -> Option<WatchingStatus> {
+        anime.watch.as_ref().and_then(|watch| watch.status)
+    }
+
+    fn status_code(status: WatchingStatus) -> &'static str {
+        match status {
+            WatchingStatus::Watching => "CURRENT",
+            WatchingStatus::PlanToWatch => "PLANNING",
+            WatchingStatus::Completed => "COMPLETED",
+            WatchingStatus::Dropped => "DROPPED",
+            WatchingStatus::OnHold => "PAUSED",
+            WatchingStatus::Rewatching => "REPEATING",
+        }
+    }
+
+    fn format(anime_type: AnimeType) -> Option<&'static str> {
+        match anime_type {
+            AnimeType::TV => Some("TV"),
+            AnimeType::Movie => Some("MOVIE"),
+            AnimeType::OVA => Some("OVA"),
+            AnimeType::Special => Some("SPECIAL"),
+            AnimeType::ONA => Some("ONA"),
+            AnimeType::Music => Some("MUSIC"),
+            AnimeType::Unknown => None,
+        }
+    }
+
+    fn date(date: Option<DateTime<Utc>>) -> JsonDate {
+        date.map(|date| JsonDate {
+            year: Some(date.year()),
+            month: Some(date.month()),
+            day: Some(date.day()),
+        })
+        .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::collection::Collection;
+    use crate::modules::data_import::domain::services::list_import::AniListJsonParser;
+    use crate::modules::tracking::WatchEntry;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_written_collection_reads_back_as_anilist_list() {
+        let collection = Collection::new("Space".to_string());
+
+        let id = Uuid::new_v4();
+        let mut watch = WatchEntry::new(id);
+        watch.set_status(WatchingStatus::Watching, Some(26));
+        watch.episodes_watched = 5;
+        watch.notes = Some("Session 5 next".to_string());
+
+        let bebop = ExportedAnime {
+            id,
+            title: "Cowboy Bebop".to_string(),
+            title_english: None,
+            title_romaji: Some("Cowboy Bebop".to_string()),
+            anime_type: AnimeType::TV,
+            episodes: Some(26),
+            mal_id: Some("1".to_string()),
+            anilist_id: Some("1".to_string()),
+            watch: Some(watch),
+            collections: vec![CollectionMembership {
+                collection_id: collection.id,
+                collection_name: collection.name.clone(),
+                added_at: Utc::now(),
+                user_score: Some(9.5),
+                notes: Some("Favourite".to_string()),
+            }],
+        };
+
+        let library = LibraryExport {
+            exported_at: Utc::now(),
+            anime: vec![bebop],
+            collections: vec![collection],
+        };
+
+        let json = AniListJsonWriter::write(&library).unwrap();
+        let entries = AniListJsonParser::parse(&json).unwrap();
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].external_id, "1");
+        assert_eq!(entries[0].status, Some(WatchingStatus::Watching));
+        assert_eq!(entries[0].episodes_watched, 5);
+        assert_eq!(entries[0].notes.as_deref(), Some("Session 5 next"));
+        assert_eq!(entries[0].score, Some(9.5));
+        assert_eq!(entries[0].tags, vec!["Space"]);
+        assert!(entries[0].start_date.is_some());
+    }
+}
@@ -0,0 +1,144 @@
+use super::super::entities::{ExportedAnime, LibraryExport};
+use chrono::{DateTime, Utc};
+
+const HEADER: [&str; 16] = [
+    "title",
+    "title_english",
+    "type",
+    "episodes",
+    "mal_id",
+    "anilist_id",
+    "status",
+    "episodes_watched",
+    "score",
+    "times_rewatched",
+    "favorite",
+    "start_date",
+    "finish_date",
+    "collections",
+    "tags",
+    "notes",
+];
+
+/// Writes the library as CSV, one row per anime
+///
+/// Fields are quoted per RFC 4180 so titles and notes may hold commas,
+/// quotes and line breaks.
+pub struct CsvWriter;
+
+impl CsvWriter {
+    pub fn write(library: &LibraryExport) -> String {
+        let mut csv = Self::row(HEADER.iter().map(|field| field.to_string()));
+        for anime in &library.anime {
+            csv.push_str(&Self::row(Self::fields(anime)));
+        }
+        csv
+    }
+
+    fn fields(anime: &ExportedAnime) -> Vec<String> {
+        let watch = anime.watch.as_ref();
+        let collections: Vec<&str> = anime
+            .collections
+            .iter()
+            .map(|membership| membership.collection_name.as_str())
+            .collect();
+        let watch_tags: &[String] = watch.map(|watch| watch.tags.as_slice()).unwrap_or(&[]);
+
+        vec![
+            anime.title.clone(),
+            anime.title_english.clone().unwrap_or_default(),
+            anime.anime_type.as_str().to_string(),
+            Self::optional(anime.episodes),
+            anime.mal_id.clone().unwrap_or_default(),
+            anime.anilist_id.clone().unwrap_or_default(),
+            watch
+                .and_then(|watch| watch.status)
+                .map(|status| status.as_str().to_string())
+                .unwrap_or_default(),
+            Self::optional(watch.map(|watch| watch.episodes_watched)),
+            Self::optional(anime.score()),
+            Self::optional(watch.map(|watch| watch.rewatched_count)),
+            watch
+                .map(|watch| watch.is_favorite.to_string())
+                .unwrap_or_default(),
+            Self::date(watch.and_then(|watch| watch.start_date)),
+            Self::date(watch.and_then(|watch| watch.finish_date)),
+            collections.join("; "),
+            watch_tags.join("; "),
+            anime.notes().unwrap_or_default(),
+        ]
+    }
+
+    fn row(fields: impl IntoIterator<Item = String>) -> String {
+        let mut row = fields
+            .into_iter()
+            .map(|field| Self::escape(&field))
+            .collect::<Vec<_>>()
+            .join(",");
+        row.push_str("\r\n");
+        row
+    }
+
+    fn escape(field: &str) -> String {
+        if field.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.to_string()
+        }
+    }
+
+    fn optional(value: Option<impl ToString>) -> String {
+        value.map(|value| value.to_string()).unwrap_or_default()
+    }
+
+    fn date(date: Option<DateTime<Utc>>) -> String {
+        date.map(|date| date.format("%Y-%m-%d").to_string())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::anime::AnimeType;
+    use crate::modules::tracking::{WatchEntry, WatchingStatus};
+    use uuid::Uuid;
+
+    #[test]
+    fn test_quotes_fields_with_separators() {
+        let id = Uuid::new_v4();
+        let mut watch = WatchEntry::new(id);
+        watch.status = Some(WatchingStatus::Completed);
+        watch.episodes_watched = 1;
+        watch.notes = Some("Says \"Hi\",\nthen leaves".to_string());
+
+        let library = LibraryExport {
+            exported_at: Utc::now(),
+            anime: vec![ExportedAnime {
+                id,
+                title: "Kaguya-sama: Love is War".to_string(),
+                title_english: None,
+                title_romaji: None,
+                anime_type: AnimeType::Movie,
+                episodes: Some(1),
+                mal_id: Some("43608".to_string()),
+                anilist_id: None,
+                watch: Some(watch),
+                collections: Vec::new(),
+            }],
+            collections: Vec::new(),
+        };
+
+        let csv = CsvWriter::write(&library);
+        let mut lines = csv.split("\r\n");
+
+        assert_eq!(lines.next(), Some(HEADER.join(",").as_str()));
+        assert_eq!(
+            lines.next(),
+            Some(
+                "Kaguya-sama: Love is War,,Movie,1,43608,,completed,1,,0,false,,,,,\
+                 \"Says \"\"Hi\"\",\nthen leaves\""
+            )
+        );
+    }
+}
@@ -0,0 +1,212 @@
+use super::super::entities::{ExportedAnime, LibraryExport};
+use crate::modules::tracking::WatchingStatus;
+use chrono::{DateTime, Utc};
+use quick_xml::escape::escape;
+use std::fmt::Write;
+
+/// Writes the library as a MyAnimeList `animelist.xml` export
+///
+/// MyAnimeList matches entries by `series_animedb_id` alone, so anime
+/// without a stored MAL id are left out. Collection names become tags.
+pub struct MalXmlWriter;
+
+impl MalXmlWriter {
+    pub fn write(library: &LibraryExport) -> String {
+        let anime: Vec<&ExportedAnime> = library
+            .anime
+            .iter()
+            .filter(|anime| anime.mal_id.is_some())
+            .collect();
+
+        let count = |status: WatchingStatus| {
+            anime
+                .iter()
+                .filter(|anime| Self::status(anime) == status)
+                .count()
+        };
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\" ?>\n<myanimelist>\n");
+        xml.push_str("\t<myinfo>\n");
+        Self::field(&mut xml, "user_export_type", "1");
+        Self::field(&mut xml, "user_total_anime", anime.len());
+        Self::field(
+            &mut xml,
+            "user_total_watching",
+            count(WatchingStatus::Watching) + count(WatchingStatus::Rewatching),
+        );
+        Self::field(
+            &mut xml,
+            "user_total_completed",
+            count(WatchingStatus::Completed),
+        );
+        Self::field(&mut xml, "user_total_onhold", count(WatchingStatus::OnHold));
+        Self::field(
+            &mut xml,
+            "user_total_dropped",
+            count(WatchingStatus::Dropped),
+        );
+        Self::field(
+            &mut xml,
+            "user_total_plantowatch",
+            count(WatchingStatus::PlanToWatch),
+        );
+        xml.push_str("\t</myinfo>\n");
+
+        for anime in anime {
+            Self::write_anime(&mut xml, anime);
+        }
+
+        xml.push_str("</myanimelist>\n");
+        xml
+    }
+
+    fn write_anime(xml: &mut String, anime: &ExportedAnime) {
+        let watch = anime.watch.as_ref();
+        let status = Self::status(anime);
+
+        xml.push_str("\t<anime>\n");
+        Self::field(
+            xml,
+            "series_animedb_id",
+            anime.mal_id.as_deref().unwrap_or_default(),
+        );
+        Self::field(xml, "series_title", escape(anime.title.as_str()));
+        Self::field(xml, "series_type", anime.anime_type.as_str());
+        Self::field(xml, "series_episodes", anime.episodes.unwrap_or(0));
+        Self::field(xml, "my_id", 0);
+        Self::field(
+            xml,
+            "my_watched_episodes",
+            watch.map(|watch| watch.episodes_watched).unwrap_or(0),
+        );
+        Self::field(
+            xml,
+            "my_start_date",
+            Self::date(watch.and_then(|watch| watch.start_date)),
+        );
+        Self::field(
+            xml,
+            "my_finish_date",
+            Self::date(watch.and_then(|watch| watch.finish_date)),
+        );
+        Self::field(
+            xml,
+            "my_score",
+            anime.score().map(|score| score.round() as u32).unwrap_or(0),
+        );
+        Self::field(xml, "my_status", Self::status_name(status));
+        Self::field(
+            xml,
+            "my_comments",
+            escape(anime.notes().unwrap_or_default()),
+        );
+        Self::field(
+            xml,
+            "my_times_watched",
+            watch.map(|watch| watch.rewatched_count).unwrap_or(0),
+        );
+        Self::field(xml, "my_tags", escape(anime.tags().join(", ")));
+        Self::field(
+            xml,
+            "my_rewatching",
+            u8::from(status == WatchingStatus::Rewatching),
+        );
+        Self::field(xml, "update_on_import", 1);
+        xml.push_str("\t</anime>\n");
+    }
+
+    fn field(xml: &mut String, name: &str, value: impl std::fmt::Display) {
+        let _ = writeln!(xml, "\t\t<{name}>{value}</{name}>");
+    }
+
+    /// MyAnimeList needs a status; anime only kept in collections are planned
+    fn status(anime: &ExportedAnime) -> WatchingStatus {
+        anime
+            .watch
+            .as_ref()
+            .and_then(|watch| watch.status)
+            .unwrap_or(WatchingStatus::PlanToWatch)
+    }
+
+    /// A rewatch is a completed entry with `my_rewatching` set
+    fn status_name(status: WatchingStatus) -> &'static str {
+        match status {
+            WatchingStatus::Watching => "Watching",
+            WatchingStatus::Completed | WatchingStatus::Rewatching => "Completed",
+            WatchingStatus::OnHold => "On-Hold",
+            WatchingStatus::Dropped => "Dropped",
+            WatchingStatus::PlanToWatch => "Plan to Watch",
+        }
+    }
+
+    fn date(date: Option<DateTime<Utc>>) -> String {
+        date.map(|date| date.format("%Y-%m-%d").to_string())
+            .unwrap_or_else(|| "0000-00-00".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::anime::AnimeType;
+    use crate::modules::data_export::domain::CollectionMembership;
+    use crate::modules::data_import::domain::services::list_import::MalXmlParser;
+    use crate::modules::tracking::WatchEntry;
+    use uuid::Uuid;
+
+    fn anime(title: &str, mal_id: Option<&str>) -> ExportedAnime {
+        ExportedAnime {
+            id: Uuid::new_v4(),
+            title: title.to_string(),
+            title_english: None,
+            title_romaji: None,
+            anime_type: AnimeType::TV,
+            episodes: Some(26),
+            mal_id: mal_id.map(str::to_string),
+            anilist_id: None,
+            watch: None,
+            collections: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_written_list_reads_back_as_mal_export() {
+        let mut bebop = anime("Cowboy Bebop & Friends", Some("1"));
+        let mut watch = WatchEntry::new(bebop.id);
+        watch.set_status(WatchingStatus::Rewatching, Some(26));
+        watch.episodes_watched = 4;
+        watch.rewatched_count = 1;
+        watch.personal_rating = Some(8.6);
+        bebop.watch = Some(watch);
+
+        let mut planned = anime("Monster", Some("19"));
+        planned.collections.push(CollectionMembership {
+            collection_id: Uuid::new_v4(),
+            collection_name: "Thrillers".to_string(),
+            added_at: Utc::now(),
+            user_score: Some(7.0),
+            notes: Some("<recommended>".to_string()),
+        });
+
+        let library = LibraryExport {
+            exported_at: Utc::now(),
+            anime: vec![bebop, planned, anime("Original", None)],
+            collections: Vec::new(),
+        };
+
+        let entries = MalXmlParser::parse(&MalXmlWriter::write(&library)).unwrap();
+        assert_eq!(entries.len(), 2);
+
+        assert_eq!(entries[0].external_id, "1");
+        assert_eq!(entries[0].title, "Cowboy Bebop & Friends");
+        assert_eq!(entries[0].status, Some(WatchingStatus::Rewatching));
+        assert_eq!(entries[0].episodes_watched, 4);
+        assert_eq!(entries[0].score, Some(9.0));
+        assert!(entries[0].start_date.is_some());
+
+        assert_eq!(entries[1].status, Some(WatchingStatus::PlanToWatch));
+        assert_eq!(entries[1].score, Some(7.0));
+        assert_eq!(entries[1].notes.as_deref(), Some("<recommended>"));
+        assert_eq!(entries[1].tags, vec!["Thrillers"]);
+    }
+}
@@ -0,0 +1,7 @@
+pub mod anilist_json_writer;
+pub mod csv_writer;
+pub mod mal_xml_writer;
+
+pub use anilist_json_writer::AniListJsonWriter;
+pub use csv_writer::CsvWriter;
+pub use mal_xml_writer::MalXmlWriter;
@@ -0,0 +1,3 @@
+pub mod export_format;
+
+pub use export_format::ExportFormat;
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+use tokio::task;
+use uuid::Uuid;
+
+use crate::modules::anime::AnimeType;
+use crate::modules::collection::infrastructure::models::CollectionAnime as CollectionAnimeModel;
+use crate::modules::collection::CollectionAnime;
+use crate::modules::data_export::domain::{ExportRepository, ExportedAnime};
+use crate::schema::{anime, anime_external_ids, collection_anime};
+use crate::shared::domain::value_objects::AnimeProvider;
+use crate::shared::errors::AppResult;
+use crate::shared::Database;
+
+type AnimeRow = (
+    Uuid,
+    String,
+    Option<String>,
+    Option<String>,
+    AnimeType,
+    Option<i32>,
+);
+
+pub struct ExportRepositoryImpl {
+    db: Arc<Database>,
+}
+
+impl ExportRepositoryImpl {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl ExportRepository for ExportRepositoryImpl {
+    async fn find_collection_entries(&self) -> AppResult<Vec<CollectionAnime>> {
+        let db = Arc::clone(&self.db);
+
+        let models = task::spawn_blocking(move || -> AppResult<Vec<CollectionAnimeModel>> {
+            let mut conn = db.get_connection()?;
+            let models = collection_anime::table
+                .order(collection_anime::added_at.asc())
+                .load::<CollectionAnimeModel>(&mut conn)?;
+            Ok(models)
+        })
+        .await??;
+
+        Ok(models
+            .into_iter()
+            .map(|m| CollectionAnime {
+                collection_id: m.collection_id,
+                anime_id: m.anime_id,
+                added_at: m.added_at,
+                user_score: m.user_score,
+                notes: m.notes,
+            })
+            .collect())
+    }
+
+    async fn find_anime(&self, anime_ids: &[Uuid]) -> AppResult<Vec<ExportedAnime>> {
+        let db = Arc::clone(&self.db);
+        let anime_ids = anime_ids.to_vec();
+        let mal_code = AnimeProvider::Jikan.to_string();
+        let anilist_code = AnimeProvider::AniList.to_string();
+
+        let (rows, external_ids) = task::spawn_blocking({
+            let mal_code = mal_code.clone();
+            let anilist_code = anilist_code.clone();
+            move || -> AppResult<(Vec<AnimeRow>, Vec<(Uuid, String, String)>)> {
+                let mut conn = db.get_connection()?;
+
+                let rows = anime::table
+                    .filter(anime::id.eq_any(&anime_ids))
+                    .order(anime::title_main.asc())
+                    .select((
+                        anime::id,
+                        anime::title_main,
+                        anime::title_english,
+                        anime::title_romaji,
+                        anime::anime_type,
+                        anime::episodes,
+                    ))
+                    .load::<AnimeRow>(&mut conn)?;
+
+                let external_ids = anime_external_ids::table
+                    .filter(anime_external_ids::anime_id.eq_any(&anime_ids))
+                    .filter(anime_external_ids::provider_code.eq_any([mal_code, anilist_code]))
+                    .select((
+                        anime_external_ids::anime_id,
+                        anime_external_ids::provider_code,
+                        anime_external_ids::external_id,
+                    ))
+                    .load(&mut conn)?;
+
+                Ok((rows, external_ids))
+            }
+        })
+        .await??;
+
+        let mut ids: HashMap<(Uuid, String), String> = external_ids
+            .into_iter()
+            .map(|(anime_id, provider_code, external_id)| ((anime_id, provider_code), external_id))
+            .collect();
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(id, title, title_english, title_romaji, anime_type, episodes)| ExportedAnime {
+                    id,
+                    title,
+                    title_english,
+                    title_romaji,
+                    anime_type,
+                    episodes,
+                    mal_id: ids.remove(&(id, mal_code.clone())),
+                    anilist_id: ids.remove(&(id, anilist_code.clone())),
+                    watch: None,
+                    collections: Vec::new(),
+                },
+            )
+            .collect())
+    }
+}
@@ -0,0 +1,3 @@
+pub mod export_repository_impl;
+
+pub use export_repository_impl::ExportRepositoryImpl;
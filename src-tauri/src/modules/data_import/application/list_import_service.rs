@@ -0,0 +1,432 @@
+use super::super::domain::services::import_components::progress_tracker::ProgressTracker;
+use super::super::domain::services::import_components::validation_service::{
+    EnhancedValidationSingleResult, ValidationService,
+};
+use super::super::domain::services::import_components::{
+    ImportError, ImportProgress, ImportResult, ImportedAnime, ListImportResult, SkippedAnime,
+};
+use super::super::domain::services::list_import::{
+    AniListJsonParser, ListEntry, MalXmlParser, TextListFormat, TextListMapping, TextListParser,
+    TextListPreview, TextListRow,
+};
+use crate::modules::anime::{
+    AnimeDetailed, AnimeIngestionService, AnimeRepository, AnimeSource, IngestionOptions,
+    JobPriority,
+};
+use crate::modules::provider::ProviderService;
+use crate::modules::tracking::{WatchEntry, WatchEntryRepository};
+use crate::shared::domain::value_objects::AnimeProvider;
+use crate::shared::errors::{AppError, AppResult};
+use crate::{log_debug, log_info, log_warn};
+use flate2::read::GzDecoder;
+use std::future::Future;
+use std::io::Read;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Instant;
+
+/// A list item resolved to a library anime
+struct ImportedItem {
+    anime: AnimeDetailed,
+    was_new: bool,
+    /// Id and provider reported for the anime in the import result
+    external_id: String,
+    provider: AnimeProvider,
+    /// Whether the item's watch state or score was saved
+    tracked: bool,
+}
+
+/// Imports list exports from other trackers by their provider ids
+///
+/// Unlike title imports, entries are resolved directly by the id the export
+/// carries, created through the ingestion pipeline, and the user's progress
+/// is written to watch tracking. Hand-kept text lists fall back to title
+/// validation for rows without an id.
+pub struct ListImportService {
+    anime_repo: Arc<dyn AnimeRepository>,
+    watch_repo: Arc<dyn WatchEntryRepository>,
+    provider_service: Arc<ProviderService>,
+    ingestion_service: Arc<AnimeIngestionService>,
+    validation_service: ValidationService,
+}
+
+impl ListImportService {
+    pub fn new(
+        anime_repo: Arc<dyn AnimeRepository>,
+        watch_repo: Arc<dyn WatchEntryRepository>,
+        provider_service: Arc<ProviderService>,
+        ingestion_service: Arc<AnimeIngestionService>,
+    ) -> Self {
+        Self {
+            validation_service: ValidationService::new(
+                Arc::clone(&anime_repo),
+                Arc::clone(&provider_service),
+            ),
+            anime_repo,
+            watch_repo,
+            provider_service,
+            ingestion_service,
+        }
+    }
+
+    /// Import a MyAnimeList `animelist.xml` export, gzipped or not
+    pub async fn import_mal_list(
+        &self,
+        path: &Path,
+        app_handle: Option<tauri::AppHandle>,
+    ) -> AppResult<ListImportResult> {
+        let xml = Self::read_export(path).await?;
+        let entries = MalXmlParser::parse(&xml)?;
+        log_info!(
+            "Importing {} entries from MyAnimeList export {}",
+            entries.len(),
+            path.display()
+        );
+
+        self.import_entries(entries, app_handle).await
+    }
+
+    /// Import an AniList list saved from a `MediaListCollection` query
+    ///
+    /// Entries resolve by AniList media id through the same detail query as
+    /// `AniListAdapter::get_anime_full`.
+    pub async fn import_anilist_list(
+        &self,
+        path: &Path,
+        app_handle: Option<tauri::AppHandle>,
+    ) -> AppResult<ListImportResult> {
+        let json = Self::read_export(path).await?;
+        let entries = AniListJsonParser::parse(&json)?;
+        log_info!(
+            "Importing {} entries from AniList export {}",
+            entries.len(),
+            path.display()
+        );
+
+        self.import_entries(entries, app_handle).await
+    }
+
+    /// Split the first rows of a CSV, TSV or plain text list into columns
+    pub async fn preview_text_list(
+        path: &Path,
+        format: TextListFormat,
+    ) -> AppResult<TextListPreview> {
+        let content = Self::read_export(path).await?;
+        Ok(TextListParser::preview(&content, format))
+    }
+
+    /// Import a CSV, TSV or plain text list with the given column mapping
+    ///
+    /// Rows with an external id resolve by it; the rest are matched by title
+    /// with their year and type as hints. Mapped scores are saved as personal
+    /// ratings, and watch statuses or checkboxes as the tracked status.
+    pub async fn import_text_list(
+        &self,
+        path: &Path,
+        format: TextListFormat,
+        mapping: &TextListMapping,
+        app_handle: Option<tauri::AppHandle>,
+    ) -> AppResult<ListImportResult> {
+        let content = Self::read_export(path).await?;
+        let rows = TextListParser::parse(&content, format, mapping)?;
+        log_info!(
+            "Importing {} rows from {} list {}",
+            rows.len(),
+            match format {
+                TextListFormat::Csv => "CSV",
+                TextListFormat::Tsv => "TSV",
+                TextListFormat::PlainText => "plain text",
+            },
+            path.display()
+        );
+
+        self.import_items(
+            "Text list",
+            &rows,
+            |row| &row.title,
+            |row| async move {
+                let (anime, was_new) = self.resolve_row(row).await?;
+                let (external_id, provider) = match &row.external_id {
+                    Some((provider, external_id)) => (external_id.clone(), *provider),
+                    None => ValidationService::get_primary_external_info(&anime),
+                };
+                let tracked = (row.score.is_some() || row.status.is_some())
+                    && self
+                        .track(&anime, |watch, total| row.apply_to(watch, total))
+                        .await;
+
+                Ok(ImportedItem {
+                    anime,
+                    was_new,
+                    external_id,
+                    provider,
+                    tracked,
+                })
+            },
+            app_handle,
+        )
+        .await
+    }
+
+    /// Resolve, create and track exported entries by their provider ids
+    async fn import_entries(
+        &self,
+        entries: Vec<ListEntry>,
+        app_handle: Option<tauri::AppHandle>,
+    ) -> AppResult<ListImportResult> {
+        self.import_items(
+            "List",
+            &entries,
+            |entry| &entry.title,
+            |entry| async move {
+                let (anime, was_new) = self
+                    .resolve_by_id(entry.provider, &entry.external_id)
+                    .await?;
+                let tracked = self
+                    .track(&anime, |watch, total| entry.apply_to(watch, total))
+                    .await;
+
+                Ok(ImportedItem {
+                    anime,
+                    was_new,
+                    external_id: entry.external_id.clone(),
+                    provider: entry.provider,
+                    tracked,
+                })
+            },
+            app_handle,
+        )
+        .await
+    }
+
+    /// Run `import` for each item one by one, reporting progress as it goes
+    ///
+    /// Items run sequentially so large lists stay within provider rate limits.
+    /// A failing item is recorded and the import moves on to the next one.
+    async fn import_items<'a, I, Fut>(
+        &self,
+        kind: &str,
+        items: &'a [I],
+        title: impl Fn(&I) -> &str,
+        import: impl Fn(&'a I) -> Fut,
+        app_handle: Option<tauri::AppHandle>,
+    ) -> AppResult<ListImportResult>
+    where
+        Fut: Future<Output = AppResult<ImportedItem>>,
+    {
+        let start_time = Instant::now();
+        let progress_tracker = ProgressTracker::new(app_handle);
+        let total = items.len() as u32;
+
+        let mut imported = Vec::new();
+        let mut skipped = Vec::new();
+        let mut failed = Vec::new();
+        let mut watch_entries_written = 0;
+
+        for (index, item) in items.iter().enumerate() {
+            progress_tracker.emit_import_progress(ImportProgress {
+                current: index as u32 + 1,
+                total,
+                current_title: format!("Importing: {}", title(item)),
+                processed: index as u32,
+                imported_count: imported.len() as u32,
+                failed_count: failed.len() as u32,
+                skipped_count: skipped.len() as u32,
+            });
+
+            let result = match import(item).await {
+                Ok(result) => result,
+                Err(e) => {
+                    log_warn!("Failed to import '{}': {}", title(item), e);
+                    failed.push(ImportError {
+                        title: title(item).to_string(),
+                        reason: e.to_string(),
+                    });
+                    continue;
+                }
+            };
+
+            if result.tracked {
+                watch_entries_written += 1;
+            }
+
+            if result.was_new {
+                imported.push(ImportedAnime {
+                    title: result.anime.title.main,
+                    primary_external_id: result.external_id,
+                    provider: result.provider,
+                    id: result.anime.id,
+                });
+            } else {
+                skipped.push(SkippedAnime {
+                    title: result.anime.title.main,
+                    external_id: result.external_id,
+                    provider: result.provider,
+                    reason: "Already in library".to_string(),
+                });
+            }
+        }
+
+        progress_tracker.emit_import_progress(ImportProgress {
+            current: total,
+            total,
+            current_title: "List import completed".to_string(),
+            processed: total,
+            imported_count: imported.len() as u32,
+            failed_count: failed.len() as u32,
+            skipped_count: skipped.len() as u32,
+        });
+
+        log_info!(
+            "{} import finished - Imported: {}, Skipped: {}, Failed: {}, Tracking saved: {}",
+            kind,
+            imported.len(),
+            skipped.len(),
+            failed.len(),
+            watch_entries_written
+        );
+
+        Ok(ListImportResult {
+            import: ImportResult {
+                imported,
+                failed,
+                skipped,
+                total,
+                duration_ms: start_time.elapsed().as_millis() as u64,
+            },
+            watch_entries_written,
+        })
+    }
+
+    /// The library anime with a provider id, created from the provider when missing
+    ///
+    /// Returns whether the anime was newly created.
+    async fn resolve_by_id(
+        &self,
+        provider: AnimeProvider,
+        external_id: &str,
+    ) -> AppResult<(AnimeDetailed, bool)> {
+        if let Some(existing) = self
+            .anime_repo
+            .find_by_external_id(&provider, external_id)
+            .await?
+        {
+            log_debug!(
+                "{} anime {} already in library as {}",
+                provider,
+                external_id,
+                existing.id
+            );
+            return Ok((existing, false));
+        }
+
+        let anime = self
+            .provider_service
+            .fetch_anime_by_id(external_id, provider)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!("No {} anime with ID {}", provider, external_id))
+            })?;
+
+        self.ingest(anime, format!("{} list import", provider))
+            .await
+    }
+
+    /// The library anime for a text list row, by id when it has one, otherwise by title
+    async fn resolve_row(&self, row: &TextListRow) -> AppResult<(AnimeDetailed, bool)> {
+        if let Some((provider, external_id)) = &row.external_id {
+            return self.resolve_by_id(*provider, external_id).await;
+        }
+
+        match self
+            .validation_service
+            .validate_single_title_enhanced(&row.title, row.hints)
+            .await
+        {
+            EnhancedValidationSingleResult::Found(validated) => {
+                self.ingest(validated.anime_data, "text list import".to_string())
+                    .await
+            }
+            EnhancedValidationSingleResult::AlreadyExists(existing) => Ok((existing.anime, false)),
+            EnhancedValidationSingleResult::Failed(error) => Err(AppError::NotFound(error.reason)),
+        }
+    }
+
+    async fn ingest(
+        &self,
+        anime: AnimeDetailed,
+        context: String,
+    ) -> AppResult<(AnimeDetailed, bool)> {
+        let result = self
+            .ingestion_service
+            .ingest_anime(
+                AnimeSource::DirectData { anime, context },
+                IngestionOptions {
+                    enrich_async: true,
+                    priority: JobPriority::Low,
+                    ..IngestionOptions::default()
+                },
+            )
+            .await?;
+
+        Ok((result.anime, result.was_new))
+    }
+
+    /// Apply what the list recorded to the anime's watch entry
+    ///
+    /// The anime is already imported at this point, so a failed write is
+    /// logged rather than failing the item. Returns whether it was saved.
+    async fn track(
+        &self,
+        anime: &AnimeDetailed,
+        apply: impl FnOnce(&mut WatchEntry, Option<u32>) -> AppResult<()>,
+    ) -> bool {
+        let saved = async {
+            let mut watch_entry = self
+                .watch_repo
+                .find_by_anime(&anime.id)
+                .await?
+                .unwrap_or_else(|| WatchEntry::new(anime.id));
+
+            apply(&mut watch_entry, anime.episodes.map(u32::from))?;
+            self.watch_repo.save(&watch_entry).await
+        };
+
+        match saved.await {
+            Ok(_) => true,
+            Err(e) => {
+                log_warn!(
+                    "Imported '{}' but could not save its watch progress: {}",
+                    anime.title.main,
+                    e
+                );
+                false
+            }
+        }
+    }
+
+    /// Read an export from disk, decompressing it when gzipped
+    async fn read_export(path: &Path) -> AppResult<String> {
+        let bytes = tokio::fs::read(path).await.map_err(|e| {
+            AppError::InvalidInput(format!("Failed to read {}: {}", path.display(), e))
+        })?;
+
+        if bytes.starts_with(&[0x1f, 0x8b]) {
+            let mut decoded = String::new();
+            GzDecoder::new(bytes.as_slice())
+                .read_to_string(&mut decoded)
+                .map_err(|e| {
+                    AppError::SerializationError(format!(
+                        "Failed to decompress {}: {}",
+                        path.display(),
+                        e
+                    ))
+                })?;
+            return Ok(decoded);
+        }
+
+        String::from_utf8(bytes).map_err(|e| {
+            AppError::SerializationError(format!("{} is not UTF-8: {}", path.display(), e))
+        })
+    }
+}
@@ -1 +1,2 @@
+pub mod list_import_service;
 pub mod service;
@@ -1,4 +1,4 @@
-use crate::modules::anime::AnimeRepository;
+use crate::modules::anime::{AnimeRepository, AnimeService};
 use crate::shared::errors::AppResult;
 
 use std::sync::Arc;
@@ -19,16 +19,19 @@ use super::super::domain::services::import_components::{
 pub struct ImportService {
     anime_repo: Arc<dyn AnimeRepository>,
     provider_service: Arc<ProviderService>,
+    anime_service: Arc<AnimeService>,
 }
 
 impl ImportService {
     pub fn new(
         anime_repo: Arc<dyn AnimeRepository>,
         provider_service: Arc<ProviderService>,
+        anime_service: Arc<AnimeService>,
     ) -> Self {
         Self {
             anime_repo,
             provider_service,
+            anime_service,
         }
     }
 
@@ -42,6 +45,7 @@ impl ImportService {
         let coordinator = ImportCoordinator::new(
             self.anime_repo.clone(),
             self.provider_service.clone(),
+            self.anime_service.clone(),
             app_handle,
         );
 
@@ -96,6 +100,7 @@ impl ImportService {
         let coordinator = ImportCoordinator::new(
             self.anime_repo.clone(),
             self.provider_service.clone(),
+            self.anime_service.clone(),
             app_handle,
         );
 
@@ -111,6 +116,7 @@ impl ImportService {
         let coordinator = ImportCoordinator::new(
             self.anime_repo.clone(),
             self.provider_service.clone(),
+            self.anime_service.clone(),
             app_handle.cloned(),
         );
 
@@ -126,6 +132,7 @@ impl ImportService {
         let coordinator = ImportCoordinator::new(
             self.anime_repo.clone(),
             self.provider_service.clone(),
+            self.anime_service.clone(),
             app_handle.cloned(),
         );
 
@@ -141,6 +148,7 @@ impl ImportService {
         let coordinator = ImportCoordinator::new(
             self.anime_repo.clone(),
             self.provider_service.clone(),
+            self.anime_service.clone(),
             app_handle,
         );
 
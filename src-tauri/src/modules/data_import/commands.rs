@@ -1,10 +1,16 @@
 use crate::modules::data_import::domain::services::import_components::{
     BatchQualityInsights, EnhancedValidationResult,
 };
-use crate::modules::data_import::{ImportResult, ImportService, ValidatedAnime};
+use crate::modules::data_import::domain::services::list_import::{
+    TextListFormat, TextListMapping, TextListPreview,
+};
+use crate::modules::data_import::{
+    ImportResult, ImportService, ListImportResult, ListImportService, ValidatedAnime,
+};
 use crate::{log_debug, log_info};
 use serde::Deserialize;
 use specta::Type;
+use std::path::Path;
 use std::sync::Arc;
 use tauri::State;
 
@@ -27,6 +33,32 @@ pub struct ImportValidatedAnimeRequest {
     pub validated_anime: Vec<ValidatedAnime>,
 }
 
+#[derive(Debug, Deserialize, Type)]
+pub struct ImportMalListRequest {
+    /// Path to `animelist.xml` or the `.xml.gz` file MyAnimeList hands out
+    pub path: String,
+}
+
+#[derive(Debug, Deserialize, Type)]
+pub struct ImportAniListListRequest {
+    /// Path to a saved `MediaListCollection` response
+    pub path: String,
+}
+
+#[derive(Debug, Deserialize, Type)]
+pub struct PreviewTextListRequest {
+    pub path: String,
+    pub format: TextListFormat,
+}
+
+#[derive(Debug, Deserialize, Type)]
+pub struct ImportTextListRequest {
+    pub path: String,
+    pub format: TextListFormat,
+    #[serde(default)]
+    pub mapping: TextListMapping,
+}
+
 #[derive(Debug, serde::Serialize, Type)]
 pub struct ImportBatchResult {
     pub imported_anime: Vec<ImportResult>,
@@ -144,3 +176,61 @@ pub async fn import_validated_anime(
 
     result
 }
+
+#[tauri::command]
+#[specta::specta]
+pub async fn import_mal_list(
+    request: ImportMalListRequest,
+    list_import_service: State<'_, Arc<ListImportService>>,
+    app_handle: tauri::AppHandle,
+) -> Result<ListImportResult, String> {
+    log_debug!("import_mal_list command called with {}", request.path);
+
+    list_import_service
+        .import_mal_list(Path::new(&request.path), Some(app_handle))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn import_anilist_list(
+    request: ImportAniListListRequest,
+    list_import_service: State<'_, Arc<ListImportService>>,
+    app_handle: tauri::AppHandle,
+) -> Result<ListImportResult, String> {
+    log_debug!("import_anilist_list command called with {}", request.path);
+
+    list_import_service
+        .import_anilist_list(Path::new(&request.path), Some(app_handle))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn preview_text_list(request: PreviewTextListRequest) -> Result<TextListPreview, String> {
+    ListImportService::preview_text_list(Path::new(&request.path), request.format)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn import_text_list(
+    request: ImportTextListRequest,
+    list_import_service: State<'_, Arc<ListImportService>>,
+    app_handle: tauri::AppHandle,
+) -> Result<ListImportResult, String> {
+    log_debug!("import_text_list command called with {}", request.path);
+
+    list_import_service
+        .import_text_list(
+            Path::new(&request.path),
+            request.format,
+            &request.mapping,
+            Some(app_handle),
+        )
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -38,16 +38,12 @@ impl ImportCoordinator {
     pub fn new(
         anime_repo: Arc<dyn AnimeRepository>,
         provider_service: Arc<ProviderService>,
+        anime_service: Arc<AnimeService>,
         app_handle: Option<tauri::AppHandle>,
     ) -> Self {
         let validation_service =
             ValidationService::new(anime_repo.clone(), provider_service.clone());
 
-        // Create AnimeService for proper business logic handling
-        let anime_service = Arc::new(AnimeService::new(
-            anime_repo.clone(),
-            provider_service.clone(),
-        ));
         let import_executor = ImportExecutor::new(anime_repo.clone(), anime_service);
         let progress_tracker = ProgressTracker::new(app_handle);
 
@@ -1,3 +1,4 @@
+use crate::modules::anime::AnimeType;
 use specta::Type;
 use std::collections::HashMap;
 
@@ -11,6 +12,16 @@ pub struct ImportResult {
     pub duration_ms: u64,
 }
 
+/// Outcome of importing an exported anime list
+///
+/// Anime already in the library are reported as skipped; their progress is
+/// still written to watch tracking.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Type)]
+pub struct ListImportResult {
+    pub import: ImportResult,
+    pub watch_entries_written: u32,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Type)]
 pub struct ImportedAnime {
     pub title: String,
@@ -163,3 +174,35 @@ pub struct DataEnhancementStats {
     pub most_enhanced_fields: Vec<String>,
     pub enhancement_success_rate: f32,
 }
+
+/// What a list says about a title beyond its name, used to tell apart
+/// same-named anime such as the 1999 and 2011 Hunter x Hunter
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TitleHints {
+    pub year: Option<i32>,
+    pub anime_type: Option<AnimeType>,
+}
+
+impl TitleHints {
+    pub fn is_empty(&self) -> bool {
+        self.year.is_none() && self.anime_type.is_none()
+    }
+
+    /// Whether the anime fits the hints; details the anime lacks count as a fit
+    pub fn matches(&self, anime: &crate::modules::anime::AnimeDetailed) -> bool {
+        use chrono::Datelike;
+
+        let year_matches = match (self.year, anime.aired.from) {
+            (Some(year), Some(aired)) => aired.year() == year,
+            _ => true,
+        };
+        let type_matches = match self.anime_type {
+            Some(anime_type) => {
+                anime.anime_type == AnimeType::Unknown || anime.anime_type == anime_type
+            }
+            None => true,
+        };
+
+        year_matches && type_matches
+    }
+}
@@ -10,9 +10,12 @@ use std::sync::Arc;
 
 use super::types::{
     DataQualityMetrics, DataQualitySummary, EnhancedValidatedAnime, EnhancedValidationResult,
-    ExistingAnime, ImportError, ValidatedAnime,
+    ExistingAnime, ImportError, TitleHints, ValidatedAnime,
 };
 
+/// Candidates fetched per title when hints are given to choose between them
+const HINTED_SEARCH_LIMIT: usize = 10;
+
 /// Progress event structure for real-time validation updates
 #[derive(Clone, serde::Serialize)]
 struct ValidationProgress {
@@ -78,8 +81,13 @@ impl ValidationService {
     pub async fn search_anime_multi_provider(
         &self,
         query: &str,
+        limit: usize,
     ) -> AppResult<Vec<crate::modules::anime::AnimeDetailed>> {
-        match self.provider_service.search_anime_internal(query, 1).await {
+        match self
+            .provider_service
+            .search_anime_internal(query, limit)
+            .await
+        {
             Ok(results) if !results.is_empty() => {
                 LogContext::search_operation(query, Some("provider_service"), Some(results.len()));
                 Ok(results)
@@ -123,7 +131,7 @@ impl ValidationService {
         }
 
         // STEP 2: Search providers only if not found in DB (reused from existing)
-        match self.search_anime_multi_provider(title).await {
+        match self.search_anime_multi_provider(title, 1).await {
             Ok(anime_list) if !anime_list.is_empty() => {
                 let anime = anime_list.into_iter().next().unwrap();
                 let (external_id, provider) = Self::get_primary_external_info(&anime);
@@ -202,14 +210,27 @@ impl ValidationService {
     // ========================================================================
 
     /// Enhanced validation that uses comprehensive provider data and quality assessment
+    ///
+    /// Non-empty `hints` reject library matches that contradict them and pick
+    /// the first fitting search result out of several candidates; when none
+    /// fits, the title fails rather than importing the wrong anime.
     pub async fn validate_single_title_enhanced(
         &self,
         title: &str,
+        hints: TitleHints,
     ) -> EnhancedValidationSingleResult {
         let item_timer = TimedOperation::new("validate_single_title_enhanced");
 
         // STEP 1: Check database first - return existing anime directly (no double lookup)
         match self.anime_repo.find_by_title_variations(title).await {
+            Ok(Some(existing_anime)) if !hints.matches(&existing_anime) => {
+                log_info!(
+                    "Library match '{}' for '{}' does not fit {:?}, searching providers",
+                    existing_anime.title.main,
+                    title,
+                    hints
+                );
+            }
             Ok(Some(existing_anime)) => {
                 item_timer.finish();
                 return EnhancedValidationSingleResult::AlreadyExists(ExistingAnime {
@@ -229,9 +250,21 @@ impl ValidationService {
         }
 
         // STEP 2: Use comprehensive search for enhanced data
-        match self.search_anime_multi_provider(title).await {
+        let limit = if hints.is_empty() {
+            1
+        } else {
+            HINTED_SEARCH_LIMIT
+        };
+        match self.search_anime_multi_provider(title, limit).await {
             Ok(anime_list) if !anime_list.is_empty() => {
-                let anime = anime_list.into_iter().next().unwrap();
+                let Some(anime) = anime_list.into_iter().find(|anime| hints.matches(anime)) else {
+                    log_warn!("No result for '{}' fits {:?}", title, hints);
+                    item_timer.finish();
+                    return EnhancedValidationSingleResult::Failed(ImportError {
+                        title: title.to_string(),
+                        reason: "No provider result fits the year or type given for it".to_string(),
+                    });
+                };
                 let (external_id, provider) = Self::get_primary_external_info(&anime);
 
                 // STEP 3: Re-check external ID to avoid duplicates (critical fix)
@@ -617,7 +650,10 @@ impl ValidationService {
                 };
                 let _ = app_handle.emit("validation-step", step_info);
             }
-            match self.validate_single_title_enhanced(title).await {
+            match self
+                .validate_single_title_enhanced(title, TitleHints::default())
+                .await
+            {
                 EnhancedValidationSingleResult::Found(enhanced_anime) => {
                     total_confidence += enhanced_anime.confidence_score;
                     total_completeness += enhanced_anime.data_quality.completeness_score;
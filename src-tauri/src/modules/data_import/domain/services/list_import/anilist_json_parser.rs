@@ -0,0 +1,386 @@
+use super::list_entry::ListEntry;
+use crate::modules::tracking::WatchingStatus;
+use crate::shared::domain::value_objects::AnimeProvider;
+use crate::shared::errors::{AppError, AppResult};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// A saved `MediaListCollection` query, with or without the `data` envelope
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum AniListExport {
+    Response { data: AniListData },
+    Data(AniListData),
+    Collection(AniListCollection),
+}
+
+#[derive(Debug, Deserialize)]
+struct AniListData {
+    #[serde(rename = "MediaListCollection")]
+    media_list_collection: AniListCollection,
+    /// Present when the query also asked for `User(name:)` next to the list
+    #[serde(rename = "User", default)]
+    user: Option<AniListUser>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AniListCollection {
+    #[serde(default)]
+    user: Option<AniListUser>,
+    lists: Vec<AniListList>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AniListUser {
+    #[serde(default)]
+    media_list_options: Option<AniListMediaListOptions>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AniListMediaListOptions {
+    #[serde(default)]
+    score_format: Option<ScoreFormat>,
+}
+
+/// The scale a user's AniList scores are kept on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+enum ScoreFormat {
+    #[serde(rename = "POINT_100")]
+    Point100,
+    #[serde(rename = "POINT_10_DECIMAL")]
+    Point10Decimal,
+    #[serde(rename = "POINT_10")]
+    Point10,
+    #[serde(rename = "POINT_5")]
+    Point5,
+    /// Smileys, stored as 1 to 3
+    #[serde(rename = "POINT_3")]
+    Point3,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AniListList {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    is_custom_list: bool,
+    #[serde(default)]
+    entries: Vec<AniListListEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AniListListEntry {
+    #[serde(default)]
+    media_id: Option<u32>,
+    #[serde(default)]
+    status: Option<String>,
+    #[serde(default)]
+    score: Option<f32>,
+    #[serde(default)]
+    progress: Option<u32>,
+    #[serde(default)]
+    repeat: Option<u32>,
+    #[serde(default)]
+    notes: Option<String>,
+    #[serde(default)]
+    started_at: Option<FuzzyDate>,
+    #[serde(default)]
+    completed_at: Option<FuzzyDate>,
+    #[serde(default)]
+    media: Option<AniListListMedia>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AniListListMedia {
+    #[serde(default)]
+    id: Option<u32>,
+    #[serde(default)]
+    title: Option<AniListListTitle>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AniListListTitle {
+    #[serde(default)]
+    user_preferred: Option<String>,
+    #[serde(default)]
+    romaji: Option<String>,
+    #[serde(default)]
+    english: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FuzzyDate {
+    year: Option<i32>,
+    month: Option<u32>,
+    day: Option<u32>,
+}
+
+/// Parses an AniList anime list saved from a `MediaListCollection` query
+///
+/// An anime appears once per list it is on. Entries are merged by media id
+/// and the names of custom lists holding them become tags. Scores are scaled
+/// by the `user { mediaListOptions { scoreFormat } }` saved with the list.
+pub struct AniListJsonParser;
+
+impl AniListJsonParser {
+    pub fn parse(json: &str) -> AppResult<Vec<ListEntry>> {
+        let export: AniListExport = serde_json::from_str(json).map_err(|e| {
+            AppError::SerializationError(format!("Failed to parse AniList list export: {}", e))
+        })?;
+
+        let (collection, user) = match export {
+            AniListExport::Response { data } | AniListExport::Data(data) => {
+                (data.media_list_collection, data.user)
+            }
+            AniListExport::Collection(collection) => (collection, None),
+        };
+
+        let score_format = user
+            .or(collection.user)
+            .and_then(|user| user.media_list_options)
+            .and_then(|options| options.score_format);
+        if score_format.is_none() {
+            log::warn!("AniList list export has no score format; guessing it from each score");
+        }
+
+        let mut entries: Vec<ListEntry> = Vec::new();
+        let mut positions: HashMap<String, usize> = HashMap::new();
+
+        // Status lists hold the real entry, so they are read before custom lists
+        let (custom, status): (Vec<_>, Vec<_>) = collection
+            .lists
+            .into_iter()
+            .partition(|list| list.is_custom_list);
+
+        for list in status.into_iter().chain(custom) {
+            let tag = list
+                .is_custom_list
+                .then(|| list.name.clone())
+                .flatten()
+                .map(|name| name.trim().to_string())
+                .filter(|name| !name.is_empty());
+
+            for raw in list.entries {
+                let Some(entry) = Self::to_entry(raw, score_format) else {
+                    log::warn!("Skipping AniList list entry without a media id");
+                    continue;
+                };
+
+                let position = *positions
+                    .entry(entry.external_id.clone())
+                    .or_insert_with(|| {
+                        entries.push(entry);
+                        entries.len() - 1
+                    });
+
+                if let Some(tag) = &tag {
+                    entries[position].tags.push(tag.clone());
+                }
+            }
+        }
+
+        Ok(entries)
+    }
+
+    fn to_entry(raw: AniListListEntry, score_format: Option<ScoreFormat>) -> Option<ListEntry> {
+        let media_id = raw
+            .media_id
+            .or_else(|| raw.media.as_ref().and_then(|media| media.id))?;
+
+        let title = raw
+            .media
+            .and_then(|media| media.title)
+            .and_then(|title| title.user_preferred.or(title.romaji).or(title.english))
+            .unwrap_or_else(|| format!("AniList {}", media_id));
+
+        Some(ListEntry {
+            provider: AnimeProvider::AniList,
+            external_id: media_id.to_string(),
+            title,
+            status: raw.status.as_deref().and_then(Self::parse_status),
+            score: raw
+                .score
+                .filter(|score| *score > 0.0)
+                .map(|score| Self::normalize_score(score, score_format)),
+            episodes_watched: raw.progress.unwrap_or(0),
+            rewatched_count: raw.repeat.unwrap_or(0),
+            notes: raw
+                .notes
+                .map(|notes| notes.trim().to_string())
+                .filter(|notes| !notes.is_empty()),
+            tags: Vec::new(),
+            start_date: raw.started_at.and_then(Self::parse_date),
+            finish_date: raw.completed_at.and_then(Self::parse_date),
+        })
+    }
+
+    fn parse_status(value: &str) -> Option<WatchingStatus> {
+        match value {
+            "CURRENT" => Some(WatchingStatus::Watching),
+            "PLANNING" => Some(WatchingStatus::PlanToWatch),
+            "COMPLETED" => Some(WatchingStatus::Completed),
+            "DROPPED" => Some(WatchingStatus::Dropped),
+            "PAUSED" => Some(WatchingStatus::OnHold),
+            "REPEATING" => Some(WatchingStatus::Rewatching),
+            _ => None,
+        }
+    }
+
+    /// Scale a score in the user's format to 0-10, rounded to one decimal
+    ///
+    /// Without a known format, anything above 10 is read as out of 100.
+    fn normalize_score(score: f32, format: Option<ScoreFormat>) -> f32 {
+        let scale = match format {
+            Some(ScoreFormat::Point100) => 100.0,
+            Some(ScoreFormat::Point10Decimal | ScoreFormat::Point10) => 10.0,
+            Some(ScoreFormat::Point5) => 5.0,
+            Some(ScoreFormat::Point3) => 3.0,
+            Some(ScoreFormat::Unknown) | None if score > 10.0 => 100.0,
+            Some(ScoreFormat::Unknown) | None => 10.0,
+        };
+
+        ((score / scale * 10.0).min(10.0) * 10.0).round() / 10.0
+    }
+
+    /// A date missing its day or month falls back to the first of it
+    fn parse_date(date: FuzzyDate) -> Option<DateTime<Utc>> {
+        NaiveDate::from_ymd_opt(
+            date.year?,
+            date.month.unwrap_or(1).max(1),
+            date.day.unwrap_or(1).max(1),
+        )?
+        .and_hms_opt(0, 0, 0)
+        .map(|date| date.and_utc())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Datelike;
+
+    const EXPORT: &str = r#"{
+        "data": {
+            "MediaListCollection": {
+                "lists": [
+                    {
+                        "name": "Favourite OSTs",
+                        "isCustomList": true,
+                        "entries": [
+                            { "mediaId": 1, "status": "COMPLETED", "score": 90, "progress": 26 }
+                        ]
+                    },
+                    {
+                        "name": "Completed",
+                        "isCustomList": false,
+                        "status": "COMPLETED",
+                        "entries": [
+                            {
+                                "mediaId": 1,
+                                "status": "COMPLETED",
+                                "score": 90,
+                                "progress": 26,
+                                "repeat": 2,
+                                "notes": "  ",
+                                "startedAt": { "year": 2018, "month": 5, "day": null },
+                                "completedAt": { "year": null, "month": null, "day": null },
+                                "media": { "id": 1, "title": { "userPreferred": "Cowboy Bebop" } }
+                            }
+                        ]
+                    },
+                    {
+                        "name": "Watching",
+                        "isCustomList": false,
+                        "entries": [
+                            {
+                                "status": "REPEATING",
+                                "score": 7.5,
+                                "progress": 3,
+                                "media": { "id": 21, "title": { "romaji": "One Piece" } }
+                            }
+                        ]
+                    }
+                ]
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_merges_entries_across_lists() {
+        let entries = AniListJsonParser::parse(EXPORT).unwrap();
+        assert_eq!(entries.len(), 2);
+
+        let bebop = &entries[0];
+        assert_eq!(bebop.provider, AnimeProvider::AniList);
+        assert_eq!(bebop.external_id, "1");
+        assert_eq!(bebop.title, "Cowboy Bebop");
+        assert_eq!(bebop.status, Some(WatchingStatus::Completed));
+        assert_eq!(bebop.score, Some(9.0));
+        assert_eq!(bebop.rewatched_count, 2);
+        assert_eq!(bebop.notes, None);
+        assert_eq!(bebop.tags, vec!["Favourite OSTs"]);
+
+        let started = bebop.start_date.unwrap();
+        assert_eq!(
+            (started.year(), started.month(), started.day()),
+            (2018, 5, 1)
+        );
+        assert_eq!(bebop.finish_date, None);
+    }
+
+    #[test]
+    fn test_reads_media_id_from_media_and_bare_collections() {
+        let entries = AniListJsonParser::parse(EXPORT).unwrap();
+        let one_piece = &entries[1];
+        assert_eq!(one_piece.external_id, "21");
+        assert_eq!(one_piece.title, "One Piece");
+        assert_eq!(one_piece.status, Some(WatchingStatus::Rewatching));
+        assert_eq!(one_piece.score, Some(7.5));
+
+        let bare = r#"{ "lists": [ { "entries": [ { "mediaId": 5, "status": "PLANNING" } ] } ] }"#;
+        let entries = AniListJsonParser::parse(bare).unwrap();
+        assert_eq!(entries[0].title, "AniList 5");
+        assert_eq!(entries[0].status, Some(WatchingStatus::PlanToWatch));
+    }
+
+    #[test]
+    fn test_scales_scores_by_the_saved_score_format() {
+        let export = |format: &str, score: f32| {
+            format!(
+                r#"{{ "data": {{ "MediaListCollection": {{
+                    "user": {{ "mediaListOptions": {{ "scoreFormat": "{}" }} }},
+                    "lists": [ {{ "entries": [ {{ "mediaId": 1, "score": {} }} ] }} ]
+                }} }} }}"#,
+                format, score
+            )
+        };
+        let score = |format: &str, score: f32| {
+            AniListJsonParser::parse(&export(format, score)).unwrap()[0].score
+        };
+
+        assert_eq!(score("POINT_100", 85.0), Some(8.5));
+        assert_eq!(score("POINT_100", 9.0), Some(0.9));
+        assert_eq!(score("POINT_10_DECIMAL", 7.5), Some(7.5));
+        assert_eq!(score("POINT_10", 8.0), Some(8.0));
+        assert_eq!(score("POINT_5", 4.0), Some(8.0));
+        assert_eq!(score("POINT_3", 2.0), Some(6.7));
+        assert_eq!(score("POINT_3", 0.0), None);
+
+        let with_user = r#"{ "data": {
+            "User": { "mediaListOptions": { "scoreFormat": "POINT_5" } },
+            "MediaListCollection": { "lists": [ { "entries": [ { "mediaId": 1, "score": 3 } ] } ] }
+        } }"#;
+        assert_eq!(
+            AniListJsonParser::parse(with_user).unwrap()[0].score,
+            Some(6.0)
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use crate::modules::tracking::{WatchEntry, WatchingStatus};
+use crate::shared::domain::value_objects::AnimeProvider;
+use crate::shared::errors::AppResult;
+use chrono::{DateTime, Utc};
+
+/// One anime of an exported list together with the user's progress on it
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListEntry {
+    pub provider: AnimeProvider,
+    pub external_id: String,
+    pub title: String,
+    pub status: Option<WatchingStatus>,
+    /// Personal score on the 0-10 scale used by watch tracking
+    pub score: Option<f32>,
+    pub episodes_watched: u32,
+    pub rewatched_count: u32,
+    pub notes: Option<String>,
+    pub tags: Vec<String>,
+    pub start_date: Option<DateTime<Utc>>,
+    pub finish_date: Option<DateTime<Utc>>,
+}
+
+impl ListEntry {
+    /// Overwrite the tracked state with what the list recorded
+    ///
+    /// Status and progress go through `WatchEntry::set_status` and
+    /// `WatchEntry::record_progress`, so dates follow the status and a
+    /// finished show completes. Progress past `total_episodes` stops at the
+    /// last episode. Values the list leaves empty keep what is already
+    /// tracked, so re-importing an older export does not wipe notes or tags.
+    pub fn apply_to(&self, entry: &mut WatchEntry, total_episodes: Option<u32>) -> AppResult<()> {
+        if let Some(status) = self.status {
+            entry.set_status(status, total_episodes);
+        }
+
+        // Completing fills progress up to the episode count already
+        let completed = self.status == Some(WatchingStatus::Completed);
+        if !(completed && total_episodes.is_some()) {
+            let episodes_watched = match total_episodes {
+                Some(total) if total > 0 => self.episodes_watched.min(total),
+                _ => self.episodes_watched,
+            };
+            entry.record_progress(episodes_watched, total_episodes)?;
+        }
+
+        if self.score.is_some() {
+            entry.set_rating(self.score)?;
+        }
+        entry.rewatched_count = self.rewatched_count;
+        if self.notes.is_some() {
+            entry.notes = self.notes.clone();
+        }
+        if !self.tags.is_empty() {
+            entry.set_tags(self.tags.clone());
+        }
+        if self.start_date.is_some() {
+            entry.start_date = self.start_date;
+        }
+        if self.finish_date.is_some() {
+            entry.finish_date = self.finish_date;
+        }
+        entry.updated_at = Utc::now();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn entry(status: Option<WatchingStatus>, episodes_watched: u32) -> ListEntry {
+        ListEntry {
+            provider: AnimeProvider::Jikan,
+            external_id: "1".to_string(),
+            title: "Cowboy Bebop".to_string(),
+            status,
+            score: None,
+            episodes_watched,
+            rewatched_count: 0,
+            notes: None,
+            tags: Vec::new(),
+            start_date: None,
+            finish_date: None,
+        }
+    }
+
+    #[test]
+    fn test_progress_past_the_episode_count_completes_the_entry() {
+        let mut watch = WatchEntry::new(Uuid::new_v4());
+        entry(Some(WatchingStatus::Watching), 30)
+            .apply_to(&mut watch, Some(12))
+            .unwrap();
+
+        assert_eq!(watch.episodes_watched, 12);
+        assert_eq!(watch.status, Some(WatchingStatus::Completed));
+        assert!(watch.start_date.is_some());
+        assert!(watch.finish_date.is_some());
+    }
+
+    #[test]
+    fn test_progress_is_kept_when_the_episode_count_is_unknown() {
+        let mut watch = WatchEntry::new(Uuid::new_v4());
+        entry(None, 30).apply_to(&mut watch, None).unwrap();
+
+        assert_eq!(watch.episodes_watched, 30);
+        assert_eq!(watch.status, Some(WatchingStatus::Watching));
+    }
+
+    #[test]
+    fn test_completed_entries_fill_progress_to_the_episode_count() {
+        let mut watch = WatchEntry::new(Uuid::new_v4());
+        entry(Some(WatchingStatus::Completed), 0)
+            .apply_to(&mut watch, Some(26))
+            .unwrap();
+
+        assert_eq!(watch.episodes_watched, 26);
+        assert_eq!(watch.status, Some(WatchingStatus::Completed));
+    }
+}
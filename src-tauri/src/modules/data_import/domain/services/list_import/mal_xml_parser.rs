@@ -0,0 +1,219 @@
+use super::list_entry::ListEntry;
+use crate::modules::tracking::WatchingStatus;
+use crate::shared::domain::value_objects::AnimeProvider;
+use crate::shared::errors::{AppError, AppResult};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Deserialize;
+
+/// Root of a MyAnimeList `animelist.xml` export
+#[derive(Debug, Deserialize)]
+struct MalExport {
+    #[serde(rename = "anime", default)]
+    anime: Vec<MalExportAnime>,
+}
+
+/// Every field is read as text: exports leave numbers empty and use
+/// `0000-00-00` for unknown dates.
+#[derive(Debug, Deserialize)]
+struct MalExportAnime {
+    #[serde(default)]
+    series_animedb_id: String,
+    #[serde(default)]
+    series_title: String,
+    #[serde(default)]
+    my_watched_episodes: String,
+    #[serde(default)]
+    my_start_date: String,
+    #[serde(default)]
+    my_finish_date: String,
+    #[serde(default)]
+    my_score: String,
+    #[serde(default)]
+    my_status: String,
+    #[serde(default)]
+    my_comments: String,
+    #[serde(default)]
+    my_times_watched: String,
+    #[serde(default)]
+    my_rewatching: String,
+    #[serde(default)]
+    my_tags: String,
+}
+
+/// Parses the `animelist.xml` list export of MyAnimeList
+///
+/// Entries carry the MAL anime id, so they resolve through Jikan without
+/// any title matching.
+pub struct MalXmlParser;
+
+impl MalXmlParser {
+    pub fn parse(xml: &str) -> AppResult<Vec<ListEntry>> {
+        let export: MalExport = quick_xml::de::from_str(xml).map_err(|e| {
+            AppError::SerializationError(format!("Failed to parse MyAnimeList export: {}", e))
+        })?;
+
+        Ok(export
+            .anime
+            .into_iter()
+            .filter_map(|anime| {
+                let entry = Self::to_entry(anime);
+                if entry.is_none() {
+                    log::warn!("Skipping MyAnimeList export entry without an anime id");
+                }
+                entry
+            })
+            .collect())
+    }
+
+    fn to_entry(anime: MalExportAnime) -> Option<ListEntry> {
+        let mal_id: u32 = anime.series_animedb_id.trim().parse().ok()?;
+
+        let mut status = Self::parse_status(&anime.my_status);
+        if anime.my_rewatching.trim() == "1" {
+            status = Some(WatchingStatus::Rewatching);
+        }
+
+        Some(ListEntry {
+            provider: AnimeProvider::Jikan,
+            external_id: mal_id.to_string(),
+            title: anime.series_title.trim().to_string(),
+            status,
+            score: Self::parse_number(&anime.my_score)
+                .filter(|score| *score > 0)
+                .map(|score| score.min(10) as f32),
+            episodes_watched: Self::parse_number(&anime.my_watched_episodes).unwrap_or(0),
+            rewatched_count: Self::parse_number(&anime.my_times_watched).unwrap_or(0),
+            notes: Some(anime.my_comments.trim().to_string()).filter(|notes| !notes.is_empty()),
+            tags: anime
+                .my_tags
+                .split(',')
+                .map(|tag| tag.trim().to_string())
+                .filter(|tag| !tag.is_empty())
+                .collect(),
+            start_date: Self::parse_date(&anime.my_start_date),
+            finish_date: Self::parse_date(&anime.my_finish_date),
+        })
+    }
+
+    /// Exports spell statuses out, older ones use MAL's numeric codes
+    fn parse_status(value: &str) -> Option<WatchingStatus> {
+        match value.trim().to_lowercase().as_str() {
+            "watching" | "1" => Some(WatchingStatus::Watching),
+            "completed" | "2" => Some(WatchingStatus::Completed),
+            "on-hold" | "on hold" | "3" => Some(WatchingStatus::OnHold),
+            "dropped" | "4" => Some(WatchingStatus::Dropped),
+            "plan to watch" | "6" => Some(WatchingStatus::PlanToWatch),
+            _ => None,
+        }
+    }
+
+    fn parse_number(value: &str) -> Option<u32> {
+        value.trim().parse().ok()
+    }
+
+    /// `2021-04-00` keeps the known year and month; `0000-00-00` is no date
+    fn parse_date(value: &str) -> Option<DateTime<Utc>> {
+        let mut parts = value.trim().splitn(3, '-').map(|part| part.parse::<u32>());
+        let year = parts.next()?.ok().filter(|year| *year > 0)?;
+        let month = parts.next().and_then(Result::ok).unwrap_or(0).max(1);
+        let day = parts.next().and_then(Result::ok).unwrap_or(0).max(1);
+
+        NaiveDate::from_ymd_opt(year as i32, month, day)?
+            .and_hms_opt(0, 0, 0)
+            .map(|date| date.and_utc())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Datelike;
+
+    const EXPORT: &str = r#"<?xml version="1.0" encoding="UTF-8" ?>
+<myanimelist>
+    <myinfo>
+        <user_id>123</user_id>
+        <user_name>example</user_name>
+        <user_export_type>1</user_export_type>
+    </myinfo>
+    <anime>
+        <series_animedb_id>1</series_animedb_id>
+        <series_title><![CDATA[Cowboy Bebop]]></series_title>
+        <series_type>TV</series_type>
+        <series_episodes>26</series_episodes>
+        <my_id>0</my_id>
+        <my_watched_episodes>26</my_watched_episodes>
+        <my_start_date>2019-03-02</my_start_date>
+        <my_finish_date>2019-04-00</my_finish_date>
+        <my_score>9</my_score>
+        <my_status>Completed</my_status>
+        <my_comments><![CDATA[Rewatch the finale]]></my_comments>
+        <my_times_watched>1</my_times_watched>
+        <my_rewatching>0</my_rewatching>
+        <my_tags><![CDATA[space, jazz]]></my_tags>
+    </anime>
+    <anime>
+        <series_animedb_id>5114</series_animedb_id>
+        <series_title><![CDATA[Fullmetal Alchemist: Brotherhood]]></series_title>
+        <my_watched_episodes>0</my_watched_episodes>
+        <my_start_date>0000-00-00</my_start_date>
+        <my_finish_date>0000-00-00</my_finish_date>
+        <my_score>0</my_score>
+        <my_status>Plan to Watch</my_status>
+        <my_comments><![CDATA[]]></my_comments>
+        <my_times_watched>0</my_times_watched>
+        <my_tags><![CDATA[]]></my_tags>
+    </anime>
+    <anime>
+        <series_title><![CDATA[No id]]></series_title>
+    </anime>
+</myanimelist>"#;
+
+    #[test]
+    fn test_parses_entries_with_progress() {
+        let entries = MalXmlParser::parse(EXPORT).unwrap();
+        assert_eq!(entries.len(), 2);
+
+        let bebop = &entries[0];
+        assert_eq!(bebop.provider, AnimeProvider::Jikan);
+        assert_eq!(bebop.external_id, "1");
+        assert_eq!(bebop.title, "Cowboy Bebop");
+        assert_eq!(bebop.status, Some(WatchingStatus::Completed));
+        assert_eq!(bebop.score, Some(9.0));
+        assert_eq!(bebop.episodes_watched, 26);
+        assert_eq!(bebop.rewatched_count, 1);
+        assert_eq!(bebop.notes.as_deref(), Some("Rewatch the finale"));
+        assert_eq!(bebop.tags, vec!["space", "jazz"]);
+        assert_eq!(bebop.start_date.map(|d| d.day()), Some(2));
+
+        let finish = bebop.finish_date.unwrap();
+        assert_eq!((finish.month(), finish.day()), (4, 1));
+    }
+
+    #[test]
+    fn test_empty_values_are_left_unset() {
+        let entries = MalXmlParser::parse(EXPORT).unwrap();
+        let planned = &entries[1];
+
+        assert_eq!(planned.status, Some(WatchingStatus::PlanToWatch));
+        assert_eq!(planned.score, None);
+        assert_eq!(planned.notes, None);
+        assert!(planned.tags.is_empty());
+        assert_eq!(planned.start_date, None);
+        assert_eq!(planned.finish_date, None);
+    }
+
+    #[test]
+    fn test_rewatching_flag_and_numeric_status() {
+        assert_eq!(
+            MalXmlParser::parse_status("3"),
+            Some(WatchingStatus::OnHold)
+        );
+
+        let xml = "<myanimelist><anime><series_animedb_id>30</series_animedb_id>\
+                   <my_status>Completed</my_status><my_rewatching>1</my_rewatching>\
+                   </anime></myanimelist>";
+        let entries = MalXmlParser::parse(xml).unwrap();
+        assert_eq!(entries[0].status, Some(WatchingStatus::Rewatching));
+    }
+}
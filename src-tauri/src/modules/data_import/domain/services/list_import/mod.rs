@@ -0,0 +1,11 @@
+pub mod anilist_json_parser;
+pub mod list_entry;
+pub mod mal_xml_parser;
+pub mod text_list_parser;
+
+pub use anilist_json_parser::AniListJsonParser;
+pub use list_entry::ListEntry;
+pub use mal_xml_parser::MalXmlParser;
+pub use text_list_parser::{
+    TextListFormat, TextListMapping, TextListParser, TextListPreview, TextListRow,
+};
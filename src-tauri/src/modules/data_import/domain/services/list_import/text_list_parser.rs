@@ -0,0 +1,458 @@
+use super::super::import_components::TitleHints;
+use crate::modules::anime::AnimeType;
+use crate::modules::tracking::{WatchEntry, WatchingStatus};
+use crate::shared::domain::value_objects::AnimeProvider;
+use crate::shared::errors::{AppError, AppResult};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Rows returned when previewing a list for column mapping
+const PREVIEW_ROWS: usize = 20;
+
+/// Layout of a hand-kept anime list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum TextListFormat {
+    Csv,
+    Tsv,
+    /// One title per line; bullets and numbering are ignored, and a ticked
+    /// or empty checkbox marks the title completed or planned
+    PlainText,
+}
+
+/// Which zero-based columns hold what
+///
+/// Only `title` is required. Plain text lists have a single column.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TextListMapping {
+    pub title: usize,
+    pub year: Option<usize>,
+    pub anime_type: Option<usize>,
+    pub external_id: Option<usize>,
+    /// Provider the external ids belong to, MyAnimeList when not given
+    pub external_id_provider: Option<AnimeProvider>,
+    pub score: Option<usize>,
+    /// A watch status, or a watched flag such as "yes" or "x"
+    pub status: Option<usize>,
+    /// Skip the first row as column names
+    pub has_header: bool,
+}
+
+/// The first rows of a list, split into columns, for choosing a mapping
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct TextListPreview {
+    pub rows: Vec<Vec<String>>,
+    pub column_count: u32,
+    pub total_rows: u32,
+}
+
+/// One mapped row of a hand-kept list
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextListRow {
+    pub title: String,
+    pub hints: TitleHints,
+    pub external_id: Option<(AnimeProvider, String)>,
+    /// Personal score on the 0-10 scale used by watch tracking
+    pub score: Option<f32>,
+    pub status: Option<WatchingStatus>,
+}
+
+impl TextListRow {
+    /// Overwrite the tracked status and rating with what the row recorded
+    ///
+    /// Like `ListEntry::apply_to`, the status goes through
+    /// `WatchEntry::set_status` and values the row leaves empty are kept.
+    pub fn apply_to(&self, entry: &mut WatchEntry, total_episodes: Option<u32>) -> AppResult<()> {
+        if let Some(status) = self.status {
+            entry.set_status(status, total_episodes);
+        }
+        if self.score.is_some() {
+            entry.set_rating(self.score)?;
+        }
+        entry.updated_at = Utc::now();
+        Ok(())
+    }
+}
+
+/// Parses CSV, TSV and plain text lists into titles with hints
+///
+/// CSV follows RFC 4180 quoting. A year in parentheses after a title, as in
+/// "Hunter x Hunter (2011)", is used when no year column is mapped.
+pub struct TextListParser;
+
+impl TextListParser {
+    pub fn preview(content: &str, format: TextListFormat) -> TextListPreview {
+        let records = Self::records(content, format);
+
+        TextListPreview {
+            column_count: records.iter().map(Vec::len).max().unwrap_or(0) as u32,
+            total_rows: records.len() as u32,
+            rows: records.into_iter().take(PREVIEW_ROWS).collect(),
+        }
+    }
+
+    pub fn parse(
+        content: &str,
+        format: TextListFormat,
+        mapping: &TextListMapping,
+    ) -> AppResult<Vec<TextListRow>> {
+        let records = Self::records(content, format);
+        let skip = usize::from(mapping.has_header);
+        let checkboxes: Vec<Option<WatchingStatus>> = match format {
+            TextListFormat::PlainText => Self::plain_lines(content)
+                .map(|(_, checkbox)| checkbox)
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        if records.len() <= skip {
+            return Err(AppError::InvalidInput("The list has no rows".to_string()));
+        }
+
+        if !records.iter().any(|record| record.len() > mapping.title) {
+            return Err(AppError::InvalidInput(format!(
+                "Title column {} is past the last column",
+                mapping.title + 1
+            )));
+        }
+
+        let provider = mapping.external_id_provider.unwrap_or(AnimeProvider::Jikan);
+
+        Ok(records
+            .iter()
+            .enumerate()
+            .skip(skip)
+            .filter_map(|(index, record)| {
+                let column = |index: Option<usize>| {
+                    index
+                        .and_then(|index| record.get(index))
+                        .map(|value| value.trim())
+                        .filter(|value| !value.is_empty())
+                };
+
+                let (title, title_year) = Self::split_year(column(Some(mapping.title))?);
+                Some(TextListRow {
+                    title,
+                    hints: TitleHints {
+                        year: column(mapping.year).and_then(Self::year).or(title_year),
+                        anime_type: column(mapping.anime_type).and_then(Self::anime_type),
+                    },
+                    external_id: column(mapping.external_id)
+                        .filter(|id| id.chars().all(|c| c.is_ascii_digit()))
+                        .map(|id| (provider, id.to_string())),
+                    score: column(mapping.score).and_then(Self::score),
+                    status: column(mapping.status)
+                        .and_then(Self::status)
+                        .or_else(|| checkboxes.get(index).copied().flatten()),
+                })
+            })
+            .collect())
+    }
+
+    fn records(content: &str, format: TextListFormat) -> Vec<Vec<String>> {
+        let content = content.trim_start_matches('\u{feff}');
+
+        match format {
+            TextListFormat::Csv => Self::delimited(content, ','),
+            TextListFormat::Tsv => Self::delimited(content, '\t'),
+            TextListFormat::PlainText => Self::plain_lines(content)
+                .map(|(line, _)| vec![line.to_string()])
+                .collect(),
+        }
+    }
+
+    /// Titles of a plain text list with the status their checkbox gives
+    ///
+    /// Blank lines and headings such as "# Watched" are skipped; a "#" with
+    /// no space after it is part of a title, as in "#Compass 2.0".
+    fn plain_lines(content: &str) -> impl Iterator<Item = (&str, Option<WatchingStatus>)> {
+        content
+            .trim_start_matches('\u{feff}')
+            .lines()
+            .map(Self::strip_marker)
+            .filter(|(line, _)| !line.is_empty() && !Self::is_heading(line))
+    }
+
+    fn is_heading(line: &str) -> bool {
+        let text = line.trim_start_matches('#');
+        line.starts_with('#') && (text.is_empty() || text.starts_with(char::is_whitespace))
+    }
+
+    /// Split delimited text into records, honouring quoted fields
+    fn delimited(content: &str, delimiter: char) -> Vec<Vec<String>> {
+        let mut records = Vec::new();
+        let mut record = Vec::new();
+        let mut field = String::new();
+        let mut quoted = false;
+        let mut chars = content.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '"' if quoted && chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' if quoted => quoted = false,
+                '"' if field.is_empty() => quoted = true,
+                c if quoted => field.push(c),
+                c if c == delimiter => record.push(std::mem::take(&mut field)),
+                '\r' => {}
+                '\n' => {
+                    record.push(std::mem::take(&mut field));
+                    records.push(std::mem::take(&mut record));
+                }
+                c => field.push(c),
+            }
+        }
+
+        if !field.is_empty() || !record.is_empty() {
+            record.push(field);
+            records.push(record);
+        }
+
+        records.retain(|record| record.iter().any(|field| !field.trim().is_empty()));
+        records
+    }
+
+    /// Drop list bullets, numbering and checkboxes from a plain text line
+    ///
+    /// Markers need a space after them so titles like "2.43" stay intact. A
+    /// ticked checkbox reads as completed and an empty one as planned.
+    fn strip_marker(line: &str) -> (&str, Option<WatchingStatus>) {
+        let marker = |rest: &str| rest.starts_with(char::is_whitespace);
+        let mut line = line.trim();
+
+        if let Some(rest) = line
+            .strip_prefix(['-', '*', '•'])
+            .filter(|rest| marker(rest))
+        {
+            line = rest.trim_start();
+        }
+
+        let digits = line.chars().take_while(char::is_ascii_digit).count();
+        if let Some(rest) = line[digits..]
+            .strip_prefix(['.', ')'])
+            .filter(|rest| digits > 0 && marker(rest))
+        {
+            line = rest.trim_start();
+        }
+
+        let mut status = None;
+        for (checkbox, checked) in [
+            ("[ ]", WatchingStatus::PlanToWatch),
+            ("[x]", WatchingStatus::Completed),
+            ("[X]", WatchingStatus::Completed),
+        ] {
+            if let Some(rest) = line.strip_prefix(checkbox) {
+                line = rest.trim_start();
+                status = Some(checked);
+            }
+        }
+
+        (line, status)
+    }
+
+    /// Separate a trailing "(2011)" from a title
+    fn split_year(title: &str) -> (String, Option<i32>) {
+        let year = title
+            .strip_suffix(')')
+            .and_then(|rest| rest.rsplit_once('('))
+            .and_then(|(name, year)| Some((name.trim_end(), Self::year(year)?)))
+            .filter(|(name, _)| !name.is_empty());
+
+        match year {
+            Some((name, year)) => (name.to_string(), Some(year)),
+            None => (title.to_string(), None),
+        }
+    }
+
+    /// A four digit year, also read from the start of a date
+    fn year(value: &str) -> Option<i32> {
+        let value = value.trim();
+        let digits: String = value.chars().take_while(char::is_ascii_digit).collect();
+        let rest = &value[digits.len()..];
+
+        if digits.len() != 4 || !(rest.is_empty() || rest.starts_with(['-', '/', '.'])) {
+            return None;
+        }
+
+        digits
+            .parse()
+            .ok()
+            .filter(|year| (1900..=2100).contains(year))
+    }
+
+    fn anime_type(value: &str) -> Option<AnimeType> {
+        match value.to_ascii_lowercase().as_str() {
+            "tv" | "series" | "tv series" => Some(AnimeType::TV),
+            "movie" | "film" => Some(AnimeType::Movie),
+            "ova" | "oad" => Some(AnimeType::OVA),
+            "ona" | "web" => Some(AnimeType::ONA),
+            "special" | "tv special" => Some(AnimeType::Special),
+            "music" => Some(AnimeType::Music),
+            _ => None,
+        }
+    }
+
+    /// A status name, or a watched flag read as completed or planned
+    fn status(value: &str) -> Option<WatchingStatus> {
+        match value.to_ascii_lowercase().replace(['-', '_'], " ").as_str() {
+            "watching" | "current" | "in progress" => Some(WatchingStatus::Watching),
+            "completed" | "complete" | "watched" | "finished" | "done" | "yes" | "y" | "true"
+            | "x" | "1" => Some(WatchingStatus::Completed),
+            "on hold" | "paused" => Some(WatchingStatus::OnHold),
+            "dropped" => Some(WatchingStatus::Dropped),
+            "plan to watch" | "planning" | "planned" | "no" | "n" | "false" | "0" => {
+                Some(WatchingStatus::PlanToWatch)
+            }
+            "rewatching" | "repeating" => Some(WatchingStatus::Rewatching),
+            _ => None,
+        }
+    }
+
+    /// Scores such as "8", "8.5", "4/5" or "85/100", scaled to 0-10
+    ///
+    /// A bare number above 10 is read as out of 100.
+    fn score(value: &str) -> Option<f32> {
+        let (score, scale) = match value.split_once('/') {
+            Some((score, scale)) => (score, Some(scale)),
+            None => (value, None),
+        };
+
+        let score: f32 = score.trim().replace(',', ".").parse().ok()?;
+        let scale = match scale {
+            Some(scale) => scale.trim().parse::<f32>().ok().filter(|s| *s > 0.0)?,
+            None if score > 10.0 => 100.0,
+            None => 10.0,
+        };
+
+        let score = score / scale * 10.0;
+        (score > 0.0 && score <= 10.0).then_some((score * 10.0).round() / 10.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_mapped_csv_columns_and_title_years() {
+        let csv = "\u{feff}Name,Year,Format,MAL,Rating\r\n\
+                   Hunter x Hunter,2011,TV,11061,10/10\r\n\
+                   \"Kaguya-sama: Love is War, the Movie\",,Movie,,\"8,5\"\r\n\
+                   Hunter x Hunter (1999),,,,\r\n\
+                   ,,,,\r\n";
+
+        let mapping = TextListMapping {
+            title: 0,
+            year: Some(1),
+            anime_type: Some(2),
+            external_id: Some(3),
+            score: Some(4),
+            has_header: true,
+            ..TextListMapping::default()
+        };
+
+        let rows = TextListParser::parse(csv, TextListFormat::Csv, &mapping).unwrap();
+        assert_eq!(rows.len(), 3);
+
+        assert_eq!(rows[0].title, "Hunter x Hunter");
+        assert_eq!(rows[0].hints.year, Some(2011));
+        assert_eq!(rows[0].hints.anime_type, Some(AnimeType::TV));
+        assert_eq!(
+            rows[0].external_id,
+            Some((AnimeProvider::Jikan, "11061".to_string()))
+        );
+        assert_eq!(rows[0].score, Some(10.0));
+
+        assert_eq!(rows[1].title, "Kaguya-sama: Love is War, the Movie");
+        assert_eq!(rows[1].hints.anime_type, Some(AnimeType::Movie));
+        assert_eq!(rows[1].external_id, None);
+        assert_eq!(rows[1].score, Some(8.5));
+
+        assert_eq!(rows[2].title, "Hunter x Hunter");
+        assert_eq!(rows[2].hints.year, Some(1999));
+        assert_eq!(rows[2].score, None);
+    }
+
+    #[test]
+    fn test_plain_text_drops_list_markers() {
+        let text = "# Watched\n- [x] Cowboy Bebop\n2) Steins;Gate (2011)\n\n* Monster\n2.43: Seiin High School\n";
+
+        let preview = TextListParser::preview(text, TextListFormat::PlainText);
+        assert_eq!(preview.column_count, 1);
+        assert_eq!(preview.total_rows, 4);
+
+        let rows =
+            TextListParser::parse(text, TextListFormat::PlainText, &TextListMapping::default())
+                .unwrap();
+        let titles: Vec<&str> = rows.iter().map(|row| row.title.as_str()).collect();
+
+        assert_eq!(
+            titles,
+            vec![
+                "Cowboy Bebop",
+                "Steins;Gate",
+                "Monster",
+                "2.43: Seiin High School"
+            ]
+        );
+        assert_eq!(rows[1].hints.year, Some(2011));
+        assert_eq!(rows[0].status, Some(WatchingStatus::Completed));
+        assert_eq!(rows[1].status, None);
+    }
+
+    #[test]
+    fn test_plain_text_reads_checkboxes_and_keeps_hash_titles() {
+        let text = "## Backlog\n- [ ] #Compass 2.0\n- [X] Cowboy Bebop\n#\n";
+
+        let rows =
+            TextListParser::parse(text, TextListFormat::PlainText, &TextListMapping::default())
+                .unwrap();
+        let parsed: Vec<(&str, Option<WatchingStatus>)> = rows
+            .iter()
+            .map(|row| (row.title.as_str(), row.status))
+            .collect();
+
+        assert_eq!(
+            parsed,
+            vec![
+                ("#Compass 2.0", Some(WatchingStatus::PlanToWatch)),
+                ("Cowboy Bebop", Some(WatchingStatus::Completed)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_status_column_maps_names_and_watched_flags() {
+        let tsv = "Monster\tyes\nMushishi\tOn-Hold\nPing Pong\tsoon\n";
+        let mapping = TextListMapping {
+            status: Some(1),
+            ..TextListMapping::default()
+        };
+
+        let rows = TextListParser::parse(tsv, TextListFormat::Tsv, &mapping).unwrap();
+        let statuses: Vec<Option<WatchingStatus>> = rows.iter().map(|row| row.status).collect();
+
+        assert_eq!(
+            statuses,
+            vec![
+                Some(WatchingStatus::Completed),
+                Some(WatchingStatus::OnHold),
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn test_scores_scale_to_ten() {
+        assert_eq!(TextListParser::score("4/5"), Some(8.0));
+        assert_eq!(TextListParser::score("85"), Some(8.5));
+        assert_eq!(TextListParser::score("7.26"), Some(7.3));
+        assert_eq!(TextListParser::score("0"), None);
+        assert_eq!(TextListParser::score("great"), None);
+    }
+}
@@ -1 +1,2 @@
 pub mod import_components;
+pub mod list_import;
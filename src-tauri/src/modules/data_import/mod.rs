@@ -3,7 +3,10 @@ pub mod commands;
 pub mod domain;
 
 // Re-exports for easy external access
+pub use application::list_import_service::ListImportService;
 pub use application::service::ImportService;
 
 // Re-export common types for shorter imports
-pub use domain::services::import_components::types::{ImportResult, ValidatedAnime};
+pub use domain::services::import_components::types::{
+    ImportResult, ListImportResult, ValidatedAnime,
+};
@@ -0,0 +1,152 @@
+use super::super::domain::{
+    CachedFeed, DiscoveryEntry, DiscoveryFeed, DiscoveryFeedPage, DiscoveryRepository, FeedCache,
+};
+use crate::modules::anime::AnimeDetailed;
+use crate::modules::collection::CollectionRepository;
+use crate::modules::provider::application::service::ProviderService;
+use crate::shared::domain::value_objects::AnimeProvider;
+use crate::shared::errors::AppResult;
+use crate::{log_debug, log_info};
+use chrono::Utc;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// Number of anime fetched and cached per feed; requests are served from this
+const FEED_SIZE: usize = 50;
+
+/// Default number of entries returned for a feed
+const DEFAULT_FEED_LIMIT: usize = 20;
+
+pub struct DiscoveryService {
+    discovery_repo: Arc<dyn DiscoveryRepository>,
+    collection_repo: Arc<dyn CollectionRepository>,
+    provider_service: Arc<ProviderService>,
+    cache: RwLock<FeedCache>,
+}
+
+impl DiscoveryService {
+    pub fn new(
+        discovery_repo: Arc<dyn DiscoveryRepository>,
+        collection_repo: Arc<dyn CollectionRepository>,
+        provider_service: Arc<ProviderService>,
+    ) -> Self {
+        Self {
+            discovery_repo,
+            collection_repo,
+            provider_service,
+            cache: RwLock::new(FeedCache::new()),
+        }
+    }
+
+    /// A discovery feed merged across providers and marked against the library
+    ///
+    /// The merged feed is cached for the feed's TTL; `refresh` skips the cache.
+    /// Library and collection marks are always computed fresh.
+    pub async fn get_feed(
+        &self,
+        feed: DiscoveryFeed,
+        limit: Option<u32>,
+        refresh: bool,
+    ) -> AppResult<DiscoveryFeedPage> {
+        let limit = limit
+            .map(|limit| limit as usize)
+            .unwrap_or(DEFAULT_FEED_LIMIT)
+            .clamp(1, FEED_SIZE);
+
+        let mut cached = self.load_feed(feed, refresh).await?;
+        cached.anime.truncate(limit);
+
+        let entries = self.mark_entries(cached.anime).await?;
+        Ok(DiscoveryFeedPage {
+            feed,
+            entries,
+            fetched_at: cached.fetched_at,
+            expires_at: cached.expires_at,
+        })
+    }
+
+    async fn load_feed(&self, feed: DiscoveryFeed, refresh: bool) -> AppResult<CachedFeed> {
+        if !refresh {
+            if let Some(cached) = self.cache.read().await.get_fresh(feed, Utc::now()) {
+                log_debug!("Serving cached {} feed", feed.as_str());
+                return Ok(cached.clone());
+            }
+        }
+
+        match self
+            .provider_service
+            .get_discovery_feed(feed, FEED_SIZE)
+            .await
+        {
+            Ok(anime) => {
+                log_info!("Fetched {} feed with {} anime", feed.as_str(), anime.len());
+                Ok(self.cache.write().await.insert(feed, anime, Utc::now()))
+            }
+            Err(e) => {
+                // An outdated feed beats an error page while providers are down
+                if let Some(stale) = self.cache.read().await.get_stale(feed) {
+                    log::warn!(
+                        "Serving stale {} feed after provider failure: {}",
+                        feed.as_str(),
+                        e
+                    );
+                    return Ok(stale.clone());
+                }
+                Err(e)
+            }
+        }
+    }
+
+    async fn mark_entries(&self, anime: Vec<AnimeDetailed>) -> AppResult<Vec<DiscoveryEntry>> {
+        let mut library_ids: HashMap<(AnimeProvider, String), Uuid> = HashMap::new();
+        for provider in [
+            AnimeProvider::AniList,
+            AnimeProvider::Jikan,
+            AnimeProvider::TMDB,
+        ] {
+            let external_ids: Vec<String> = anime
+                .iter()
+                .filter_map(|anime| anime.provider_metadata.get_external_id(&provider))
+                .cloned()
+                .collect();
+
+            for (external_id, anime_id) in self
+                .discovery_repo
+                .find_library_ids(provider, &external_ids)
+                .await?
+            {
+                library_ids.insert((provider, external_id), anime_id);
+            }
+        }
+
+        let collected: HashSet<Uuid> = self
+            .collection_repo
+            .find_collected_anime_ids()
+            .await?
+            .into_iter()
+            .collect();
+
+        Ok(anime
+            .into_iter()
+            .enumerate()
+            .map(|(index, anime)| {
+                let library_anime_id = anime
+                    .provider_metadata
+                    .external_ids
+                    .iter()
+                    .find_map(|(provider, id)| library_ids.get(&(*provider, id.clone())))
+                    .copied();
+
+                DiscoveryEntry {
+                    rank: index as u32 + 1,
+                    anime,
+                    library_anime_id,
+                    in_library: library_anime_id.is_some(),
+                    in_collection: library_anime_id.is_some_and(|id| collected.contains(&id)),
+                }
+            })
+            .collect())
+    }
+}
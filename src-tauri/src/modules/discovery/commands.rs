@@ -0,0 +1,29 @@
+use super::application::service::DiscoveryService;
+use super::domain::{DiscoveryFeed, DiscoveryFeedPage};
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::sync::Arc;
+use tauri::State;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct GetDiscoveryFeedRequest {
+    pub feed: DiscoveryFeed,
+    pub limit: Option<u32>,
+    /// Ask the providers again even if the cached feed is still fresh
+    #[serde(default)]
+    pub refresh: bool,
+}
+
+/// Trending, popular or upcoming anime merged across providers
+#[tauri::command]
+#[specta::specta]
+pub async fn get_discovery_feed(
+    request: GetDiscoveryFeedRequest,
+    discovery_service: State<'_, Arc<DiscoveryService>>,
+) -> Result<DiscoveryFeedPage, String> {
+    discovery_service
+        .get_feed(request.feed, request.limit, request.refresh)
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,29 @@
+use super::super::value_objects::DiscoveryFeed;
+use crate::modules::anime::AnimeDetailed;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use uuid::Uuid;
+
+/// One anime of a discovery feed, marked against the local library
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscoveryEntry {
+    /// 1-based position in the merged feed
+    pub rank: u32,
+    pub anime: AnimeDetailed,
+    /// ID of the matching library anime, when it has already been added
+    pub library_anime_id: Option<Uuid>,
+    pub in_library: bool,
+    pub in_collection: bool,
+}
+
+/// A discovery feed as served to the UI
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscoveryFeedPage {
+    pub feed: DiscoveryFeed,
+    pub entries: Vec<DiscoveryEntry>,
+    pub fetched_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
@@ -0,0 +1,3 @@
+pub mod discovery_entry;
+
+pub use discovery_entry::{DiscoveryEntry, DiscoveryFeedPage};
@@ -0,0 +1,10 @@
+pub mod entities;
+pub mod repositories;
+pub mod services;
+pub mod value_objects;
+
+// Re-exports for easy access
+pub use entities::{DiscoveryEntry, DiscoveryFeedPage};
+pub use repositories::DiscoveryRepository;
+pub use services::{CachedFeed, FeedCache};
+pub use value_objects::DiscoveryFeed;
@@ -0,0 +1,14 @@
+use crate::shared::domain::value_objects::AnimeProvider;
+use crate::shared::errors::AppResult;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait DiscoveryRepository: Send + Sync {
+    /// Library anime IDs for the given provider IDs, as `(external_id, anime_id)` pairs
+    async fn find_library_ids(
+        &self,
+        provider: AnimeProvider,
+        external_ids: &[String],
+    ) -> AppResult<Vec<(String, Uuid)>>;
+}
@@ -0,0 +1,3 @@
+pub mod discovery_repository;
+
+pub use discovery_repository::DiscoveryRepository;
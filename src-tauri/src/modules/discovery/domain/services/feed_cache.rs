@@ -0,0 +1,78 @@
+use super::super::value_objects::DiscoveryFeed;
+use crate::modules::anime::AnimeDetailed;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+/// A merged provider feed kept until its TTL runs out
+#[derive(Debug, Clone)]
+pub struct CachedFeed {
+    pub anime: Vec<AnimeDetailed>,
+    pub fetched_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl CachedFeed {
+    pub fn is_fresh(&self, now: DateTime<Utc>) -> bool {
+        now < self.expires_at
+    }
+}
+
+/// In-memory store of the last merged result of each discovery feed
+///
+/// Expired feeds are kept so they can still be served when the providers
+/// are unreachable.
+#[derive(Debug, Default)]
+pub struct FeedCache {
+    feeds: HashMap<DiscoveryFeed, CachedFeed>,
+}
+
+impl FeedCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The cached feed, only while it is within its TTL
+    pub fn get_fresh(&self, feed: DiscoveryFeed, now: DateTime<Utc>) -> Option<&CachedFeed> {
+        self.feeds.get(&feed).filter(|cached| cached.is_fresh(now))
+    }
+
+    /// The cached feed regardless of age
+    pub fn get_stale(&self, feed: DiscoveryFeed) -> Option<&CachedFeed> {
+        self.feeds.get(&feed)
+    }
+
+    pub fn insert(
+        &mut self,
+        feed: DiscoveryFeed,
+        anime: Vec<AnimeDetailed>,
+        now: DateTime<Utc>,
+    ) -> CachedFeed {
+        let cached = CachedFeed {
+            anime,
+            fetched_at: now,
+            expires_at: now + feed.cache_ttl(),
+        };
+        self.feeds.insert(feed, cached.clone());
+        cached
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_feed_expires_after_its_ttl() {
+        let now = Utc::now();
+        let mut cache = FeedCache::new();
+        cache.insert(DiscoveryFeed::Trending, Vec::new(), now);
+        cache.insert(DiscoveryFeed::Popular, Vec::new(), now);
+
+        let later = now + Duration::hours(2);
+        assert!(cache.get_fresh(DiscoveryFeed::Trending, later).is_none());
+        assert!(cache.get_stale(DiscoveryFeed::Trending).is_some());
+        assert!(cache.get_fresh(DiscoveryFeed::Popular, later).is_some());
+        assert!(cache.get_fresh(DiscoveryFeed::Upcoming, now).is_none());
+    }
+}
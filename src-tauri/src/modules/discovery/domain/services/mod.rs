@@ -0,0 +1,3 @@
+pub mod feed_cache;
+
+pub use feed_cache::{CachedFeed, FeedCache};
@@ -0,0 +1,36 @@
+use chrono::Duration;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// A provider-curated list of anime to browse
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum DiscoveryFeed {
+    /// What people are watching right now
+    Trending,
+    /// All-time most popular
+    Popular,
+    /// Announced but not yet airing
+    Upcoming,
+}
+
+impl DiscoveryFeed {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DiscoveryFeed::Trending => "trending",
+            DiscoveryFeed::Popular => "popular",
+            DiscoveryFeed::Upcoming => "upcoming",
+        }
+    }
+
+    /// How long a fetched feed is served before asking the providers again
+    ///
+    /// Trending shifts within hours; all-time popularity barely moves in a day.
+    pub fn cache_ttl(&self) -> Duration {
+        match self {
+            DiscoveryFeed::Trending => Duration::hours(1),
+            DiscoveryFeed::Popular => Duration::hours(12),
+            DiscoveryFeed::Upcoming => Duration::hours(6),
+        }
+    }
+}
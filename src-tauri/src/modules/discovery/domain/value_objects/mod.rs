@@ -0,0 +1,3 @@
+pub mod discovery_feed;
+
+pub use discovery_feed::DiscoveryFeed;
@@ -0,0 +1,3 @@
+pub mod persistence;
+
+pub use persistence::DiscoveryRepositoryImpl;
@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+use tokio::task;
+use uuid::Uuid;
+
+use crate::modules::discovery::domain::DiscoveryRepository;
+use crate::schema::anime_external_ids;
+use crate::shared::domain::value_objects::AnimeProvider;
+use crate::shared::errors::AppResult;
+use crate::shared::Database;
+
+pub struct DiscoveryRepositoryImpl {
+    db: Arc<Database>,
+}
+
+impl DiscoveryRepositoryImpl {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl DiscoveryRepository for DiscoveryRepositoryImpl {
+    async fn find_library_ids(
+        &self,
+        provider: AnimeProvider,
+        external_ids: &[String],
+    ) -> AppResult<Vec<(String, Uuid)>> {
+        if external_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let db = Arc::clone(&self.db);
+        let provider_code = provider.to_string();
+        let external_ids = external_ids.to_vec();
+
+        task::spawn_blocking(move || -> AppResult<Vec<(String, Uuid)>> {
+            let mut conn = db.get_connection()?;
+            let rows = anime_external_ids::table
+                .filter(anime_external_ids::provider_code.eq(&provider_code))
+                .filter(anime_external_ids::external_id.eq_any(&external_ids))
+                .select((
+                    anime_external_ids::external_id,
+                    anime_external_ids::anime_id,
+                ))
+                .load(&mut conn)?;
+            Ok(rows)
+        })
+        .await?
+    }
+}
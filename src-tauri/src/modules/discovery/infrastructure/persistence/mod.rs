@@ -0,0 +1,3 @@
+pub mod discovery_repository_impl;
+
+pub use discovery_repository_impl::DiscoveryRepositoryImpl;
@@ -0,0 +1,66 @@
+use super::super::domain::{Episode, EpisodeFilter, EpisodeMerger, EpisodeRepository};
+use crate::modules::anime::domain::repositories::anime_repository::AnimeRepository;
+use crate::modules::provider::application::service::ProviderService;
+use crate::shared::errors::{AppError, AppResult};
+use crate::{log_debug, log_info};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct EpisodeService {
+    episode_repo: Arc<dyn EpisodeRepository>,
+    anime_repo: Arc<dyn AnimeRepository>,
+    provider_service: Arc<ProviderService>,
+}
+
+impl EpisodeService {
+    pub fn new(
+        episode_repo: Arc<dyn EpisodeRepository>,
+        anime_repo: Arc<dyn AnimeRepository>,
+        provider_service: Arc<ProviderService>,
+    ) -> Self {
+        Self {
+            episode_repo,
+            anime_repo,
+            provider_service,
+        }
+    }
+
+    /// Stored episodes of an anime in order, optionally without filler or recaps
+    pub async fn get_episodes(
+        &self,
+        anime_id: &Uuid,
+        filter: EpisodeFilter,
+    ) -> AppResult<Vec<Episode>> {
+        self.episode_repo.find_by_anime(anime_id, filter).await
+    }
+
+    /// Fetch the episode list from MyAnimeList and AniList, merge it and store it
+    ///
+    /// Returns the number of episodes written. Stored episodes that providers no
+    /// longer list are kept.
+    pub async fn sync_episodes(&self, anime_id: &Uuid) -> AppResult<usize> {
+        let anime =
+            self.anime_repo.find_by_id(anime_id).await?.ok_or_else(|| {
+                AppError::NotFound(format!("Anime with ID {} not found", anime_id))
+            })?;
+
+        let provider_episodes = self
+            .provider_service
+            .fetch_anime_episodes(&anime.provider_metadata)
+            .await?;
+
+        let merged = EpisodeMerger::merge(provider_episodes);
+        if merged.is_empty() {
+            log_debug!(
+                "No episodes returned by providers for '{}'",
+                anime.title.main
+            );
+            return Ok(0);
+        }
+
+        let written = self.episode_repo.upsert_episodes(anime_id, &merged).await?;
+        log_info!("Synced {} episodes for '{}'", written, anime.title.main);
+
+        Ok(written)
+    }
+}
@@ -0,0 +1,53 @@
+use super::application::service::EpisodeService;
+use super::domain::{Episode, EpisodeFilter};
+use crate::modules::anime::application::ingestion_service::JobPriority;
+use crate::modules::jobs::domain::entities::{Job, JobRecord};
+use crate::modules::jobs::domain::repository::JobRepository;
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::sync::Arc;
+use tauri::State;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct GetAnimeEpisodesRequest {
+    pub anime_id: Uuid,
+    #[serde(default)]
+    pub filter: EpisodeFilter,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SyncAnimeEpisodesRequest {
+    pub anime_id: Uuid,
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_anime_episodes(
+    request: GetAnimeEpisodesRequest,
+    episode_service: State<'_, Arc<EpisodeService>>,
+) -> Result<Vec<Episode>, String> {
+    episode_service
+        .get_episodes(&request.anime_id, request.filter)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Queue a background job that pulls the anime's episode list page by page
+///
+/// Requested from the UI, so it jumps ahead of bulk enrichment work.
+#[tauri::command]
+#[specta::specta]
+pub async fn sync_anime_episodes(
+    request: SyncAnimeEpisodesRequest,
+    job_repository: State<'_, Arc<dyn JobRepository>>,
+) -> Result<JobRecord, String> {
+    job_repository
+        .enqueue(Job::episode_sync(
+            request.anime_id,
+            JobPriority::High as i32,
+        ))
+        .await
+        .map_err(|e| e.to_string())
+}
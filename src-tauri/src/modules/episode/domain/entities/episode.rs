@@ -0,0 +1,39 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use uuid::Uuid;
+
+/// Single episode of an anime, numbered from 1
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct Episode {
+    pub id: Uuid,
+    pub anime_id: Uuid,
+    pub number: i32,
+    pub title: Option<String>,
+    pub title_japanese: Option<String>,
+    pub title_romaji: Option<String>,
+    pub aired_at: Option<DateTime<Utc>>,
+    pub score: Option<f32>,
+    /// Not adapted from the source material
+    pub is_filler: bool,
+    /// Mostly recaps earlier episodes
+    pub is_recap: bool,
+    pub thumbnail_url: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Episode data to insert or merge into the stored row with the same number
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewEpisode {
+    pub number: i32,
+    pub title: Option<String>,
+    pub title_japanese: Option<String>,
+    pub title_romaji: Option<String>,
+    pub aired_at: Option<DateTime<Utc>>,
+    pub score: Option<f32>,
+    pub is_filler: bool,
+    pub is_recap: bool,
+    pub thumbnail_url: Option<String>,
+}
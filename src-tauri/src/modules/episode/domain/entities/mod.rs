@@ -0,0 +1,5 @@
+pub mod episode;
+pub mod provider_episode;
+
+pub use episode::{Episode, NewEpisode};
+pub use provider_episode::ProviderEpisode;
@@ -0,0 +1,17 @@
+use crate::shared::domain::value_objects::AnimeProvider;
+use chrono::{DateTime, Utc};
+
+/// Episode as reported by a single provider
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProviderEpisode {
+    pub provider: AnimeProvider,
+    pub number: i32,
+    pub title: Option<String>,
+    pub title_japanese: Option<String>,
+    pub title_romaji: Option<String>,
+    pub aired_at: Option<DateTime<Utc>>,
+    pub score: Option<f32>,
+    pub is_filler: bool,
+    pub is_recap: bool,
+    pub thumbnail_url: Option<String>,
+}
@@ -0,0 +1,8 @@
+pub mod entities;
+pub mod repositories;
+pub mod services;
+
+// Re-exports for easy access
+pub use entities::{Episode, NewEpisode, ProviderEpisode};
+pub use repositories::{EpisodeFilter, EpisodeRepository};
+pub use services::EpisodeMerger;
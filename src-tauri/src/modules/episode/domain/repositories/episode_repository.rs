@@ -0,0 +1,33 @@
+use super::super::entities::{Episode, NewEpisode};
+use crate::shared::errors::AppResult;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use uuid::Uuid;
+
+/// Filter for listing an anime's episodes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct EpisodeFilter {
+    /// Leave out filler episodes
+    #[serde(default)]
+    pub skip_filler: bool,
+    /// Leave out recap episodes
+    #[serde(default)]
+    pub skip_recap: bool,
+}
+
+#[async_trait]
+pub trait EpisodeRepository: Send + Sync {
+    /// Insert episodes or update the stored ones with the same number
+    ///
+    /// Episodes missing from `episodes` are left untouched. Returns the number
+    /// of rows written.
+    async fn upsert_episodes(&self, anime_id: &Uuid, episodes: &[NewEpisode]) -> AppResult<usize>;
+
+    /// Episodes of an anime ordered by number
+    async fn find_by_anime(
+        &self,
+        anime_id: &Uuid,
+        filter: EpisodeFilter,
+    ) -> AppResult<Vec<Episode>>;
+}
@@ -0,0 +1,3 @@
+pub mod episode_repository;
+
+pub use episode_repository::{EpisodeFilter, EpisodeRepository};
@@ -0,0 +1,115 @@
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+
+use crate::modules::episode::domain::entities::{NewEpisode, ProviderEpisode};
+use crate::shared::domain::value_objects::AnimeProvider;
+
+/// Merges MyAnimeList and AniList episode lists into one entry per episode number
+///
+/// MyAnimeList is the reference: its titles, air dates and filler/recap flags win,
+/// AniList only fills what is missing (mostly thumbnails from streaming sites).
+pub struct EpisodeMerger;
+
+impl EpisodeMerger {
+    pub fn merge(mut episodes: Vec<ProviderEpisode>) -> Vec<NewEpisode> {
+        // Stable: each provider's own order is kept
+        episodes.sort_by_key(|episode| episode.provider != AnimeProvider::Jikan);
+
+        let mut merged: BTreeMap<i32, NewEpisode> = BTreeMap::new();
+        for episode in episodes {
+            if episode.number <= 0 {
+                continue;
+            }
+
+            match merged.entry(episode.number) {
+                Entry::Vacant(entry) => {
+                    entry.insert(NewEpisode {
+                        number: episode.number,
+                        title: episode.title,
+                        title_japanese: episode.title_japanese,
+                        title_romaji: episode.title_romaji,
+                        aired_at: episode.aired_at,
+                        score: episode.score,
+                        is_filler: episode.is_filler,
+                        is_recap: episode.is_recap,
+                        thumbnail_url: episode.thumbnail_url,
+                    });
+                }
+                Entry::Occupied(mut entry) => {
+                    let existing = entry.get_mut();
+                    fill(&mut existing.title, episode.title);
+                    fill(&mut existing.title_japanese, episode.title_japanese);
+                    fill(&mut existing.title_romaji, episode.title_romaji);
+                    fill(&mut existing.aired_at, episode.aired_at);
+                    fill(&mut existing.score, episode.score);
+                    fill(&mut existing.thumbnail_url, episode.thumbnail_url);
+                    existing.is_filler |= episode.is_filler;
+                    existing.is_recap |= episode.is_recap;
+                }
+            }
+        }
+
+        merged.into_values().collect()
+    }
+}
+
+fn fill<T>(target: &mut Option<T>, value: Option<T>) {
+    if target.is_none() {
+        *target = value;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn episode(provider: AnimeProvider, number: i32, title: Option<&str>) -> ProviderEpisode {
+        ProviderEpisode {
+            provider,
+            number,
+            title: title.map(str::to_string),
+            title_japanese: None,
+            title_romaji: None,
+            aired_at: None,
+            score: None,
+            is_filler: false,
+            is_recap: false,
+            thumbnail_url: None,
+        }
+    }
+
+    #[test]
+    fn test_myanimelist_titles_win_and_anilist_fills_thumbnails() {
+        let episodes = EpisodeMerger::merge(vec![
+            ProviderEpisode {
+                thumbnail_url: Some("https://img/1.jpg".to_string()),
+                ..episode(AnimeProvider::AniList, 1, Some("Asteroid Blues (Dub)"))
+            },
+            ProviderEpisode {
+                is_filler: true,
+                ..episode(AnimeProvider::Jikan, 1, Some("Asteroid Blues"))
+            },
+        ]);
+
+        assert_eq!(episodes.len(), 1);
+        assert_eq!(episodes[0].title.as_deref(), Some("Asteroid Blues"));
+        assert_eq!(
+            episodes[0].thumbnail_url.as_deref(),
+            Some("https://img/1.jpg")
+        );
+        assert!(episodes[0].is_filler);
+    }
+
+    #[test]
+    fn test_orders_by_number_and_drops_unnumbered() {
+        let episodes = EpisodeMerger::merge(vec![
+            episode(AnimeProvider::Jikan, 2, Some("Stray Dog Strut")),
+            episode(AnimeProvider::AniList, 3, Some("Honky Tonk Women")),
+            episode(AnimeProvider::Jikan, 1, Some("Asteroid Blues")),
+            episode(AnimeProvider::AniList, 0, Some("Session XX")),
+        ]);
+
+        let numbers: Vec<i32> = episodes.iter().map(|e| e.number).collect();
+        assert_eq!(numbers, vec![1, 2, 3]);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod episode_merger;
+
+pub use episode_merger::EpisodeMerger;
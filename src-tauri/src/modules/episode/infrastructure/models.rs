@@ -0,0 +1,77 @@
+use crate::modules::episode::domain::{Episode, NewEpisode};
+use crate::schema::anime_episodes;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use uuid::Uuid;
+
+// For reading from database
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone)]
+#[diesel(table_name = anime_episodes)]
+pub struct EpisodeModel {
+    pub id: Uuid,
+    pub anime_id: Uuid,
+    pub number: i32,
+    pub title: Option<String>,
+    pub title_japanese: Option<String>,
+    pub title_romaji: Option<String>,
+    pub aired_at: Option<DateTime<Utc>>,
+    pub score: Option<f32>,
+    pub is_filler: bool,
+    pub is_recap: bool,
+    pub thumbnail_url: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// For inserting new rows
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = anime_episodes)]
+pub struct NewEpisodeModel {
+    pub anime_id: Uuid,
+    pub number: i32,
+    pub title: Option<String>,
+    pub title_japanese: Option<String>,
+    pub title_romaji: Option<String>,
+    pub aired_at: Option<DateTime<Utc>>,
+    pub score: Option<f32>,
+    pub is_filler: bool,
+    pub is_recap: bool,
+    pub thumbnail_url: Option<String>,
+}
+
+impl From<EpisodeModel> for Episode {
+    fn from(model: EpisodeModel) -> Self {
+        Self {
+            id: model.id,
+            anime_id: model.anime_id,
+            number: model.number,
+            title: model.title,
+            title_japanese: model.title_japanese,
+            title_romaji: model.title_romaji,
+            aired_at: model.aired_at,
+            score: model.score,
+            is_filler: model.is_filler,
+            is_recap: model.is_recap,
+            thumbnail_url: model.thumbnail_url,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+impl NewEpisodeModel {
+    pub fn new(anime_id: Uuid, episode: &NewEpisode) -> Self {
+        Self {
+            anime_id,
+            number: episode.number,
+            title: episode.title.clone(),
+            title_japanese: episode.title_japanese.clone(),
+            title_romaji: episode.title_romaji.clone(),
+            aired_at: episode.aired_at,
+            score: episode.score,
+            is_filler: episode.is_filler,
+            is_recap: episode.is_recap,
+            thumbnail_url: episode.thumbnail_url.clone(),
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+use diesel::upsert::excluded;
+use tokio::task;
+use uuid::Uuid;
+
+use crate::modules::episode::domain::{Episode, EpisodeFilter, EpisodeRepository, NewEpisode};
+use crate::modules::episode::infrastructure::models::{EpisodeModel, NewEpisodeModel};
+use crate::schema::anime_episodes;
+use crate::shared::errors::AppResult;
+use crate::shared::Database;
+
+/// Rows per INSERT, keeps long-running shows well under the bind parameter limit
+const UPSERT_CHUNK_SIZE: usize = 500;
+
+pub struct EpisodeRepositoryImpl {
+    db: Arc<Database>,
+}
+
+impl EpisodeRepositoryImpl {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl EpisodeRepository for EpisodeRepositoryImpl {
+    async fn upsert_episodes(&self, anime_id: &Uuid, episodes: &[NewEpisode]) -> AppResult<usize> {
+        let db = Arc::clone(&self.db);
+        let rows: Vec<NewEpisodeModel> = episodes
+            .iter()
+            .map(|episode| NewEpisodeModel::new(*anime_id, episode))
+            .collect();
+
+        task::spawn_blocking(move || -> AppResult<usize> {
+            let mut conn = db.get_connection()?;
+            conn.transaction(|conn| {
+                let mut written = 0;
+                for chunk in rows.chunks(UPSERT_CHUNK_SIZE) {
+                    written += diesel::insert_into(anime_episodes::table)
+                        .values(chunk)
+                        .on_conflict((anime_episodes::anime_id, anime_episodes::number))
+                        .do_update()
+                        .set((
+                            anime_episodes::title.eq(excluded(anime_episodes::title)),
+                            anime_episodes::title_japanese
+                                .eq(excluded(anime_episodes::title_japanese)),
+                            anime_episodes::title_romaji.eq(excluded(anime_episodes::title_romaji)),
+                            anime_episodes::aired_at.eq(excluded(anime_episodes::aired_at)),
+                            anime_episodes::score.eq(excluded(anime_episodes::score)),
+                            anime_episodes::is_filler.eq(excluded(anime_episodes::is_filler)),
+                            anime_episodes::is_recap.eq(excluded(anime_episodes::is_recap)),
+                            anime_episodes::thumbnail_url
+                                .eq(excluded(anime_episodes::thumbnail_url)),
+                        ))
+                        .execute(conn)?;
+                }
+                Ok(written)
+            })
+        })
+        .await?
+    }
+
+    async fn find_by_anime(
+        &self,
+        anime_id: &Uuid,
+        filter: EpisodeFilter,
+    ) -> AppResult<Vec<Episode>> {
+        let db = Arc::clone(&self.db);
+        let anime_id = *anime_id;
+
+        let models = task::spawn_blocking(move || -> AppResult<Vec<EpisodeModel>> {
+            let mut conn = db.get_connection()?;
+            let mut query = anime_episodes::table
+                .filter(anime_episodes::anime_id.eq(anime_id))
+                .into_boxed();
+
+            if filter.skip_filler {
+                query = query.filter(anime_episodes::is_filler.eq(false));
+            }
+            if filter.skip_recap {
+                query = query.filter(anime_episodes::is_recap.eq(false));
+            }
+
+            let models = query
+                .order(anime_episodes::number.asc())
+                .select(EpisodeModel::as_select())
+                .load(&mut conn)?;
+            Ok(models)
+        })
+        .await??;
+
+        Ok(models.into_iter().map(Into::into).collect())
+    }
+}
@@ -0,0 +1,3 @@
+pub mod episode_repository_impl;
+
+pub use episode_repository_impl::EpisodeRepositoryImpl;
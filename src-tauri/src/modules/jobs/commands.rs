@@ -0,0 +1,155 @@
+use super::domain::entities::{JobRecord, JobType};
+use super::domain::repository::{JobFilter, JobRepository, JobStatistics};
+use super::worker::{BackgroundWorker, WorkerStatistics};
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::sync::Arc;
+use tauri::State;
+use uuid::Uuid;
+
+/// Default age for purging finished jobs
+const DEFAULT_PURGE_AFTER_DAYS: u32 = 7;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ListJobsRequest {
+    #[serde(default)]
+    pub filter: JobFilter,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct JobIdRequest {
+    pub job_id: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct CancelPendingJobsRequest {
+    /// Only cancel jobs of this type; all pending jobs when omitted
+    pub job_type: Option<JobType>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct PurgeOldJobsRequest {
+    /// Delete finished jobs older than this many days (defaults to 7)
+    pub older_than_days: Option<u32>,
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn list_jobs(
+    request: ListJobsRequest,
+    job_repository: State<'_, Arc<dyn JobRepository>>,
+) -> Result<Vec<JobRecord>, String> {
+    job_repository
+        .list_jobs(request.filter)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_job_statistics(
+    job_repository: State<'_, Arc<dyn JobRepository>>,
+) -> Result<JobStatistics, String> {
+    job_repository
+        .get_statistics()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_worker_status(
+    worker: State<'_, Arc<BackgroundWorker>>,
+) -> Result<WorkerStatistics, String> {
+    worker.get_statistics().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn retry_job(
+    request: JobIdRequest,
+    job_repository: State<'_, Arc<dyn JobRepository>>,
+) -> Result<JobRecord, String> {
+    job_repository
+        .retry_job(request.job_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Job {} not found or not failed/cancelled", request.job_id))
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn retry_failed_jobs(
+    job_repository: State<'_, Arc<dyn JobRepository>>,
+) -> Result<u32, String> {
+    let retried = job_repository
+        .retry_all_failed()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    log::info!("Re-queued {} failed jobs", retried);
+    Ok(retried as u32)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn cancel_job(
+    request: JobIdRequest,
+    job_repository: State<'_, Arc<dyn JobRepository>>,
+) -> Result<JobRecord, String> {
+    job_repository
+        .cancel_job(request.job_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Job {} not found or no longer pending", request.job_id))
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn cancel_pending_jobs(
+    request: CancelPendingJobsRequest,
+    job_repository: State<'_, Arc<dyn JobRepository>>,
+) -> Result<u32, String> {
+    let cancelled = job_repository
+        .cancel_pending(request.job_type)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    log::info!("Cancelled {} pending jobs", cancelled);
+    Ok(cancelled as u32)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn pause_worker(
+    worker: State<'_, Arc<BackgroundWorker>>,
+) -> Result<WorkerStatistics, String> {
+    worker.pause().await;
+    worker.get_statistics().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn resume_worker(
+    worker: State<'_, Arc<BackgroundWorker>>,
+) -> Result<WorkerStatistics, String> {
+    worker.resume().await;
+    worker.get_statistics().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn purge_old_jobs(
+    request: PurgeOldJobsRequest,
+    job_repository: State<'_, Arc<dyn JobRepository>>,
+) -> Result<u32, String> {
+    let days = request.older_than_days.unwrap_or(DEFAULT_PURGE_AFTER_DAYS);
+    let deleted = job_repository
+        .delete_old_completed(days.min(i32::MAX as u32) as i32)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    log::info!("Purged {} jobs older than {} days", deleted, days);
+    Ok(deleted as u32)
+}
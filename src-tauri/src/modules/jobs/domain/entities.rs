@@ -4,16 +4,22 @@
 /// that can be queued and processed by background workers.
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use specta::Type;
 use uuid::Uuid;
 
 /// Job status enum matching database type
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
 #[serde(rename_all = "lowercase")]
 pub enum JobStatus {
     Pending,
     Running,
     Completed,
     Failed,
+    /// Pending job withdrawn by the user before a worker picked it up
+    Cancelled,
+    /// Job that kept failing until it ran out of attempts
+    #[serde(rename = "dead_letter")]
+    DeadLetter,
 }
 
 impl std::fmt::Display for JobStatus {
@@ -23,6 +29,8 @@ impl std::fmt::Display for JobStatus {
             JobStatus::Running => write!(f, "running"),
             JobStatus::Completed => write!(f, "completed"),
             JobStatus::Failed => write!(f, "failed"),
+            JobStatus::Cancelled => write!(f, "cancelled"),
+            JobStatus::DeadLetter => write!(f, "dead_letter"),
         }
     }
 }
@@ -36,17 +44,35 @@ impl std::str::FromStr for JobStatus {
             "running" => Ok(JobStatus::Running),
             "completed" => Ok(JobStatus::Completed),
             "failed" => Ok(JobStatus::Failed),
+            "cancelled" => Ok(JobStatus::Cancelled),
+            "dead_letter" => Ok(JobStatus::DeadLetter),
             _ => Err(format!("Invalid job status: {}", s)),
         }
     }
 }
 
 /// Job type enum
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type)]
 #[serde(rename_all = "snake_case")]
 pub enum JobType {
     Enrichment,
     RelationsDiscovery,
+    EpisodeSync,
+    NewsSync,
+    StatisticsSync,
+}
+
+impl JobType {
+    /// Every job type the worker pool knows how to run
+    pub fn all() -> &'static [JobType] {
+        &[
+            JobType::Enrichment,
+            JobType::RelationsDiscovery,
+            JobType::EpisodeSync,
+            JobType::NewsSync,
+            JobType::StatisticsSync,
+        ]
+    }
 }
 
 impl std::fmt::Display for JobType {
@@ -54,6 +80,9 @@ impl std::fmt::Display for JobType {
         match self {
             JobType::Enrichment => write!(f, "enrichment"),
             JobType::RelationsDiscovery => write!(f, "relations_discovery"),
+            JobType::EpisodeSync => write!(f, "episode_sync"),
+            JobType::NewsSync => write!(f, "news_sync"),
+            JobType::StatisticsSync => write!(f, "statistics_sync"),
         }
     }
 }
@@ -65,6 +94,9 @@ impl std::str::FromStr for JobType {
         match s.to_lowercase().as_str() {
             "enrichment" => Ok(JobType::Enrichment),
             "relations_discovery" => Ok(JobType::RelationsDiscovery),
+            "episode_sync" => Ok(JobType::EpisodeSync),
+            "news_sync" => Ok(JobType::NewsSync),
+            "statistics_sync" => Ok(JobType::StatisticsSync),
             _ => Err(format!("Invalid job type: {}", s)),
         }
     }
@@ -82,12 +114,28 @@ pub struct RelationsDiscoveryJobPayload {
     pub anime_id: Uuid,
 }
 
+/// Job payload for episode list sync jobs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EpisodeSyncJobPayload {
+    pub anime_id: Uuid,
+}
+
+/// Job payload for news sync jobs; the job covers every followed anime
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NewsSyncJobPayload {}
+
+/// Job payload for statistics sync jobs; the job covers every airing or upcoming anime
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StatisticsSyncJobPayload {}
+
 /// New job to be queued (before insertion to database)
 #[derive(Debug, Clone)]
 pub struct Job {
     pub job_type: JobType,
     pub payload: serde_json::Value,
     pub priority: i32,
+    /// Earliest time a worker may run the job; `None` means right away
+    pub scheduled_at: Option<DateTime<Utc>>,
 }
 
 impl Job {
@@ -98,6 +146,7 @@ impl Job {
             job_type: JobType::Enrichment,
             payload: serde_json::to_value(payload).unwrap(),
             priority,
+            scheduled_at: None,
         }
     }
 
@@ -108,12 +157,50 @@ impl Job {
             job_type: JobType::RelationsDiscovery,
             payload: serde_json::to_value(payload).unwrap(),
             priority,
+            scheduled_at: None,
+        }
+    }
+
+    /// Create a new episode sync job
+    pub fn episode_sync(anime_id: Uuid, priority: i32) -> Self {
+        let payload = EpisodeSyncJobPayload { anime_id };
+        Self {
+            job_type: JobType::EpisodeSync,
+            payload: serde_json::to_value(payload).unwrap(),
+            priority,
+            scheduled_at: None,
+        }
+    }
+
+    /// Create a new news sync job
+    pub fn news_sync(priority: i32) -> Self {
+        Self {
+            job_type: JobType::NewsSync,
+            payload: serde_json::to_value(NewsSyncJobPayload::default()).unwrap(),
+            priority,
+            scheduled_at: None,
+        }
+    }
+
+    /// Create a new statistics sync job
+    pub fn statistics_sync(priority: i32) -> Self {
+        Self {
+            job_type: JobType::StatisticsSync,
+            payload: serde_json::to_value(StatisticsSyncJobPayload::default()).unwrap(),
+            priority,
+            scheduled_at: None,
         }
     }
+
+    /// Delay the job until `at`
+    pub fn scheduled_at(mut self, at: DateTime<Utc>) -> Self {
+        self.scheduled_at = Some(at);
+        self
+    }
 }
 
 /// Job record from database (with metadata)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
 pub struct JobRecord {
     pub id: Uuid,
     pub job_type: String,
@@ -123,6 +210,8 @@ pub struct JobRecord {
     pub attempts: i32,
     pub max_attempts: i32,
     pub created_at: DateTime<Utc>,
+    /// Earliest time a worker may pick the job up (pushed back after failures)
+    pub scheduled_at: DateTime<Utc>,
     pub started_at: Option<DateTime<Utc>>,
     pub completed_at: Option<DateTime<Utc>>,
     pub error: Option<String>,
@@ -155,6 +244,11 @@ impl JobRecord {
     ) -> Result<RelationsDiscoveryJobPayload, serde_json::Error> {
         serde_json::from_value(self.payload.clone())
     }
+
+    /// Parse episode sync payload
+    pub fn parse_episode_sync_payload(&self) -> Result<EpisodeSyncJobPayload, serde_json::Error> {
+        serde_json::from_value(self.payload.clone())
+    }
 }
 
 #[cfg(test)]
@@ -167,12 +261,22 @@ mod tests {
         assert_eq!(JobStatus::Running.to_string(), "running");
         assert_eq!(JobStatus::Completed.to_string(), "completed");
         assert_eq!(JobStatus::Failed.to_string(), "failed");
+        assert_eq!(JobStatus::Cancelled.to_string(), "cancelled");
+        assert_eq!(JobStatus::DeadLetter.to_string(), "dead_letter");
     }
 
     #[test]
     fn test_job_status_from_str() {
         assert_eq!("pending".parse::<JobStatus>().unwrap(), JobStatus::Pending);
         assert_eq!("RUNNING".parse::<JobStatus>().unwrap(), JobStatus::Running);
+        assert_eq!(
+            "cancelled".parse::<JobStatus>().unwrap(),
+            JobStatus::Cancelled
+        );
+        assert_eq!(
+            "dead_letter".parse::<JobStatus>().unwrap(),
+            JobStatus::DeadLetter
+        );
         assert!("invalid".parse::<JobStatus>().is_err());
     }
 
@@ -183,6 +287,18 @@ mod tests {
             JobType::RelationsDiscovery.to_string(),
             "relations_discovery"
         );
+        assert_eq!(JobType::EpisodeSync.to_string(), "episode_sync");
+        assert_eq!(
+            "episode_sync".parse::<JobType>().unwrap(),
+            JobType::EpisodeSync
+        );
+        assert_eq!(JobType::NewsSync.to_string(), "news_sync");
+        assert_eq!("news_sync".parse::<JobType>().unwrap(), JobType::NewsSync);
+        assert_eq!(JobType::StatisticsSync.to_string(), "statistics_sync");
+        assert_eq!(
+            "statistics_sync".parse::<JobType>().unwrap(),
+            JobType::StatisticsSync
+        );
     }
 
     #[test]
@@ -206,6 +322,28 @@ mod tests {
         assert_eq!(job.priority, 3);
     }
 
+    #[test]
+    fn test_create_episode_sync_job() {
+        let anime_id = Uuid::new_v4();
+        let job = Job::episode_sync(anime_id, 2);
+
+        assert_eq!(job.job_type, JobType::EpisodeSync);
+        assert_eq!(job.priority, 2);
+
+        let payload: EpisodeSyncJobPayload = serde_json::from_value(job.payload).unwrap();
+        assert_eq!(payload.anime_id, anime_id);
+    }
+
+    #[test]
+    fn test_create_scheduled_news_sync_job() {
+        let at = Utc::now() + chrono::Duration::hours(6);
+        let job = Job::news_sync(1).scheduled_at(at);
+
+        assert_eq!(job.job_type, JobType::NewsSync);
+        assert_eq!(job.scheduled_at, Some(at));
+        assert!(Job::news_sync(1).scheduled_at.is_none());
+    }
+
     #[test]
     fn test_job_record_can_retry() {
         use chrono::Utc;
@@ -219,6 +357,7 @@ mod tests {
             attempts: 2,
             max_attempts: 3,
             created_at: Utc::now(),
+            scheduled_at: Utc::now(),
             started_at: None,
             completed_at: None,
             error: Some("Test error".to_string()),
@@ -252,6 +391,7 @@ mod tests {
             attempts: 0,
             max_attempts: 3,
             created_at: Utc::now(),
+            scheduled_at: Utc::now(),
             started_at: None,
             completed_at: None,
             error: None,
@@ -4,4 +4,4 @@ pub mod value_objects;
 
 pub use entities::{Job, JobRecord, JobStatus, JobType};
 pub use repository::JobRepository;
-pub use value_objects::JobStatusDb;
+pub use value_objects::{JobStatusDb, RetryBackoff};
@@ -2,9 +2,12 @@
 ///
 /// Defines the interface for job storage and retrieval operations.
 /// Implementation will use Diesel ORM with PostgreSQL.
-use crate::modules::jobs::domain::entities::{Job, JobRecord};
+use crate::modules::jobs::domain::entities::{Job, JobRecord, JobStatus, JobType};
 use crate::shared::errors::AppResult;
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::time::Duration;
 use uuid::Uuid;
 
 #[async_trait]
@@ -16,12 +19,31 @@ pub trait JobRepository: Send + Sync {
     /// Returns None if no jobs are available
     async fn dequeue(&self) -> AppResult<Option<JobRecord>>;
 
+    /// Dequeue the next pending job restricted to the given types
+    /// Used by the worker pool to respect per-type concurrency limits
+    async fn dequeue_matching(&self, job_types: &[JobType]) -> AppResult<Option<JobRecord>>;
+
+    /// Reset jobs stuck in 'running' (e.g. after a crash or abandoned drain) back to pending
+    async fn requeue_interrupted(&self) -> AppResult<usize>;
+
     /// Mark job as completed
     async fn mark_completed(&self, job_id: Uuid) -> AppResult<()>;
 
-    /// Mark job as failed with error message
+    /// Record a failed attempt: reschedule with exponential backoff while attempts remain,
+    /// otherwise move the job to the dead-letter state
     async fn mark_failed(&self, job_id: Uuid, error: &str) -> AppResult<()>;
 
+    /// Like `mark_failed`, but reschedule after the delay the provider asked for
+    async fn mark_rate_limited(
+        &self,
+        job_id: Uuid,
+        error: &str,
+        retry_after: Duration,
+    ) -> AppResult<()>;
+
+    /// Fail a job without further retries (errors that will never succeed)
+    async fn mark_failed_permanently(&self, job_id: Uuid, error: &str) -> AppResult<()>;
+
     /// Get job by ID
     async fn get_by_id(&self, job_id: Uuid) -> AppResult<Option<JobRecord>>;
 
@@ -31,19 +53,48 @@ pub trait JobRepository: Send + Sync {
     /// Get all jobs for a specific anime (for UI progress tracking)
     async fn get_jobs_for_anime(&self, anime_id: Uuid) -> AppResult<Vec<JobRecord>>;
 
-    /// Delete old completed jobs (cleanup)
+    /// List jobs matching a filter, newest first
+    async fn list_jobs(&self, filter: JobFilter) -> AppResult<Vec<JobRecord>>;
+
+    /// Reset a failed, dead-lettered or cancelled job to pending with a fresh attempt budget
+    /// Returns None if the job doesn't exist or is not in a retryable state
+    async fn retry_job(&self, job_id: Uuid) -> AppResult<Option<JobRecord>>;
+
+    /// Reset every failed and dead-lettered job to pending
+    async fn retry_all_failed(&self) -> AppResult<usize>;
+
+    /// Cancel a pending job
+    /// Returns None if the job doesn't exist or has already been picked up
+    async fn cancel_job(&self, job_id: Uuid) -> AppResult<Option<JobRecord>>;
+
+    /// Cancel all pending jobs, optionally only those of one type
+    async fn cancel_pending(&self, job_type: Option<JobType>) -> AppResult<usize>;
+
+    /// Delete old finished (completed, failed, dead-lettered or cancelled) jobs (cleanup)
     async fn delete_old_completed(&self, days: i32) -> AppResult<usize>;
 
     /// Get job statistics
     async fn get_statistics(&self) -> AppResult<JobStatistics>;
 }
 
+/// Filter for listing jobs
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+pub struct JobFilter {
+    pub status: Option<JobStatus>,
+    pub job_type: Option<JobType>,
+    pub anime_id: Option<Uuid>,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+}
+
 /// Job queue statistics
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
 pub struct JobStatistics {
     pub pending_count: i64,
     pub running_count: i64,
     pub completed_count: i64,
     pub failed_count: i64,
+    pub cancelled_count: i64,
+    pub dead_letter_count: i64,
     pub total_count: i64,
 }
@@ -1,5 +1,8 @@
 /// Value objects for jobs domain
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use super::entities::JobStatus;
 
 /// Job status enum matching database type
 #[derive(
@@ -20,6 +23,10 @@ pub enum JobStatusDb {
     Running,
     Completed,
     Failed,
+    Cancelled,
+    #[serde(rename = "dead_letter")]
+    #[db_rename = "dead_letter"]
+    DeadLetter,
 }
 
 impl std::fmt::Display for JobStatusDb {
@@ -29,6 +36,114 @@ impl std::fmt::Display for JobStatusDb {
             JobStatusDb::Running => write!(f, "running"),
             JobStatusDb::Completed => write!(f, "completed"),
             JobStatusDb::Failed => write!(f, "failed"),
+            JobStatusDb::Cancelled => write!(f, "cancelled"),
+            JobStatusDb::DeadLetter => write!(f, "dead_letter"),
+        }
+    }
+}
+
+impl From<JobStatus> for JobStatusDb {
+    fn from(status: JobStatus) -> Self {
+        match status {
+            JobStatus::Pending => JobStatusDb::Pending,
+            JobStatus::Running => JobStatusDb::Running,
+            JobStatus::Completed => JobStatusDb::Completed,
+            JobStatus::Failed => JobStatusDb::Failed,
+            JobStatus::Cancelled => JobStatusDb::Cancelled,
+            JobStatus::DeadLetter => JobStatusDb::DeadLetter,
+        }
+    }
+}
+
+/// Exponential backoff with jitter for rescheduling failed jobs
+///
+/// Attempt `n` waits `base_delay * multiplier^(n-1)`, capped at `max_delay`, then
+/// spread by up to `jitter` of that delay so jobs failing together don't retry together.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryBackoff {
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    pub multiplier: f64,
+    /// Fraction of the delay that is randomised (0.0 - 1.0)
+    pub jitter: f64,
+}
+
+impl Default for RetryBackoff {
+    fn default() -> Self {
+        Self {
+            base_delay: Duration::from_secs(30),
+            max_delay: Duration::from_secs(60 * 60),
+            multiplier: 2.0,
+            jitter: 0.25,
+        }
+    }
+}
+
+impl RetryBackoff {
+    /// Retry immediately (for testing)
+    pub fn none() -> Self {
+        Self {
+            base_delay: Duration::ZERO,
+            max_delay: Duration::ZERO,
+            multiplier: 1.0,
+            jitter: 0.0,
+        }
+    }
+
+    /// Delay before the next try after `attempts` failed attempts, without jitter
+    pub fn base_delay_for(&self, attempts: i32) -> Duration {
+        let exponent = attempts.saturating_sub(1).clamp(0, 32);
+        let secs = self.base_delay.as_secs_f64() * self.multiplier.powi(exponent);
+        Duration::from_secs_f64(secs.min(self.max_delay.as_secs_f64()))
+    }
+
+    /// Delay before the next try after `attempts` failed attempts
+    pub fn delay_for(&self, attempts: i32) -> Duration {
+        self.with_jitter(self.base_delay_for(attempts))
+    }
+
+    /// Keep `1 - jitter` of the delay and randomise the rest
+    pub fn with_jitter(&self, delay: Duration) -> Duration {
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        if jitter == 0.0 || delay.is_zero() {
+            return delay;
         }
+
+        let factor = 1.0 - jitter * rand::random::<f64>();
+        delay.mul_f64(factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_doubles_until_cap() {
+        let backoff = RetryBackoff {
+            jitter: 0.0,
+            ..RetryBackoff::default()
+        };
+
+        assert_eq!(backoff.delay_for(1), Duration::from_secs(30));
+        assert_eq!(backoff.delay_for(2), Duration::from_secs(60));
+        assert_eq!(backoff.delay_for(3), Duration::from_secs(120));
+        assert_eq!(backoff.delay_for(20), Duration::from_secs(3600));
+    }
+
+    #[test]
+    fn test_jitter_stays_within_bounds() {
+        let backoff = RetryBackoff::default();
+
+        for _ in 0..100 {
+            let delay = backoff.delay_for(2);
+            assert!(delay <= Duration::from_secs(60));
+            assert!(delay >= Duration::from_secs(45));
+        }
+    }
+
+    #[test]
+    fn test_no_backoff_retries_immediately() {
+        assert_eq!(RetryBackoff::none().delay_for(3), Duration::ZERO);
     }
 }
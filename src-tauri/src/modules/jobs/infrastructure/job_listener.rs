@@ -0,0 +1,105 @@
+/// Postgres LISTEN/NOTIFY bridge that wakes the worker pool on enqueue
+///
+/// A trigger on `background_jobs` sends a notification on the `background_jobs`
+/// channel whenever a row becomes pending. Diesel connections can't receive
+/// notifications, so this listener keeps one dedicated tokio-postgres connection
+/// and forwards every notification to the pool's wakeup handle.
+use crate::shared::errors::{AppError, AppResult};
+use crate::{log_debug, log_info, log_warn};
+use futures::StreamExt;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, Notify};
+use tokio_postgres::{AsyncMessage, NoTls};
+
+/// Channel used by the `notify_background_job` trigger
+pub const JOB_CHANNEL: &str = "background_jobs";
+
+const INITIAL_RECONNECT_DELAY: Duration = Duration::from_secs(1);
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(60);
+
+pub struct PgJobListener {
+    database_url: String,
+}
+
+impl PgJobListener {
+    pub fn new(database_url: String) -> Self {
+        Self { database_url }
+    }
+
+    /// Create a listener from `DATABASE_URL`, if set
+    pub fn from_env() -> Option<Self> {
+        std::env::var("DATABASE_URL").ok().map(Self::new)
+    }
+
+    /// Listen forever, reconnecting with backoff; the pool keeps polling meanwhile
+    pub async fn run(self, wakeup: Arc<Notify>) {
+        let mut delay = INITIAL_RECONNECT_DELAY;
+
+        loop {
+            match self.listen(&wakeup).await {
+                Ok(()) => {
+                    log_warn!("Job listener connection closed, reconnecting");
+                    delay = INITIAL_RECONNECT_DELAY;
+                }
+                Err(e) => {
+                    log_warn!("Job listener unavailable ({}), retrying in {:?}", e, delay);
+                }
+            }
+
+            tokio::time::sleep(delay).await;
+            delay = (delay * 2).min(MAX_RECONNECT_DELAY);
+        }
+    }
+
+    /// Hold one LISTEN session until the connection drops
+    async fn listen(&self, wakeup: &Notify) -> AppResult<()> {
+        let (client, mut connection) = tokio_postgres::connect(&self.database_url, NoTls)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Failed to connect listener: {}", e)))?;
+
+        // The connection has to be polled for the LISTEN query itself to complete
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let driver = tokio::spawn(async move {
+            let mut messages = futures::stream::poll_fn(move |cx| connection.poll_message(cx));
+            while let Some(message) = messages.next().await {
+                match message {
+                    Ok(AsyncMessage::Notification(notification)) => {
+                        if tx.send(notification).is_err() {
+                            break;
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(e) => return Err(e),
+                }
+            }
+            Ok(())
+        });
+
+        client
+            .batch_execute(&format!("LISTEN {}", JOB_CHANNEL))
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Failed to LISTEN: {}", e)))?;
+        log_info!("Listening for new jobs on '{}'", JOB_CHANNEL);
+
+        // Catch up on anything enqueued while we were disconnected
+        wakeup.notify_one();
+
+        while let Some(notification) = rx.recv().await {
+            log_debug!("Job notification: {}", notification.payload());
+            wakeup.notify_one();
+        }
+
+        match driver.await {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => Err(AppError::DatabaseError(format!(
+                "Listener connection failed: {}",
+                e
+            ))),
+            Err(e) => Err(AppError::InternalError(format!(
+                "Listener task failed: {}",
+                e
+            ))),
+        }
+    }
+}
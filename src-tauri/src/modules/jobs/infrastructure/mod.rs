@@ -1,4 +1,6 @@
+pub mod job_listener;
 pub mod models;
 pub mod repository;
 
+pub use job_listener::PgJobListener;
 pub use repository::JobRepositoryImpl;
@@ -13,6 +13,8 @@ pub struct NewJob {
     pub job_type: String,
     pub payload: JsonValue,
     pub priority: i32,
+    /// `None` keeps the column default (`NOW()`)
+    pub scheduled_at: Option<DateTime<Utc>>,
 }
 
 /// Diesel model for querying existing jobs
@@ -30,6 +32,7 @@ pub struct BackgroundJobModel {
     pub started_at: Option<DateTime<Utc>>,
     pub completed_at: Option<DateTime<Utc>>,
     pub error: Option<String>,
+    pub scheduled_at: DateTime<Utc>,
 }
 
 impl BackgroundJobModel {
@@ -44,6 +47,7 @@ impl BackgroundJobModel {
             attempts: self.attempts,
             max_attempts: self.max_attempts,
             created_at: self.created_at,
+            scheduled_at: self.scheduled_at,
             started_at: self.started_at,
             completed_at: self.completed_at,
             error: self.error,
@@ -1,14 +1,16 @@
 /// Diesel-based implementation of JobRepository
 ///
 /// Uses PostgreSQL with SELECT FOR UPDATE SKIP LOCKED for atomic job dequeuing.
-use crate::modules::jobs::domain::entities::{Job, JobRecord};
-use crate::modules::jobs::domain::repository::{JobRepository, JobStatistics};
+use crate::modules::jobs::domain::entities::{Job, JobRecord, JobType};
+use crate::modules::jobs::domain::repository::{JobFilter, JobRepository, JobStatistics};
+use crate::modules::jobs::domain::value_objects::{JobStatusDb, RetryBackoff};
 use crate::modules::jobs::infrastructure::models::{BackgroundJobModel, NewJob};
 use crate::schema::background_jobs;
 use crate::shared::errors::{AppError, AppResult};
 use crate::shared::infrastructure::database::DbPool;
 use async_trait::async_trait;
 use diesel::prelude::*;
+use std::time::Duration;
 use uuid::Uuid;
 
 /// Helper struct for COUNT queries
@@ -18,13 +20,33 @@ struct CountResult {
     count: i64,
 }
 
+/// Helper struct for reading a job's retry budget
+#[derive(QueryableByName)]
+struct AttemptsResult {
+    #[diesel(sql_type = diesel::sql_types::Integer)]
+    attempts: i32,
+    #[diesel(sql_type = diesel::sql_types::Integer)]
+    max_attempts: i32,
+}
+
+/// Default page size when listing jobs
+const DEFAULT_LIST_LIMIT: u32 = 100;
+
+/// Upper bound for a single job listing
+const MAX_LIST_LIMIT: u32 = 500;
+
 pub struct JobRepositoryImpl {
     pool: DbPool,
+    backoff: RetryBackoff,
 }
 
 impl JobRepositoryImpl {
     pub fn new(pool: DbPool) -> Self {
-        Self { pool }
+        Self::with_backoff(pool, RetryBackoff::default())
+    }
+
+    pub fn with_backoff(pool: DbPool, backoff: RetryBackoff) -> Self {
+        Self { pool, backoff }
     }
 
     /// Get database connection from pool
@@ -37,6 +59,62 @@ impl JobRepositoryImpl {
             .get()
             .map_err(|e| AppError::DatabaseError(format!("Failed to get connection: {}", e)))
     }
+
+    /// Push a failed job back by `delay(attempts)` while attempts remain, otherwise dead-letter it
+    fn record_failure(
+        &self,
+        job_id: Uuid,
+        error: &str,
+        delay: impl FnOnce(i32) -> Duration,
+    ) -> AppResult<()> {
+        let mut conn = self.get_conn()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let job: Option<AttemptsResult> = diesel::sql_query(
+                "SELECT attempts, max_attempts FROM background_jobs WHERE id = $1 FOR UPDATE",
+            )
+            .bind::<diesel::sql_types::Uuid, _>(job_id)
+            .get_result(conn)
+            .optional()?;
+
+            let Some(job) = job else {
+                return Ok(());
+            };
+
+            if job.attempts >= job.max_attempts {
+                diesel::sql_query(
+                    "UPDATE background_jobs
+                     SET status = 'dead_letter',
+                         started_at = NULL,
+                         completed_at = NOW(),
+                         error = $2
+                     WHERE id = $1",
+                )
+                .bind::<diesel::sql_types::Uuid, _>(job_id)
+                .bind::<diesel::sql_types::Text, _>(error)
+                .execute(conn)?;
+            } else {
+                let delay_ms = delay(job.attempts).as_millis().min(i64::MAX as u128) as i64;
+
+                diesel::sql_query(
+                    "UPDATE background_jobs
+                     SET status = 'pending',
+                         scheduled_at = NOW() + $3 * INTERVAL '1 millisecond',
+                         started_at = NULL,
+                         completed_at = NULL,
+                         error = $2
+                     WHERE id = $1",
+                )
+                .bind::<diesel::sql_types::Uuid, _>(job_id)
+                .bind::<diesel::sql_types::Text, _>(error)
+                .bind::<diesel::sql_types::BigInt, _>(delay_ms)
+                .execute(conn)?;
+            }
+
+            Ok(())
+        })
+        .map_err(|e| AppError::DatabaseError(format!("Failed to mark job as failed: {}", e)))
+    }
 }
 
 #[async_trait]
@@ -46,6 +124,7 @@ impl JobRepository for JobRepositoryImpl {
             job_type: job.job_type.to_string(),
             payload: job.payload,
             priority: job.priority,
+            scheduled_at: job.scheduled_at,
         };
 
         let mut conn = self.get_conn()?;
@@ -74,13 +153,14 @@ impl JobRepository for JobRepositoryImpl {
                 FROM background_jobs
                 WHERE status = 'pending'
                   AND attempts < max_attempts
+                  AND scheduled_at <= NOW()
                 ORDER BY priority ASC, created_at ASC
                 LIMIT 1
                 FOR UPDATE SKIP LOCKED
             )
             RETURNING id, job_type, payload, priority, status,
                       attempts, max_attempts, created_at,
-                      started_at, completed_at, error
+                      started_at, completed_at, error, scheduled_at
             "#,
         )
         .get_result(&mut conn)
@@ -90,6 +170,63 @@ impl JobRepository for JobRepositoryImpl {
         Ok(result.map(|job| job.to_job_record()))
     }
 
+    async fn dequeue_matching(&self, job_types: &[JobType]) -> AppResult<Option<JobRecord>> {
+        if job_types.is_empty() {
+            return Ok(None);
+        }
+
+        let mut conn = self.get_conn()?;
+        let types: Vec<String> = job_types.iter().map(|t| t.to_string()).collect();
+
+        let result: Option<BackgroundJobModel> = diesel::sql_query(
+            r#"
+            UPDATE background_jobs
+            SET status = 'running',
+                started_at = NOW(),
+                attempts = attempts + 1
+            WHERE id = (
+                SELECT id
+                FROM background_jobs
+                WHERE status = 'pending'
+                  AND attempts < max_attempts
+                  AND job_type = ANY($1)
+                  AND scheduled_at <= NOW()
+                ORDER BY priority ASC, created_at ASC
+                LIMIT 1
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING id, job_type, payload, priority, status,
+                      attempts, max_attempts, created_at,
+                      started_at, completed_at, error, scheduled_at
+            "#,
+        )
+        .bind::<diesel::sql_types::Array<diesel::sql_types::Text>, _>(types)
+        .get_result(&mut conn)
+        .optional()
+        .map_err(|e| AppError::DatabaseError(format!("Failed to dequeue job: {}", e)))?;
+
+        Ok(result.map(|job| job.to_job_record()))
+    }
+
+    async fn requeue_interrupted(&self) -> AppResult<usize> {
+        let mut conn = self.get_conn()?;
+
+        // The interrupted attempt doesn't count against the retry budget
+        let requeued = diesel::sql_query(
+            "UPDATE background_jobs
+             SET status = 'pending',
+                 started_at = NULL,
+                 attempts = GREATEST(attempts - 1, 0)
+             WHERE status = 'running'",
+        )
+        .execute(&mut conn)
+        .map_err(|e| {
+            AppError::DatabaseError(format!("Failed to re-queue interrupted jobs: {}", e))
+        })?;
+
+        Ok(requeued)
+    }
+
     async fn mark_completed(&self, job_id: Uuid) -> AppResult<()> {
         let mut conn = self.get_conn()?;
 
@@ -106,22 +243,30 @@ impl JobRepository for JobRepositoryImpl {
     }
 
     async fn mark_failed(&self, job_id: Uuid, error: &str) -> AppResult<()> {
+        self.record_failure(job_id, error, |attempts| self.backoff.delay_for(attempts))
+    }
+
+    async fn mark_rate_limited(
+        &self,
+        job_id: Uuid,
+        error: &str,
+        retry_after: Duration,
+    ) -> AppResult<()> {
+        // Jitter only ever adds to the provider's delay, retrying earlier would just be rejected again
+        self.record_failure(job_id, error, |_| {
+            retry_after + self.backoff.with_jitter(retry_after / 4)
+        })
+    }
+
+    async fn mark_failed_permanently(&self, job_id: Uuid, error: &str) -> AppResult<()> {
         let mut conn = self.get_conn()?;
 
-        // If attempts < max_attempts, reset to pending for retry
-        // Otherwise, mark as permanently failed
         diesel::sql_query(
             "UPDATE background_jobs
-             SET status = CASE
-                 WHEN attempts < max_attempts THEN 'pending'::job_status
-                 ELSE 'failed'::job_status
-             END,
-             completed_at = CASE
-                 WHEN attempts >= max_attempts THEN NOW()
-                 ELSE NULL
-             END,
-             started_at = NULL,
-             error = $2
+             SET status = 'failed',
+                 started_at = NULL,
+                 completed_at = NOW(),
+                 error = $2
              WHERE id = $1",
         )
         .bind::<diesel::sql_types::Uuid, _>(job_id)
@@ -150,7 +295,7 @@ impl JobRepository for JobRepositoryImpl {
         let jobs: Vec<BackgroundJobModel> = diesel::sql_query(
             "SELECT id, job_type, payload, priority, status,
                     attempts, max_attempts, created_at,
-                    started_at, completed_at, error
+                    started_at, completed_at, error, scheduled_at
              FROM background_jobs
              WHERE status = 'pending'
              ORDER BY priority ASC, created_at ASC",
@@ -168,7 +313,7 @@ impl JobRepository for JobRepositoryImpl {
         let jobs: Vec<BackgroundJobModel> = diesel::sql_query(
             "SELECT id, job_type, payload, priority, status,
                     attempts, max_attempts, created_at,
-                    started_at, completed_at, error
+                    started_at, completed_at, error, scheduled_at
              FROM background_jobs
              WHERE payload->>'anime_id' = $1
              ORDER BY created_at DESC",
@@ -180,12 +325,131 @@ impl JobRepository for JobRepositoryImpl {
         Ok(jobs.into_iter().map(|j| j.to_job_record()).collect())
     }
 
+    async fn list_jobs(&self, filter: JobFilter) -> AppResult<Vec<JobRecord>> {
+        let mut conn = self.get_conn()?;
+
+        let mut query = background_jobs::table.into_boxed();
+
+        if let Some(status) = filter.status {
+            let status: JobStatusDb = status.into();
+            query = query.filter(background_jobs::status.eq(status));
+        }
+        if let Some(job_type) = filter.job_type {
+            query = query.filter(background_jobs::job_type.eq(job_type.to_string()));
+        }
+        if let Some(anime_id) = filter.anime_id {
+            query = query.filter(
+                diesel::dsl::sql::<diesel::sql_types::Bool>("payload->>'anime_id' = ")
+                    .bind::<diesel::sql_types::Text, _>(anime_id.to_string()),
+            );
+        }
+
+        let limit = filter
+            .limit
+            .unwrap_or(DEFAULT_LIST_LIMIT)
+            .clamp(1, MAX_LIST_LIMIT);
+
+        let jobs: Vec<BackgroundJobModel> = query
+            .order((
+                background_jobs::created_at.desc(),
+                background_jobs::id.asc(),
+            ))
+            .offset(filter.offset.unwrap_or(0) as i64)
+            .limit(limit as i64)
+            .load(&mut conn)
+            .map_err(|e| AppError::DatabaseError(format!("Failed to list jobs: {}", e)))?;
+
+        Ok(jobs.into_iter().map(|j| j.to_job_record()).collect())
+    }
+
+    async fn retry_job(&self, job_id: Uuid) -> AppResult<Option<JobRecord>> {
+        let mut conn = self.get_conn()?;
+
+        let job: Option<BackgroundJobModel> = diesel::sql_query(
+            "UPDATE background_jobs
+             SET status = 'pending',
+                 attempts = 0,
+                 started_at = NULL,
+                 completed_at = NULL,
+                 error = NULL,
+                 scheduled_at = NOW()
+             WHERE id = $1
+               AND status IN ('failed', 'dead_letter', 'cancelled')
+             RETURNING id, job_type, payload, priority, status,
+                       attempts, max_attempts, created_at,
+                       started_at, completed_at, error, scheduled_at",
+        )
+        .bind::<diesel::sql_types::Uuid, _>(job_id)
+        .get_result(&mut conn)
+        .optional()
+        .map_err(|e| AppError::DatabaseError(format!("Failed to retry job: {}", e)))?;
+
+        Ok(job.map(|j| j.to_job_record()))
+    }
+
+    async fn retry_all_failed(&self) -> AppResult<usize> {
+        let mut conn = self.get_conn()?;
+
+        let updated = diesel::sql_query(
+            "UPDATE background_jobs
+             SET status = 'pending',
+                 attempts = 0,
+                 started_at = NULL,
+                 completed_at = NULL,
+                 error = NULL,
+                 scheduled_at = NOW()
+             WHERE status IN ('failed', 'dead_letter')",
+        )
+        .execute(&mut conn)
+        .map_err(|e| AppError::DatabaseError(format!("Failed to retry failed jobs: {}", e)))?;
+
+        Ok(updated)
+    }
+
+    async fn cancel_job(&self, job_id: Uuid) -> AppResult<Option<JobRecord>> {
+        let mut conn = self.get_conn()?;
+
+        let job: Option<BackgroundJobModel> = diesel::sql_query(
+            "UPDATE background_jobs
+             SET status = 'cancelled', completed_at = NOW()
+             WHERE id = $1
+               AND status = 'pending'
+             RETURNING id, job_type, payload, priority, status,
+                       attempts, max_attempts, created_at,
+                       started_at, completed_at, error, scheduled_at",
+        )
+        .bind::<diesel::sql_types::Uuid, _>(job_id)
+        .get_result(&mut conn)
+        .optional()
+        .map_err(|e| AppError::DatabaseError(format!("Failed to cancel job: {}", e)))?;
+
+        Ok(job.map(|j| j.to_job_record()))
+    }
+
+    async fn cancel_pending(&self, job_type: Option<JobType>) -> AppResult<usize> {
+        let mut conn = self.get_conn()?;
+
+        let cancelled = diesel::sql_query(
+            "UPDATE background_jobs
+             SET status = 'cancelled', completed_at = NOW()
+             WHERE status = 'pending'
+               AND ($1::text IS NULL OR job_type = $1)",
+        )
+        .bind::<diesel::sql_types::Nullable<diesel::sql_types::Text>, _>(
+            job_type.map(|t| t.to_string()),
+        )
+        .execute(&mut conn)
+        .map_err(|e| AppError::DatabaseError(format!("Failed to cancel pending jobs: {}", e)))?;
+
+        Ok(cancelled)
+    }
+
     async fn delete_old_completed(&self, days: i32) -> AppResult<usize> {
         let mut conn = self.get_conn()?;
 
         let deleted = diesel::sql_query(
             "DELETE FROM background_jobs
-             WHERE status IN ('completed', 'failed')
+             WHERE status IN ('completed', 'failed', 'dead_letter', 'cancelled')
              AND completed_at < NOW() - INTERVAL '1 day' * $1",
         )
         .bind::<diesel::sql_types::Integer, _>(days)
@@ -223,6 +487,18 @@ impl JobRepository for JobRepositoryImpl {
         .get_result(&mut conn)
         .map_err(|e| AppError::DatabaseError(format!("Failed to count failed: {}", e)))?;
 
+        let cancelled: CountResult = diesel::sql_query(
+            "SELECT COUNT(*) as count FROM background_jobs WHERE status = 'cancelled'",
+        )
+        .get_result(&mut conn)
+        .map_err(|e| AppError::DatabaseError(format!("Failed to count cancelled: {}", e)))?;
+
+        let dead_letter: CountResult = diesel::sql_query(
+            "SELECT COUNT(*) as count FROM background_jobs WHERE status = 'dead_letter'",
+        )
+        .get_result(&mut conn)
+        .map_err(|e| AppError::DatabaseError(format!("Failed to count dead-lettered: {}", e)))?;
+
         let total: CountResult = diesel::sql_query("SELECT COUNT(*) as count FROM background_jobs")
             .get_result(&mut conn)
             .map_err(|e| AppError::DatabaseError(format!("Failed to count total: {}", e)))?;
@@ -232,6 +508,8 @@ impl JobRepository for JobRepositoryImpl {
             running_count: running.count,
             completed_count: completed.count,
             failed_count: failed.count,
+            cancelled_count: cancelled.count,
+            dead_letter_count: dead_letter.count,
             total_count: total.count,
         })
     }
@@ -3,11 +3,15 @@
 /// Provides a PostgreSQL-based job queue for async operations like:
 /// - Anime enrichment (fetching missing data from providers)
 /// - Relations discovery (finding and ingesting related anime)
+/// - Episode sync (pulling an anime's episode list page by page)
+/// - News and statistics syncs, each scheduling its next run
 ///
 /// Architecture:
 /// - Domain: Entities and repository trait
 /// - Infrastructure: Diesel-based repository implementation
-/// - Worker: Background worker that processes jobs
+/// - Worker: Background worker pool that processes jobs concurrently
+/// - Commands: Queue inspection and lifecycle controls for the frontend
+pub mod commands;
 pub mod domain;
 pub mod infrastructure;
 pub mod worker;
@@ -15,9 +19,11 @@ pub mod worker;
 // Re-exports for easy access
 pub use domain::{
     entities::{
-        EnrichmentJobPayload, Job, JobRecord, JobStatus, JobType, RelationsDiscoveryJobPayload,
+        EnrichmentJobPayload, EpisodeSyncJobPayload, Job, JobRecord, JobStatus, JobType,
+        RelationsDiscoveryJobPayload,
     },
-    repository::{JobRepository, JobStatistics},
+    repository::{JobFilter, JobRepository, JobStatistics},
+    value_objects::RetryBackoff,
 };
-pub use infrastructure::JobRepositoryImpl;
-pub use worker::{BackgroundWorker, WorkerStatistics};
+pub use infrastructure::{JobRepositoryImpl, PgJobListener};
+pub use worker::{BackgroundWorker, WorkerPoolConfig, WorkerStatistics};
@@ -1,104 +1,359 @@
-/// Background worker for processing anime enrichment and relations discovery jobs
+/// Background worker pool for processing anime enrichment, relations discovery,
+/// episode sync, news sync and statistics sync jobs
 ///
-/// This worker continuously polls the job queue and processes jobs asynchronously.
-/// It uses tokio::spawn for background execution, suitable for desktop applications.
+/// The pool runs several jobs concurrently, capped globally and per job type so one
+/// kind of work can't starve the others. It wakes as soon as a job is enqueued (via
+/// Postgres LISTEN/NOTIFY, see `PgJobListener`), falls back to polling, and drains
+/// in-flight jobs on shutdown.
+use crate::modules::anime::application::ingestion_service::JobPriority;
+use crate::modules::anime::application::ports::EventPublisher;
 use crate::modules::anime::application::service::AnimeService;
+use crate::modules::anime::domain::events::{AnimeEnrichedEvent, RelationsDiscoveredEvent};
 use crate::modules::anime::domain::services::anime_relations_service::AnimeRelationsService;
+use crate::modules::episode::EpisodeService;
 use crate::modules::jobs::domain::entities::{
-    EnrichmentJobPayload, JobType, RelationsDiscoveryJobPayload,
+    EnrichmentJobPayload, EpisodeSyncJobPayload, Job, JobRecord, JobStatus, JobType,
+    RelationsDiscoveryJobPayload,
 };
-use crate::modules::jobs::domain::repository::JobRepository;
+use crate::modules::jobs::domain::repository::{JobFilter, JobRepository};
+use crate::modules::news::NewsService;
 use crate::modules::provider::ProviderService;
+use crate::modules::statistics::StatisticsService;
 use crate::shared::errors::AppResult;
 use crate::{log_debug, log_error, log_info, log_warn};
+use chrono::Utc;
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::{watch, Notify, OwnedSemaphorePermit, Semaphore};
+use tokio::task::JoinSet;
+
+/// Time between two news syncs; each run schedules the next one
+const NEWS_SYNC_INTERVAL_HOURS: i64 = 6;
+
+/// Time between two statistics syncs; momentum needs roughly daily snapshots
+const STATISTICS_SYNC_INTERVAL_HOURS: i64 = 24;
+
+/// Concurrency and timing configuration for the worker pool
+#[derive(Debug, Clone)]
+pub struct WorkerPoolConfig {
+    /// Maximum number of jobs running at once across all types
+    pub max_concurrency: usize,
+    /// Per-type caps; types without an entry are only bounded by `max_concurrency`
+    pub per_type_limits: HashMap<JobType, usize>,
+    /// Fallback poll interval when no notification arrives
+    pub poll_interval: Duration,
+    /// How long shutdown waits for in-flight jobs before abandoning them
+    pub drain_timeout: Duration,
+}
 
-/// Background worker that processes jobs from the queue
+impl Default for WorkerPoolConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrency: 4,
+            per_type_limits: HashMap::from([
+                (JobType::Enrichment, 4),
+                (JobType::RelationsDiscovery, 2),
+                // Each sync walks every episode page, keep it off Jikan's rate limit
+                (JobType::EpisodeSync, 1),
+                (JobType::NewsSync, 1),
+                (JobType::StatisticsSync, 1),
+            ]),
+            poll_interval: Duration::from_secs(5),
+            drain_timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Background worker pool that processes jobs from the queue
 pub struct BackgroundWorker {
     job_repository: Arc<dyn JobRepository>,
     anime_service: Arc<AnimeService>,
     provider_service: Arc<ProviderService>,
     relations_service: Arc<AnimeRelationsService>,
-    poll_interval: Duration,
+    episode_service: Arc<EpisodeService>,
+    news_service: Arc<NewsService>,
+    statistics_service: Arc<StatisticsService>,
+    event_publisher: Arc<dyn EventPublisher>,
+    config: WorkerPoolConfig,
+    global_slots: Arc<Semaphore>,
+    type_slots: HashMap<JobType, Arc<Semaphore>>,
+    wakeup: Arc<Notify>,
+    drained: watch::Sender<bool>,
     is_running: Arc<tokio::sync::RwLock<bool>>,
+    is_paused: Arc<tokio::sync::RwLock<bool>>,
 }
 
 impl BackgroundWorker {
-    /// Create a new background worker
+    /// Create a new background worker pool with the default configuration
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         job_repository: Arc<dyn JobRepository>,
         anime_service: Arc<AnimeService>,
         provider_service: Arc<ProviderService>,
         relations_service: Arc<AnimeRelationsService>,
+        episode_service: Arc<EpisodeService>,
+        news_service: Arc<NewsService>,
+        statistics_service: Arc<StatisticsService>,
+        event_publisher: Arc<dyn EventPublisher>,
     ) -> Self {
+        let config = WorkerPoolConfig::default();
+        let (drained, _) = watch::channel(true);
+
         Self {
             job_repository,
             anime_service,
             provider_service,
             relations_service,
-            poll_interval: Duration::from_secs(5), // Poll every 5 seconds
+            episode_service,
+            news_service,
+            statistics_service,
+            event_publisher,
+            global_slots: Arc::new(Semaphore::new(config.max_concurrency)),
+            type_slots: Self::build_type_slots(&config),
+            config,
+            wakeup: Arc::new(Notify::new()),
+            drained,
             is_running: Arc::new(tokio::sync::RwLock::new(false)),
+            is_paused: Arc::new(tokio::sync::RwLock::new(false)),
         }
     }
 
-    /// Start the background worker
+    /// Replace the pool configuration (call before `run`)
+    pub fn with_config(mut self, config: WorkerPoolConfig) -> Self {
+        self.global_slots = Arc::new(Semaphore::new(config.max_concurrency.max(1)));
+        self.type_slots = Self::build_type_slots(&config);
+        self.config = config;
+        self
+    }
+
+    fn build_type_slots(config: &WorkerPoolConfig) -> HashMap<JobType, Arc<Semaphore>> {
+        JobType::all()
+            .iter()
+            .map(|job_type| {
+                let limit = config
+                    .per_type_limits
+                    .get(job_type)
+                    .copied()
+                    .unwrap_or(config.max_concurrency)
+                    .clamp(1, config.max_concurrency.max(1));
+                (*job_type, Arc::new(Semaphore::new(limit)))
+            })
+            .collect()
+    }
+
+    /// Handle used to wake the pool when new jobs are enqueued
+    pub fn wakeup_handle(&self) -> Arc<Notify> {
+        Arc::clone(&self.wakeup)
+    }
+
+    /// Start the worker pool
     ///
-    /// This method runs the worker loop. Call it with tokio::spawn or tauri::async_runtime::spawn
-    /// to run in the background.
+    /// This method runs the dispatch loop until `stop` is called, then drains in-flight
+    /// jobs. Call it with tokio::spawn or tauri::async_runtime::spawn to run in the background.
     pub async fn run(self: Arc<Self>) {
-        log_info!("Background worker started");
+        log_info!(
+            "Background worker pool started (max {} concurrent jobs)",
+            self.config.max_concurrency
+        );
 
         // Mark as running
         {
             let mut running = self.is_running.write().await;
             *running = true;
         }
+        self.drained.send_replace(false);
+
+        // Jobs left running by a previous session will never complete on their own
+        match self.job_repository.requeue_interrupted().await {
+            Ok(0) => {}
+            Ok(count) => log_info!("Re-queued {} jobs interrupted by the last shutdown", count),
+            Err(e) => log_warn!("Failed to re-queue interrupted jobs: {}", e),
+        }
+
+        // Periodic syncs reschedule themselves, but each chain has to start somewhere
+        for job in [
+            Job::news_sync(JobPriority::Low as i32),
+            Job::statistics_sync(JobPriority::Low as i32),
+        ] {
+            let job_type = job.job_type;
+            if let Err(e) = self.schedule_recurring(job, Utc::now()).await {
+                log_warn!("Failed to schedule {}: {}", job_type, e);
+            }
+        }
+
+        let mut in_flight = JoinSet::new();
 
         loop {
             // Check if we should stop
-            {
-                let running = self.is_running.read().await;
-                if !*running {
-                    log_info!("Background worker stopped");
-                    break;
+            if !*self.is_running.read().await {
+                break;
+            }
+
+            // Reap finished jobs so their panics are logged
+            while let Some(result) = in_flight.try_join_next() {
+                if let Err(e) = result {
+                    log_error!("Job task panicked: {}", e);
                 }
             }
 
-            // Try to dequeue and process a job
-            match self.process_next_job().await {
-                Ok(processed) => {
-                    if !processed {
-                        // No jobs available, sleep before next poll
-                        tokio::time::sleep(self.poll_interval).await;
+            // While paused, keep the loop alive but leave the queue untouched
+            let dispatched = if *self.is_paused.read().await {
+                false
+            } else {
+                match self.dispatch_next_job(&mut in_flight).await {
+                    Ok(dispatched) => dispatched,
+                    Err(e) => {
+                        log_error!("Error in worker loop: {}", e);
+                        false
                     }
-                    // If job was processed, immediately try to get next one
                 }
-                Err(e) => {
-                    log_error!("Error in worker loop: {}", e);
-                    tokio::time::sleep(self.poll_interval).await;
+            };
+
+            // If a job was dispatched, immediately try to fill the next slot
+            if dispatched {
+                continue;
+            }
+
+            tokio::select! {
+                _ = self.wakeup.notified() => {}
+                _ = tokio::time::sleep(self.config.poll_interval) => {}
+                Some(result) = in_flight.join_next(), if !in_flight.is_empty() => {
+                    if let Err(e) = result {
+                        log_error!("Job task panicked: {}", e);
+                    }
                 }
             }
         }
+
+        self.drain(in_flight).await;
+        self.drained.send_replace(true);
+        log_info!("Background worker stopped");
     }
 
-    /// Stop the background worker
+    /// Claim a free slot and start the highest priority job that fits it
+    ///
+    /// Returns true if a job was started, false if no slot or job was available
+    async fn dispatch_next_job(self: &Arc<Self>, in_flight: &mut JoinSet<()>) -> AppResult<bool> {
+        let Ok(global_permit) = Arc::clone(&self.global_slots).try_acquire_owned() else {
+            return Ok(false);
+        };
+
+        let available: Vec<JobType> = self
+            .type_slots
+            .iter()
+            .filter(|(_, slots)| slots.available_permits() > 0)
+            .map(|(job_type, _)| *job_type)
+            .collect();
+        if available.is_empty() {
+            return Ok(false);
+        }
+
+        // Atomically dequeue the next job of a type with spare capacity
+        let job = match self.job_repository.dequeue_matching(&available).await? {
+            Some(job) => job,
+            None => return Ok(false), // No jobs available
+        };
+
+        let type_permit = match job.parse_job_type() {
+            Ok(job_type) => self
+                .type_slots
+                .get(&job_type)
+                .and_then(|slots| Arc::clone(slots).try_acquire_owned().ok()),
+            Err(_) => None,
+        };
+
+        let worker = Arc::clone(self);
+        in_flight.spawn(async move {
+            let _permits: (OwnedSemaphorePermit, Option<OwnedSemaphorePermit>) =
+                (global_permit, type_permit);
+            if let Err(e) = worker.process_job(job).await {
+                log_error!("Failed to record job result: {}", e);
+            }
+        });
+
+        Ok(true)
+    }
+
+    /// Wait for in-flight jobs, abandoning them back to the queue after the drain timeout
+    async fn drain(&self, mut in_flight: JoinSet<()>) {
+        if in_flight.is_empty() {
+            return;
+        }
+
+        log_info!(
+            "Draining {} in-flight jobs (timeout {:?})",
+            in_flight.len(),
+            self.config.drain_timeout
+        );
+
+        let drained = tokio::time::timeout(self.config.drain_timeout, async {
+            while in_flight.join_next().await.is_some() {}
+        })
+        .await;
+
+        if drained.is_err() {
+            log_warn!(
+                "Drain timed out; abandoning {} jobs back to the queue",
+                in_flight.len()
+            );
+            in_flight.shutdown().await;
+            if let Err(e) = self.job_repository.requeue_interrupted().await {
+                log_error!("Failed to re-queue abandoned jobs: {}", e);
+            }
+        }
+    }
+
+    /// Stop the background worker (in-flight jobs keep running until drained)
     pub async fn stop(&self) {
         let mut running = self.is_running.write().await;
         *running = false;
+        self.wakeup.notify_one();
         log_info!("Background worker stop requested");
     }
 
-    /// Process the next job in the queue
-    ///
-    /// Returns true if a job was processed, false if queue was empty
-    async fn process_next_job(&self) -> AppResult<bool> {
-        // Atomically dequeue the next job
-        let job = match self.job_repository.dequeue().await? {
-            Some(job) => job,
-            None => return Ok(false), // No jobs available
-        };
+    /// Stop the worker and wait until in-flight jobs have drained
+    pub async fn shutdown(&self) {
+        self.stop().await;
+
+        let mut drained = self.drained.subscribe();
+        let wait = drained.wait_for(|done| *done);
+        // Allow a little longer than the drain itself for the final status updates
+        let grace = self.config.drain_timeout + Duration::from_secs(5);
+        if tokio::time::timeout(grace, wait).await.is_err() {
+            log_warn!("Background worker did not drain in time");
+        }
+    }
+
+    /// Pause job processing; jobs in progress still finish
+    pub async fn pause(&self) {
+        let mut paused = self.is_paused.write().await;
+        *paused = true;
+        log_info!("Background worker paused");
+    }
+
+    /// Resume job processing after a pause
+    pub async fn resume(&self) {
+        let mut paused = self.is_paused.write().await;
+        *paused = false;
+        self.wakeup.notify_one();
+        log_info!("Background worker resumed");
+    }
+
+    /// Whether job processing is currently paused
+    pub async fn is_paused(&self) -> bool {
+        *self.is_paused.read().await
+    }
+
+    /// Number of jobs currently being processed
+    pub fn active_jobs(&self) -> usize {
+        self.config
+            .max_concurrency
+            .saturating_sub(self.global_slots.available_permits())
+    }
 
+    /// Execute a dequeued job and record the outcome
+    async fn process_job(&self, job: JobRecord) -> AppResult<()> {
         log_info!(
             "Processing job {} (type: {}, attempts: {}/{})",
             job.id,
@@ -111,6 +366,9 @@ impl BackgroundWorker {
         let result = match job.parse_job_type() {
             Ok(JobType::Enrichment) => self.handle_enrichment_job(&job).await,
             Ok(JobType::RelationsDiscovery) => self.handle_relations_job(&job).await,
+            Ok(JobType::EpisodeSync) => self.handle_episode_sync_job(&job).await,
+            Ok(JobType::NewsSync) => self.handle_news_sync_job().await,
+            Ok(JobType::StatisticsSync) => self.handle_statistics_sync_job().await,
             Err(e) => {
                 log_error!("Invalid job type '{}': {}", job.job_type, e);
                 Err(crate::shared::errors::AppError::ValidationError(format!(
@@ -130,6 +388,14 @@ impl BackgroundWorker {
                 let error_msg = format!("{}", e);
                 log_warn!("Job {} failed: {}", job.id, error_msg);
 
+                if !e.is_retryable() {
+                    log_error!("Job {} failed permanently: error is not retryable", job.id);
+                    self.job_repository
+                        .mark_failed_permanently(job.id, &error_msg)
+                        .await?;
+                    return Ok(());
+                }
+
                 if job.can_retry() {
                     log_info!(
                         "Job {} will be retried (attempt {}/{})",
@@ -137,22 +403,27 @@ impl BackgroundWorker {
                         job.attempts,
                         job.max_attempts
                     );
-                    // Job will be retried automatically (status is already 'running' with incremented attempts)
-                    // We just need to reset it to 'pending' for the next worker cycle
-                    // For now, mark as failed so it doesn't get stuck in 'running' state
-                    self.job_repository.mark_failed(job.id, &error_msg).await?;
                 } else {
                     log_error!(
-                        "Job {} failed permanently after {} attempts",
+                        "Job {} moved to dead letter after {} attempts",
                         job.id,
                         job.attempts
                     );
-                    self.job_repository.mark_failed(job.id, &error_msg).await?;
+                }
+
+                // Reschedules the job while attempts remain, otherwise moves it to 'dead_letter'
+                match e.retry_after() {
+                    Some(retry_after) => {
+                        self.job_repository
+                            .mark_rate_limited(job.id, &error_msg, retry_after)
+                            .await?
+                    }
+                    None => self.job_repository.mark_failed(job.id, &error_msg).await?,
                 }
             }
         }
 
-        Ok(true)
+        Ok(())
     }
 
     /// Handle an enrichment job
@@ -220,20 +491,24 @@ impl BackgroundWorker {
         // Merge data intelligently
         let mut enriched = anime.clone();
         let mut improvements = Vec::new();
+        let mut fields_updated = Vec::new();
 
         // Fill missing fields from AniList
         if let Some(anilist) = anilist_data {
             if enriched.synopsis.is_none() && anilist.synopsis.is_some() {
                 enriched.synopsis = anilist.synopsis.clone();
                 improvements.push("Added synopsis from AniList");
+                fields_updated.push("synopsis".to_string());
             }
             if enriched.genres.is_empty() && !anilist.genres.is_empty() {
                 enriched.genres = anilist.genres.clone();
                 improvements.push("Added genres from AniList");
+                fields_updated.push("genres".to_string());
             }
             if enriched.studios.is_empty() && !anilist.studios.is_empty() {
                 enriched.studios = anilist.studios.clone();
                 improvements.push("Added studios from AniList");
+                fields_updated.push("studios".to_string());
             }
         }
 
@@ -242,6 +517,7 @@ impl BackgroundWorker {
             if enriched.age_restriction.is_none() && jikan.age_restriction.is_some() {
                 enriched.age_restriction = jikan.age_restriction.clone();
                 improvements.push("Added age_restriction from Jikan");
+                fields_updated.push("age_restriction".to_string());
             }
         }
 
@@ -261,6 +537,19 @@ impl BackgroundWorker {
                 payload.anime_id,
                 enriched.tier
             );
+
+            let event = AnimeEnrichedEvent::new(
+                payload.anime_id,
+                "background_worker".to_string(),
+                fields_updated,
+            );
+            if let Err(e) = self.event_publisher.publish(Box::new(event)).await {
+                log_warn!(
+                    "Failed to publish enrichment event for {}: {}",
+                    payload.anime_id,
+                    e
+                );
+            }
         } else {
             log_debug!("No improvements found for anime {}", payload.anime_id);
         }
@@ -297,6 +586,19 @@ impl BackgroundWorker {
                     relations.len(),
                     payload.anime_id
                 );
+
+                let event = RelationsDiscoveredEvent::new(
+                    payload.anime_id,
+                    relations.len(),
+                    "relations_service".to_string(),
+                );
+                if let Err(e) = self.event_publisher.publish(Box::new(event)).await {
+                    log_warn!(
+                        "Failed to publish relations event for {}: {}",
+                        payload.anime_id,
+                        e
+                    );
+                }
                 Ok(())
             }
             Err(e) => {
@@ -310,17 +612,117 @@ impl BackgroundWorker {
         }
     }
 
+    /// Handle an episode sync job
+    async fn handle_episode_sync_job(
+        &self,
+        job: &crate::modules::jobs::domain::entities::JobRecord,
+    ) -> AppResult<()> {
+        // Parse payload
+        let payload: EpisodeSyncJobPayload = job.parse_episode_sync_payload().map_err(|e| {
+            crate::shared::errors::AppError::ValidationError(format!(
+                "Invalid episode sync payload: {}",
+                e
+            ))
+        })?;
+
+        log_info!("Syncing episodes for anime {}", payload.anime_id);
+
+        let synced = self
+            .episode_service
+            .sync_episodes(&payload.anime_id)
+            .await?;
+
+        log_info!("Synced {} episodes for anime {}", synced, payload.anime_id);
+        Ok(())
+    }
+
+    /// Handle a news sync job, then schedule the next one
+    async fn handle_news_sync_job(&self) -> AppResult<()> {
+        log_info!("Syncing news for followed anime");
+
+        let result = self.news_service.sync_news().await;
+
+        // Keep the chain going even if this run failed; a retry would only add a second link
+        let next_run = Utc::now() + chrono::Duration::hours(NEWS_SYNC_INTERVAL_HOURS);
+        if let Err(e) = self
+            .schedule_recurring(Job::news_sync(JobPriority::Low as i32), next_run)
+            .await
+        {
+            log_warn!("Failed to schedule next news sync: {}", e);
+        }
+
+        let summary = result?;
+        log_info!(
+            "News sync stored {} new articles for {} anime",
+            summary.items_added,
+            summary.anime_synced
+        );
+        Ok(())
+    }
+
+    /// Handle a statistics sync job, then schedule the next one
+    async fn handle_statistics_sync_job(&self) -> AppResult<()> {
+        log_info!("Snapshotting statistics for airing anime");
+
+        let result = self.statistics_service.sync_statistics(None).await;
+
+        // Same as news: the next run is the retry
+        let next_run = Utc::now() + chrono::Duration::hours(STATISTICS_SYNC_INTERVAL_HOURS);
+        if let Err(e) = self
+            .schedule_recurring(Job::statistics_sync(JobPriority::Low as i32), next_run)
+            .await
+        {
+            log_warn!("Failed to schedule next statistics sync: {}", e);
+        }
+
+        let summary = result?;
+        log_info!(
+            "Statistics sync stored {} snapshots for {} anime ({} rescored)",
+            summary.snapshots_stored,
+            summary.anime_synced,
+            summary.rescored
+        );
+        Ok(())
+    }
+
+    /// Enqueue a periodic sync to run at `at`, unless one of its type is already pending
+    async fn schedule_recurring(&self, job: Job, at: chrono::DateTime<Utc>) -> AppResult<()> {
+        let job_type = job.job_type;
+        let pending = self
+            .job_repository
+            .list_jobs(JobFilter {
+                status: Some(JobStatus::Pending),
+                job_type: Some(job_type),
+                limit: Some(1),
+                ..JobFilter::default()
+            })
+            .await?;
+        if !pending.is_empty() {
+            return Ok(());
+        }
+
+        let job = self.job_repository.enqueue(job.scheduled_at(at)).await?;
+        log_debug!("Scheduled {} job {} for {}", job_type, job.id, at);
+        Ok(())
+    }
+
     /// Get statistics about the worker and job queue
     pub async fn get_statistics(&self) -> AppResult<WorkerStatistics> {
         let job_stats = self.job_repository.get_statistics().await?;
         let is_running = *self.is_running.read().await;
+        let is_paused = *self.is_paused.read().await;
 
         Ok(WorkerStatistics {
             is_running,
+            is_paused,
+            active_jobs: self.active_jobs() as u32,
+            max_concurrency: self.config.max_concurrency as u32,
             pending_jobs: job_stats.pending_count,
             running_jobs: job_stats.running_count,
             completed_jobs: job_stats.completed_count,
             failed_jobs: job_stats.failed_count,
+            cancelled_jobs: job_stats.cancelled_count,
+            dead_letter_jobs: job_stats.dead_letter_count,
             total_jobs: job_stats.total_count,
         })
     }
@@ -330,10 +732,15 @@ impl BackgroundWorker {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
 pub struct WorkerStatistics {
     pub is_running: bool,
+    pub is_paused: bool,
+    pub active_jobs: u32,
+    pub max_concurrency: u32,
     pub pending_jobs: i64,
     pub running_jobs: i64,
     pub completed_jobs: i64,
     pub failed_jobs: i64,
+    pub cancelled_jobs: i64,
+    pub dead_letter_jobs: i64,
     pub total_jobs: i64,
 }
 
@@ -346,4 +753,27 @@ mod tests {
         // Test that worker can be created with proper configuration
         // (Actual integration tests would require database setup)
     }
+
+    #[test]
+    fn test_default_config_limits_relations_below_pool_size() {
+        let config = WorkerPoolConfig::default();
+        let relations = config.per_type_limits[&JobType::RelationsDiscovery];
+
+        assert!(relations < config.max_concurrency);
+        assert!(config.per_type_limits[&JobType::Enrichment] <= config.max_concurrency);
+    }
+
+    #[test]
+    fn test_type_slots_are_clamped_to_pool_size() {
+        let config = WorkerPoolConfig {
+            max_concurrency: 2,
+            per_type_limits: HashMap::from([(JobType::Enrichment, 10)]),
+            ..WorkerPoolConfig::default()
+        };
+        let slots = BackgroundWorker::build_type_slots(&config);
+
+        assert_eq!(slots[&JobType::Enrichment].available_permits(), 2);
+        // Types without an explicit limit share the whole pool
+        assert_eq!(slots[&JobType::RelationsDiscovery].available_permits(), 2);
+    }
 }
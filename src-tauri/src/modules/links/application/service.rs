@@ -0,0 +1,71 @@
+use super::super::domain::{AnimeLink, AnimeLinkRepository, LinkMerger, WhereToWatch};
+use crate::modules::anime::{AnimeDetailed, AnimeRepository};
+use crate::modules::provider::application::service::ProviderService;
+use crate::shared::errors::{AppError, AppResult};
+use crate::{log_debug, log_info};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct LinkService {
+    link_repo: Arc<dyn AnimeLinkRepository>,
+    anime_repo: Arc<dyn AnimeRepository>,
+    provider_service: Arc<ProviderService>,
+}
+
+impl LinkService {
+    pub fn new(
+        link_repo: Arc<dyn AnimeLinkRepository>,
+        anime_repo: Arc<dyn AnimeRepository>,
+        provider_service: Arc<ProviderService>,
+    ) -> Self {
+        Self {
+            link_repo,
+            anime_repo,
+            provider_service,
+        }
+    }
+
+    /// Stored links of an anime, streaming services first
+    pub async fn get_links(&self, anime_id: &Uuid) -> AppResult<Vec<AnimeLink>> {
+        self.link_repo.find_by_anime(anime_id).await
+    }
+
+    /// Streaming services carrying an anime, fetching links first if none are stored
+    pub async fn get_where_to_watch(&self, anime_id: &Uuid) -> AppResult<WhereToWatch> {
+        let mut links = self.link_repo.find_by_anime(anime_id).await?;
+        if links.is_empty() {
+            links = self.sync_links(anime_id).await?;
+        }
+
+        Ok(WhereToWatch::from_links(*anime_id, links))
+    }
+
+    /// Fetch links from MyAnimeList and AniList, merge them and replace the stored ones
+    pub async fn sync_links(&self, anime_id: &Uuid) -> AppResult<Vec<AnimeLink>> {
+        let anime = self.require_anime(anime_id).await?;
+
+        let provider_links = self
+            .provider_service
+            .fetch_anime_links(&anime.provider_metadata)
+            .await?;
+
+        let merged = LinkMerger::merge(provider_links);
+        if merged.is_empty() {
+            // Keep whatever we had rather than wiping it on an empty answer
+            log_debug!("No links returned by providers for '{}'", anime.title.main);
+            return self.link_repo.find_by_anime(anime_id).await;
+        }
+
+        let stored = self.link_repo.replace_links(anime_id, &merged).await?;
+        log_info!("Stored {} links for '{}'", stored, anime.title.main);
+
+        self.link_repo.find_by_anime(anime_id).await
+    }
+
+    async fn require_anime(&self, anime_id: &Uuid) -> AppResult<AnimeDetailed> {
+        self.anime_repo
+            .find_by_id(anime_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Anime with ID {} not found", anime_id)))
+    }
+}
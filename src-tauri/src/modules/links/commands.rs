@@ -0,0 +1,60 @@
+use super::application::service::LinkService;
+use super::domain::{AnimeLink, WhereToWatch};
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::sync::Arc;
+use tauri::State;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct GetAnimeLinksRequest {
+    pub anime_id: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SyncAnimeLinksRequest {
+    pub anime_id: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct GetWhereToWatchRequest {
+    pub anime_id: Uuid,
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_anime_links(
+    request: GetAnimeLinksRequest,
+    link_service: State<'_, Arc<LinkService>>,
+) -> Result<Vec<AnimeLink>, String> {
+    link_service
+        .get_links(&request.anime_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn sync_anime_links(
+    request: SyncAnimeLinksRequest,
+    link_service: State<'_, Arc<LinkService>>,
+) -> Result<Vec<AnimeLink>, String> {
+    link_service
+        .sync_links(&request.anime_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Streaming services carrying an anime, grouped by service
+#[tauri::command]
+#[specta::specta]
+pub async fn get_where_to_watch(
+    request: GetWhereToWatchRequest,
+    link_service: State<'_, Arc<LinkService>>,
+) -> Result<WhereToWatch, String> {
+    link_service
+        .get_where_to_watch(&request.anime_id)
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,34 @@
+use super::super::value_objects::LinkCategory;
+use crate::shared::domain::value_objects::AnimeProvider;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use uuid::Uuid;
+
+/// A merged link ready to be stored
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewAnimeLink {
+    pub category: LinkCategory,
+    pub site: String,
+    /// Normalised host the link was deduplicated by
+    pub host: String,
+    pub url: String,
+    pub language: Option<String>,
+    pub provider: AnimeProvider,
+}
+
+/// A stored external link of an anime
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct AnimeLink {
+    pub id: Uuid,
+    pub anime_id: Uuid,
+    pub category: LinkCategory,
+    pub site: String,
+    pub host: String,
+    pub url: String,
+    pub language: Option<String>,
+    /// Provider the link was first seen on
+    pub provider: AnimeProvider,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,7 @@
+pub mod anime_link;
+pub mod provider_link;
+pub mod where_to_watch;
+
+pub use anime_link::{AnimeLink, NewAnimeLink};
+pub use provider_link::ProviderLink;
+pub use where_to_watch::{StreamingService, WhereToWatch};
@@ -0,0 +1,14 @@
+use super::super::value_objects::LinkCategory;
+use crate::shared::domain::value_objects::AnimeProvider;
+
+/// An external link as listed by a single provider
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProviderLink {
+    pub provider: AnimeProvider,
+    pub category: LinkCategory,
+    /// Site label, e.g. `Crunchyroll` or `Official Site`
+    pub site: String,
+    pub url: String,
+    /// Audience language for regional streaming sites (AniList only)
+    pub language: Option<String>,
+}
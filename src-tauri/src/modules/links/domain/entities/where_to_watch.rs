@@ -0,0 +1,48 @@
+use super::super::value_objects::LinkCategory;
+use super::anime_link::AnimeLink;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use uuid::Uuid;
+
+/// Streaming links of one service, e.g. every regional Crunchyroll page
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamingService {
+    pub site: String,
+    pub host: String,
+    pub links: Vec<AnimeLink>,
+}
+
+/// Streaming services carrying an anime
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct WhereToWatch {
+    pub anime_id: Uuid,
+    pub services: Vec<StreamingService>,
+}
+
+impl WhereToWatch {
+    /// Group streaming links by service, keeping the stored order
+    pub fn from_links(anime_id: Uuid, links: Vec<AnimeLink>) -> Self {
+        let mut services: Vec<StreamingService> = Vec::new();
+
+        for link in links
+            .into_iter()
+            .filter(|link| link.category == LinkCategory::Streaming)
+        {
+            match services
+                .iter_mut()
+                .find(|service| service.host == link.host)
+            {
+                Some(service) => service.links.push(link),
+                None => services.push(StreamingService {
+                    site: link.site.clone(),
+                    host: link.host.clone(),
+                    links: vec![link],
+                }),
+            }
+        }
+
+        Self { anime_id, services }
+    }
+}
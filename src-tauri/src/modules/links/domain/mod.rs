@@ -0,0 +1,10 @@
+pub mod entities;
+pub mod repositories;
+pub mod services;
+pub mod value_objects;
+
+// Re-exports for easy access
+pub use entities::{AnimeLink, NewAnimeLink, ProviderLink, StreamingService, WhereToWatch};
+pub use repositories::AnimeLinkRepository;
+pub use services::LinkMerger;
+pub use value_objects::LinkCategory;
@@ -0,0 +1,13 @@
+use super::super::entities::{AnimeLink, NewAnimeLink};
+use crate::shared::errors::AppResult;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait AnimeLinkRepository: Send + Sync {
+    /// Replace every stored link of an anime, keeping the given order
+    async fn replace_links(&self, anime_id: &Uuid, links: &[NewAnimeLink]) -> AppResult<usize>;
+
+    /// Stored links of an anime, streaming services first
+    async fn find_by_anime(&self, anime_id: &Uuid) -> AppResult<Vec<AnimeLink>>;
+}
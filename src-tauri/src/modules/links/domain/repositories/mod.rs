@@ -0,0 +1,3 @@
+pub mod anime_link_repository;
+
+pub use anime_link_repository::AnimeLinkRepository;
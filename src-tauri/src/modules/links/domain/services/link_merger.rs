@@ -0,0 +1,206 @@
+use super::super::entities::{NewAnimeLink, ProviderLink};
+
+/// Merges MyAnimeList and AniList links into one link per host
+///
+/// Both providers list the same official site and streaming pages, often with
+/// different spellings of the URL (`www.`, `http`, `x.com`). Links are keyed
+/// by their normalised host, plus the language for regional streaming pages;
+/// a link without a language joins the first page of its host. The first
+/// provider's URL and label are kept.
+pub struct LinkMerger;
+
+impl LinkMerger {
+    pub fn merge(links: Vec<ProviderLink>) -> Vec<NewAnimeLink> {
+        let mut merged: Vec<NewAnimeLink> = Vec::new();
+
+        for link in links {
+            let Some(host) = Self::normalize_host(&link.url) else {
+                continue;
+            };
+            let language = link
+                .language
+                .map(|language| language.trim().to_string())
+                .filter(|language| !language.is_empty());
+
+            let existing = merged.iter_mut().find(|existing| {
+                existing.host == host
+                    && match (&existing.language, &language) {
+                        (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+                        _ => true,
+                    }
+            });
+
+            match existing {
+                Some(existing) => {
+                    if link.category.specificity() > existing.category.specificity() {
+                        existing.category = link.category;
+                    }
+                    if existing.url.starts_with("http://") && link.url.starts_with("https://") {
+                        existing.url = link.url;
+                    }
+                    if existing.language.is_none() {
+                        existing.language = language;
+                    }
+                }
+                None => merged.push(NewAnimeLink {
+                    category: link.category,
+                    site: link.site.trim().to_string(),
+                    host,
+                    url: link.url,
+                    language,
+                    provider: link.provider,
+                }),
+            }
+        }
+
+        // Stable: providers' own ordering is kept within each category
+        merged.sort_by_key(|link| std::cmp::Reverse(link.category.specificity()));
+        merged
+    }
+
+    /// Lowercased host without `www.`/`m.`, `x.com` folded into `twitter.com`
+    ///
+    /// Returns `None` for anything that isn't an absolute http(s) URL.
+    pub fn normalize_host(url: &str) -> Option<String> {
+        let url = url.trim();
+        let (scheme, rest) = url.split_once("://")?;
+        if !scheme.eq_ignore_ascii_case("http") && !scheme.eq_ignore_ascii_case("https") {
+            return None;
+        }
+
+        let authority = rest.split(['/', '?', '#']).next()?;
+        let host = authority.rsplit('@').next()?.split(':').next()?;
+        let host = host.trim_end_matches('.').to_lowercase();
+        let host = host
+            .strip_prefix("www.")
+            .or_else(|| host.strip_prefix("m."))
+            .unwrap_or(&host);
+
+        match host {
+            "" => None,
+            "x.com" => Some("twitter.com".to_string()),
+            _ => Some(host.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::links::domain::LinkCategory;
+    use crate::shared::domain::value_objects::AnimeProvider;
+
+    fn link(
+        provider: AnimeProvider,
+        category: LinkCategory,
+        site: &str,
+        url: &str,
+        language: Option<&str>,
+    ) -> ProviderLink {
+        ProviderLink {
+            provider,
+            category,
+            site: site.to_string(),
+            url: url.to_string(),
+            language: language.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_dedups_same_host_across_providers() {
+        let links = LinkMerger::merge(vec![
+            link(
+                AnimeProvider::Jikan,
+                LinkCategory::Official,
+                "Official Site",
+                "http://www.cowboy-bebop.net/",
+                None,
+            ),
+            link(
+                AnimeProvider::Jikan,
+                LinkCategory::Social,
+                "Twitter",
+                "https://x.com/bebop_official",
+                None,
+            ),
+            link(
+                AnimeProvider::AniList,
+                LinkCategory::Info,
+                "Official Site",
+                "https://cowboy-bebop.net",
+                None,
+            ),
+            link(
+                AnimeProvider::AniList,
+                LinkCategory::Social,
+                "Twitter",
+                "https://twitter.com/bebop_official",
+                None,
+            ),
+        ]);
+
+        assert_eq!(links.len(), 2);
+        assert_eq!(links[0].category, LinkCategory::Official);
+        assert_eq!(links[0].host, "cowboy-bebop.net");
+        assert_eq!(links[0].url, "https://cowboy-bebop.net");
+        assert_eq!(links[0].provider, AnimeProvider::Jikan);
+        assert_eq!(links[1].host, "twitter.com");
+    }
+
+    #[test]
+    fn test_keeps_regional_streaming_pages_apart() {
+        let links = LinkMerger::merge(vec![
+            link(
+                AnimeProvider::Jikan,
+                LinkCategory::Info,
+                "AniDB",
+                "https://anidb.net/anime/23",
+                None,
+            ),
+            link(
+                AnimeProvider::Jikan,
+                LinkCategory::Streaming,
+                "Crunchyroll",
+                "http://www.crunchyroll.com/series/GYVNXMVP6",
+                None,
+            ),
+            link(
+                AnimeProvider::AniList,
+                LinkCategory::Streaming,
+                "Crunchyroll",
+                "https://www.crunchyroll.com/series/GYVNXMVP6",
+                Some("English"),
+            ),
+            link(
+                AnimeProvider::AniList,
+                LinkCategory::Streaming,
+                "Crunchyroll",
+                "https://www.crunchyroll.com/de/series/GYVNXMVP6",
+                Some("German"),
+            ),
+        ]);
+
+        let hosts: Vec<(&str, Option<&str>)> = links
+            .iter()
+            .map(|link| (link.host.as_str(), link.language.as_deref()))
+            .collect();
+        assert_eq!(
+            hosts,
+            vec![
+                ("crunchyroll.com", Some("English")),
+                ("crunchyroll.com", Some("German")),
+                ("anidb.net", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_normalize_host_rejects_relative_and_non_http_urls() {
+        assert_eq!(
+            LinkMerger::normalize_host("HTTPS://M.YouTube.com:443/watch?v=1"),
+            Some("youtube.com".to_string())
+        );
+        assert_eq!(LinkMerger::normalize_host("/anime/1/Cowboy_Bebop"), None);
+        assert_eq!(LinkMerger::normalize_host("mailto:info@example.com"), None);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod link_merger;
+
+pub use link_merger::LinkMerger;
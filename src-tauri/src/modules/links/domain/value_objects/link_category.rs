@@ -0,0 +1,76 @@
+use diesel_derive_enum::DbEnum;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// What an external link points to
+///
+/// MyAnimeList keeps streaming links apart from everything else, AniList tags
+/// each link `INFO`, `STREAMING` or `SOCIAL`; neither marks the official site,
+/// which is recognised by its label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, DbEnum, Type)]
+#[ExistingTypePath = "crate::schema::sql_types::LinkCategory"]
+#[serde(rename_all = "snake_case")]
+pub enum LinkCategory {
+    Official,
+    Streaming,
+    Social,
+    Info,
+}
+
+impl LinkCategory {
+    /// Category of a non-streaming link from its site label
+    pub fn from_site(site: &str) -> Self {
+        let site = site.trim().to_lowercase();
+        if site.starts_with("official site") || site == "official website" {
+            return LinkCategory::Official;
+        }
+
+        match site.as_str() {
+            "twitter" | "x" | "instagram" | "facebook" | "youtube" | "tiktok" | "bluesky"
+            | "weibo" | "bilibili" | "niconico" => LinkCategory::Social,
+            _ => LinkCategory::Info,
+        }
+    }
+
+    /// Category of an AniList link from its `type` and site label
+    pub fn from_anilist(link_type: Option<&str>, site: &str) -> Self {
+        match link_type.map(str::to_uppercase).as_deref() {
+            Some("STREAMING") => LinkCategory::Streaming,
+            Some("SOCIAL") => LinkCategory::Social,
+            _ => Self::from_site(site),
+        }
+    }
+
+    /// When providers disagree, the more specific category wins
+    pub fn specificity(&self) -> u8 {
+        match self {
+            LinkCategory::Streaming => 3,
+            LinkCategory::Official => 2,
+            LinkCategory::Social => 1,
+            LinkCategory::Info => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_official_site_is_recognised_by_label() {
+        assert_eq!(
+            LinkCategory::from_site("Official Site"),
+            LinkCategory::Official
+        );
+        assert_eq!(
+            LinkCategory::from_anilist(Some("INFO"), "Official Site (Japanese)"),
+            LinkCategory::Official
+        );
+        assert_eq!(LinkCategory::from_site("Twitter"), LinkCategory::Social);
+        assert_eq!(LinkCategory::from_site("AniDB"), LinkCategory::Info);
+        assert_eq!(
+            LinkCategory::from_anilist(Some("STREAMING"), "Crunchyroll"),
+            LinkCategory::Streaming
+        );
+    }
+}
@@ -0,0 +1,3 @@
+pub mod link_category;
+
+pub use link_category::LinkCategory;
@@ -0,0 +1,67 @@
+use crate::modules::links::domain::{AnimeLink, LinkCategory, NewAnimeLink};
+use crate::schema::anime_links;
+use crate::shared::domain::value_objects::AnimeProvider;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use uuid::Uuid;
+
+// For reading from database
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone)]
+#[diesel(table_name = anime_links)]
+pub struct AnimeLinkModel {
+    pub id: Uuid,
+    pub anime_id: Uuid,
+    pub category: LinkCategory,
+    pub site: String,
+    pub host: String,
+    pub url: String,
+    pub language: Option<String>,
+    pub provider: AnimeProvider,
+    pub position: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+// For inserting new rows
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = anime_links)]
+pub struct NewAnimeLinkModel {
+    pub anime_id: Uuid,
+    pub category: LinkCategory,
+    pub site: String,
+    pub host: String,
+    pub url: String,
+    pub language: Option<String>,
+    pub provider: AnimeProvider,
+    pub position: i32,
+}
+
+impl From<AnimeLinkModel> for AnimeLink {
+    fn from(model: AnimeLinkModel) -> Self {
+        Self {
+            id: model.id,
+            anime_id: model.anime_id,
+            category: model.category,
+            site: model.site,
+            host: model.host,
+            url: model.url,
+            language: model.language,
+            provider: model.provider,
+            created_at: model.created_at,
+        }
+    }
+}
+
+impl NewAnimeLinkModel {
+    pub fn new(anime_id: Uuid, link: &NewAnimeLink, position: usize) -> Self {
+        Self {
+            anime_id,
+            category: link.category,
+            site: link.site.clone(),
+            host: link.host.clone(),
+            url: link.url.clone(),
+            language: link.language.clone(),
+            provider: link.provider,
+            position: position as i32,
+        }
+    }
+}
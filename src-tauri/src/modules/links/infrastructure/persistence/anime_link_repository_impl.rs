@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+use tokio::task;
+use uuid::Uuid;
+
+use crate::modules::links::domain::{AnimeLink, AnimeLinkRepository, NewAnimeLink};
+use crate::modules::links::infrastructure::models::{AnimeLinkModel, NewAnimeLinkModel};
+use crate::schema::anime_links;
+use crate::shared::errors::AppResult;
+use crate::shared::Database;
+
+pub struct AnimeLinkRepositoryImpl {
+    db: Arc<Database>,
+}
+
+impl AnimeLinkRepositoryImpl {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl AnimeLinkRepository for AnimeLinkRepositoryImpl {
+    async fn replace_links(&self, anime_id: &Uuid, links: &[NewAnimeLink]) -> AppResult<usize> {
+        let db = Arc::clone(&self.db);
+        let anime_id = *anime_id;
+        let rows: Vec<NewAnimeLinkModel> = links
+            .iter()
+            .enumerate()
+            .map(|(position, link)| NewAnimeLinkModel::new(anime_id, link, position))
+            .collect();
+
+        task::spawn_blocking(move || -> AppResult<usize> {
+            let mut conn = db.get_connection()?;
+
+            conn.transaction(|conn| {
+                diesel::delete(anime_links::table.filter(anime_links::anime_id.eq(anime_id)))
+                    .execute(conn)?;
+
+                let inserted = diesel::insert_into(anime_links::table)
+                    .values(&rows)
+                    .execute(conn)?;
+                Ok(inserted)
+            })
+        })
+        .await?
+    }
+
+    async fn find_by_anime(&self, anime_id: &Uuid) -> AppResult<Vec<AnimeLink>> {
+        let db = Arc::clone(&self.db);
+        let anime_id = *anime_id;
+
+        let models = task::spawn_blocking(move || -> AppResult<Vec<AnimeLinkModel>> {
+            let mut conn = db.get_connection()?;
+            let models = anime_links::table
+                .filter(anime_links::anime_id.eq(anime_id))
+                .order(anime_links::position.asc())
+                .select(AnimeLinkModel::as_select())
+                .load(&mut conn)?;
+            Ok(models)
+        })
+        .await??;
+
+        Ok(models.into_iter().map(Into::into).collect())
+    }
+}
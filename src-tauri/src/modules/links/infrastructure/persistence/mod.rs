@@ -0,0 +1,3 @@
+pub mod anime_link_repository_impl;
+
+pub use anime_link_repository_impl::AnimeLinkRepositoryImpl;
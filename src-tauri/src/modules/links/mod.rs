@@ -0,0 +1,10 @@
+pub mod application;
+pub mod commands;
+pub mod domain;
+pub mod infrastructure;
+
+// Re-exports for easy external access
+pub use application::service::LinkService;
+pub use domain::{
+    AnimeLink, AnimeLinkRepository, LinkCategory, ProviderLink, StreamingService, WhereToWatch,
+};
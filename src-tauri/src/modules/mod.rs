@@ -1,7 +1,18 @@
 pub mod anime;
+pub mod backup;
+pub mod character;
 pub mod collection;
+pub mod data_export;
 pub mod data_import;
+pub mod discovery;
+pub mod episode;
 pub mod jobs;
+pub mod links;
 pub mod media;
+pub mod news;
 pub mod provider;
+pub mod recommendation;
 pub mod season;
+pub mod statistics;
+pub mod themes;
+pub mod tracking;
@@ -0,0 +1,89 @@
+use super::super::domain::{NewsFeedItem, NewsFilter, NewsRepository};
+use crate::modules::anime::AnimeRepository;
+use crate::modules::provider::application::service::ProviderService;
+use crate::shared::application::run_library_batch;
+use crate::shared::errors::{AppError, AppResult};
+use crate::{log_debug, log_info};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Outcome of fetching news for followed anime
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct NewsSyncSummary {
+    pub anime_synced: u32,
+    /// Articles stored for the first time
+    pub items_added: u32,
+    pub failed: u32,
+}
+
+pub struct NewsService {
+    news_repo: Arc<dyn NewsRepository>,
+    anime_repo: Arc<dyn AnimeRepository>,
+    provider_service: Arc<ProviderService>,
+}
+
+impl NewsService {
+    pub fn new(
+        news_repo: Arc<dyn NewsRepository>,
+        anime_repo: Arc<dyn AnimeRepository>,
+        provider_service: Arc<ProviderService>,
+    ) -> Self {
+        Self {
+            news_repo,
+            anime_repo,
+            provider_service,
+        }
+    }
+
+    /// Fetch the latest news for every anime in a collection or on the watch list
+    ///
+    /// Articles are deduplicated by URL, so news listed under several anime is
+    /// stored once and linked to each of them.
+    pub async fn sync_news(&self) -> AppResult<NewsSyncSummary> {
+        let anime_ids = self.news_repo.find_followed_anime_ids().await?;
+        log_debug!("Fetching news for {} followed anime", anime_ids.len());
+
+        let outcome = run_library_batch(
+            anime_ids,
+            |anime_id| format!("Failed to fetch news for anime {}", anime_id),
+            |anime_id| async move { self.sync_anime(&anime_id).await },
+        )
+        .await;
+
+        let summary = NewsSyncSummary {
+            anime_synced: outcome.completed.len() as u32,
+            items_added: outcome.completed.iter().sum::<usize>() as u32,
+            failed: outcome.failed,
+        };
+
+        log_info!(
+            "Fetched news for {} anime ({} new articles, {} failed)",
+            summary.anime_synced,
+            summary.items_added,
+            summary.failed
+        );
+        Ok(summary)
+    }
+
+    /// Stored news matching the filter, newest first
+    pub async fn get_news_feed(&self, filter: &NewsFilter) -> AppResult<Vec<NewsFeedItem>> {
+        self.news_repo.find_feed(filter).await
+    }
+
+    async fn sync_anime(&self, anime_id: &Uuid) -> AppResult<usize> {
+        let anime =
+            self.anime_repo.find_by_id(anime_id).await?.ok_or_else(|| {
+                AppError::NotFound(format!("Anime with ID {} not found", anime_id))
+            })?;
+
+        let items = self
+            .provider_service
+            .fetch_anime_news(&anime.provider_metadata)
+            .await?;
+
+        self.news_repo.save_items(anime_id, &items).await
+    }
+}
@@ -0,0 +1,34 @@
+use super::application::service::{NewsService, NewsSyncSummary};
+use super::domain::{NewsFeedItem, NewsFilter};
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::sync::Arc;
+use tauri::State;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct GetNewsFeedRequest {
+    #[serde(default)]
+    pub filter: NewsFilter,
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_news_feed(
+    request: GetNewsFeedRequest,
+    news_service: State<'_, Arc<NewsService>>,
+) -> Result<Vec<NewsFeedItem>, String> {
+    news_service
+        .get_news_feed(&request.filter)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Fetch news for every followed anime now instead of waiting for the background job
+#[tauri::command]
+#[specta::specta]
+pub async fn sync_news(
+    news_service: State<'_, Arc<NewsService>>,
+) -> Result<NewsSyncSummary, String> {
+    news_service.sync_news().await.map_err(|e| e.to_string())
+}
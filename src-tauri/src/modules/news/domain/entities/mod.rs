@@ -0,0 +1,5 @@
+pub mod news_feed;
+pub mod provider_news_item;
+
+pub use news_feed::{NewsAnime, NewsFeedItem, NewsFilter};
+pub use provider_news_item::ProviderNewsItem;
@@ -0,0 +1,41 @@
+use crate::shared::domain::value_objects::AnimeProvider;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use uuid::Uuid;
+
+/// Library anime a news item was listed under
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct NewsAnime {
+    pub anime_id: Uuid,
+    pub title: String,
+}
+
+/// A stored news item with the anime it covers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct NewsFeedItem {
+    pub id: Uuid,
+    pub provider: AnimeProvider,
+    pub url: String,
+    pub title: String,
+    pub excerpt: Option<String>,
+    pub image_url: Option<String>,
+    pub author: Option<String>,
+    pub forum_url: Option<String>,
+    pub comments: i32,
+    pub published_at: Option<DateTime<Utc>>,
+    pub anime: Vec<NewsAnime>,
+}
+
+/// Which part of the feed to return
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+pub struct NewsFilter {
+    /// Only news about anime in this collection
+    pub collection_id: Option<Uuid>,
+    /// Only news about this anime
+    pub anime_id: Option<Uuid>,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+}
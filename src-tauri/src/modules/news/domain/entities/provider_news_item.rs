@@ -0,0 +1,17 @@
+use crate::shared::domain::value_objects::AnimeProvider;
+use chrono::{DateTime, Utc};
+
+/// A news article as listed by a provider for one anime
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProviderNewsItem {
+    pub provider: AnimeProvider,
+    /// Canonical article URL, used to recognise the same article listed under several anime
+    pub url: String,
+    pub title: String,
+    pub excerpt: Option<String>,
+    pub image_url: Option<String>,
+    pub author: Option<String>,
+    pub forum_url: Option<String>,
+    pub comments: i32,
+    pub published_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,6 @@
+pub mod entities;
+pub mod repositories;
+
+// Re-exports for easy access
+pub use entities::{NewsAnime, NewsFeedItem, NewsFilter, ProviderNewsItem};
+pub use repositories::NewsRepository;
@@ -0,0 +1,3 @@
+pub mod news_repository;
+
+pub use news_repository::NewsRepository;
@@ -0,0 +1,18 @@
+use super::super::entities::{NewsFeedItem, NewsFilter, ProviderNewsItem};
+use crate::shared::errors::AppResult;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait NewsRepository: Send + Sync {
+    /// Store news items listed for an anime, updating articles already stored under the same URL
+    ///
+    /// Returns the number of articles that were not stored before.
+    async fn save_items(&self, anime_id: &Uuid, items: &[ProviderNewsItem]) -> AppResult<usize>;
+
+    /// Stored news matching the filter, newest first
+    async fn find_feed(&self, filter: &NewsFilter) -> AppResult<Vec<NewsFeedItem>>;
+
+    /// Anime in any collection or on the watch list
+    async fn find_followed_anime_ids(&self) -> AppResult<Vec<Uuid>>;
+}
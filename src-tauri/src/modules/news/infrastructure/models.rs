@@ -0,0 +1,80 @@
+use crate::modules::news::domain::{NewsAnime, NewsFeedItem, ProviderNewsItem};
+use crate::schema::{anime_news, news_items};
+use crate::shared::domain::value_objects::AnimeProvider;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use uuid::Uuid;
+
+// For reading from database
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone)]
+#[diesel(table_name = news_items)]
+pub struct NewsItemModel {
+    pub id: Uuid,
+    pub provider: AnimeProvider,
+    pub url: String,
+    pub title: String,
+    pub excerpt: Option<String>,
+    pub image_url: Option<String>,
+    pub author: Option<String>,
+    pub forum_url: Option<String>,
+    pub comments: i32,
+    pub published_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// For inserting new rows
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = news_items)]
+pub struct NewNewsItemModel {
+    pub provider: AnimeProvider,
+    pub url: String,
+    pub title: String,
+    pub excerpt: Option<String>,
+    pub image_url: Option<String>,
+    pub author: Option<String>,
+    pub forum_url: Option<String>,
+    pub comments: i32,
+    pub published_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = anime_news)]
+pub struct NewAnimeNewsModel {
+    pub anime_id: Uuid,
+    pub news_item_id: Uuid,
+}
+
+impl From<&ProviderNewsItem> for NewNewsItemModel {
+    fn from(item: &ProviderNewsItem) -> Self {
+        Self {
+            provider: item.provider,
+            url: item.url.clone(),
+            title: item.title.clone(),
+            excerpt: item.excerpt.clone(),
+            image_url: item.image_url.clone(),
+            author: item.author.clone(),
+            forum_url: item.forum_url.clone(),
+            comments: item.comments,
+            published_at: item.published_at,
+        }
+    }
+}
+
+impl NewsItemModel {
+    pub fn into_feed_item(self, anime: Vec<NewsAnime>) -> NewsFeedItem {
+        NewsFeedItem {
+            id: self.id,
+            provider: self.provider,
+            url: self.url,
+            title: self.title,
+            excerpt: self.excerpt,
+            image_url: self.image_url,
+            author: self.author,
+            forum_url: self.forum_url,
+            comments: self.comments,
+            published_at: self.published_at,
+            anime,
+        }
+    }
+}
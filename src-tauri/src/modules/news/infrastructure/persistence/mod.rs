@@ -0,0 +1,3 @@
+pub mod news_repository_impl;
+
+pub use news_repository_impl::NewsRepositoryImpl;
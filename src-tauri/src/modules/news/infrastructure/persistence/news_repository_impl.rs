@@ -0,0 +1,184 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+use diesel::upsert::excluded;
+use tokio::task;
+use uuid::Uuid;
+
+use crate::modules::news::domain::{
+    NewsAnime, NewsFeedItem, NewsFilter, NewsRepository, ProviderNewsItem,
+};
+use crate::modules::news::infrastructure::models::{
+    NewAnimeNewsModel, NewNewsItemModel, NewsItemModel,
+};
+use crate::schema::{anime, anime_news, collection_anime, news_items, user_anime_data};
+use crate::shared::domain::LOCAL_USER_ID;
+use crate::shared::errors::{AppError, AppResult};
+use crate::shared::Database;
+
+/// Feed page size when the caller doesn't ask for one
+const DEFAULT_FEED_LIMIT: i64 = 50;
+
+pub struct NewsRepositoryImpl {
+    db: Arc<Database>,
+}
+
+impl NewsRepositoryImpl {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl NewsRepository for NewsRepositoryImpl {
+    async fn save_items(&self, anime_id: &Uuid, items: &[ProviderNewsItem]) -> AppResult<usize> {
+        if items.is_empty() {
+            return Ok(0);
+        }
+
+        let db = Arc::clone(&self.db);
+        let anime_id = *anime_id;
+        let rows: Vec<NewNewsItemModel> = items.iter().map(NewNewsItemModel::from).collect();
+
+        task::spawn_blocking(move || -> AppResult<usize> {
+            let mut conn = db.get_connection()?;
+
+            conn.transaction::<_, AppError, _>(|conn| {
+                let urls: Vec<&str> = rows.iter().map(|row| row.url.as_str()).collect();
+                let known: HashSet<String> = news_items::table
+                    .filter(news_items::url.eq_any(&urls))
+                    .select(news_items::url)
+                    .load::<String>(conn)?
+                    .into_iter()
+                    .collect();
+
+                let mut added = HashSet::new();
+                for row in &rows {
+                    // Comment counts and excerpts change after publication
+                    let news_item_id: Uuid = diesel::insert_into(news_items::table)
+                        .values(row)
+                        .on_conflict(news_items::url)
+                        .do_update()
+                        .set((
+                            news_items::title.eq(excluded(news_items::title)),
+                            news_items::excerpt.eq(excluded(news_items::excerpt)),
+                            news_items::image_url.eq(excluded(news_items::image_url)),
+                            news_items::comments.eq(excluded(news_items::comments)),
+                        ))
+                        .returning(news_items::id)
+                        .get_result(conn)?;
+
+                    diesel::insert_into(anime_news::table)
+                        .values(&NewAnimeNewsModel {
+                            anime_id,
+                            news_item_id,
+                        })
+                        .on_conflict((anime_news::anime_id, anime_news::news_item_id))
+                        .do_nothing()
+                        .execute(conn)?;
+
+                    if !known.contains(&row.url) {
+                        added.insert(row.url.as_str());
+                    }
+                }
+
+                Ok(added.len())
+            })
+        })
+        .await?
+    }
+
+    async fn find_feed(&self, filter: &NewsFilter) -> AppResult<Vec<NewsFeedItem>> {
+        let db = Arc::clone(&self.db);
+        let filter = filter.clone();
+
+        task::spawn_blocking(move || -> AppResult<Vec<NewsFeedItem>> {
+            let mut conn = db.get_connection()?;
+
+            let mut query = news_items::table.into_boxed();
+            if let Some(anime_id) = filter.anime_id {
+                query = query.filter(
+                    news_items::id.eq_any(
+                        anime_news::table
+                            .filter(anime_news::anime_id.eq(anime_id))
+                            .select(anime_news::news_item_id),
+                    ),
+                );
+            }
+            if let Some(collection_id) = filter.collection_id {
+                query = query.filter(
+                    news_items::id.eq_any(
+                        anime_news::table
+                            .filter(
+                                anime_news::anime_id.eq_any(
+                                    collection_anime::table
+                                        .filter(collection_anime::collection_id.eq(collection_id))
+                                        .select(collection_anime::anime_id),
+                                ),
+                            )
+                            .select(anime_news::news_item_id),
+                    ),
+                );
+            }
+
+            let models: Vec<NewsItemModel> = query
+                .order((
+                    news_items::published_at.desc().nulls_last(),
+                    news_items::created_at.desc(),
+                ))
+                .limit(filter.limit.map_or(DEFAULT_FEED_LIMIT, i64::from))
+                .offset(filter.offset.map_or(0, i64::from))
+                .select(NewsItemModel::as_select())
+                .load(&mut conn)?;
+
+            let ids: Vec<Uuid> = models.iter().map(|model| model.id).collect();
+            let links: Vec<(Uuid, Uuid, String)> = anime_news::table
+                .inner_join(anime::table)
+                .filter(anime_news::news_item_id.eq_any(&ids))
+                .order(anime::title_main.asc())
+                .select((anime_news::news_item_id, anime::id, anime::title_main))
+                .load(&mut conn)?;
+
+            let mut anime_by_item: HashMap<Uuid, Vec<NewsAnime>> = HashMap::new();
+            for (news_item_id, anime_id, title) in links {
+                anime_by_item
+                    .entry(news_item_id)
+                    .or_default()
+                    .push(NewsAnime { anime_id, title });
+            }
+
+            Ok(models
+                .into_iter()
+                .map(|model| {
+                    let anime = anime_by_item.remove(&model.id).unwrap_or_default();
+                    model.into_feed_item(anime)
+                })
+                .collect())
+        })
+        .await?
+    }
+
+    async fn find_followed_anime_ids(&self) -> AppResult<Vec<Uuid>> {
+        let db = Arc::clone(&self.db);
+
+        task::spawn_blocking(move || -> AppResult<Vec<Uuid>> {
+            let mut conn = db.get_connection()?;
+
+            let mut ids: Vec<Uuid> = collection_anime::table
+                .select(collection_anime::anime_id)
+                .distinct()
+                .load(&mut conn)?;
+            let watched: Vec<Uuid> = user_anime_data::table
+                .filter(user_anime_data::user_id.eq(LOCAL_USER_ID))
+                .select(user_anime_data::anime_id)
+                .load(&mut conn)?;
+
+            let mut seen: HashSet<Uuid> = ids.iter().copied().collect();
+            ids.extend(watched.into_iter().filter(|id| seen.insert(*id)));
+            Ok(ids)
+        })
+        .await?
+    }
+}
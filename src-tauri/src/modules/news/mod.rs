@@ -0,0 +1,8 @@
+pub mod application;
+pub mod commands;
+pub mod domain;
+pub mod infrastructure;
+
+// Re-exports for easy external access
+pub use application::service::NewsService;
+pub use domain::{NewsFeedItem, NewsFilter, NewsRepository, ProviderNewsItem};
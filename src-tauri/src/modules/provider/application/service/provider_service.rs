@@ -1,21 +1,49 @@
 use crate::modules::anime::domain::entities::anime_detailed::AnimeDetailed;
 use crate::modules::anime::domain::services::data_quality_service::DataQualityService;
+use crate::modules::character::domain::{ProviderCastMember, ProviderStaffMember};
+use crate::modules::discovery::domain::DiscoveryFeed;
+use crate::modules::episode::domain::ProviderEpisode;
+use crate::modules::links::domain::ProviderLink;
 use crate::modules::media::domain::entities::{NewAnimeImage, NewAnimeVideo};
+use crate::modules::news::domain::ProviderNewsItem;
 use crate::modules::provider::application::dto::SearchResultDTO;
 use crate::modules::provider::domain::entities::anime_data::AnimeData;
 use crate::modules::provider::domain::repositories::{
-    AnimeProviderRepository, MediaProviderRepository, RelationshipProviderRepository,
+    AnimeProviderRepository, CastProviderRepository, CatalogProviderRepository,
+    DiscoveryProviderRepository, EpisodeProviderRepository, LinkProviderRepository,
+    MediaProviderRepository, NewsProviderRepository, RecommendationProviderRepository,
+    RelationshipProviderRepository, ScheduleProviderRepository, StatisticsProviderRepository,
+    ThemeProviderRepository,
+};
+use crate::modules::provider::domain::services::{
+    AnimeSearchService, ProviderSelectionService, SearchResultsProcessor,
 };
-use crate::modules::provider::domain::services::{AnimeSearchService, ProviderSelectionService};
 use crate::modules::provider::domain::value_objects::SearchCriteria;
 use crate::modules::provider::infrastructure::adapters::anilist::models::{
     CategorizedFranchise, FranchiseRelation,
 };
-use crate::shared::domain::value_objects::AnimeProvider;
-use crate::shared::errors::AppResult;
+use crate::modules::recommendation::domain::ProviderRecommendation;
+use crate::modules::season::{AiringEpisode, ProviderAiringSchedule, SeasonPeriod};
+use crate::modules::statistics::domain::ProviderStatistics;
+use crate::modules::themes::domain::ProviderThemeSong;
+use crate::shared::domain::value_objects::{AnimeProvider, ProviderMetadata};
+use crate::shared::errors::{AppError, AppResult};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::future::Future;
 use std::sync::Arc;
 use uuid::Uuid;
 
+/// Upper bound on pages fetched per provider for a single season lineup
+const MAX_SEASON_PAGES: u32 = 20;
+
+/// Upper bound on pages fetched per provider for a single episode list
+const MAX_EPISODE_PAGES: u32 = 50;
+
+/// Upper bound on pages fetched for a single airing schedule
+const MAX_AIRING_PAGES: u32 = 5;
+
 /// Clean application service for provider operations
 ///
 /// This service orchestrates all provider operations and maintains separation of concerns.
@@ -35,13 +63,47 @@ pub struct ProviderService {
     relationship_repository: Arc<dyn RelationshipProviderRepository>,
     /// Media provider repository for fetching images and videos
     media_provider_repository: Arc<dyn MediaProviderRepository>,
+    /// Catalog provider repository for seasonal lineups
+    catalog_repository: Arc<dyn CatalogProviderRepository>,
+    /// Dedup/merge pipeline for catalog results from multiple providers
+    catalog_processor: Arc<SearchResultsProcessor>,
+    /// Cast provider repository for characters and voice actors
+    cast_repository: Arc<dyn CastProviderRepository>,
+    /// Episode provider repository for paginated episode lists
+    episode_repository: Arc<dyn EpisodeProviderRepository>,
+    /// Schedule provider repository for airing times and broadcast slots
+    schedule_repository: Arc<dyn ScheduleProviderRepository>,
+    /// Recommendation provider repository for "users also liked" lists
+    recommendation_repository: Arc<dyn RecommendationProviderRepository>,
+    /// Statistics provider repository for list status counts and score distributions
+    statistics_repository: Arc<dyn StatisticsProviderRepository>,
+    /// News provider repository for per-anime news articles
+    news_repository: Arc<dyn NewsProviderRepository>,
+    /// Link provider repository for official, streaming and social links
+    link_repository: Arc<dyn LinkProviderRepository>,
+    /// Theme provider repository for opening and ending songs
+    theme_repository: Arc<dyn ThemeProviderRepository>,
+    /// Discovery provider repository for trending, popular and upcoming lists
+    discovery_repository: Arc<dyn DiscoveryProviderRepository>,
 }
 
 impl ProviderService {
+    // One repository per provider capability; they are usually the same adapter
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         provider_repository: Arc<dyn AnimeProviderRepository>,
         media_provider_repository: Arc<dyn MediaProviderRepository>,
         relationship_repository: Arc<dyn RelationshipProviderRepository>,
+        catalog_repository: Arc<dyn CatalogProviderRepository>,
+        cast_repository: Arc<dyn CastProviderRepository>,
+        episode_repository: Arc<dyn EpisodeProviderRepository>,
+        schedule_repository: Arc<dyn ScheduleProviderRepository>,
+        recommendation_repository: Arc<dyn RecommendationProviderRepository>,
+        statistics_repository: Arc<dyn StatisticsProviderRepository>,
+        news_repository: Arc<dyn NewsProviderRepository>,
+        link_repository: Arc<dyn LinkProviderRepository>,
+        theme_repository: Arc<dyn ThemeProviderRepository>,
+        discovery_repository: Arc<dyn DiscoveryProviderRepository>,
     ) -> Self {
         let data_quality_service = Arc::new(DataQualityService::new());
         let provider_selection_service = Arc::new(ProviderSelectionService::new());
@@ -49,6 +111,7 @@ impl ProviderService {
             provider_repository,
             (*data_quality_service).clone(),
         ));
+        let catalog_processor = Arc::new(SearchResultsProcessor::new(data_quality_service.clone()));
 
         Self {
             anime_search_service,
@@ -56,6 +119,17 @@ impl ProviderService {
             provider_selection_service,
             relationship_repository,
             media_provider_repository,
+            catalog_repository,
+            catalog_processor,
+            cast_repository,
+            episode_repository,
+            schedule_repository,
+            recommendation_repository,
+            statistics_repository,
+            news_repository,
+            link_repository,
+            theme_repository,
+            discovery_repository,
         }
     }
 
@@ -123,6 +197,221 @@ impl ProviderService {
         }
     }
 
+    /// Get anime by ID from a specific provider, failing when the provider does
+    ///
+    /// `get_anime_by_id` treats provider errors as a miss; use this where a
+    /// rate limit or timeout must not be mistaken for an unknown id.
+    pub async fn fetch_anime_by_id(
+        &self,
+        id: &str,
+        provider: AnimeProvider,
+    ) -> AppResult<Option<AnimeDetailed>> {
+        Ok(self
+            .anime_search_service
+            .get_details_from(id, provider)
+            .await?
+            .map(|data| data.anime))
+    }
+
+    // ========================================================================
+    // SEASONAL CATALOG METHODS
+    // ========================================================================
+
+    /// Fetch the complete lineup for a season across providers
+    ///
+    /// Walks every page each seasonal provider publishes (bounded by
+    /// `MAX_SEASON_PAGES`), then deduplicates and merges the combined list
+    /// through `SearchResultsProcessor` so each title appears once.
+    pub async fn get_season_lineup(&self, period: SeasonPeriod) -> AppResult<Vec<AnimeDetailed>> {
+        let targets = self
+            .catalog_repository
+            .season_providers()
+            .into_iter()
+            .map(|provider| (provider, period.label()))
+            .collect();
+
+        let provider_results: Vec<Vec<AnimeData>> =
+            Self::fan_out("SEASON", targets, |provider, _| {
+                self.fetch_full_season(provider, period)
+            })
+            .await?
+            .into_iter()
+            .map(|(_, items)| items)
+            .collect();
+
+        if provider_results.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let lineup = Self::merge_lineup(&self.catalog_processor, provider_results).await?;
+
+        log::info!(
+            "SEASON: {} contains {} unique titles",
+            period.label(),
+            lineup.len()
+        );
+        Ok(lineup)
+    }
+
+    /// Deduplicate and merge per-provider lineups, highest score first
+    ///
+    /// Seasonal lineups are not ranked against a query and must not be
+    /// truncated or quality-filtered: every airing title is kept.
+    async fn merge_lineup(
+        catalog_processor: &SearchResultsProcessor,
+        provider_results: Vec<Vec<AnimeData>>,
+    ) -> AppResult<Vec<AnimeDetailed>> {
+        let total: usize = provider_results.iter().map(|items| items.len()).sum();
+        let criteria = SearchCriteria::new(String::new())
+            .with_limit(total)
+            .with_quality_threshold(0.0);
+
+        let merged = catalog_processor
+            .process(provider_results, &criteria)
+            .await?;
+
+        let mut lineup: Vec<AnimeDetailed> = merged.into_iter().map(|data| data.anime).collect();
+        lineup.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.title.main.cmp(&b.title.main))
+        });
+
+        Ok(lineup)
+    }
+
+    /// Deduplicate discovery feeds from several providers, best provider rank first
+    ///
+    /// Each anime takes its best position across the feeds listing it; ties go to
+    /// the anime more providers agree on.
+    async fn rank_feed(
+        catalog_processor: &SearchResultsProcessor,
+        answers: Vec<(AnimeProvider, Vec<AnimeData>)>,
+        limit: usize,
+    ) -> AppResult<Vec<AnimeDetailed>> {
+        let mut provider_results = Vec::new();
+        let mut ranks: HashMap<(AnimeProvider, String), usize> = HashMap::new();
+        for (provider, items) in answers {
+            for (rank, item) in items.iter().enumerate() {
+                if let Some(id) = item.anime.provider_metadata.get_external_id(&provider) {
+                    ranks.entry((provider, id.clone())).or_insert(rank);
+                }
+            }
+            provider_results.push(items);
+        }
+
+        if provider_results.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Feeds are already ranked by the providers, so nothing is cut before reordering
+        let total: usize = provider_results.iter().map(|items| items.len()).sum();
+        let criteria = SearchCriteria::new(String::new())
+            .with_limit(total)
+            .with_quality_threshold(0.0);
+
+        let merged = catalog_processor
+            .process(provider_results, &criteria)
+            .await?;
+
+        let mut ranked: Vec<(usize, usize, AnimeDetailed)> = merged
+            .into_iter()
+            .map(|data| {
+                let positions: Vec<usize> = data
+                    .anime
+                    .provider_metadata
+                    .external_ids
+                    .iter()
+                    .filter_map(|(provider, id)| ranks.get(&(*provider, id.clone())).copied())
+                    .collect();
+                let best = positions.iter().min().copied().unwrap_or(usize::MAX);
+                (best, positions.len(), data.anime)
+            })
+            .collect();
+        ranked.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| b.1.cmp(&a.1)));
+
+        Ok(ranked
+            .into_iter()
+            .take(limit)
+            .map(|(_, _, anime)| anime)
+            .collect())
+    }
+
+    /// Fetch every page of a season lineup from a single provider
+    async fn fetch_full_season(
+        &self,
+        provider: AnimeProvider,
+        period: SeasonPeriod,
+    ) -> AppResult<Vec<AnimeData>> {
+        let mut items = Vec::new();
+
+        for page in 1..=MAX_SEASON_PAGES {
+            let catalog_page = match self
+                .catalog_repository
+                .fetch_season_page(provider, period, page)
+                .await
+            {
+                Ok(catalog_page) => catalog_page,
+                // Keep what we already have if a later page fails
+                Err(e) if page > 1 => {
+                    log::warn!(
+                        "SEASON: Stopping {} at page {} for {}: {}",
+                        provider,
+                        page,
+                        period.label(),
+                        e
+                    );
+                    break;
+                }
+                Err(e) => return Err(e),
+            };
+
+            items.extend(catalog_page.items);
+            if !catalog_page.has_next_page {
+                break;
+            }
+        }
+
+        Ok(items)
+    }
+
+    // ========================================================================
+    // DISCOVERY FEED METHODS
+    // ========================================================================
+
+    /// Fetch a discovery feed from every provider publishing it and merge the lists
+    ///
+    /// Lists are deduplicated and merged through `SearchResultsProcessor`, then
+    /// ordered by the best position any provider gave the title, with titles
+    /// listed by more providers first on ties.
+    pub async fn get_discovery_feed(
+        &self,
+        feed: DiscoveryFeed,
+        limit: usize,
+    ) -> AppResult<Vec<AnimeDetailed>> {
+        let targets = self
+            .discovery_repository
+            .discovery_providers(feed)
+            .into_iter()
+            .map(|provider| (provider, feed.as_str()))
+            .collect();
+
+        let answers = Self::fan_out("DISCOVERY", targets, |provider, _| {
+            self.discovery_repository.fetch_feed(provider, feed, limit)
+        })
+        .await?;
+
+        let feed_anime = Self::rank_feed(&self.catalog_processor, answers, limit).await?;
+
+        log::info!(
+            "DISCOVERY: {} feed contains {} unique titles",
+            feed.as_str(),
+            feed_anime.len()
+        );
+        Ok(feed_anime)
+    }
+
     /// Check if a provider is healthy
     pub fn is_provider_healthy(&self, provider: &AnimeProvider) -> bool {
         self.provider_selection_service
@@ -321,6 +610,335 @@ impl ProviderService {
             .fetch_videos(provider_anime_id, anime_id)
             .await
     }
+
+    /// Fetch an anime's cast from every provider it is linked to
+    ///
+    /// Entries are returned per provider, unmerged.
+    pub async fn fetch_anime_cast(
+        &self,
+        provider_metadata: &ProviderMetadata,
+    ) -> AppResult<Vec<ProviderCastMember>> {
+        let targets =
+            Self::provider_anime_ids(self.cast_repository.cast_providers(), provider_metadata);
+
+        Self::fan_out("CAST", targets, |provider, provider_anime_id| {
+            self.cast_repository.fetch_cast(provider, provider_anime_id)
+        })
+        .await
+        .map(Self::flatten)
+    }
+
+    /// Fetch an anime's staff from every provider it is linked to
+    ///
+    /// Entries are returned per provider, unmerged.
+    pub async fn fetch_anime_staff(
+        &self,
+        provider_metadata: &ProviderMetadata,
+    ) -> AppResult<Vec<ProviderStaffMember>> {
+        let targets =
+            Self::provider_anime_ids(self.cast_repository.cast_providers(), provider_metadata);
+
+        Self::fan_out("STAFF", targets, |provider, provider_anime_id| {
+            self.cast_repository
+                .fetch_staff(provider, provider_anime_id)
+        })
+        .await
+        .map(Self::flatten)
+    }
+
+    /// Fetch an anime's episodes from every provider it is linked to, page by page
+    ///
+    /// Entries are returned per provider, unmerged.
+    pub async fn fetch_anime_episodes(
+        &self,
+        provider_metadata: &ProviderMetadata,
+    ) -> AppResult<Vec<ProviderEpisode>> {
+        let targets = Self::provider_anime_ids(
+            self.episode_repository.episode_providers(),
+            provider_metadata,
+        );
+
+        Self::fan_out("EPISODES", targets, |provider, provider_anime_id| {
+            self.fetch_all_episode_pages(provider, provider_anime_id)
+        })
+        .await
+        .map(Self::flatten)
+    }
+
+    /// Fetch every page of an episode list from a single provider
+    async fn fetch_all_episode_pages(
+        &self,
+        provider: AnimeProvider,
+        provider_anime_id: u32,
+    ) -> AppResult<Vec<ProviderEpisode>> {
+        let mut items = Vec::new();
+
+        for page in 1..=MAX_EPISODE_PAGES {
+            let episode_page = match self
+                .episode_repository
+                .fetch_episode_page(provider, provider_anime_id, page)
+                .await
+            {
+                Ok(episode_page) => episode_page,
+                // Keep what we already have if a later page fails
+                Err(e) if page > 1 => {
+                    log::warn!(
+                        "EPISODES: Stopping {} at page {} for anime {}: {}",
+                        provider,
+                        page,
+                        provider_anime_id,
+                        e
+                    );
+                    break;
+                }
+                Err(e) => return Err(e),
+            };
+
+            items.extend(episode_page.items);
+            if !episode_page.has_next_page {
+                break;
+            }
+        }
+
+        Ok(items)
+    }
+
+    /// Fetch episode airing times after `since` from AniList and the broadcast slot from MyAnimeList
+    ///
+    /// Either part is left empty when the anime isn't linked to that provider.
+    pub async fn fetch_airing_schedule(
+        &self,
+        provider_metadata: &ProviderMetadata,
+        since: DateTime<Utc>,
+    ) -> AppResult<ProviderAiringSchedule> {
+        let targets = Self::provider_anime_ids(
+            self.schedule_repository.schedule_providers(),
+            provider_metadata,
+        );
+
+        let parts = Self::fan_out(
+            "SCHEDULE",
+            targets,
+            |provider, provider_anime_id| async move {
+                match provider {
+                    AnimeProvider::AniList => Ok(ProviderAiringSchedule {
+                        episodes: self
+                            .fetch_all_airing_pages(provider_anime_id, since)
+                            .await?,
+                        broadcast: None,
+                    }),
+                    AnimeProvider::Jikan => Ok(ProviderAiringSchedule {
+                        episodes: Vec::new(),
+                        broadcast: self
+                            .schedule_repository
+                            .fetch_broadcast(provider_anime_id)
+                            .await?,
+                    }),
+                    AnimeProvider::Kitsu | AnimeProvider::TMDB | AnimeProvider::AniDB => {
+                        Err(AppError::InvalidInput(format!(
+                            "Provider {} does not expose an airing schedule",
+                            provider
+                        )))
+                    }
+                }
+            },
+        )
+        .await?;
+
+        let mut schedule = ProviderAiringSchedule::default();
+        for (_, part) in parts {
+            schedule.episodes.extend(part.episodes);
+            schedule.broadcast = schedule.broadcast.or(part.broadcast);
+        }
+        Ok(schedule)
+    }
+
+    /// Fetch "users also liked" recommendations from every linked provider
+    ///
+    /// Entries are returned per provider; merging them is up to the caller.
+    pub async fn fetch_anime_recommendations(
+        &self,
+        provider_metadata: &ProviderMetadata,
+    ) -> AppResult<Vec<ProviderRecommendation>> {
+        let targets = Self::provider_anime_ids(
+            self.recommendation_repository.recommendation_providers(),
+            provider_metadata,
+        );
+
+        Self::fan_out("RECOMMENDATIONS", targets, |provider, provider_anime_id| {
+            self.recommendation_repository
+                .fetch_recommendations(provider, provider_anime_id)
+        })
+        .await
+        .map(Self::flatten)
+    }
+
+    /// Fetch current audience statistics from every linked provider, one entry per provider
+    pub async fn fetch_anime_statistics(
+        &self,
+        provider_metadata: &ProviderMetadata,
+    ) -> AppResult<Vec<ProviderStatistics>> {
+        let targets = Self::provider_anime_ids(
+            self.statistics_repository.statistics_providers(),
+            provider_metadata,
+        );
+
+        let answers = Self::fan_out("STATISTICS", targets, |provider, provider_anime_id| {
+            self.statistics_repository
+                .fetch_statistics(provider, provider_anime_id)
+        })
+        .await?;
+
+        Ok(answers
+            .into_iter()
+            .map(|(_, statistics)| statistics)
+            .collect())
+    }
+
+    /// Fetch the latest news from every linked provider that lists news
+    pub async fn fetch_anime_news(
+        &self,
+        provider_metadata: &ProviderMetadata,
+    ) -> AppResult<Vec<ProviderNewsItem>> {
+        let targets =
+            Self::provider_anime_ids(self.news_repository.news_providers(), provider_metadata);
+
+        Self::fan_out("NEWS", targets, |provider, provider_anime_id| {
+            self.news_repository.fetch_news(provider, provider_anime_id)
+        })
+        .await
+        .map(Self::flatten)
+    }
+
+    /// Fetch external links from every linked provider, in `link_providers()` order
+    pub async fn fetch_anime_links(
+        &self,
+        provider_metadata: &ProviderMetadata,
+    ) -> AppResult<Vec<ProviderLink>> {
+        let targets =
+            Self::provider_anime_ids(self.link_repository.link_providers(), provider_metadata);
+
+        Self::fan_out("LINKS", targets, |provider, provider_anime_id| {
+            self.link_repository
+                .fetch_links(provider, provider_anime_id)
+        })
+        .await
+        .map(Self::flatten)
+    }
+
+    /// Fetch theme song lines from every linked provider, in `theme_providers()` order
+    pub async fn fetch_anime_themes(
+        &self,
+        provider_metadata: &ProviderMetadata,
+    ) -> AppResult<Vec<ProviderThemeSong>> {
+        let targets =
+            Self::provider_anime_ids(self.theme_repository.theme_providers(), provider_metadata);
+
+        Self::fan_out("THEMES", targets, |provider, provider_anime_id| {
+            self.theme_repository
+                .fetch_themes(provider, provider_anime_id)
+        })
+        .await
+        .map(Self::flatten)
+    }
+
+    /// Walk AniList's airing schedule pages until the last one or the page cap
+    async fn fetch_all_airing_pages(
+        &self,
+        anilist_id: u32,
+        since: DateTime<Utc>,
+    ) -> AppResult<Vec<AiringEpisode>> {
+        let mut items = Vec::new();
+
+        for page in 1..=MAX_AIRING_PAGES {
+            let airing_page = match self
+                .schedule_repository
+                .fetch_airing_page(anilist_id, since, page)
+                .await
+            {
+                Ok(airing_page) => airing_page,
+                // Keep what we already have if a later page fails
+                Err(e) if page > 1 => {
+                    log::warn!(
+                        "SCHEDULE: Stopping at page {} for anime {}: {}",
+                        page,
+                        anilist_id,
+                        e
+                    );
+                    break;
+                }
+                Err(e) => return Err(e),
+            };
+
+            items.extend(airing_page.items);
+            if !airing_page.has_next_page {
+                break;
+            }
+        }
+
+        Ok(items)
+    }
+
+    /// Numeric ids of the anime on each of `providers` it is linked to
+    fn provider_anime_ids(
+        providers: Vec<AnimeProvider>,
+        provider_metadata: &ProviderMetadata,
+    ) -> Vec<(AnimeProvider, u32)> {
+        providers
+            .into_iter()
+            .filter_map(|provider| {
+                provider_metadata
+                    .get_external_id(&provider)
+                    .and_then(|id| id.parse::<u32>().ok())
+                    .map(|id| (provider, id))
+            })
+            .collect()
+    }
+
+    /// Ask each provider in `targets` in turn, keyed by what it is asked for
+    ///
+    /// Answers come back in `targets` order, tagged with their provider. A
+    /// provider that fails is logged and skipped as long as another one
+    /// answered; when every provider fails, the last error is returned.
+    async fn fan_out<K, T, F, Fut>(
+        log_tag: &str,
+        targets: Vec<(AnimeProvider, K)>,
+        fetch: F,
+    ) -> AppResult<Vec<(AnimeProvider, T)>>
+    where
+        K: Clone + Display,
+        F: Fn(AnimeProvider, K) -> Fut,
+        Fut: Future<Output = AppResult<T>>,
+    {
+        let mut answers = Vec::new();
+        let mut last_error = None;
+
+        for (provider, key) in targets {
+            match fetch(provider, key.clone()).await {
+                Ok(answer) => answers.push((provider, answer)),
+                Err(e) => {
+                    log::warn!(
+                        "{}: Provider {} failed for {}: {}",
+                        log_tag,
+                        provider,
+                        key,
+                        e
+                    );
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        match last_error {
+            Some(e) if answers.is_empty() => Err(e),
+            _ => Ok(answers),
+        }
+    }
+
+    /// Concatenate per-provider lists in provider order
+    fn flatten<T>(answers: Vec<(AnimeProvider, Vec<T>)>) -> Vec<T> {
+        answers.into_iter().flat_map(|(_, items)| items).collect()
+    }
 }
 
 /// Information about relationship discovery capabilities
@@ -340,3 +958,162 @@ pub struct PerformanceComparison {
     pub other_provider_time_seconds: f64,
     pub efficiency_multiplier: f64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::provider::domain::entities::{DataQuality, DataSource};
+
+    fn provider_anime(
+        provider: AnimeProvider,
+        id: &str,
+        title: &str,
+        score: Option<f32>,
+    ) -> AnimeData {
+        let mut anime = AnimeDetailed::new(provider, id.to_string(), title.to_string());
+        anime.score = score;
+
+        AnimeData {
+            anime,
+            quality: DataQuality {
+                score: 0.8,
+                completeness: 0.8,
+                consistency: 0.8,
+                relevance_score: 0.0,
+                missing_fields: vec![],
+            },
+            source: DataSource {
+                primary_provider: provider,
+                providers_used: vec![provider],
+                confidence: 0.8,
+                fetch_time_ms: 100,
+            },
+        }
+    }
+
+    fn titles(lineup: &[AnimeDetailed]) -> Vec<&str> {
+        lineup
+            .iter()
+            .map(|anime| anime.title.main.as_str())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_merge_lineup_keeps_each_title_once_by_score() {
+        let processor = SearchResultsProcessor::new(Arc::new(DataQualityService::new()));
+
+        let jikan = vec![
+            provider_anime(AnimeProvider::Jikan, "1", "Frieren", Some(9.1)),
+            provider_anime(AnimeProvider::Jikan, "2", "Dandadan", Some(8.4)),
+            provider_anime(AnimeProvider::Jikan, "3", "Unscored Short", None),
+        ];
+        let anilist = vec![
+            provider_anime(AnimeProvider::AniList, "10", "Dandadan", Some(8.6)),
+            provider_anime(
+                AnimeProvider::AniList,
+                "11",
+                "Apothecary Diaries",
+                Some(8.8),
+            ),
+            provider_anime(AnimeProvider::AniList, "12", "Blue Box", Some(8.8)),
+        ];
+
+        let lineup = ProviderService::merge_lineup(&processor, vec![jikan, anilist])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            titles(&lineup),
+            vec![
+                "Frieren",
+                "Apothecary Diaries",
+                "Blue Box",
+                "Dandadan",
+                "Unscored Short"
+            ]
+        );
+
+        let dandadan = &lineup[3];
+        let score = dandadan.score.unwrap();
+        assert!((8.4..=8.6).contains(&score));
+    }
+
+    #[tokio::test]
+    async fn test_merge_lineup_keeps_low_quality_titles() {
+        let processor = SearchResultsProcessor::new(Arc::new(DataQualityService::new()));
+
+        let mut sparse = provider_anime(AnimeProvider::Jikan, "1", "Barely Listed", None);
+        sparse.quality.score = 0.0;
+
+        let lineup = ProviderService::merge_lineup(&processor, vec![vec![sparse], vec![]])
+            .await
+            .unwrap();
+
+        assert_eq!(titles(&lineup), vec!["Barely Listed"]);
+    }
+
+    #[tokio::test]
+    async fn test_rank_feed_merges_overlap_and_orders_by_best_rank() {
+        let processor = SearchResultsProcessor::new(Arc::new(DataQualityService::new()));
+
+        let jikan = vec![
+            provider_anime(AnimeProvider::Jikan, "1", "Frieren", Some(9.1)),
+            provider_anime(AnimeProvider::Jikan, "2", "Blue Box", Some(8.0)),
+            provider_anime(AnimeProvider::Jikan, "3", "Dandadan", Some(8.4)),
+        ];
+        let anilist = vec![
+            provider_anime(
+                AnimeProvider::AniList,
+                "10",
+                "Apothecary Diaries",
+                Some(8.8),
+            ),
+            provider_anime(AnimeProvider::AniList, "11", "Dandadan", Some(8.6)),
+            provider_anime(AnimeProvider::AniList, "12", "Frieren", Some(9.0)),
+        ];
+
+        let feed = ProviderService::rank_feed(
+            &processor,
+            vec![
+                (AnimeProvider::Jikan, jikan),
+                (AnimeProvider::AniList, anilist),
+            ],
+            10,
+        )
+        .await
+        .unwrap();
+
+        // Frieren and Apothecary Diaries both top a feed, but only Frieren is on
+        // both; the same goes for Dandadan and Blue Box in second place
+        assert_eq!(
+            titles(&feed),
+            vec!["Frieren", "Apothecary Diaries", "Dandadan", "Blue Box"]
+        );
+
+        let frieren = &feed[0].provider_metadata;
+        assert_eq!(
+            frieren.get_external_id(&AnimeProvider::Jikan),
+            Some(&"1".to_string())
+        );
+        assert_eq!(
+            frieren.get_external_id(&AnimeProvider::AniList),
+            Some(&"12".to_string())
+        );
+
+        let top_two = ProviderService::rank_feed(
+            &processor,
+            vec![(
+                AnimeProvider::Jikan,
+                vec![
+                    provider_anime(AnimeProvider::Jikan, "1", "Frieren", Some(9.1)),
+                    provider_anime(AnimeProvider::Jikan, "2", "Blue Box", Some(8.0)),
+                    provider_anime(AnimeProvider::Jikan, "3", "Dandadan", Some(8.4)),
+                ],
+            )],
+            2,
+        )
+        .await
+        .unwrap();
+        assert_eq!(titles(&top_two), vec!["Frieren", "Blue Box"]);
+    }
+}
@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+
+use crate::modules::character::domain::{ProviderCastMember, ProviderStaffMember};
+use crate::shared::domain::value_objects::AnimeProvider;
+use crate::shared::errors::AppResult;
+
+/// Repository interface for fetching an anime's cast and staff credits
+///
+/// Each provider reports credits with its own character and person ids;
+/// merging entries across providers is left to the character module.
+#[async_trait]
+pub trait CastProviderRepository: Send + Sync {
+    /// Fetch the cast of an anime from a provider
+    ///
+    /// # Arguments
+    /// * `provider` - Provider to query (must be one of `cast_providers()`)
+    /// * `provider_anime_id` - The anime ID in the provider's system
+    async fn fetch_cast(
+        &self,
+        provider: AnimeProvider,
+        provider_anime_id: u32,
+    ) -> AppResult<Vec<ProviderCastMember>>;
+
+    /// Fetch the staff of an anime from a provider, raw position labels included
+    ///
+    /// # Arguments
+    /// * `provider` - Provider to query (must be one of `cast_providers()`)
+    /// * `provider_anime_id` - The anime ID in the provider's system
+    async fn fetch_staff(
+        &self,
+        provider: AnimeProvider,
+        provider_anime_id: u32,
+    ) -> AppResult<Vec<ProviderStaffMember>>;
+
+    /// Providers that expose cast and staff data
+    fn cast_providers(&self) -> Vec<AnimeProvider>;
+}
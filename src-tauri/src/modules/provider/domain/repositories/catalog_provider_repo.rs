@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+
+use crate::modules::provider::domain::entities::AnimeData;
+use crate::modules::season::SeasonPeriod;
+use crate::shared::domain::value_objects::AnimeProvider;
+use crate::shared::errors::AppResult;
+
+/// A single page of catalog results returned by one provider
+#[derive(Debug, Clone, Default)]
+pub struct CatalogPage {
+    pub items: Vec<AnimeData>,
+    pub has_next_page: bool,
+}
+
+/// Repository interface for browsing provider catalogs
+///
+/// Unlike free-text search, catalog queries return the lineup a provider
+/// actually publishes for a given period (e.g. every title airing in a season).
+#[async_trait]
+pub trait CatalogProviderRepository: Send + Sync {
+    /// Fetch one page of a season lineup from a provider
+    ///
+    /// # Arguments
+    /// * `provider` - Provider to query (must be one of `season_providers()`)
+    /// * `period` - Season to fetch
+    /// * `page` - 1-based page number
+    async fn fetch_season_page(
+        &self,
+        provider: AnimeProvider,
+        period: SeasonPeriod,
+        page: u32,
+    ) -> AppResult<CatalogPage>;
+
+    /// Providers that expose a seasonal catalog
+    fn season_providers(&self) -> Vec<AnimeProvider>;
+}
@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+
+use crate::modules::discovery::domain::DiscoveryFeed;
+use crate::modules::provider::domain::entities::AnimeData;
+use crate::shared::domain::value_objects::AnimeProvider;
+use crate::shared::errors::AppResult;
+
+/// Repository interface for provider-curated lists such as trending or upcoming anime
+#[async_trait]
+pub trait DiscoveryProviderRepository: Send + Sync {
+    /// Fetch a feed from a provider, best-ranked first
+    ///
+    /// # Arguments
+    /// * `provider` - Provider to query (must be one of `discovery_providers(feed)`)
+    /// * `feed` - Which list to fetch
+    /// * `limit` - Maximum number of anime; providers may return fewer
+    async fn fetch_feed(
+        &self,
+        provider: AnimeProvider,
+        feed: DiscoveryFeed,
+        limit: usize,
+    ) -> AppResult<Vec<AnimeData>>;
+
+    /// Providers that publish the given feed
+    fn discovery_providers(&self, feed: DiscoveryFeed) -> Vec<AnimeProvider>;
+}
@@ -0,0 +1,35 @@
+use async_trait::async_trait;
+
+use crate::modules::episode::domain::ProviderEpisode;
+use crate::shared::domain::value_objects::AnimeProvider;
+use crate::shared::errors::AppResult;
+
+/// A single page of an anime's episode list returned by one provider
+#[derive(Debug, Clone, Default)]
+pub struct EpisodePage {
+    pub items: Vec<ProviderEpisode>,
+    pub has_next_page: bool,
+}
+
+/// Repository interface for fetching an anime's episode list
+///
+/// MyAnimeList pages its episode list; providers that return everything at once
+/// answer page 1 with `has_next_page` unset.
+#[async_trait]
+pub trait EpisodeProviderRepository: Send + Sync {
+    /// Fetch one page of an anime's episodes from a provider
+    ///
+    /// # Arguments
+    /// * `provider` - Provider to query (must be one of `episode_providers()`)
+    /// * `provider_anime_id` - The anime ID in the provider's system
+    /// * `page` - 1-based page number
+    async fn fetch_episode_page(
+        &self,
+        provider: AnimeProvider,
+        provider_anime_id: u32,
+        page: u32,
+    ) -> AppResult<EpisodePage>;
+
+    /// Providers that expose episode lists
+    fn episode_providers(&self) -> Vec<AnimeProvider>;
+}
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+
+use crate::modules::links::domain::ProviderLink;
+use crate::shared::domain::value_objects::AnimeProvider;
+use crate::shared::errors::AppResult;
+
+/// Repository interface for fetching an anime's official, streaming and social links
+#[async_trait]
+pub trait LinkProviderRepository: Send + Sync {
+    /// Fetch every external link a provider lists for an anime
+    ///
+    /// # Arguments
+    /// * `provider` - Provider to query (must be one of `link_providers()`)
+    /// * `provider_anime_id` - The anime ID in the provider's system
+    async fn fetch_links(
+        &self,
+        provider: AnimeProvider,
+        provider_anime_id: u32,
+    ) -> AppResult<Vec<ProviderLink>>;
+
+    /// Providers that list external links
+    fn link_providers(&self) -> Vec<AnimeProvider>;
+}
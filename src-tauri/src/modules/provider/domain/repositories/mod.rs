@@ -1,9 +1,29 @@
 mod anime_provider_repo;
 mod cache_repo;
+mod cast_provider_repo;
+mod catalog_provider_repo;
+mod discovery_provider_repo;
+mod episode_provider_repo;
+mod link_provider_repo;
 mod media_provider_repo;
+mod news_provider_repo;
+mod recommendation_provider_repo;
 mod relationship_provider_repo;
+mod schedule_provider_repo;
+mod statistics_provider_repo;
+mod theme_provider_repo;
 
 pub use anime_provider_repo::*;
 pub use cache_repo::*;
+pub use cast_provider_repo::*;
+pub use catalog_provider_repo::*;
+pub use discovery_provider_repo::*;
+pub use episode_provider_repo::*;
+pub use link_provider_repo::*;
 pub use media_provider_repo::*;
+pub use news_provider_repo::*;
+pub use recommendation_provider_repo::*;
 pub use relationship_provider_repo::*;
+pub use schedule_provider_repo::*;
+pub use statistics_provider_repo::*;
+pub use theme_provider_repo::*;
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+
+use crate::modules::news::domain::ProviderNewsItem;
+use crate::shared::domain::value_objects::AnimeProvider;
+use crate::shared::errors::AppResult;
+
+/// Repository interface for fetching news articles about an anime
+#[async_trait]
+pub trait NewsProviderRepository: Send + Sync {
+    /// Fetch the latest news listed for an anime
+    ///
+    /// # Arguments
+    /// * `provider` - Provider to query (must be one of `news_providers()`)
+    /// * `provider_anime_id` - The anime ID in the provider's system
+    async fn fetch_news(
+        &self,
+        provider: AnimeProvider,
+        provider_anime_id: u32,
+    ) -> AppResult<Vec<ProviderNewsItem>>;
+
+    /// Providers that list news per anime
+    fn news_providers(&self) -> Vec<AnimeProvider>;
+}
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+
+use crate::modules::recommendation::domain::ProviderRecommendation;
+use crate::shared::domain::value_objects::AnimeProvider;
+use crate::shared::errors::AppResult;
+
+/// Repository interface for fetching "users also liked" recommendations
+#[async_trait]
+pub trait RecommendationProviderRepository: Send + Sync {
+    /// Fetch the anime that a provider's users recommend alongside an anime
+    ///
+    /// # Arguments
+    /// * `provider` - Provider to query (must be one of `recommendation_providers()`)
+    /// * `provider_anime_id` - The anime ID in the provider's system
+    async fn fetch_recommendations(
+        &self,
+        provider: AnimeProvider,
+        provider_anime_id: u32,
+    ) -> AppResult<Vec<ProviderRecommendation>>;
+
+    /// Providers that expose user recommendations
+    fn recommendation_providers(&self) -> Vec<AnimeProvider>;
+}
@@ -0,0 +1,39 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::modules::season::domain::{AiringEpisode, BroadcastInfo};
+use crate::shared::domain::value_objects::AnimeProvider;
+use crate::shared::errors::AppResult;
+
+/// A single page of an anime's airing schedule
+#[derive(Debug, Clone, Default)]
+pub struct AiringPage {
+    pub items: Vec<AiringEpisode>,
+    pub has_next_page: bool,
+}
+
+/// Repository interface for airing times of broadcasting anime
+///
+/// AniList publishes the exact airing time of every upcoming episode, while
+/// MyAnimeList only knows the weekly broadcast slot.
+#[async_trait]
+pub trait ScheduleProviderRepository: Send + Sync {
+    /// Fetch one page of episode airing times from AniList
+    ///
+    /// # Arguments
+    /// * `anilist_id` - The anime ID on AniList
+    /// * `since` - Only episodes airing after this moment are returned
+    /// * `page` - 1-based page number
+    async fn fetch_airing_page(
+        &self,
+        anilist_id: u32,
+        since: DateTime<Utc>,
+        page: u32,
+    ) -> AppResult<AiringPage>;
+
+    /// Fetch the weekly broadcast slot from MyAnimeList
+    async fn fetch_broadcast(&self, mal_id: u32) -> AppResult<Option<BroadcastInfo>>;
+
+    /// Providers that expose airing times or broadcast slots
+    fn schedule_providers(&self) -> Vec<AnimeProvider>;
+}
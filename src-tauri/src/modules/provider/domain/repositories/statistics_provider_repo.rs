@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+
+use crate::modules::statistics::domain::ProviderStatistics;
+use crate::shared::domain::value_objects::AnimeProvider;
+use crate::shared::errors::AppResult;
+
+/// Repository interface for fetching audience statistics (list status counts, score distribution)
+#[async_trait]
+pub trait StatisticsProviderRepository: Send + Sync {
+    /// Fetch an anime's current statistics from a provider
+    ///
+    /// # Arguments
+    /// * `provider` - Provider to query (must be one of `statistics_providers()`)
+    /// * `provider_anime_id` - The anime ID in the provider's system
+    async fn fetch_statistics(
+        &self,
+        provider: AnimeProvider,
+        provider_anime_id: u32,
+    ) -> AppResult<ProviderStatistics>;
+
+    /// Providers that expose audience statistics
+    fn statistics_providers(&self) -> Vec<AnimeProvider>;
+}
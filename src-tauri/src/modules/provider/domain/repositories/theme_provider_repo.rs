@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+
+use crate::modules::themes::domain::ProviderThemeSong;
+use crate::shared::domain::value_objects::AnimeProvider;
+use crate::shared::errors::AppResult;
+
+/// Repository interface for fetching an anime's opening and ending theme songs
+#[async_trait]
+pub trait ThemeProviderRepository: Send + Sync {
+    /// Fetch the theme song lines a provider lists for an anime
+    ///
+    /// # Arguments
+    /// * `provider` - Provider to query (must be one of `theme_providers()`)
+    /// * `provider_anime_id` - The anime ID in the provider's system
+    async fn fetch_themes(
+        &self,
+        provider: AnimeProvider,
+        provider_anime_id: u32,
+    ) -> AppResult<Vec<ProviderThemeSong>>;
+
+    /// Providers that list theme songs
+    fn theme_providers(&self) -> Vec<AnimeProvider>;
+}
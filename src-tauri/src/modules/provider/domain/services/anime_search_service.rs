@@ -131,6 +131,23 @@ impl AnimeSearchService {
         Ok(result)
     }
 
+    /// Get anime details from a single provider without fallback
+    ///
+    /// Unlike `get_details`, provider failures are returned instead of being
+    /// read as "not found", so callers can tell a rate limit from a missing id.
+    pub async fn get_details_from(
+        &self,
+        id: &str,
+        provider: AnimeProvider,
+    ) -> AppResult<Option<AnimeData>> {
+        log::info!(
+            "DETAILS: Fetching details for id '{}' from {:?}",
+            id,
+            provider
+        );
+        self.provider_repo.get_anime_by_id(id, provider).await
+    }
+
     /// Enhance anime data by fetching from additional providers and merging
     ///
     /// This method is used by use cases to optionally enhance a single anime's data
@@ -0,0 +1,361 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::sync::OnceCell;
+
+use crate::{
+    modules::provider::domain::entities::anime_data::AnimeData,
+    modules::provider::infrastructure::http_client::RateLimitClient,
+    shared::errors::{AppError, AppResult},
+};
+
+use super::mapper::AniDbMapper;
+use super::models::*;
+use super::title_index::{AniDbTitleIndex, TitleMatch};
+
+/// HTTP API protocol version understood by this adapter
+const PROTOCOL_VERSION: u32 = 1;
+
+/// Registered AniDB client credentials required by the HTTP API
+#[derive(Debug, Clone)]
+pub struct AniDbClientConfig {
+    pub name: String,
+    pub version: u32,
+}
+
+/// AniDB provider adapter: offline title dump for lookups, HTTP API (XML) for details
+pub struct AniDbAdapter {
+    http_client: RateLimitClient,
+    base_url: String,
+    client: Option<AniDbClientConfig>,
+    titles_path: Option<PathBuf>,
+    title_index: OnceCell<Option<Arc<AniDbTitleIndex>>>,
+    mapper: AniDbMapper,
+}
+
+impl AniDbAdapter {
+    /// Create adapter configured from `ANIDB_CLIENT`, `ANIDB_CLIENT_VERSION` and `ANIDB_TITLES_PATH`
+    pub fn new() -> Self {
+        let client = match (
+            std::env::var("ANIDB_CLIENT").ok(),
+            std::env::var("ANIDB_CLIENT_VERSION")
+                .ok()
+                .and_then(|v| v.parse().ok()),
+        ) {
+            (Some(name), Some(version)) => Some(AniDbClientConfig { name, version }),
+            _ => None,
+        };
+        let titles_path = std::env::var("ANIDB_TITLES_PATH").ok().map(PathBuf::from);
+
+        if client.is_none() {
+            log::warn!("AniDB HTTP API disabled: ANIDB_CLIENT/ANIDB_CLIENT_VERSION not found in environment");
+        }
+        if titles_path.is_none() {
+            log::warn!("AniDB title lookup disabled: ANIDB_TITLES_PATH not found in environment");
+        }
+
+        Self::with_config(client, titles_path)
+    }
+
+    pub fn with_config(client: Option<AniDbClientConfig>, titles_path: Option<PathBuf>) -> Self {
+        Self {
+            http_client: RateLimitClient::for_anidb(),
+            base_url: "http://api.anidb.net:9001/httpapi".to_string(),
+            client,
+            titles_path,
+            title_index: OnceCell::new(),
+            mapper: AniDbMapper::new(),
+        }
+    }
+
+    /// Create adapter around an already loaded title index (for testing)
+    pub fn with_title_index(index: AniDbTitleIndex) -> Self {
+        Self {
+            title_index: OnceCell::new_with(Some(Some(Arc::new(index)))),
+            ..Self::with_config(None, None)
+        }
+    }
+
+    /// Check if a request can be made immediately (for testing and monitoring)
+    pub fn can_make_request_now(&self) -> bool {
+        self.http_client.can_make_request_now()
+    }
+
+    /// Whether HTTP API credentials are configured
+    pub fn has_api_client(&self) -> bool {
+        self.client.is_some()
+    }
+
+    /// Title index, loaded from disk on first use
+    async fn title_index(&self) -> Option<Arc<AniDbTitleIndex>> {
+        self.title_index
+            .get_or_init(|| async {
+                let path = self.titles_path.clone()?;
+                let display = path.display().to_string();
+
+                let loaded =
+                    tokio::task::spawn_blocking(move || AniDbTitleIndex::from_file(&path)).await;
+
+                match loaded {
+                    Ok(Ok(index)) => {
+                        log::info!(
+                            "AniDB: Loaded {} anime from title dump '{}'",
+                            index.len(),
+                            display
+                        );
+                        Some(Arc::new(index))
+                    }
+                    Ok(Err(e)) => {
+                        log::error!("AniDB: Failed to load title dump '{}': {}", display, e);
+                        None
+                    }
+                    Err(e) => {
+                        log::error!("AniDB: Title dump loader panicked: {}", e);
+                        None
+                    }
+                }
+            })
+            .await
+            .clone()
+    }
+
+    fn parse_id(id: &str) -> AppResult<u32> {
+        id.parse()
+            .map_err(|_| AppError::ValidationError(format!("Invalid AniDB ID: {}", id)))
+    }
+
+    /// Parse an HTTP API response; unknown anime yield `None`, other `<error>` bodies fail
+    pub fn parse_anime_document(xml: &str) -> AppResult<Option<AniDbAnime>> {
+        if Self::is_error_document(xml) {
+            let error: AniDbError = quick_xml::de::from_str(xml).map_err(|e| {
+                AppError::SerializationError(format!("Failed to parse AniDB error: {}", e))
+            })?;
+            let message = error.message.trim();
+
+            if message.to_lowercase().contains("not found") {
+                return Ok(None);
+            }
+            if message.eq_ignore_ascii_case("banned") {
+                return Err(AppError::rate_limited(
+                    "AniDB client is temporarily banned",
+                    None,
+                ));
+            }
+            return Err(AppError::ApiError(format!("AniDB API error: {}", message)));
+        }
+
+        quick_xml::de::from_str(xml).map(Some).map_err(|e| {
+            AppError::SerializationError(format!("Failed to parse AniDB anime: {}", e))
+        })
+    }
+
+    /// AniDB answers errors with 200 and an `<error>` root element
+    fn is_error_document(xml: &str) -> bool {
+        let mut rest = xml.trim_start();
+        while rest.starts_with("<?") || rest.starts_with("<!--") {
+            let end = if rest.starts_with("<?") { "?>" } else { "-->" };
+            match rest.find(end) {
+                Some(position) => rest = rest[position + end.len()..].trim_start(),
+                None => return false,
+            }
+        }
+        rest.starts_with("<error")
+    }
+}
+
+impl AniDbAdapter {
+    // =============================================================================
+    // OFFLINE TITLE LOOKUP
+    // =============================================================================
+
+    /// Resolve a title to AniDB ids using the local title dump
+    pub async fn resolve_title(&self, title: &str, limit: usize) -> AppResult<Vec<TitleMatch>> {
+        let index = self.title_index().await.ok_or_else(|| {
+            AppError::ServiceUnavailable("AniDB title dump is not available".to_string())
+        })?;
+
+        Ok(index.search(title, limit))
+    }
+
+    /// Search the title dump; results carry titles only until details are fetched
+    pub async fn search_anime(&self, query: &str, limit: usize) -> AppResult<Vec<AnimeData>> {
+        log::info!(
+            "AniDB: Searching title dump for '{}' (limit: {})",
+            query,
+            limit
+        );
+
+        let index = self.title_index().await.ok_or_else(|| {
+            AppError::ServiceUnavailable("AniDB title dump is not available".to_string())
+        })?;
+
+        let results: Vec<AnimeData> = index
+            .search(query, limit)
+            .into_iter()
+            .filter_map(|title_match| {
+                index.titles(title_match.aid).map(|titles| {
+                    let mut anime_data = self.mapper.map_title_entry(title_match.aid, titles);
+                    anime_data.quality.relevance_score = title_match.score as f32;
+                    anime_data
+                })
+            })
+            .collect();
+
+        log::info!("AniDB: Found {} results for '{}'", results.len(), query);
+        Ok(results)
+    }
+
+    // =============================================================================
+    // HTTP API
+    // =============================================================================
+
+    /// Fetch the raw anime document from the HTTP API
+    pub async fn fetch_anime(&self, aid: u32) -> AppResult<Option<AniDbAnime>> {
+        let client = self.client.as_ref().ok_or_else(|| {
+            AppError::ServiceUnavailable("AniDB HTTP API client is not configured".to_string())
+        })?;
+
+        let url = format!(
+            "{}?request=anime&client={}&clientver={}&protover={}&aid={}",
+            self.base_url,
+            urlencoding::encode(&client.name),
+            client.version,
+            PROTOCOL_VERSION,
+            aid
+        );
+
+        log::info!("AniDB: Getting anime by ID '{}'", aid);
+
+        let body = self.http_client.get_text(&url).await?;
+        let anime = Self::parse_anime_document(&body)?;
+
+        if anime.is_none() {
+            log::info!("AniDB: No anime found for ID '{}'", aid);
+        }
+        Ok(anime)
+    }
+
+    pub async fn get_anime_by_id(&self, id: &str) -> AppResult<Option<AnimeData>> {
+        let aid = Self::parse_id(id)?;
+
+        if !self.has_api_client() {
+            // Without API access the title dump is the best we can offer
+            return match self.title_index().await {
+                Some(index) => Ok(index
+                    .titles(aid)
+                    .map(|titles| self.mapper.map_title_entry(aid, titles))),
+                None => Err(AppError::ServiceUnavailable(
+                    "AniDB is not configured".to_string(),
+                )),
+            };
+        }
+
+        match self.fetch_anime(aid).await? {
+            Some(anime) => {
+                let anime_data = self.mapper.map_to_anime_data(anime).map_err(|e| {
+                    AppError::MappingError(format!("Failed to map AniDB data: {}", e))
+                })?;
+                log::info!("AniDB: Found anime by ID '{}'", id);
+                Ok(Some(anime_data))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Get the episode list with AniDB's per-episode lengths, air dates and ratings
+    pub async fn get_anime_episodes(&self, aid: u32) -> AppResult<Vec<AniDbEpisode>> {
+        let episodes = self
+            .fetch_anime(aid)
+            .await?
+            .and_then(|anime| anime.episodes)
+            .map(|episodes| episodes.items)
+            .unwrap_or_default();
+
+        log::info!(
+            "AniDB: Found {} episodes for anime ID '{}'",
+            episodes.len(),
+            aid
+        );
+        Ok(episodes)
+    }
+}
+
+impl Default for AniDbAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TITLE_DUMP: &str = include_str!("fixtures/anime-titles.xml");
+    const ANIME: &str = include_str!("fixtures/anime.xml");
+    const BANNED: &str = include_str!("fixtures/banned.xml");
+
+    fn adapter() -> AniDbAdapter {
+        AniDbAdapter::with_title_index(AniDbTitleIndex::from_xml(TITLE_DUMP).unwrap())
+    }
+
+    #[test]
+    fn test_adapter_creation() {
+        let adapter = AniDbAdapter::with_config(None, None);
+        assert!(adapter.can_make_request_now());
+        assert!(!adapter.has_api_client());
+    }
+
+    #[test]
+    fn test_parse_anime_document() {
+        let anime = AniDbAdapter::parse_anime_document(ANIME).unwrap().unwrap();
+        assert_eq!(anime.id, 23);
+    }
+
+    #[test]
+    fn test_parse_not_found_document() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?><error>Anime not found</error>"#;
+        assert!(AniDbAdapter::parse_anime_document(xml).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_parse_banned_document() {
+        assert!(AniDbAdapter::parse_anime_document(BANNED).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_search_uses_title_dump() {
+        let results = adapter()
+            .search_anime("Crest of the Stars", 5)
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].anime.title.main, "Seikai no Monshou");
+        assert_eq!(results[0].quality.relevance_score, 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_title() {
+        let matches = adapter()
+            .resolve_title("Shingeki no Kyojin", 1)
+            .await
+            .unwrap();
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].aid, 9541);
+    }
+
+    #[tokio::test]
+    async fn test_get_by_id_falls_back_to_title_dump() {
+        let data = adapter().get_anime_by_id("23").await.unwrap().unwrap();
+        assert_eq!(data.anime.title.main, "Cowboy Bebop");
+
+        assert!(adapter().get_anime_by_id("42").await.unwrap().is_none());
+        assert!(adapter().get_anime_by_id("abc").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_search_without_dump_is_unavailable() {
+        let adapter = AniDbAdapter::with_config(None, None);
+        assert!(adapter.search_anime("Cowboy Bebop", 5).await.is_err());
+    }
+}
@@ -0,0 +1,521 @@
+use super::models::*;
+use crate::modules::anime::domain::{
+    entities::{
+        anime_detailed::{AiredDates, AnimeDetailed},
+        genre::Genre,
+    },
+    value_objects::{AnimeStatus, AnimeTier, AnimeTitle, AnimeType, QualityMetrics},
+};
+use crate::modules::provider::domain::entities::anime_data::{AnimeData, DataQuality, DataSource};
+use crate::shared::domain::value_objects::{AnimeProvider, ProviderMetadata};
+
+use crate::shared::domain::value_objects::UnifiedAgeRestriction;
+use crate::shared::errors::AppError;
+use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
+
+/// AniDB resource type for MyAnimeList entries
+const MAL_RESOURCE_TYPE: u32 = 2;
+
+/// Minimum tag weight (0-600) for a tag to be treated as a genre
+const GENRE_TAG_MIN_WEIGHT: u32 = 300;
+
+const IMAGE_BASE_URL: &str = "https://cdn-eu.anidb.net/images/main";
+
+/// Capability trait to describe what each adapter can provide
+pub trait AdapterCapabilities {
+    /// Get the name of the adapter
+    fn name(&self) -> &'static str;
+
+    /// Get the provider fields this adapter can populate
+    fn supported_fields(&self) -> Vec<&'static str>;
+
+    /// Get the provider fields this adapter cannot populate
+    fn unsupported_fields(&self) -> Vec<&'static str>;
+
+    /// Check if the adapter supports a specific field
+    fn supports_field(&self, field: &str) -> bool {
+        self.supported_fields().contains(&field)
+    }
+
+    /// Get quality score for this adapter (0.0 to 1.0)
+    fn quality_score(&self) -> f64;
+
+    /// Get response time estimate in milliseconds
+    fn estimated_response_time(&self) -> u64;
+
+    /// Check if the adapter has rate limiting
+    fn has_rate_limiting(&self) -> bool;
+}
+
+/// AniDB specific mapper implementation
+#[derive(Debug, Clone)]
+pub struct AniDbMapper;
+
+impl AniDbMapper {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Map AniDB type to AnimeType
+    fn map_anime_type(anime_type: &Option<String>) -> AnimeType {
+        match anime_type.as_deref() {
+            Some("TV Series") => AnimeType::TV,
+            Some("Movie") => AnimeType::Movie,
+            Some("OVA") => AnimeType::OVA,
+            Some("TV Special") => AnimeType::Special,
+            Some("Web") => AnimeType::ONA,
+            Some("Music Video") => AnimeType::Music,
+            _ => AnimeType::Unknown,
+        }
+    }
+
+    /// Derive airing status from the start and end dates
+    fn derive_status(from: Option<DateTime<Utc>>, to: Option<DateTime<Utc>>) -> AnimeStatus {
+        let now = Utc::now();
+        match (from, to) {
+            (_, Some(end)) if end <= now => AnimeStatus::Finished,
+            (Some(start), _) if start > now => AnimeStatus::NotYetAired,
+            (Some(_), _) => AnimeStatus::Airing,
+            (None, _) => AnimeStatus::Unknown,
+        }
+    }
+
+    /// Parse an AniDB date, which may be `YYYY-MM-DD`, `YYYY-MM` or `YYYY`
+    fn parse_date(date_str: &Option<String>) -> Option<DateTime<Utc>> {
+        let value = date_str.as_deref()?;
+        NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .or_else(|_| NaiveDate::parse_from_str(&format!("{}-01", value), "%Y-%m-%d"))
+            .or_else(|_| NaiveDate::parse_from_str(&format!("{}-01-01", value), "%Y-%m-%d"))
+            .ok()
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .map(|dt| DateTime::<Utc>::from_naive_utc_and_offset(dt, Utc))
+    }
+
+    /// Build the domain title from AniDB's typed, language-tagged titles
+    pub fn build_title(titles: &[AniDbTitle]) -> AnimeTitle {
+        let main = titles
+            .iter()
+            .find(|t| t.is_main())
+            .or_else(|| titles.first())
+            .map(|t| t.value.clone())
+            .unwrap_or_else(|| "Unknown Title".to_string());
+        let official = |lang: &str| {
+            titles
+                .iter()
+                .find(|t| t.is_official_in(lang))
+                .map(|t| t.value.clone())
+        };
+        let native = official("ja");
+
+        AnimeTitle {
+            main: main.clone(),
+            english: official("en"),
+            japanese: native.clone(),
+            romaji: Some(main),
+            native,
+            synonyms: titles
+                .iter()
+                .filter(|t| matches!(t.title_type.as_deref(), Some("syn" | "synonym" | "short")))
+                .map(|t| t.value.clone())
+                .collect(),
+        }
+    }
+
+    /// Non-spoiler, highly weighted tags as genres
+    fn extract_genres(tags: &Option<AniDbTags>) -> Vec<Genre> {
+        tags.as_ref()
+            .map(|t| {
+                t.items
+                    .iter()
+                    .filter(|tag| {
+                        !tag.is_spoiler() && tag.weight.unwrap_or(0) >= GENRE_TAG_MIN_WEIGHT
+                    })
+                    .filter_map(|tag| tag.name.as_deref())
+                    .map(|name| Genre::new(Self::title_case(name)))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Studios are credited as `Animation Work`
+    fn extract_studios(creators: &Option<AniDbCreators>) -> Vec<String> {
+        creators
+            .as_ref()
+            .map(|c| {
+                c.items
+                    .iter()
+                    .filter(|creator| creator.role.as_deref() == Some("Animation Work"))
+                    .map(|creator| creator.name.clone())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn extract_mal_id(resources: &Option<AniDbResources>) -> Option<String> {
+        resources.as_ref().and_then(|r| {
+            r.items
+                .iter()
+                .filter(|resource| resource.resource_type == MAL_RESOURCE_TYPE)
+                .flat_map(|resource| resource.entities.iter())
+                .flat_map(|entity| entity.identifiers.iter())
+                .next()
+                .cloned()
+        })
+    }
+
+    /// Typical episode length, taken from the first regular episode
+    fn extract_duration(episodes: &Option<AniDbEpisodes>) -> Option<String> {
+        episodes
+            .as_ref()
+            .and_then(|e| e.items.iter().find(|ep| ep.is_regular()))
+            .and_then(|ep| ep.length)
+            .map(|minutes| format!("{} min per ep", minutes))
+    }
+
+    fn title_case(value: &str) -> String {
+        value
+            .split(' ')
+            .map(|word| {
+                let mut chars = word.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect(),
+                    None => String::new(),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Calculate data completeness based on available fields
+    fn calculate_completeness(anime: &AnimeDetailed) -> f32 {
+        let checks = [
+            !anime.title.main.is_empty(),
+            anime.title.english.is_some(),
+            anime.title.japanese.is_some(),
+            anime.synopsis.is_some(),
+            anime.episodes.is_some(),
+            anime.score.is_some(),
+            anime.image_url.is_some(),
+            !anime.genres.is_empty(),
+            !anime.studios.is_empty(),
+            anime.aired.from.is_some(),
+            anime.duration.is_some(),
+        ];
+
+        checks.iter().filter(|present| **present).count() as f32 / checks.len() as f32
+    }
+
+    /// Identify missing critical fields
+    fn identify_missing_fields(anime: &AnimeDetailed) -> Vec<String> {
+        let mut missing = Vec::new();
+
+        if anime.title.english.is_none() {
+            missing.push("title_english".to_string());
+        }
+        if anime.synopsis.is_none() {
+            missing.push("synopsis".to_string());
+        }
+        if anime.episodes.is_none() {
+            missing.push("episodes".to_string());
+        }
+        if anime.image_url.is_none() {
+            missing.push("cover_image".to_string());
+        }
+        if anime.genres.is_empty() {
+            missing.push("genres".to_string());
+        }
+
+        missing
+    }
+
+    fn provider_metadata(aid: u32) -> ProviderMetadata {
+        let mut metadata = ProviderMetadata::new(AnimeProvider::AniDB, aid.to_string());
+        metadata.add_provider_url(
+            AnimeProvider::AniDB,
+            format!("https://anidb.net/anime/{}", aid),
+        );
+        metadata
+    }
+
+    fn wrap(anime_detailed: AnimeDetailed, score: f32, confidence: f32) -> AnimeData {
+        let quality = DataQuality {
+            score,
+            completeness: Self::calculate_completeness(&anime_detailed),
+            consistency: 0.95,    // AniDB data is strictly moderated
+            relevance_score: 0.0, // Will be set during search ranking
+            missing_fields: Self::identify_missing_fields(&anime_detailed),
+        };
+
+        let source_info = DataSource {
+            primary_provider: AnimeProvider::AniDB,
+            providers_used: vec![AnimeProvider::AniDB],
+            confidence,
+            fetch_time_ms: 2000, // Bound by the one-request-per-2s limit
+        };
+
+        AnimeData::with_metadata(anime_detailed, quality, source_info)
+    }
+}
+
+impl AniDbMapper {
+    /// Map a full HTTP API anime document
+    pub fn map_to_anime_data(&self, source: AniDbAnime) -> Result<AnimeData, AppError> {
+        let now = Utc::now();
+
+        let mut provider_metadata = Self::provider_metadata(source.id);
+        if let Some(mal_id) = Self::extract_mal_id(&source.resources) {
+            provider_metadata.add_external_id(AnimeProvider::Jikan, mal_id);
+        }
+
+        let titles = source.titles.map(|t| t.items).unwrap_or_default();
+        let aired_from = Self::parse_date(&source.startdate);
+        let aired_to = Self::parse_date(&source.enddate);
+        let score = source
+            .ratings
+            .as_ref()
+            .and_then(|r| r.permanent.as_ref().or(r.temporary.as_ref()))
+            .map(|r| (r.value * 100.0).round() / 100.0);
+        let image_url = source
+            .picture
+            .as_ref()
+            .map(|picture| format!("{}/{}", IMAGE_BASE_URL, picture));
+
+        let anime_detailed = AnimeDetailed {
+            id: Uuid::new_v4(),
+            title: Self::build_title(&titles),
+            provider_metadata,
+            score,
+            rating: score,
+            favorites: None,
+            synopsis: source.description.clone(),
+            description: source.description,
+            // AniDB reports 0 episodes while the count is still unknown
+            episodes: source
+                .episodecount
+                .filter(|count| *count > 0)
+                .map(|count| count as u16),
+            status: Self::derive_status(aired_from, aired_to),
+            aired: AiredDates {
+                from: aired_from,
+                to: aired_to,
+            },
+            anime_type: Self::map_anime_type(&source.anime_type),
+            age_restriction: source
+                .restricted
+                .filter(|restricted| *restricted)
+                .map(|_| UnifiedAgeRestriction::Explicit),
+            genres: Self::extract_genres(&source.tags),
+            studios: Self::extract_studios(&source.creators),
+            source: None,
+            duration: Self::extract_duration(&source.episodes),
+            image_url: image_url.clone(),
+            images: image_url,
+            banner_image: None, // AniDB doesn't provide banner images
+            trailer_url: None,
+            composite_score: score.unwrap_or(0.0),
+            tier: AnimeTier::default(),
+            quality_metrics: QualityMetrics::default(),
+            created_at: now,
+            updated_at: now,
+            last_synced_at: Some(now),
+        };
+
+        Ok(Self::wrap(anime_detailed, 0.85, 0.9))
+    }
+
+    /// Map a title dump entry; only titles are known until details are fetched
+    pub fn map_title_entry(&self, aid: u32, titles: &[AniDbTitle]) -> AnimeData {
+        let now = Utc::now();
+
+        let anime_detailed = AnimeDetailed {
+            id: Uuid::new_v4(),
+            title: Self::build_title(titles),
+            provider_metadata: Self::provider_metadata(aid),
+            score: None,
+            rating: None,
+            favorites: None,
+            synopsis: None,
+            description: None,
+            episodes: None,
+            status: AnimeStatus::Unknown,
+            aired: AiredDates {
+                from: None,
+                to: None,
+            },
+            anime_type: AnimeType::Unknown,
+            age_restriction: None,
+            genres: Vec::new(),
+            studios: Vec::new(),
+            source: None,
+            duration: None,
+            image_url: None,
+            images: None,
+            banner_image: None,
+            trailer_url: None,
+            composite_score: 0.0,
+            tier: AnimeTier::default(),
+            quality_metrics: QualityMetrics::default(),
+            created_at: now,
+            updated_at: now,
+            last_synced_at: None,
+        };
+
+        Self::wrap(anime_detailed, 0.4, 0.95)
+    }
+}
+
+impl AdapterCapabilities for AniDbMapper {
+    fn name(&self) -> &'static str {
+        "AniDB"
+    }
+
+    fn supported_fields(&self) -> Vec<&'static str> {
+        vec![
+            "id",
+            "mal_id",
+            "title",
+            "title_english",
+            "title_japanese",
+            "title_synonyms",
+            "synopsis",
+            "description",
+            "episode_count",
+            "episodes",
+            "duration",
+            "status",
+            "anime_type",
+            "start_date",
+            "end_date",
+            "cover_image",
+            "score",
+            "age_restriction",
+            "studios",
+            "genres",
+            "tags",
+        ]
+    }
+
+    fn unsupported_fields(&self) -> Vec<&'static str> {
+        vec![
+            "anilist_id",   // Not tracked by AniDB
+            "banner_image", // Not provided by AniDB
+            "trailer_url",
+            "favorites",
+            "source",
+        ]
+    }
+
+    fn quality_score(&self) -> f64 {
+        0.85
+    }
+
+    fn estimated_response_time(&self) -> u64 {
+        2000 // One request every two seconds at most
+    }
+
+    fn has_rate_limiting(&self) -> bool {
+        true // AniDB bans clients that exceed its limit
+    }
+}
+
+impl Default for AniDbMapper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::title_index::AniDbTitleIndex;
+    use super::*;
+
+    const TITLE_DUMP: &str = include_str!("fixtures/anime-titles.xml");
+    const ANIME: &str = include_str!("fixtures/anime.xml");
+
+    fn anime() -> AniDbAnime {
+        quick_xml::de::from_str(ANIME).unwrap()
+    }
+
+    #[test]
+    fn test_maps_core_fields() {
+        let detailed = AniDbMapper::new().map_to_anime_data(anime()).unwrap().anime;
+
+        assert_eq!(detailed.title.main, "Cowboy Bebop");
+        assert_eq!(detailed.title.english.as_deref(), Some("Cowboy Bebop"));
+        assert_eq!(
+            detailed.title.japanese.as_deref(),
+            Some("カウボーイビバップ")
+        );
+        assert_eq!(detailed.title.synonyms, vec!["CB".to_string()]);
+        assert_eq!(detailed.episodes, Some(26));
+        assert_eq!(detailed.score, Some(8.85));
+        assert_eq!(detailed.status, AnimeStatus::Finished);
+        assert_eq!(detailed.anime_type, AnimeType::TV);
+        assert!(detailed.age_restriction.is_none());
+        assert_eq!(detailed.duration.as_deref(), Some("25 min per ep"));
+        assert_eq!(
+            detailed.image_url.as_deref(),
+            Some("https://cdn-eu.anidb.net/images/main/1993.jpg")
+        );
+    }
+
+    #[test]
+    fn test_maps_tags_and_credits() {
+        let detailed = AniDbMapper::new().map_to_anime_data(anime()).unwrap().anime;
+
+        // Spoiler and low-weight tags are not genres
+        let genres: Vec<_> = detailed.genres.iter().map(|g| g.name.as_str()).collect();
+        assert_eq!(genres, vec!["Science Fiction", "Space"]);
+
+        assert_eq!(detailed.studios, vec!["Sunrise".to_string()]);
+    }
+
+    #[test]
+    fn test_collects_external_ids() {
+        let metadata = AniDbMapper::new()
+            .map_to_anime_data(anime())
+            .unwrap()
+            .anime
+            .provider_metadata;
+
+        assert_eq!(metadata.primary_provider, AnimeProvider::AniDB);
+        assert_eq!(
+            metadata.get_external_id(&AnimeProvider::AniDB),
+            Some(&"23".to_string())
+        );
+        assert_eq!(
+            metadata.get_external_id(&AnimeProvider::Jikan),
+            Some(&"1".to_string())
+        );
+        assert_eq!(
+            metadata.get_provider_url(&AnimeProvider::AniDB),
+            Some(&"https://anidb.net/anime/23".to_string())
+        );
+    }
+
+    #[test]
+    fn test_restricted_anime_is_explicit() {
+        let xml = ANIME.replace(r#"restricted="false""#, r#"restricted="true""#);
+        let anime: AniDbAnime = quick_xml::de::from_str(&xml).unwrap();
+
+        let detailed = AniDbMapper::new().map_to_anime_data(anime).unwrap().anime;
+        assert!(detailed.age_restriction.is_some());
+    }
+
+    #[test]
+    fn test_maps_title_dump_entry() {
+        let index = AniDbTitleIndex::from_xml(TITLE_DUMP).unwrap();
+        let data = AniDbMapper::new().map_title_entry(1, index.titles(1).unwrap());
+
+        assert_eq!(data.anime.title.main, "Seikai no Monshou");
+        assert_eq!(
+            data.anime.title.english.as_deref(),
+            Some("Crest of the Stars")
+        );
+        assert_eq!(data.anime.title.native.as_deref(), Some("星界の紋章"));
+        assert_eq!(data.anime.title.synonyms.len(), 2);
+        assert_eq!(data.anime.status, AnimeStatus::Unknown);
+        assert!(data.anime.last_synced_at.is_none());
+    }
+}
@@ -0,0 +1,6 @@
+pub mod adapter;
+pub mod mapper;
+pub mod models;
+pub mod title_index;
+
+pub use adapter::*;
@@ -0,0 +1,340 @@
+#![allow(unused)]
+use serde::{Deserialize, Serialize};
+
+// =============================================================================
+// ANIME TITLES DUMP (anime-titles.xml)
+// =============================================================================
+
+/// Root of the daily `anime-titles.xml` dump
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnimeTitlesDump {
+    #[serde(rename = "anime", default)]
+    pub anime: Vec<TitleDumpAnime>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TitleDumpAnime {
+    #[serde(rename = "@aid")]
+    pub aid: u32,
+    #[serde(rename = "title", default)]
+    pub titles: Vec<AniDbTitle>,
+}
+
+/// A single title with its kind (`main`, `official`, `syn`, `short`) and language
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AniDbTitle {
+    #[serde(rename = "@type", default)]
+    pub title_type: Option<String>,
+    #[serde(rename = "@lang", alias = "@xml:lang", default)]
+    pub lang: Option<String>,
+    #[serde(rename = "$text", default)]
+    pub value: String,
+}
+
+impl AniDbTitle {
+    pub fn is_main(&self) -> bool {
+        self.title_type.as_deref() == Some("main")
+    }
+
+    pub fn is_official_in(&self, lang: &str) -> bool {
+        self.title_type.as_deref() == Some("official") && self.lang.as_deref() == Some(lang)
+    }
+}
+
+// =============================================================================
+// HTTP API (request=anime)
+// =============================================================================
+
+/// `<error>` document returned with a 200 status for bans and unknown ids
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AniDbError {
+    #[serde(rename = "@code", default)]
+    pub code: Option<u32>,
+    #[serde(rename = "$text", default)]
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AniDbAnime {
+    #[serde(rename = "@id")]
+    pub id: u32,
+    #[serde(rename = "@restricted", default)]
+    pub restricted: Option<bool>,
+    /// One of `TV Series`, `Movie`, `OVA`, `Web`, `TV Special`, `Music Video`, `Other`
+    #[serde(rename = "type", default)]
+    pub anime_type: Option<String>,
+    #[serde(default)]
+    pub episodecount: Option<u32>,
+    #[serde(default)]
+    pub startdate: Option<String>,
+    #[serde(default)]
+    pub enddate: Option<String>,
+    #[serde(default)]
+    pub titles: Option<AniDbTitles>,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub creators: Option<AniDbCreators>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub ratings: Option<AniDbRatings>,
+    /// Picture file name, served from the AniDB image CDN
+    #[serde(default)]
+    pub picture: Option<String>,
+    #[serde(default)]
+    pub resources: Option<AniDbResources>,
+    #[serde(default)]
+    pub tags: Option<AniDbTags>,
+    #[serde(default)]
+    pub episodes: Option<AniDbEpisodes>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AniDbTitles {
+    #[serde(rename = "title", default)]
+    pub items: Vec<AniDbTitle>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AniDbCreators {
+    #[serde(rename = "name", default)]
+    pub items: Vec<AniDbCreator>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AniDbCreator {
+    #[serde(rename = "@id", default)]
+    pub id: Option<u32>,
+    /// Credit such as `Direction`, `Music` or `Animation Work`
+    #[serde(rename = "@type", default)]
+    pub role: Option<String>,
+    #[serde(rename = "$text", default)]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AniDbRatings {
+    #[serde(default)]
+    pub permanent: Option<AniDbRating>,
+    #[serde(default)]
+    pub temporary: Option<AniDbRating>,
+    #[serde(default)]
+    pub review: Option<AniDbRating>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AniDbRating {
+    #[serde(rename = "@count", default)]
+    pub count: Option<u32>,
+    #[serde(rename = "@votes", default)]
+    pub votes: Option<u32>,
+    #[serde(rename = "$text")]
+    pub value: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AniDbResources {
+    #[serde(rename = "resource", default)]
+    pub items: Vec<AniDbResource>,
+}
+
+/// External resource; `type` 2 is MyAnimeList
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AniDbResource {
+    #[serde(rename = "@type")]
+    pub resource_type: u32,
+    #[serde(rename = "externalentity", default)]
+    pub entities: Vec<AniDbExternalEntity>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AniDbExternalEntity {
+    #[serde(rename = "identifier", default)]
+    pub identifiers: Vec<String>,
+    #[serde(default)]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AniDbTags {
+    #[serde(rename = "tag", default)]
+    pub items: Vec<AniDbTag>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AniDbTag {
+    #[serde(rename = "@id", default)]
+    pub id: Option<u32>,
+    /// Relevance weight, 0-600
+    #[serde(rename = "@weight", default)]
+    pub weight: Option<u32>,
+    #[serde(rename = "@localspoiler", default)]
+    pub local_spoiler: Option<bool>,
+    #[serde(rename = "@globalspoiler", default)]
+    pub global_spoiler: Option<bool>,
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+impl AniDbTag {
+    pub fn is_spoiler(&self) -> bool {
+        self.local_spoiler.unwrap_or(false) || self.global_spoiler.unwrap_or(false)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AniDbEpisodes {
+    #[serde(rename = "episode", default)]
+    pub items: Vec<AniDbEpisode>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AniDbEpisode {
+    #[serde(rename = "@id")]
+    pub id: u32,
+    pub epno: AniDbEpisodeNumber,
+    /// Length in minutes
+    #[serde(default)]
+    pub length: Option<u32>,
+    #[serde(default)]
+    pub airdate: Option<String>,
+    #[serde(default)]
+    pub rating: Option<AniDbRating>,
+    #[serde(rename = "title", default)]
+    pub titles: Vec<AniDbTitle>,
+    #[serde(default)]
+    pub summary: Option<String>,
+}
+
+/// Episode number with its kind: 1 regular, 2 special, 3 credit, 4 trailer, 5 parody, 6 other
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AniDbEpisodeNumber {
+    #[serde(rename = "@type")]
+    pub kind: u8,
+    #[serde(rename = "$text")]
+    pub value: String,
+}
+
+impl AniDbEpisode {
+    pub fn is_regular(&self) -> bool {
+        self.epno.kind == 1
+    }
+
+    /// Numeric episode number for regular episodes (`"12"`), `None` for `S1`, `C2`, ...
+    pub fn number(&self) -> Option<u32> {
+        self.epno.value.parse().ok()
+    }
+
+    /// English title, falling back to the romanised one
+    pub fn title(&self) -> Option<&str> {
+        ["en", "x-jat"].iter().find_map(|lang| {
+            self.titles
+                .iter()
+                .find(|t| t.lang.as_deref() == Some(*lang))
+                .map(|t| t.value.as_str())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TITLE_DUMP: &str = include_str!("fixtures/anime-titles.xml");
+    const ANIME: &str = include_str!("fixtures/anime.xml");
+    const BANNED: &str = include_str!("fixtures/banned.xml");
+
+    #[test]
+    fn test_title_dump_deserialization() {
+        let dump: AnimeTitlesDump = quick_xml::de::from_str(TITLE_DUMP).unwrap();
+
+        assert_eq!(dump.anime.len(), 4);
+        assert_eq!(dump.anime[0].aid, 1);
+        assert_eq!(dump.anime[0].titles.len(), 5);
+
+        let main = &dump.anime[0].titles[0];
+        assert!(main.is_main());
+        assert_eq!(main.lang.as_deref(), Some("x-jat"));
+        assert_eq!(main.value, "Seikai no Monshou");
+        assert!(dump.anime[0].titles[1].is_official_in("en"));
+    }
+
+    #[test]
+    fn test_title_dump_unescapes_entities() {
+        let dump: AnimeTitlesDump = quick_xml::de::from_str(TITLE_DUMP).unwrap();
+        let movie = dump.anime.iter().find(|a| a.aid == 5).unwrap();
+
+        assert!(movie
+            .titles
+            .iter()
+            .any(|t| t.value == "Cowboy Bebop: Knockin' on Heaven's Door"));
+    }
+
+    #[test]
+    fn test_anime_deserialization() {
+        let anime: AniDbAnime = quick_xml::de::from_str(ANIME).unwrap();
+
+        assert_eq!(anime.id, 23);
+        assert_eq!(anime.restricted, Some(false));
+        assert_eq!(anime.anime_type.as_deref(), Some("TV Series"));
+        assert_eq!(anime.episodecount, Some(26));
+        assert_eq!(anime.picture.as_deref(), Some("1993.jpg"));
+        assert_eq!(anime.titles.as_ref().unwrap().items.len(), 4);
+        assert_eq!(anime.creators.as_ref().unwrap().items.len(), 3);
+
+        let ratings = anime.ratings.as_ref().unwrap();
+        assert_eq!(ratings.permanent.as_ref().unwrap().value, 8.85);
+        assert_eq!(ratings.permanent.as_ref().unwrap().count, Some(14821));
+    }
+
+    #[test]
+    fn test_tags_and_resources() {
+        let anime: AniDbAnime = quick_xml::de::from_str(ANIME).unwrap();
+
+        let tags = &anime.tags.as_ref().unwrap().items;
+        assert_eq!(tags.len(), 3);
+        assert_eq!(tags[0].name.as_deref(), Some("science fiction"));
+        assert_eq!(tags[0].weight, Some(600));
+        assert!(!tags[0].is_spoiler());
+        assert!(tags[2].is_spoiler());
+
+        let resources = &anime.resources.as_ref().unwrap().items;
+        assert_eq!(resources[0].resource_type, 2);
+        assert_eq!(resources[0].entities[0].identifiers, vec!["1".to_string()]);
+    }
+
+    #[test]
+    fn test_episodes_deserialization() {
+        let anime: AniDbAnime = quick_xml::de::from_str(ANIME).unwrap();
+        let episodes = anime.episodes.unwrap().items;
+
+        assert_eq!(episodes.len(), 3);
+
+        let first = &episodes[0];
+        assert!(first.is_regular());
+        assert_eq!(first.number(), Some(1));
+        assert_eq!(first.length, Some(25));
+        assert_eq!(first.airdate.as_deref(), Some("1998-10-24"));
+        assert_eq!(first.rating.as_ref().unwrap().votes, Some(26));
+        assert_eq!(first.title(), Some("Asteroid Blues"));
+
+        let special = &episodes[2];
+        assert!(!special.is_regular());
+        assert_eq!(special.number(), None);
+        assert_eq!(special.title(), Some("Session XX: Mish-Mash Blues"));
+    }
+
+    #[test]
+    fn test_error_deserialization() {
+        let error: AniDbError = quick_xml::de::from_str(BANNED).unwrap();
+        assert_eq!(error.code, Some(302));
+        assert_eq!(error.message, "Banned");
+    }
+
+    #[test]
+    fn test_error_is_not_an_anime() {
+        let result = quick_xml::de::from_str::<AniDbAnime>(BANNED);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,290 @@
+//! Offline title → aid resolution backed by the AniDB `anime-titles.xml` dump
+//!
+//! AniDB publishes every known title (main, official, synonyms, short forms)
+//! for every anime in a single daily dump. Loading it once gives exact and
+//! fuzzy matching without spending any of the strict HTTP API quota.
+
+use flate2::read::GzDecoder;
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::Path;
+use strsim::jaro_winkler;
+
+use super::models::{AniDbTitle, AnimeTitlesDump};
+use crate::shared::errors::{AppError, AppResult};
+
+/// Minimum similarity for a fuzzy title match
+const MIN_MATCH_SCORE: f64 = 0.85;
+
+/// Score given to titles that contain the whole query
+const CONTAINS_SCORE: f64 = 0.9;
+
+/// A title dump entry matched by a query
+#[derive(Debug, Clone, PartialEq)]
+pub struct TitleMatch {
+    pub aid: u32,
+    pub title: String,
+    pub score: f64,
+}
+
+/// In-memory index over the AniDB title dump
+#[derive(Debug, Default)]
+pub struct AniDbTitleIndex {
+    titles: HashMap<u32, Vec<AniDbTitle>>,
+    exact: HashMap<String, Vec<u32>>,
+    normalized: Vec<(String, u32, usize)>,
+}
+
+impl AniDbTitleIndex {
+    /// Build the index from the dump's XML content
+    pub fn from_xml(xml: &str) -> AppResult<Self> {
+        let dump: AnimeTitlesDump = quick_xml::de::from_str(xml).map_err(|e| {
+            AppError::SerializationError(format!("Failed to parse AniDB title dump: {}", e))
+        })?;
+
+        let mut index = Self::default();
+        for anime in dump.anime {
+            for (position, title) in anime.titles.iter().enumerate() {
+                let key = normalize_title(&title.value);
+                if key.is_empty() {
+                    continue;
+                }
+
+                let aids = index.exact.entry(key.clone()).or_default();
+                if !aids.contains(&anime.aid) {
+                    aids.push(anime.aid);
+                }
+                index.normalized.push((key, anime.aid, position));
+            }
+            index.titles.insert(anime.aid, anime.titles);
+        }
+
+        Ok(index)
+    }
+
+    /// Load the dump from disk, accepting both `anime-titles.xml` and `anime-titles.xml.gz`
+    pub fn from_file(path: &Path) -> AppResult<Self> {
+        let bytes = std::fs::read(path).map_err(|e| {
+            AppError::InternalError(format!(
+                "Failed to read AniDB title dump {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+
+        let xml = if bytes.starts_with(&[0x1f, 0x8b]) {
+            let mut decoded = String::new();
+            GzDecoder::new(bytes.as_slice())
+                .read_to_string(&mut decoded)
+                .map_err(|e| {
+                    AppError::SerializationError(format!(
+                        "Failed to decompress AniDB title dump: {}",
+                        e
+                    ))
+                })?;
+            decoded
+        } else {
+            String::from_utf8(bytes).map_err(|e| {
+                AppError::SerializationError(format!("AniDB title dump is not UTF-8: {}", e))
+            })?
+        };
+
+        Self::from_xml(&xml)
+    }
+
+    /// Number of anime in the index
+    pub fn len(&self) -> usize {
+        self.titles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.titles.is_empty()
+    }
+
+    /// All known titles for an anime
+    pub fn titles(&self, aid: u32) -> Option<&[AniDbTitle]> {
+        self.titles.get(&aid).map(Vec::as_slice)
+    }
+
+    /// Anime ids with a title equal to `title` after normalisation
+    pub fn exact_matches(&self, title: &str) -> Vec<u32> {
+        self.exact
+            .get(&normalize_title(title))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Rank anime by their best matching title, highest score first
+    pub fn search(&self, query: &str, limit: usize) -> Vec<TitleMatch> {
+        let normalized_query = normalize_title(query);
+        if normalized_query.is_empty() || limit == 0 {
+            return Vec::new();
+        }
+
+        let mut best: HashMap<u32, (f64, usize)> = HashMap::new();
+        for (title, aid, position) in &self.normalized {
+            let score = if *title == normalized_query {
+                1.0
+            } else {
+                let similarity = jaro_winkler(&normalized_query, title);
+                if title.contains(&normalized_query) {
+                    similarity.max(CONTAINS_SCORE)
+                } else {
+                    similarity
+                }
+            };
+
+            if score < MIN_MATCH_SCORE {
+                continue;
+            }
+
+            let entry = best.entry(*aid).or_insert((score, *position));
+            if score > entry.0 {
+                *entry = (score, *position);
+            }
+        }
+
+        let mut matches: Vec<TitleMatch> = best
+            .into_iter()
+            .filter_map(|(aid, (score, position))| {
+                self.titles.get(&aid).and_then(|titles| {
+                    titles.get(position).map(|title| TitleMatch {
+                        aid,
+                        title: title.value.clone(),
+                        score,
+                    })
+                })
+            })
+            .collect();
+
+        matches.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(a.aid.cmp(&b.aid))
+        });
+        matches.truncate(limit);
+        matches
+    }
+}
+
+/// Lowercase and strip punctuation so `Cowboy Bebop: The Movie` matches `cowboy bebop the movie`
+fn normalize_title(title: &str) -> String {
+    title
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::{write::GzEncoder, Compression};
+    use std::io::Write;
+
+    const DUMP: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<animetitles>
+  <anime aid="1">
+    <title type="main" xml:lang="x-jat">Seikai no Monshou</title>
+    <title type="official" xml:lang="en">Crest of the Stars</title>
+  </anime>
+  <anime aid="23">
+    <title type="main" xml:lang="x-jat">Cowboy Bebop</title>
+  </anime>
+  <anime aid="5">
+    <title type="official" xml:lang="en">Cowboy Bebop: The Movie</title>
+  </anime>
+</animetitles>"#;
+
+    const TITLE_DUMP: &str = include_str!("fixtures/anime-titles.xml");
+
+    #[test]
+    fn test_exact_match_ignores_case_and_punctuation() {
+        let index = AniDbTitleIndex::from_xml(DUMP).unwrap();
+
+        assert_eq!(index.len(), 3);
+        assert_eq!(index.exact_matches("crest of the STARS"), vec![1]);
+        assert_eq!(index.exact_matches("Cowboy Bebop - The Movie"), vec![5]);
+        assert!(index.exact_matches("Trigun").is_empty());
+    }
+
+    #[test]
+    fn test_search_ranks_exact_title_first() {
+        let index = AniDbTitleIndex::from_xml(DUMP).unwrap();
+        let matches = index.search("Cowboy Bebop", 10);
+
+        assert_eq!(matches[0].aid, 23);
+        assert_eq!(matches[0].score, 1.0);
+        assert!(matches.iter().any(|m| m.aid == 5));
+        assert!(matches.iter().all(|m| m.aid != 1));
+    }
+
+    #[test]
+    fn test_search_tolerates_typos() {
+        let index = AniDbTitleIndex::from_xml(DUMP).unwrap();
+        let matches = index.search("Seikai no Monsho", 1);
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].aid, 1);
+        assert_eq!(matches[0].title, "Seikai no Monshou");
+    }
+
+    #[test]
+    fn test_index_loads_fixture() {
+        let index = AniDbTitleIndex::from_xml(TITLE_DUMP).unwrap();
+
+        assert_eq!(index.len(), 4);
+        assert_eq!(index.titles(9541).unwrap().len(), 3);
+        assert!(index.titles(42).is_none());
+    }
+
+    #[test]
+    fn test_resolves_official_and_short_titles() {
+        let index = AniDbTitleIndex::from_xml(TITLE_DUMP).unwrap();
+
+        assert_eq!(index.exact_matches("Attack on Titan"), vec![9541]);
+        assert_eq!(index.exact_matches("aot"), vec![9541]);
+        assert_eq!(index.exact_matches("カウボーイビバップ"), vec![23]);
+    }
+
+    #[test]
+    fn test_search_prefers_exact_title() {
+        let index = AniDbTitleIndex::from_xml(TITLE_DUMP).unwrap();
+        let matches = index.search("cowboy bebop", 5);
+
+        assert_eq!(matches[0].aid, 23);
+        assert!(matches.iter().any(|m| m.aid == 5));
+        assert!(matches.windows(2).all(|w| w[0].score >= w[1].score));
+    }
+
+    #[test]
+    fn test_search_without_match() {
+        let index = AniDbTitleIndex::from_xml(TITLE_DUMP).unwrap();
+
+        assert!(index.search("Neon Genesis Evangelion", 5).is_empty());
+        assert!(index.search("   ", 5).is_empty());
+    }
+
+    #[test]
+    fn test_loads_gzipped_dump() {
+        let path =
+            std::env::temp_dir().join(format!("anime-titles-{}.xml.gz", uuid::Uuid::new_v4()));
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(TITLE_DUMP.as_bytes()).unwrap();
+        std::fs::write(&path, encoder.finish().unwrap()).unwrap();
+
+        let index = AniDbTitleIndex::from_file(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(index.len(), 4);
+    }
+
+    #[test]
+    fn test_invalid_dump_is_rejected() {
+        assert!(
+            AniDbTitleIndex::from_xml("<animetitles><anime aid=\"x\"/></animetitles>").is_err()
+        );
+    }
+}
@@ -3,19 +3,35 @@
 //! GraphQL-based adapter for the AniList API that implements the same interface
 //! as the Jikan adapter, providing comprehensive anime data retrieval capabilities.
 
-use chrono::Datelike;
+use chrono::{DateTime, Utc};
 use serde_json::{json, Value};
 use std::collections::{HashMap, HashSet};
 
 use crate::{
-    modules::provider::{
-        domain::entities::anime_data::AnimeData, infrastructure::http_client::RateLimitClient,
+    modules::{
+        character::domain::{ProviderCastMember, ProviderStaffMember},
+        discovery::domain::DiscoveryFeed,
+        episode::domain::ProviderEpisode,
+        links::domain::ProviderLink,
+        provider::{
+            domain::{entities::anime_data::AnimeData, repositories::CatalogPage},
+            infrastructure::http_client::RateLimitClient,
+        },
+        recommendation::domain::ProviderRecommendation,
+        season::{domain::AiringEpisode, Season, SeasonPeriod},
+        statistics::domain::ProviderStatistics,
     },
     shared::errors::{AppError, AppResult},
 };
 
 use super::{mapper::AniListMapper, models::*, queries::*};
 
+/// Page size used when walking seasonal lineups (AniList caps perPage at 50)
+const SEASON_PAGE_SIZE: usize = 50;
+
+/// Airing times requested per page of an anime's schedule
+const AIRING_PAGE_SIZE: usize = 50;
+
 /// AniList provider adapter with GraphQL API
 pub struct AniListAdapter {
     http_client: RateLimitClient,
@@ -236,6 +252,20 @@ impl AniListAdapter {
         Ok(characters)
     }
 
+    /// Get anime characters with their voice actors mapped to domain cast members
+    pub async fn get_anime_cast(
+        &self,
+        id: u32,
+        limit: usize,
+    ) -> AppResult<Vec<ProviderCastMember>> {
+        let characters = self.get_anime_characters(id, limit).await?;
+
+        Ok(characters
+            .into_iter()
+            .filter_map(|edge| self.mapper.map_cast_member(edge))
+            .collect())
+    }
+
     /// Get anime staff
     pub async fn get_anime_staff(&self, id: u32, limit: usize) -> AppResult<Vec<AniListStaff>> {
         let variables = json!({
@@ -258,6 +288,105 @@ impl AniListAdapter {
         Ok(staff)
     }
 
+    /// Get anime staff mapped to domain staff members, one per position
+    pub async fn get_anime_staff_members(
+        &self,
+        id: u32,
+        limit: usize,
+    ) -> AppResult<Vec<ProviderStaffMember>> {
+        let staff = self.get_anime_staff(id, limit).await?;
+
+        Ok(staff
+            .into_iter()
+            .filter_map(|edge| self.mapper.map_staff_member(edge))
+            .collect())
+    }
+
+    /// Get the episodes listed by streaming sites, with their thumbnails
+    pub async fn get_anime_streaming_episodes(&self, id: u32) -> AppResult<Vec<ProviderEpisode>> {
+        let streaming_episodes = self
+            .get_anime_full(id)
+            .await?
+            .and_then(|media| media.streaming_episodes)
+            .unwrap_or_default();
+
+        let episodes: Vec<ProviderEpisode> = streaming_episodes
+            .into_iter()
+            .filter_map(|episode| self.mapper.map_streaming_episode(episode))
+            .collect();
+
+        log::info!(
+            "AniList: Found {} streaming episodes for anime ID '{}'",
+            episodes.len(),
+            id
+        );
+        Ok(episodes)
+    }
+
+    /// Get the official, streaming and social links of an anime
+    pub async fn get_anime_links(&self, id: u32) -> AppResult<Vec<ProviderLink>> {
+        let external_links = self
+            .get_anime_full(id)
+            .await?
+            .and_then(|media| media.external_links)
+            .unwrap_or_default();
+
+        let links: Vec<ProviderLink> = external_links
+            .into_iter()
+            .filter_map(|link| self.mapper.map_external_link(link))
+            .collect();
+
+        log::info!("AniList: Found {} links for anime ID '{}'", links.len(), id);
+        Ok(links)
+    }
+
+    /// Get one page of an anime's episode airing times after `since`, earliest first
+    ///
+    /// Returns the episodes and whether another page follows.
+    pub async fn get_anime_airing_schedule(
+        &self,
+        id: u32,
+        since: DateTime<Utc>,
+        page: u32,
+    ) -> AppResult<(Vec<AiringEpisode>, bool)> {
+        let variables = json!({
+            "mediaId": id,
+            "page": page,
+            "perPage": AIRING_PAGE_SIZE,
+            "airingAt_greater": since.timestamp()
+        });
+
+        log::info!(
+            "AniList: Getting airing schedule page {} for anime ID '{}'",
+            page,
+            id
+        );
+
+        let response: AniListScheduleResponse = self
+            .make_graphql_request(MEDIA_AIRING_SCHEDULE_QUERY, Some(variables))
+            .await?;
+
+        let has_next_page = response
+            .page
+            .page_info
+            .and_then(|info| info.has_next_page)
+            .unwrap_or(false);
+        let episodes: Vec<AiringEpisode> = response
+            .page
+            .airing_schedules
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|schedule| self.mapper.map_airing_episode(schedule))
+            .collect();
+
+        log::info!(
+            "AniList: Found {} airing episodes for anime ID '{}'",
+            episodes.len(),
+            id
+        );
+        Ok((episodes, has_next_page))
+    }
+
     /// Get anime statistics
     pub async fn get_anime_statistics(&self, id: u32) -> AppResult<AniListStatistics> {
         let variables = json!({
@@ -274,6 +403,25 @@ impl AniListAdapter {
         Ok(response.media.map(|m| m.stats).unwrap_or_default())
     }
 
+    /// Get list status counts, score distribution and favourites mapped to domain statistics
+    pub async fn get_audience_statistics(&self, id: u32) -> AppResult<ProviderStatistics> {
+        let variables = json!({
+            "id": id
+        });
+
+        log::info!("AniList: Getting audience statistics for anime ID '{}'", id);
+
+        let response: AniListStatisticsResponse = self
+            .make_graphql_request(ANIME_STATISTICS_QUERY, Some(variables))
+            .await?;
+
+        let (stats, favourites) = response
+            .media
+            .map(|m| (m.stats, m.favourites))
+            .unwrap_or_default();
+        Ok(self.mapper.map_statistics(stats, favourites))
+    }
+
     /// Get anime recommendations
     pub async fn get_anime_recommendations(
         &self,
@@ -293,7 +441,7 @@ impl AniListAdapter {
 
         let recommendations = response
             .media
-            .map(|m| m.recommendations.edges)
+            .map(|m| m.recommendations.nodes)
             .unwrap_or_default();
         log::info!(
             "AniList: Found {} recommendations for anime ID '{}'",
@@ -303,6 +451,20 @@ impl AniListAdapter {
         Ok(recommendations)
     }
 
+    /// Get recommendations mapped to domain recommendations
+    pub async fn get_recommended_anime(
+        &self,
+        id: u32,
+        limit: usize,
+    ) -> AppResult<Vec<ProviderRecommendation>> {
+        let recommendations = self.get_anime_recommendations(id, limit).await?;
+
+        Ok(recommendations
+            .into_iter()
+            .filter_map(|recommendation| self.mapper.map_recommendation(recommendation))
+            .collect())
+    }
+
     /// Get related anime (raw AniList data)
     pub async fn fetch_raw_relations(
         &self,
@@ -386,22 +548,20 @@ impl AniListAdapter {
     // =============================================================================
 
     /// Get current season anime
-    pub async fn get_season_now(&self, limit: usize) -> AppResult<Vec<AniListMedia>> {
-        // Calculate current season and year
-        let now = chrono::Utc::now();
-        let year = now.year();
-        let season = match now.month() {
-            1..=3 => "WINTER",
-            4..=6 => "SPRING",
-            7..=9 => "SUMMER",
-            10..=12 => "FALL",
-            _ => "FALL",
-        };
+    pub async fn get_season_now(
+        &self,
+        limit: usize,
+        page: Option<u32>,
+    ) -> AppResult<AniListSearchResponse> {
+        let (year, season) = Season::airing_at(Utc::now());
+        let season = season.as_str().to_uppercase();
 
         let variables = json!({
             "perPage": limit,
+            "page": page.unwrap_or(1),
             "season": season,
-            "seasonYear": year
+            "seasonYear": year,
+            "sort": "POPULARITY_DESC"
         });
 
         log::info!(
@@ -418,7 +578,7 @@ impl AniListAdapter {
             "AniList: Found {} current season anime",
             response.page.media.len()
         );
-        Ok(response.page.media)
+        Ok(response)
     }
 
     /// Get anime from specific season
@@ -433,7 +593,8 @@ impl AniListAdapter {
             "perPage": limit,
             "page": page.unwrap_or(1),
             "season": season.to_uppercase(),
-            "seasonYear": year
+            "seasonYear": year,
+            "sort": "POPULARITY_DESC"
         });
 
         log::info!("AniList: Getting {} {} season anime", season, year);
@@ -452,23 +613,68 @@ impl AniListAdapter {
     }
 
     /// Get upcoming anime
-    pub async fn get_season_upcoming(&self, limit: usize) -> AppResult<Vec<AniListMedia>> {
+    pub async fn get_season_upcoming(
+        &self,
+        limit: usize,
+        page: Option<u32>,
+    ) -> AppResult<AniListSearchResponse> {
         let variables = json!({
             "perPage": limit,
-            "status": "NOT_YET_RELEASED"
+            "page": page.unwrap_or(1),
+            "status": "NOT_YET_RELEASED",
+            "sort": "POPULARITY_DESC"
         });
 
         log::info!("AniList: Getting upcoming anime");
 
         let response: AniListSearchResponse = self
-            .make_graphql_request(ANIME_SEARCH_QUERY, Some(variables))
+            .make_graphql_request(ANIME_SEARCH_ADVANCED_QUERY, Some(variables))
             .await?;
 
         log::info!(
             "AniList: Found {} upcoming anime",
             response.page.media.len()
         );
-        Ok(response.page.media)
+        Ok(response)
+    }
+
+    /// Get one page of a season lineup mapped to domain data
+    pub async fn get_season_anime_data(
+        &self,
+        period: SeasonPeriod,
+        page: u32,
+    ) -> AppResult<CatalogPage> {
+        let response = match period {
+            SeasonPeriod::Specific { year, season } => {
+                self.get_season(year, season.as_str(), SEASON_PAGE_SIZE, Some(page))
+                    .await?
+            }
+            SeasonPeriod::Current => self.get_season_now(SEASON_PAGE_SIZE, Some(page)).await?,
+            SeasonPeriod::Upcoming => {
+                self.get_season_upcoming(SEASON_PAGE_SIZE, Some(page))
+                    .await?
+            }
+        };
+
+        let has_next_page = response
+            .page
+            .page_info
+            .as_ref()
+            .and_then(|info| info.has_next_page)
+            .unwrap_or(false);
+
+        let items = response
+            .page
+            .media
+            .into_iter()
+            .map(|anime| self.mapper.map_to_anime_data(anime))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| AppError::MappingError(format!("Failed to map AniList data: {}", e)))?;
+
+        Ok(CatalogPage {
+            items,
+            has_next_page,
+        })
     }
 
     /// Get anime broadcast schedule
@@ -496,14 +702,14 @@ impl AniListAdapter {
     /// Get trending anime
     pub async fn get_trending(&self, limit: usize) -> AppResult<Vec<AniListMedia>> {
         let variables = json!({
-            "perPage": limit,
-            "sort": "TRENDING_DESC"
+            "page": 1,
+            "perPage": limit
         });
 
         log::info!("AniList: Getting trending anime");
 
         let response: AniListSearchResponse = self
-            .make_graphql_request(ANIME_SEARCH_QUERY, Some(variables))
+            .make_graphql_request(TRENDING_ANIME_QUERY, Some(variables))
             .await?;
 
         log::info!(
@@ -769,18 +975,40 @@ impl AniListAdapter {
 
     /// Get popular anime
     pub async fn get_popular(&self, limit: usize) -> AppResult<Vec<AniListMedia>> {
+        // The plain search query hardcodes SEARCH_MATCH, so sort through the advanced one
         let variables = json!({
+            "page": 1,
             "perPage": limit,
-            "sort": "POPULARITY_DESC"
+            "sort": ["POPULARITY_DESC"]
         });
 
         log::info!("AniList: Getting popular anime");
 
         let response: AniListSearchResponse = self
-            .make_graphql_request(ANIME_SEARCH_QUERY, Some(variables))
+            .make_graphql_request(ANIME_SEARCH_ADVANCED_QUERY, Some(variables))
             .await?;
 
         log::info!("AniList: Found {} popular anime", response.page.media.len());
         Ok(response.page.media)
     }
+
+    /// Get a discovery feed mapped to domain data, in AniList's order
+    pub async fn get_discovery_feed(
+        &self,
+        feed: DiscoveryFeed,
+        limit: usize,
+    ) -> AppResult<Vec<AnimeData>> {
+        let limit = limit.min(SEASON_PAGE_SIZE);
+        let media = match feed {
+            DiscoveryFeed::Trending => self.get_trending(limit).await?,
+            DiscoveryFeed::Popular => self.get_popular(limit).await?,
+            DiscoveryFeed::Upcoming => self.get_season_upcoming(limit, None).await?.page.media,
+        };
+
+        media
+            .into_iter()
+            .map(|anime| self.mapper.map_to_anime_data(anime))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| AppError::MappingError(format!("Failed to map AniList data: {}", e)))
+    }
 }
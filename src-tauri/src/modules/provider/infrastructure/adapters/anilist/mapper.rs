@@ -6,7 +6,15 @@ use crate::modules::anime::domain::{
     },
     value_objects::{AnimeStatus, AnimeTier, AnimeTitle, AnimeType, QualityMetrics},
 };
+use crate::modules::character::domain::{
+    CharacterRole, ProviderCastMember, ProviderCharacter, ProviderStaffMember, ProviderVoiceActor,
+};
+use crate::modules::episode::domain::ProviderEpisode;
+use crate::modules::links::domain::{LinkCategory, ProviderLink};
 use crate::modules::provider::domain::entities::anime_data::{AnimeData, DataQuality, DataSource};
+use crate::modules::recommendation::domain::ProviderRecommendation;
+use crate::modules::season::domain::AiringEpisode;
+use crate::modules::statistics::domain::{ProviderStatistics, ScoreBucket};
 use crate::shared::domain::value_objects::{AnimeProvider, ProviderMetadata};
 
 use crate::shared::domain::value_objects::UnifiedAgeRestriction;
@@ -427,6 +435,199 @@ impl AdapterCapabilities for AniListMapper {
     }
 }
 
+impl AniListMapper {
+    /// Map a character edge to a provider cast member, `None` when the node has no id
+    pub fn map_cast_member(&self, edge: AniListCharacter) -> Option<ProviderCastMember> {
+        let node = edge.node?;
+        let external_id = node.id?;
+        let name = edge
+            .name
+            .filter(|name| !name.trim().is_empty())
+            .or_else(|| node.name.as_ref().and_then(|n| n.full.clone()))?;
+
+        let voice_actors = edge
+            .voice_actors
+            .into_iter()
+            .filter_map(|voice_actor| {
+                let name = voice_actor.name.as_ref()?;
+                Some(ProviderVoiceActor {
+                    external_id: voice_actor.id?,
+                    name: name.full.clone()?,
+                    name_native: name.native.clone(),
+                    image_url: voice_actor
+                        .image
+                        .as_ref()
+                        .and_then(|image| image.large.clone().or(image.medium.clone())),
+                    language: voice_actor.language,
+                })
+            })
+            .collect();
+
+        Some(ProviderCastMember {
+            provider: AnimeProvider::AniList,
+            character: ProviderCharacter {
+                external_id,
+                name,
+                name_native: node.name.and_then(|n| n.native),
+                image_url: node.image.and_then(|image| image.large.or(image.medium)),
+                description: node.description,
+            },
+            role: CharacterRole::from_provider(edge.role.as_deref().unwrap_or_default()),
+            voice_actors,
+        })
+    }
+
+    /// Map a staff edge to a provider staff member, `None` when the node has no id
+    pub fn map_staff_member(&self, edge: AniListStaff) -> Option<ProviderStaffMember> {
+        let node = edge.node?;
+        let name = node.name.as_ref()?;
+
+        Some(ProviderStaffMember {
+            provider: AnimeProvider::AniList,
+            external_id: node.id?,
+            name: name.full.clone()?,
+            name_native: name.native.clone(),
+            image_url: node.image.and_then(|image| image.large.or(image.medium)),
+            positions: edge.role.into_iter().collect(),
+        })
+    }
+
+    /// Map an external link; links without a URL or site are skipped
+    pub fn map_external_link(&self, link: MediaExternalLink) -> Option<ProviderLink> {
+        let url = link.url?;
+        let site = link.site?;
+
+        Some(ProviderLink {
+            provider: AnimeProvider::AniList,
+            category: LinkCategory::from_anilist(link.link_type.as_deref(), &site),
+            site,
+            url,
+            language: link.language,
+        })
+    }
+
+    /// Map a streaming site episode; entries without an `Episode N` title are skipped
+    pub fn map_streaming_episode(&self, episode: MediaStreamingEpisode) -> Option<ProviderEpisode> {
+        let (number, title) = Self::parse_streaming_title(episode.title.as_deref()?)?;
+
+        Some(ProviderEpisode {
+            provider: AnimeProvider::AniList,
+            number,
+            title,
+            title_japanese: None,
+            title_romaji: None,
+            aired_at: None,
+            score: None,
+            is_filler: false,
+            is_recap: false,
+            thumbnail_url: episode.thumbnail,
+        })
+    }
+
+    /// Map a "users also liked" entry; entries without the recommended media are skipped
+    pub fn map_recommendation(
+        &self,
+        recommendation: AniListRecommendation,
+    ) -> Option<ProviderRecommendation> {
+        let media = recommendation.media_recommendation?;
+        let title = media.title.as_ref().and_then(|t| {
+            t.english
+                .as_ref()
+                .or(t.romaji.as_ref())
+                .or(t.native.as_ref())
+                .cloned()
+        })?;
+
+        Some(ProviderRecommendation {
+            provider: AnimeProvider::AniList,
+            mal_id: media.id_mal,
+            anilist_id: media.id,
+            title,
+            image_url: Self::extract_cover_image(&media.cover_image),
+            genres: media.genres.clone().unwrap_or_default(),
+            studios: Self::extract_studios(&media.studios),
+            // AniList's rating is net votes and can go below zero
+            votes: recommendation.rating.unwrap_or(0).max(0),
+        })
+    }
+
+    /// Map list status counts and the score distribution, folding AniList's 10-100 scores to 1-10
+    pub fn map_statistics(
+        &self,
+        stats: AniListStatistics,
+        favourites: Option<i32>,
+    ) -> ProviderStatistics {
+        let mut statistics = ProviderStatistics {
+            provider: AnimeProvider::AniList,
+            watching: 0,
+            completed: 0,
+            on_hold: 0,
+            dropped: 0,
+            plan_to_watch: 0,
+            total: 0,
+            favorites: favourites,
+            score_distribution: Vec::new(),
+        };
+
+        for entry in stats.status_distribution.unwrap_or_default() {
+            let amount = entry.amount.unwrap_or(0).max(0);
+            match entry.status.as_deref() {
+                // Rewatchers are watching again
+                Some("CURRENT") | Some("REPEATING") => statistics.watching += amount,
+                Some("COMPLETED") => statistics.completed += amount,
+                Some("PAUSED") => statistics.on_hold += amount,
+                Some("DROPPED") => statistics.dropped += amount,
+                Some("PLANNING") => statistics.plan_to_watch += amount,
+                _ => {}
+            }
+            statistics.total += amount;
+        }
+
+        statistics.score_distribution = stats
+            .score_distribution
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|entry| {
+                Some(ScoreBucket {
+                    score: entry.score? / 10,
+                    votes: entry.amount.unwrap_or(0).max(0),
+                })
+            })
+            .filter(|bucket| (1..=10).contains(&bucket.score))
+            .collect();
+
+        statistics
+    }
+
+    /// Map an airing schedule entry; entries without an episode or time are skipped
+    pub fn map_airing_episode(&self, schedule: AniListSchedule) -> Option<AiringEpisode> {
+        Some(AiringEpisode {
+            episode: schedule.episode?,
+            airs_at: DateTime::from_timestamp(schedule.airing_at?, 0)?,
+        })
+    }
+
+    /// Split `Episode 5 - Ballad of Fallen Angels` into its number and title
+    fn parse_streaming_title(title: &str) -> Option<(i32, Option<String>)> {
+        let rest = title.trim();
+        if !rest
+            .get(..7)
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case("episode"))
+        {
+            return None;
+        }
+        let rest = rest[7..].trim_start();
+
+        let digits = rest.chars().take_while(char::is_ascii_digit).count();
+        let number = rest[..digits].parse().ok()?;
+        let name = rest[digits..]
+            .trim_start_matches(|c: char| c.is_whitespace() || matches!(c, '-' | ':' | '–'))
+            .trim();
+
+        Some((number, (!name.is_empty()).then(|| name.to_string())))
+    }
+}
+
 impl Default for AniListMapper {
     fn default() -> Self {
         Self::new()
@@ -474,6 +675,19 @@ mod tests {
         );
         assert_eq!(AniListMapper::map_anime_type(&None), AnimeType::Unknown);
     }
+
+    #[test]
+    fn test_parse_streaming_title() {
+        assert_eq!(
+            AniListMapper::parse_streaming_title("Episode 5 - Ballad of Fallen Angels"),
+            Some((5, Some("Ballad of Fallen Angels".to_string())))
+        );
+        assert_eq!(
+            AniListMapper::parse_streaming_title("EPISODE 12"),
+            Some((12, None))
+        );
+        assert_eq!(AniListMapper::parse_streaming_title("Recap Special"), None);
+    }
 }
 
 impl AniListMapper {}
@@ -143,6 +143,9 @@ pub struct MediaExternalLink {
     pub url: Option<String>,
     pub site: Option<String>,
     pub site_id: Option<i32>,
+    /// `INFO`, `STREAMING` or `SOCIAL`
+    #[serde(rename = "type")]
+    pub link_type: Option<String>,
     pub language: Option<String>,
     pub color: Option<String>,
     pub icon: Option<String>,
@@ -197,6 +200,7 @@ pub struct AniListError {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct Page {
     pub media: Vec<Media>,
+    #[serde(rename = "pageInfo")]
     pub page_info: Option<PageInfo>,
 }
 
@@ -255,14 +259,36 @@ pub struct CharacterConnection {
     pub edges: Vec<AniListCharacter>,
 }
 
+/// Character edge: the media-specific role and voice actors around the character node
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AniListCharacter {
+    pub id: Option<i32>,
+    /// `MAIN`, `SUPPORTING` or `BACKGROUND`
+    pub role: Option<String>,
+    /// Name the character goes by in this media, when it differs
+    pub name: Option<String>,
+    #[serde(rename = "voiceActors", default)]
+    pub voice_actors: Vec<AniListVoiceActor>,
+    pub node: Option<AniListCharacterNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AniListCharacterNode {
     pub id: Option<i32>,
     pub name: Option<CharacterName>,
     pub image: Option<CharacterImage>,
     pub description: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AniListVoiceActor {
+    pub id: Option<i32>,
+    pub name: Option<StaffName>,
+    /// Language name such as `Japanese`
+    pub language: Option<String>,
+    pub image: Option<StaffImage>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CharacterName {
     pub full: Option<String>,
@@ -275,8 +301,17 @@ pub struct CharacterImage {
     pub medium: Option<String>,
 }
 
+/// Staff edge: one position held by the staff node on this media
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AniListStaff {
+    pub id: Option<i32>,
+    /// Position with optional episode notes, e.g. `Storyboard (eps 1, 3)`
+    pub role: Option<String>,
+    pub node: Option<AniListStaffNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AniListStaffNode {
     pub id: Option<i32>,
     pub name: Option<StaffName>,
     pub image: Option<StaffImage>,
@@ -305,9 +340,12 @@ pub struct AniListStatisticsResponse {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MediaWithStatistics {
     pub stats: AniListStatistics,
+    #[serde(default)]
+    pub favourites: Option<i32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
 pub struct AniListStatistics {
     pub score_distribution: Option<Vec<ScoreDistribution>>,
     pub status_distribution: Option<Vec<StatusDistribution>>,
@@ -338,10 +376,12 @@ pub struct MediaWithRecommendations {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RecommendationConnection {
-    pub edges: Vec<AniListRecommendation>,
+    #[serde(default)]
+    pub nodes: Vec<AniListRecommendation>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct AniListRecommendation {
     pub id: Option<i32>,
     pub rating: Option<i32>,
@@ -379,12 +419,14 @@ pub struct AniListScheduleResponse {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct SchedulePage {
     pub airing_schedules: Option<Vec<AniListSchedule>>,
     pub page_info: Option<PageInfo>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct AniListSchedule {
     pub id: Option<i32>,
     pub airing_at: Option<i64>,
@@ -472,6 +472,22 @@ query ($page: Int, $perPage: Int, $airingAt_greater: Int, $airingAt_lesser: Int)
 }
 "#;
 
+/// Airing times of one anime's episodes after a given moment
+pub const MEDIA_AIRING_SCHEDULE_QUERY: &str = r#"
+query ($mediaId: Int, $page: Int, $perPage: Int, $airingAt_greater: Int) {
+  Page(page: $page, perPage: $perPage) {
+    pageInfo {
+      hasNextPage
+    }
+    airingSchedules(mediaId: $mediaId, airingAt_greater: $airingAt_greater, sort: TIME) {
+      id
+      airingAt
+      episode
+    }
+  }
+}
+"#;
+
 /// Media relations query - equivalent to get_anime_relations
 pub const ANIME_RELATIONS_QUERY: &str = r#"
 query ($id: Int) {
@@ -558,6 +574,11 @@ query ($id: Int, $page: Int, $perPage: Int) {
             large
             medium
           }
+          studios(isMain: true) {
+            nodes {
+              name
+            }
+          }
           isAdult
         }
       }
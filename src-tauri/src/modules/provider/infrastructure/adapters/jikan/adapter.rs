@@ -1,12 +1,25 @@
 use crate::{
+    modules::character::domain::{ProviderCastMember, ProviderStaffMember},
+    modules::discovery::domain::DiscoveryFeed,
+    modules::episode::domain::ProviderEpisode,
+    modules::links::domain::ProviderLink,
+    modules::news::domain::ProviderNewsItem,
     modules::provider::domain::entities::anime_data::AnimeData,
+    modules::provider::domain::repositories::{CatalogPage, EpisodePage},
     modules::provider::infrastructure::http_client::RateLimitClient,
+    modules::recommendation::domain::ProviderRecommendation,
+    modules::season::{BroadcastInfo, SeasonPeriod},
+    modules::statistics::domain::ProviderStatistics,
+    modules::themes::domain::ProviderThemeSong,
     shared::errors::{AppError, AppResult},
 };
 
 use super::mapper::JikanMapper;
 use super::models::*;
 
+/// Page size used when walking seasonal lineups (Jikan caps limit at 25)
+const SEASON_PAGE_SIZE: u32 = 25;
+
 /// Jikan (MyAnimeList) provider adapter with REST API
 pub struct JikanAdapter {
     http_client: RateLimitClient,
@@ -118,13 +131,13 @@ impl JikanAdapter {
         self.get_anime_by_id(&id.to_string()).await
     }
 
-    /// Get full anime details (comprehensive information)
-    pub async fn get_anime_full(&self, id: u32) -> AppResult<Option<AnimeData>> {
-        let url = format!("{}/anime/{}/full", self.base_url, id);
+    /// Get the weekly broadcast slot, `None` when MyAnimeList doesn't know the day or time
+    pub async fn get_anime_broadcast(&self, id: u32) -> AppResult<Option<BroadcastInfo>> {
+        let url = format!("{}/anime/{}", self.base_url, id);
 
-        log::info!("Jikan: Getting full anime details for ID '{}'", id);
+        log::info!("Jikan: Getting broadcast slot for anime ID '{}'", id);
 
-        let jikan_response: JikanItem<AnimeFull> = match self.http_client.get(&url).await {
+        let jikan_response: JikanItem<Anime> = match self.http_client.get(&url).await {
             Ok(response) => response,
             Err(AppError::ApiError(msg)) if msg.contains("404") => {
                 log::info!("Jikan: No anime found for ID '{}'", id);
@@ -133,15 +146,77 @@ impl JikanAdapter {
             Err(e) => return Err(e),
         };
 
+        Ok(jikan_response
+            .data
+            .broadcast
+            .and_then(|broadcast| self.mapper.map_broadcast(broadcast)))
+    }
+
+    /// Get full anime details (comprehensive information)
+    pub async fn get_anime_full(&self, id: u32) -> AppResult<Option<AnimeData>> {
+        let Some(anime) = self.fetch_anime_full(id).await? else {
+            return Ok(None);
+        };
+
         let anime_data = self
             .mapper
-            .map_to_anime_data(jikan_response.data.core)
+            .map_to_anime_data(anime.core)
             .map_err(|e| AppError::MappingError(format!("Failed to map Jikan full data: {}", e)))?;
 
         log::info!("Jikan: Retrieved full details for anime ID '{}'", id);
         Ok(Some(anime_data))
     }
 
+    /// Fetch the raw full anime record, `None` when MyAnimeList doesn't know the ID
+    pub async fn fetch_anime_full(&self, id: u32) -> AppResult<Option<AnimeFull>> {
+        let url = format!("{}/anime/{}/full", self.base_url, id);
+
+        log::info!("Jikan: Getting full anime details for ID '{}'", id);
+
+        match self.http_client.get::<JikanItem<AnimeFull>>(&url).await {
+            Ok(response) => Ok(Some(response.data)),
+            Err(AppError::ApiError(msg)) if msg.contains("404") => {
+                log::info!("Jikan: No anime found for ID '{}'", id);
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Get the streaming and external links of an anime
+    pub async fn get_anime_links(&self, id: u32) -> AppResult<Vec<ProviderLink>> {
+        let Some(anime) = self.fetch_anime_full(id).await? else {
+            return Ok(Vec::new());
+        };
+
+        // The full record lists links at the top level; fall back to the core fields
+        let streaming = anime.streaming.or(anime.core.streaming).unwrap_or_default();
+        let external = anime.external.or(anime.core.external).unwrap_or_default();
+        let links = self.mapper.map_links(streaming, external);
+
+        log::info!("Jikan: Found {} links for anime ID '{}'", links.len(), id);
+        Ok(links)
+    }
+
+    /// Get the opening and ending theme songs of an anime
+    pub async fn get_anime_themes(&self, id: u32) -> AppResult<Vec<ProviderThemeSong>> {
+        let Some(anime) = self.fetch_anime_full(id).await? else {
+            return Ok(Vec::new());
+        };
+
+        let themes = anime
+            .theme
+            .map(|theme| self.mapper.map_theme_songs(theme))
+            .unwrap_or_default();
+
+        log::info!(
+            "Jikan: Found {} theme songs for anime ID '{}'",
+            themes.len(),
+            id
+        );
+        Ok(themes)
+    }
+
     /// Get anime characters
     pub async fn get_anime_characters(&self, id: u32) -> AppResult<Vec<AnimeCharacterEdge>> {
         let url = format!("{}/anime/{}/characters", self.base_url, id);
@@ -158,6 +233,16 @@ impl JikanAdapter {
         Ok(jikan_response.data)
     }
 
+    /// Get anime characters with their voice actors mapped to domain cast members
+    pub async fn get_anime_cast(&self, id: u32) -> AppResult<Vec<ProviderCastMember>> {
+        let characters = self.get_anime_characters(id).await?;
+
+        Ok(characters
+            .into_iter()
+            .map(|edge| self.mapper.map_cast_member(edge))
+            .collect())
+    }
+
     /// Get anime staff
     pub async fn get_anime_staff(&self, id: u32) -> AppResult<Vec<AnimeStaffEdge>> {
         let url = format!("{}/anime/{}/staff", self.base_url, id);
@@ -174,6 +259,16 @@ impl JikanAdapter {
         Ok(jikan_response.data)
     }
 
+    /// Get anime staff mapped to domain staff members
+    pub async fn get_anime_staff_members(&self, id: u32) -> AppResult<Vec<ProviderStaffMember>> {
+        let staff = self.get_anime_staff(id).await?;
+
+        Ok(staff
+            .into_iter()
+            .map(|edge| self.mapper.map_staff_member(edge))
+            .collect())
+    }
+
     /// Get anime episodes (paginated)
     pub async fn get_anime_episodes(
         &self,
@@ -201,6 +296,25 @@ impl JikanAdapter {
         Ok(jikan_response)
     }
 
+    /// Get one page of anime episodes mapped to domain episodes
+    pub async fn get_anime_episode_page(&self, id: u32, page: u32) -> AppResult<EpisodePage> {
+        let response = self.get_anime_episodes(id, Some(page)).await?;
+        let has_next_page = response
+            .pagination
+            .as_ref()
+            .is_some_and(|pagination| pagination.has_next_page);
+
+        let items: Vec<ProviderEpisode> = response
+            .data
+            .into_iter()
+            .map(|episode| self.mapper.map_episode(episode))
+            .collect();
+        Ok(EpisodePage {
+            items,
+            has_next_page,
+        })
+    }
+
     /// Get specific episode
     pub async fn get_anime_episode(
         &self,
@@ -251,6 +365,17 @@ impl JikanAdapter {
         Ok(jikan_response)
     }
 
+    /// Get the latest news articles for an anime (first page only)
+    pub async fn get_news(&self, id: u32) -> AppResult<Vec<ProviderNewsItem>> {
+        let news = self.get_anime_news(id, None).await?;
+
+        Ok(news
+            .data
+            .into_iter()
+            .map(|item| self.mapper.map_news_item(item))
+            .collect())
+    }
+
     /// Get anime videos
     pub async fn get_anime_videos(&self, id: u32) -> AppResult<AnimeVideos> {
         let url = format!("{}/anime/{}/videos", self.base_url, id);
@@ -291,6 +416,12 @@ impl JikanAdapter {
         Ok(jikan_response.data)
     }
 
+    /// Get list status counts and score distribution mapped to domain statistics
+    pub async fn get_audience_statistics(&self, id: u32) -> AppResult<ProviderStatistics> {
+        let statistics = self.get_anime_statistics(id).await?;
+        Ok(self.mapper.map_statistics(statistics))
+    }
+
     /// Get additional information
     pub async fn get_anime_more_info(&self, id: u32) -> AppResult<Option<String>> {
         let url = format!("{}/anime/{}/moreinfo", self.base_url, id);
@@ -310,12 +441,12 @@ impl JikanAdapter {
     }
 
     /// Get anime recommendations
-    pub async fn get_anime_recommendations(&self, id: u32) -> AppResult<Vec<EntryRecommendation>> {
+    pub async fn get_anime_recommendations(&self, id: u32) -> AppResult<Vec<AnimeRecommendation>> {
         let url = format!("{}/anime/{}/recommendations", self.base_url, id);
 
         log::info!("Jikan: Getting recommendations for anime ID '{}'", id);
 
-        let jikan_response: JikanList<EntryRecommendation> = self.http_client.get(&url).await?;
+        let jikan_response: JikanList<AnimeRecommendation> = self.http_client.get(&url).await?;
 
         log::info!(
             "Jikan: Found {} recommendations for anime ID '{}'",
@@ -325,6 +456,16 @@ impl JikanAdapter {
         Ok(jikan_response.data)
     }
 
+    /// Get recommendations mapped to domain recommendations
+    pub async fn get_recommended_anime(&self, id: u32) -> AppResult<Vec<ProviderRecommendation>> {
+        let recommendations = self.get_anime_recommendations(id).await?;
+
+        Ok(recommendations
+            .into_iter()
+            .map(|recommendation| self.mapper.map_recommendation(recommendation))
+            .collect())
+    }
+
     /// Get related anime
     pub async fn fetch_raw_relations(&self, id: u32) -> AppResult<Vec<RelationGroup>> {
         let url = format!("{}/anime/{}/relations", self.base_url, id);
@@ -476,6 +617,90 @@ impl JikanAdapter {
         Ok(jikan_response)
     }
 
+    /// Get one page of a season lineup mapped to domain data
+    pub async fn get_season_anime_data(
+        &self,
+        period: SeasonPeriod,
+        page: u32,
+    ) -> AppResult<CatalogPage> {
+        let response = match period {
+            SeasonPeriod::Specific { year, season } => {
+                self.get_season(year, season.as_str(), Some(SEASON_PAGE_SIZE), Some(page))
+                    .await?
+            }
+            SeasonPeriod::Current => {
+                self.get_season_now(Some(SEASON_PAGE_SIZE), Some(page))
+                    .await?
+            }
+            SeasonPeriod::Upcoming => {
+                self.get_season_upcoming(Some(SEASON_PAGE_SIZE), Some(page))
+                    .await?
+            }
+        };
+
+        let has_next_page = response
+            .pagination
+            .as_ref()
+            .map(|pagination| pagination.has_next_page)
+            .unwrap_or(false);
+
+        let items = response
+            .data
+            .into_iter()
+            .map(|anime| self.mapper.map_to_anime_data(anime))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| AppError::MappingError(format!("Failed to map Jikan data: {}", e)))?;
+
+        Ok(CatalogPage {
+            items,
+            has_next_page,
+        })
+    }
+
+    /// Get MyAnimeList's top anime list, e.g. `airing`, `upcoming` or `bypopularity`
+    pub async fn get_top_anime(&self, filter: &str, limit: u32) -> AppResult<JikanList<Anime>> {
+        let url = format!(
+            "{}/top/anime?filter={}&limit={}",
+            self.base_url,
+            filter,
+            limit.min(SEASON_PAGE_SIZE)
+        );
+
+        log::info!("Jikan: Getting top anime ({})", filter);
+
+        let jikan_response: JikanList<Anime> = self.http_client.get(&url).await?;
+
+        log::info!(
+            "Jikan: Found {} top anime ({})",
+            jikan_response.data.len(),
+            filter
+        );
+        Ok(jikan_response)
+    }
+
+    /// Get a discovery feed mapped to domain data, in MyAnimeList's order
+    ///
+    /// MyAnimeList has no trending list; its top airing list is the closest match.
+    pub async fn get_discovery_feed(
+        &self,
+        feed: DiscoveryFeed,
+        limit: usize,
+    ) -> AppResult<Vec<AnimeData>> {
+        let filter = match feed {
+            DiscoveryFeed::Trending => "airing",
+            DiscoveryFeed::Popular => "bypopularity",
+            DiscoveryFeed::Upcoming => "upcoming",
+        };
+
+        self.get_top_anime(filter, limit as u32)
+            .await?
+            .data
+            .into_iter()
+            .map(|anime| self.mapper.map_to_anime_data(anime))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| AppError::MappingError(format!("Failed to map Jikan data: {}", e)))
+    }
+
     /// Get anime broadcast schedule
     pub async fn get_schedules(&self, day: Option<&str>) -> AppResult<Vec<Anime>> {
         let mut url = format!("{}/schedules", self.base_url);
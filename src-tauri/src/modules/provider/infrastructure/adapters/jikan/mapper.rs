@@ -6,7 +6,17 @@ use crate::modules::anime::domain::{
     },
     value_objects::{AnimeStatus, AnimeTier, AnimeTitle, AnimeType, QualityMetrics},
 };
+use crate::modules::character::domain::{
+    CharacterRole, ProviderCastMember, ProviderCharacter, ProviderStaffMember, ProviderVoiceActor,
+};
+use crate::modules::episode::domain::ProviderEpisode;
+use crate::modules::links::domain::{LinkCategory, ProviderLink};
+use crate::modules::news::domain::ProviderNewsItem;
 use crate::modules::provider::domain::entities::anime_data::{AnimeData, DataQuality, DataSource};
+use crate::modules::recommendation::domain::ProviderRecommendation;
+use crate::modules::season::BroadcastInfo;
+use crate::modules::statistics::domain::{ProviderStatistics, ScoreBucket};
+use crate::modules::themes::domain::{ProviderThemeSong, ThemeKind};
 use crate::shared::domain::value_objects::{AnimeProvider, ProviderMetadata};
 
 use crate::shared::domain::value_objects::UnifiedAgeRestriction;
@@ -371,6 +381,197 @@ impl AdapterCapabilities for JikanMapper {
     }
 }
 
+impl JikanMapper {
+    /// Map a character edge from `/anime/{id}/characters` to a provider cast member
+    pub fn map_cast_member(&self, edge: AnimeCharacterEdge) -> ProviderCastMember {
+        let voice_actors = edge
+            .voice_actors
+            .unwrap_or_default()
+            .into_iter()
+            .map(|voice_actor| ProviderVoiceActor {
+                external_id: voice_actor.person.mal_id as i32,
+                image_url: Self::jpg_image_url(&voice_actor.person.images),
+                name: voice_actor.person.name,
+                name_native: None,
+                language: Some(voice_actor.language),
+            })
+            .collect();
+
+        ProviderCastMember {
+            provider: AnimeProvider::Jikan,
+            character: ProviderCharacter {
+                external_id: edge.character.mal_id as i32,
+                image_url: Self::jpg_image_url(&edge.character.images),
+                name: edge.character.name,
+                name_native: None,
+                description: None,
+            },
+            role: CharacterRole::from_provider(&edge.role),
+            voice_actors,
+        }
+    }
+
+    /// Map a staff edge from `/anime/{id}/staff` to a provider staff member
+    pub fn map_staff_member(&self, edge: AnimeStaffEdge) -> ProviderStaffMember {
+        ProviderStaffMember {
+            provider: AnimeProvider::Jikan,
+            external_id: edge.person.mal_id as i32,
+            image_url: Self::jpg_image_url(&edge.person.images),
+            name: edge.person.name,
+            name_native: None,
+            positions: edge.positions,
+        }
+    }
+
+    /// Map list status counts and the 1-10 score distribution
+    pub fn map_statistics(&self, statistics: AnimeStatistics) -> ProviderStatistics {
+        ProviderStatistics {
+            provider: AnimeProvider::Jikan,
+            watching: statistics.watching as i32,
+            completed: statistics.completed as i32,
+            on_hold: statistics.on_hold as i32,
+            dropped: statistics.dropped as i32,
+            plan_to_watch: statistics.plan_to_watch as i32,
+            total: statistics.total as i32,
+            // Favorites are only listed on the anime itself
+            favorites: None,
+            score_distribution: statistics
+                .scores
+                .unwrap_or_default()
+                .into_iter()
+                .map(|bucket| ScoreBucket {
+                    score: bucket.score as i32,
+                    votes: bucket.votes as i32,
+                })
+                .collect(),
+        }
+    }
+
+    /// Map a broadcast slot; `Saturdays` becomes `Saturday`, slots missing a day or time are skipped
+    pub fn map_broadcast(&self, broadcast: Broadcast) -> Option<BroadcastInfo> {
+        let day = broadcast.day?.trim().trim_end_matches('s').to_string();
+        let info = BroadcastInfo::from_strings(Some(day), broadcast.time, broadcast.timezone);
+
+        (info.get_weekday().is_some() && info.get_time().is_some()).then_some(info)
+    }
+
+    /// Map an entry from `/anime/{id}/episodes`, whose `mal_id` is the episode number
+    pub fn map_episode(&self, episode: AnimeEpisode) -> ProviderEpisode {
+        ProviderEpisode {
+            provider: AnimeProvider::Jikan,
+            number: episode.mal_id as i32,
+            title: episode.title,
+            title_japanese: episode.title_japanese,
+            title_romaji: episode.title_romanji,
+            aired_at: episode
+                .aired
+                .as_deref()
+                .and_then(|date_str| DateTime::parse_from_rfc3339(date_str).ok())
+                .map(|dt| dt.with_timezone(&Utc)),
+            score: episode.score,
+            is_filler: episode.filler.unwrap_or(false),
+            is_recap: episode.recap.unwrap_or(false),
+            thumbnail_url: None,
+        }
+    }
+
+    /// Map a "users also liked" entry; MyAnimeList lists neither genres nor studios here
+    pub fn map_recommendation(
+        &self,
+        recommendation: AnimeRecommendation,
+    ) -> ProviderRecommendation {
+        let entry = recommendation.entry;
+
+        ProviderRecommendation {
+            provider: AnimeProvider::Jikan,
+            mal_id: Some(entry.mal_id as i32),
+            anilist_id: None,
+            title: entry.title,
+            image_url: Self::jpg_image_url(&Some(entry.images)),
+            genres: Vec::new(),
+            studios: Vec::new(),
+            votes: recommendation.votes as i32,
+        }
+    }
+
+    /// Map the streaming and external link lists of a full anime record
+    pub fn map_links(
+        &self,
+        streaming: Vec<StreamingLink>,
+        external: Vec<ExternalLink>,
+    ) -> Vec<ProviderLink> {
+        let streaming = streaming.into_iter().map(|link| ProviderLink {
+            provider: AnimeProvider::Jikan,
+            category: LinkCategory::Streaming,
+            site: link.name,
+            url: link.url,
+            language: None,
+        });
+        let external = external.into_iter().map(|link| ProviderLink {
+            provider: AnimeProvider::Jikan,
+            category: LinkCategory::from_site(&link.name),
+            site: link.name,
+            url: link.url,
+            language: None,
+        });
+
+        streaming.chain(external).collect()
+    }
+
+    /// Map a news article; the excerpt is trimmed since MyAnimeList pads it with whitespace
+    pub fn map_news_item(&self, item: AnimeNewsItem) -> ProviderNewsItem {
+        ProviderNewsItem {
+            provider: AnimeProvider::Jikan,
+            url: item.url,
+            title: item.title,
+            excerpt: item
+                .excerpt
+                .map(|excerpt| excerpt.trim().to_string())
+                .filter(|excerpt| !excerpt.is_empty()),
+            image_url: Self::jpg_image_url(&item.images),
+            author: item.author_username,
+            forum_url: item.forum_url,
+            comments: item.comments.unwrap_or(0) as i32,
+            published_at: item
+                .date
+                .as_deref()
+                .and_then(|date_str| DateTime::parse_from_rfc3339(date_str).ok())
+                .map(|dt| dt.with_timezone(&Utc)),
+        }
+    }
+
+    /// Map opening and ending lines, openings first; blank lines are dropped
+    pub fn map_theme_songs(&self, theme: ThemeSongs) -> Vec<ProviderThemeSong> {
+        let openings = theme
+            .openings
+            .unwrap_or_default()
+            .into_iter()
+            .map(|text| (ThemeKind::Opening, text));
+        let endings = theme
+            .endings
+            .unwrap_or_default()
+            .into_iter()
+            .map(|text| (ThemeKind::Ending, text));
+
+        openings
+            .chain(endings)
+            .filter(|(_, text)| !text.trim().is_empty())
+            .map(|(kind, text)| ProviderThemeSong {
+                provider: AnimeProvider::Jikan,
+                kind,
+                text,
+            })
+            .collect()
+    }
+
+    fn jpg_image_url(images: &Option<Images>) -> Option<String> {
+        images
+            .as_ref()
+            .and_then(|images| images.jpg.as_ref())
+            .and_then(|jpg| jpg.image_url.clone())
+    }
+}
+
 impl Default for JikanMapper {
     fn default() -> Self {
         Self::new()
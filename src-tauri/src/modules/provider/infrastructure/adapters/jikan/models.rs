@@ -415,6 +415,17 @@ pub struct EntryRecommendation {
     pub title: String,
 }
 
+/// Entry from `/anime/{id}/recommendations`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnimeRecommendation {
+    pub entry: EntryRecommendation,
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Users who recommended the entry
+    #[serde(default)]
+    pub votes: u32,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AnimeUserUpdate {
     pub user: UserMeta,
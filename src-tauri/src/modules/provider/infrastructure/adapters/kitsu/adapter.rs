@@ -0,0 +1,268 @@
+use crate::{
+    modules::provider::domain::entities::anime_data::AnimeData,
+    modules::provider::infrastructure::http_client::RateLimitClient,
+    modules::provider::AnimeProvider,
+    shared::errors::{AppError, AppResult},
+};
+
+use super::mapper::KitsuMapper;
+use super::models::*;
+
+/// Kitsu caps JSON:API collection pages at 20 resources
+const MAX_PAGE_SIZE: usize = 20;
+
+/// Relationships side-loaded with every anime so the mapper can resolve them
+const ANIME_INCLUDES: &str = "categories,mappings,animeProductions.producer";
+
+/// Kitsu provider adapter with JSON:API
+pub struct KitsuAdapter {
+    http_client: RateLimitClient,
+    base_url: String,
+    mapper: KitsuMapper,
+}
+
+impl KitsuAdapter {
+    pub fn new() -> Self {
+        Self {
+            http_client: RateLimitClient::for_kitsu(),
+            base_url: "https://kitsu.io/api/edge".to_string(),
+            mapper: KitsuMapper::new(),
+        }
+    }
+
+    /// Create adapter with custom HTTP client (for testing)
+    pub fn with_client(http_client: RateLimitClient) -> Self {
+        Self {
+            http_client,
+            base_url: "https://kitsu.io/api/edge".to_string(),
+            mapper: KitsuMapper::new(),
+        }
+    }
+
+    /// Check if a request can be made immediately (for testing and monitoring)
+    pub fn can_make_request_now(&self) -> bool {
+        self.http_client.can_make_request_now()
+    }
+
+    fn parse_id(id: &str) -> AppResult<u32> {
+        id.parse()
+            .map_err(|_| AppError::ValidationError(format!("Invalid Kitsu ID: {}", id)))
+    }
+
+    fn map_document(&self, document: KitsuDocument<Vec<KitsuAnime>>) -> AppResult<Vec<AnimeData>> {
+        let included = document.included;
+
+        document
+            .data
+            .into_iter()
+            .map(|anime| self.mapper.map_to_anime_data(anime, &included))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| AppError::MappingError(format!("Failed to map Kitsu data: {}", e)))
+    }
+}
+
+impl KitsuAdapter {
+    // =============================================================================
+    // CORE ANIME FUNCTIONS
+    // =============================================================================
+
+    pub async fn search_anime(&self, query: &str, limit: usize) -> AppResult<Vec<AnimeData>> {
+        let url = format!(
+            "{}/anime?filter[text]={}&page[limit]={}&include={}",
+            self.base_url,
+            urlencoding::encode(query),
+            limit.clamp(1, MAX_PAGE_SIZE),
+            ANIME_INCLUDES
+        );
+
+        log::info!("Kitsu: Searching for '{}' (limit: {})", query, limit);
+
+        let response: KitsuDocument<Vec<KitsuAnime>> = self.http_client.get(&url).await?;
+        let anime_data = self.map_document(response)?;
+
+        log::info!("Kitsu: Found {} results for '{}'", anime_data.len(), query);
+        Ok(anime_data)
+    }
+
+    pub async fn get_anime_by_id(&self, id: &str) -> AppResult<Option<AnimeData>> {
+        let anime_id = Self::parse_id(id)?;
+        let url = format!(
+            "{}/anime/{}?include={}",
+            self.base_url, anime_id, ANIME_INCLUDES
+        );
+
+        log::info!("Kitsu: Getting anime by ID '{}'", id);
+
+        let response: KitsuDocument<KitsuAnime> = match self.http_client.get(&url).await {
+            Ok(response) => response,
+            Err(AppError::ApiError(msg)) if msg.contains("404") => {
+                log::info!("Kitsu: No anime found for ID '{}'", id);
+                return Ok(None);
+            }
+            Err(e) => return Err(e),
+        };
+
+        let anime_data = self
+            .mapper
+            .map_to_anime_data(response.data, &response.included)
+            .map_err(|e| AppError::MappingError(format!("Failed to map Kitsu data: {}", e)))?;
+
+        log::info!("Kitsu: Found anime by ID '{}'", id);
+        Ok(Some(anime_data))
+    }
+
+    // =============================================================================
+    // ID MAPPINGS
+    // =============================================================================
+
+    /// Get the raw external-site mappings for an anime
+    pub async fn get_anime_mappings(&self, id: u32) -> AppResult<Vec<KitsuMapping>> {
+        let url = format!("{}/anime/{}/mappings", self.base_url, id);
+
+        log::info!("Kitsu: Getting mappings for anime ID '{}'", id);
+
+        let response: KitsuDocument<Vec<KitsuMapping>> = self.http_client.get(&url).await?;
+
+        log::info!(
+            "Kitsu: Found {} mappings for anime ID '{}'",
+            response.data.len(),
+            id
+        );
+        Ok(response.data)
+    }
+
+    /// Get MAL/AniList/AniDB ids known for a Kitsu anime
+    pub async fn get_external_ids(&self, id: u32) -> AppResult<Vec<(AnimeProvider, String)>> {
+        let mappings = self.get_anime_mappings(id).await?;
+
+        Ok(mappings
+            .into_iter()
+            .filter_map(|mapping| {
+                KitsuMapper::provider_for_external_site(&mapping.attributes.external_site)
+                    .map(|provider| (provider, mapping.attributes.external_id))
+            })
+            .collect())
+    }
+
+    /// Resolve another provider's id (e.g. a MAL id) to the Kitsu anime id
+    pub async fn find_kitsu_id(
+        &self,
+        provider: AnimeProvider,
+        external_id: &str,
+    ) -> AppResult<Option<String>> {
+        let site = KitsuMapper::external_site_for_provider(provider).ok_or_else(|| {
+            AppError::InvalidInput(format!("Kitsu does not map {} ids", provider))
+        })?;
+
+        let url = format!(
+            "{}/mappings?filter[externalSite]={}&filter[externalId]={}&include=item",
+            self.base_url,
+            urlencoding::encode(site),
+            urlencoding::encode(external_id)
+        );
+
+        log::info!(
+            "Kitsu: Looking up Kitsu ID for {} ID '{}'",
+            provider,
+            external_id
+        );
+
+        let response: KitsuDocument<Vec<KitsuMapping>> = self.http_client.get(&url).await?;
+
+        let kitsu_id = response
+            .data
+            .iter()
+            .flat_map(|mapping| mapping.related_ids("item"))
+            .next()
+            .map(str::to_string);
+
+        if kitsu_id.is_none() {
+            log::info!(
+                "Kitsu: No mapping found for {} ID '{}'",
+                provider,
+                external_id
+            );
+        }
+        Ok(kitsu_id)
+    }
+
+    /// Get anime details by another provider's id
+    pub async fn get_anime_by_external_id(
+        &self,
+        provider: AnimeProvider,
+        external_id: &str,
+    ) -> AppResult<Option<AnimeData>> {
+        match self.find_kitsu_id(provider, external_id).await? {
+            Some(kitsu_id) => self.get_anime_by_id(&kitsu_id).await,
+            None => Ok(None),
+        }
+    }
+
+    // =============================================================================
+    // EPISODES
+    // =============================================================================
+
+    /// Get anime episodes (paginated, 1-based page)
+    pub async fn get_anime_episodes(
+        &self,
+        id: u32,
+        page: Option<u32>,
+    ) -> AppResult<KitsuDocument<Vec<KitsuEpisode>>> {
+        let offset = page.unwrap_or(1).saturating_sub(1) as usize * MAX_PAGE_SIZE;
+        let url = format!(
+            "{}/anime/{}/episodes?page[limit]={}&page[offset]={}&sort=number",
+            self.base_url, id, MAX_PAGE_SIZE, offset
+        );
+
+        log::info!(
+            "Kitsu: Getting episodes for anime ID '{}' (page: {:?})",
+            id,
+            page
+        );
+
+        let response: KitsuDocument<Vec<KitsuEpisode>> = self.http_client.get(&url).await?;
+
+        log::info!(
+            "Kitsu: Found {} episodes for anime ID '{}'",
+            response.data.len(),
+            id
+        );
+        Ok(response)
+    }
+}
+
+impl Default for KitsuAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adapter_creation() {
+        let adapter = KitsuAdapter::new();
+        assert!(adapter.can_make_request_now());
+    }
+
+    #[test]
+    fn test_adapter_with_client() {
+        let adapter = KitsuAdapter::with_client(RateLimitClient::for_kitsu());
+        assert!(adapter.can_make_request_now());
+    }
+
+    #[test]
+    fn test_client_profile() {
+        let client = RateLimitClient::for_kitsu();
+        assert_eq!(client.provider_name(), "Kitsu");
+    }
+
+    #[tokio::test]
+    async fn test_invalid_id_is_rejected() {
+        let adapter = KitsuAdapter::new();
+        let result = adapter.get_anime_by_id("not-a-number").await;
+        assert!(result.is_err());
+    }
+}
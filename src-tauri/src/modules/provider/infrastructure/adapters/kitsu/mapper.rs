@@ -0,0 +1,552 @@
+use super::models::*;
+use crate::modules::anime::domain::{
+    entities::{
+        anime_detailed::{AiredDates, AnimeDetailed},
+        genre::Genre,
+    },
+    value_objects::{AnimeStatus, AnimeTier, AnimeTitle, AnimeType, QualityMetrics},
+};
+use crate::modules::provider::domain::entities::anime_data::{AnimeData, DataQuality, DataSource};
+use crate::shared::domain::value_objects::{AnimeProvider, ProviderMetadata};
+
+use crate::shared::domain::value_objects::UnifiedAgeRestriction;
+use crate::shared::errors::AppError;
+use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
+
+/// Capability trait to describe what each adapter can provide
+pub trait AdapterCapabilities {
+    /// Get the name of the adapter
+    fn name(&self) -> &'static str;
+
+    /// Get the provider fields this adapter can populate
+    fn supported_fields(&self) -> Vec<&'static str>;
+
+    /// Get the provider fields this adapter cannot populate
+    fn unsupported_fields(&self) -> Vec<&'static str>;
+
+    /// Check if the adapter supports a specific field
+    fn supports_field(&self, field: &str) -> bool {
+        self.supported_fields().contains(&field)
+    }
+
+    /// Get quality score for this adapter (0.0 to 1.0)
+    fn quality_score(&self) -> f64;
+
+    /// Get response time estimate in milliseconds
+    fn estimated_response_time(&self) -> u64;
+
+    /// Check if the adapter has rate limiting
+    fn has_rate_limiting(&self) -> bool;
+}
+
+/// Kitsu specific mapper implementation
+#[derive(Debug, Clone)]
+pub struct KitsuMapper;
+
+impl KitsuMapper {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Resolve a Kitsu mapping site (`myanimelist/anime`, `anilist/anime`, ...) to a provider
+    pub fn provider_for_external_site(site: &str) -> Option<AnimeProvider> {
+        match site {
+            "myanimelist/anime" => Some(AnimeProvider::Jikan),
+            "anilist/anime" => Some(AnimeProvider::AniList),
+            "anidb" => Some(AnimeProvider::AniDB),
+            _ => None,
+        }
+    }
+
+    /// Kitsu mapping site key for a provider, if Kitsu tracks it
+    pub fn external_site_for_provider(provider: AnimeProvider) -> Option<&'static str> {
+        match provider {
+            AnimeProvider::Jikan => Some("myanimelist/anime"),
+            AnimeProvider::AniList => Some("anilist/anime"),
+            AnimeProvider::AniDB => Some("anidb"),
+            AnimeProvider::Kitsu | AnimeProvider::TMDB => None,
+        }
+    }
+
+    /// Collect external provider ids from side-loaded mapping resources
+    pub fn extract_external_ids(
+        anime: &KitsuAnime,
+        included: &[KitsuIncluded],
+    ) -> Vec<(AnimeProvider, String)> {
+        let mapping_ids = anime.related_ids("mappings");
+
+        included
+            .iter()
+            .filter_map(|resource| match resource {
+                KitsuIncluded::Mapping { id, attributes } if mapping_ids.contains(&id.as_str()) => {
+                    Self::provider_for_external_site(&attributes.external_site)
+                        .map(|provider| (provider, attributes.external_id.clone()))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Map Kitsu age rating to age restriction
+    fn map_age_rating(age_rating: &Option<String>) -> Option<UnifiedAgeRestriction> {
+        age_rating.as_deref().and_then(|r| match r {
+            "G" => Some(UnifiedAgeRestriction::GeneralAudiences),
+            "PG" => Some(UnifiedAgeRestriction::ParentalGuidance13),
+            "R" => Some(UnifiedAgeRestriction::ParentalGuidance17),
+            "R18" => Some(UnifiedAgeRestriction::Explicit),
+            _ => None,
+        })
+    }
+
+    /// Map Kitsu status to AnimeStatus
+    fn map_anime_status(status: &Option<String>) -> AnimeStatus {
+        match status.as_deref() {
+            Some("finished") => AnimeStatus::Finished,
+            Some("current") => AnimeStatus::Airing,
+            Some("upcoming") | Some("unreleased") | Some("tba") => AnimeStatus::NotYetAired,
+            _ => AnimeStatus::Unknown,
+        }
+    }
+
+    /// Map Kitsu subtype to AnimeType
+    fn map_anime_type(subtype: &Option<String>) -> AnimeType {
+        match subtype.as_deref().map(|s| s.to_lowercase()).as_deref() {
+            Some("tv") => AnimeType::TV,
+            Some("movie") => AnimeType::Movie,
+            Some("ova") => AnimeType::OVA,
+            Some("special") => AnimeType::Special,
+            Some("ona") => AnimeType::ONA,
+            Some("music") => AnimeType::Music,
+            _ => AnimeType::Unknown,
+        }
+    }
+
+    /// Convert the percentage average rating (`"82.51"`) to a 0-10 score
+    fn parse_score(average_rating: &Option<String>) -> Option<f32> {
+        average_rating
+            .as_deref()
+            .and_then(|r| r.parse::<f32>().ok())
+            .map(|r| (r / 10.0 * 100.0).round() / 100.0)
+    }
+
+    /// Parse a Kitsu `YYYY-MM-DD` date
+    fn parse_date(date_str: &Option<String>) -> Option<DateTime<Utc>> {
+        date_str
+            .as_deref()
+            .and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok())
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .map(|dt| DateTime::<Utc>::from_naive_utc_and_offset(dt, Utc))
+    }
+
+    /// Pick a title by language key, skipping empty values
+    fn title_for(
+        titles: &std::collections::HashMap<String, Option<String>>,
+        keys: &[&str],
+    ) -> Option<String> {
+        keys.iter()
+            .filter_map(|key| titles.get(*key).cloned().flatten())
+            .find(|title| !title.trim().is_empty())
+    }
+
+    /// Extract best image URL from Kitsu image variants
+    fn extract_image_url(image: &Option<KitsuImage>) -> Option<String> {
+        image.as_ref().and_then(|img| {
+            // Prefer larger images
+            img.large
+                .clone()
+                .or_else(|| img.original.clone())
+                .or_else(|| img.medium.clone())
+                .or_else(|| img.small.clone())
+        })
+    }
+
+    /// Extract genre names from side-loaded categories
+    fn extract_genres(anime: &KitsuAnime, included: &[KitsuIncluded]) -> Vec<Genre> {
+        let category_ids = anime.related_ids("categories");
+        let genre_ids = anime.related_ids("genres");
+
+        included
+            .iter()
+            .filter_map(|resource| match resource {
+                KitsuIncluded::Category { id, attributes }
+                    if category_ids.contains(&id.as_str()) =>
+                {
+                    attributes.display_name()
+                }
+                KitsuIncluded::Genre { id, attributes } if genre_ids.contains(&id.as_str()) => {
+                    attributes.display_name()
+                }
+                _ => None,
+            })
+            .map(|name| Genre::new(name.to_string()))
+            .collect()
+    }
+
+    /// Extract studio names from side-loaded anime productions
+    fn extract_studios(anime: &KitsuAnime, included: &[KitsuIncluded]) -> Vec<String> {
+        let production_ids = anime.related_ids("animeProductions");
+
+        included
+            .iter()
+            .filter_map(|resource| match resource {
+                KitsuIncluded::AnimeProduction {
+                    id,
+                    attributes,
+                    relationships,
+                } if production_ids.contains(&id.as_str())
+                    && attributes.role.as_deref() == Some("studio") =>
+                {
+                    match relationships.get("producer").and_then(|r| r.data.as_ref()) {
+                        Some(KitsuRelationshipData::One(producer)) => Some(producer.id.as_str()),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            })
+            .filter_map(|producer_id| {
+                included.iter().find_map(|resource| match resource {
+                    KitsuIncluded::Producer { id, attributes } if id == producer_id => {
+                        attributes.name.clone()
+                    }
+                    _ => None,
+                })
+            })
+            .collect()
+    }
+
+    /// Calculate data completeness based on available fields
+    fn calculate_completeness(anime: &AnimeDetailed) -> f32 {
+        let checks = [
+            !anime.title.main.is_empty(),
+            anime.title.english.is_some(),
+            anime.title.japanese.is_some(),
+            anime.synopsis.is_some(),
+            anime.episodes.is_some(),
+            anime.score.is_some(),
+            anime.image_url.is_some(),
+            anime.banner_image.is_some(),
+            !anime.genres.is_empty(),
+            !anime.studios.is_empty(),
+            anime.aired.from.is_some(),
+            anime.age_restriction.is_some(),
+        ];
+
+        checks.iter().filter(|present| **present).count() as f32 / checks.len() as f32
+    }
+
+    /// Identify missing critical fields
+    fn identify_missing_fields(anime: &AnimeDetailed) -> Vec<String> {
+        let mut missing = Vec::new();
+
+        if anime.title.english.is_none() {
+            missing.push("title_english".to_string());
+        }
+        if anime.synopsis.is_none() {
+            missing.push("synopsis".to_string());
+        }
+        if anime.episodes.is_none() {
+            missing.push("episodes".to_string());
+        }
+        if anime.image_url.is_none() {
+            missing.push("cover_image".to_string());
+        }
+        if anime.genres.is_empty() {
+            missing.push("genres".to_string());
+        }
+        if anime.studios.is_empty() {
+            missing.push("studios".to_string());
+        }
+
+        missing
+    }
+}
+
+impl KitsuMapper {
+    /// Map a Kitsu anime resource, resolving relationships against `included`
+    pub fn map_to_anime_data(
+        &self,
+        source: KitsuAnime,
+        included: &[KitsuIncluded],
+    ) -> Result<AnimeData, AppError> {
+        let now = Utc::now();
+
+        let mut provider_metadata = ProviderMetadata::new(AnimeProvider::Kitsu, source.id.clone());
+        if let Some(slug) = &source.attributes.slug {
+            provider_metadata.add_provider_url(
+                AnimeProvider::Kitsu,
+                format!("https://kitsu.io/anime/{}", slug),
+            );
+        }
+        for (provider, external_id) in Self::extract_external_ids(&source, included) {
+            provider_metadata.add_external_id(provider, external_id);
+        }
+
+        let genres = Self::extract_genres(&source, included);
+        let studios = Self::extract_studios(&source, included);
+
+        let attributes = source.attributes;
+        let score = Self::parse_score(&attributes.average_rating);
+        let romaji = Self::title_for(&attributes.titles, &["en_jp"]);
+        let native = Self::title_for(&attributes.titles, &["ja_jp"]);
+
+        let anime_detailed = AnimeDetailed {
+            id: Uuid::new_v4(),
+            title: AnimeTitle {
+                main: attributes
+                    .canonical_title
+                    .clone()
+                    .or_else(|| romaji.clone())
+                    .unwrap_or_else(|| "Unknown Title".to_string()),
+                english: Self::title_for(&attributes.titles, &["en", "en_us"]),
+                japanese: native.clone(),
+                romaji,
+                native,
+                synonyms: attributes.abbreviated_titles.unwrap_or_default(),
+            },
+            provider_metadata,
+            score,
+            rating: score,
+            favorites: attributes.favorites_count,
+            synopsis: attributes.synopsis.clone(),
+            description: attributes.description.or(attributes.synopsis),
+            episodes: attributes.episode_count.map(|e| e as u16),
+            status: Self::map_anime_status(&attributes.status),
+            aired: AiredDates {
+                from: Self::parse_date(&attributes.start_date),
+                to: Self::parse_date(&attributes.end_date),
+            },
+            anime_type: Self::map_anime_type(&attributes.subtype),
+            age_restriction: Self::map_age_rating(&attributes.age_rating),
+            genres,
+            studios,
+            source: None, // Kitsu doesn't expose source material
+            duration: attributes
+                .episode_length
+                .map(|minutes| format!("{} min per ep", minutes)),
+            image_url: Self::extract_image_url(&attributes.poster_image),
+            images: Self::extract_image_url(&attributes.poster_image),
+            banner_image: Self::extract_image_url(&attributes.cover_image),
+            trailer_url: attributes
+                .youtube_video_id
+                .filter(|id| !id.is_empty())
+                .map(|id| format!("https://www.youtube.com/watch?v={}", id)),
+            composite_score: score.unwrap_or(0.0),
+            tier: AnimeTier::default(),
+            quality_metrics: QualityMetrics::default(),
+            created_at: now,
+            updated_at: now,
+            last_synced_at: Some(now),
+        };
+
+        // Create quality assessment
+        let quality = DataQuality {
+            score: 0.8, // Solid coverage, community-maintained
+            completeness: Self::calculate_completeness(&anime_detailed),
+            consistency: 0.85,
+            relevance_score: 0.0, // Will be set during search ranking
+            missing_fields: Self::identify_missing_fields(&anime_detailed),
+        };
+
+        // Create source information
+        let source_info = DataSource {
+            primary_provider: AnimeProvider::Kitsu,
+            providers_used: vec![AnimeProvider::Kitsu],
+            confidence: 0.85,
+            fetch_time_ms: 800, // Typical Kitsu response time
+        };
+
+        Ok(AnimeData::with_metadata(
+            anime_detailed,
+            quality,
+            source_info,
+        ))
+    }
+}
+
+impl AdapterCapabilities for KitsuMapper {
+    fn name(&self) -> &'static str {
+        "Kitsu"
+    }
+
+    fn supported_fields(&self) -> Vec<&'static str> {
+        vec![
+            "id",
+            "mal_id",
+            "anilist_id",
+            "title",
+            "title_english",
+            "title_japanese",
+            "title_synonyms",
+            "synopsis",
+            "description",
+            "episode_count",
+            "duration",
+            "status",
+            "anime_type",
+            "start_date",
+            "end_date",
+            "cover_image",
+            "banner_image",
+            "trailer_url",
+            "score",
+            "favorites",
+            "age_restriction",
+            "studios",
+            "genres",
+        ]
+    }
+
+    fn unsupported_fields(&self) -> Vec<&'static str> {
+        vec![
+            "source", // Not exposed by Kitsu
+        ]
+    }
+
+    fn quality_score(&self) -> f64 {
+        0.8
+    }
+
+    fn estimated_response_time(&self) -> u64 {
+        800
+    }
+
+    fn has_rate_limiting(&self) -> bool {
+        true
+    }
+}
+
+impl Default for KitsuMapper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANIME_SEARCH: &str = include_str!("fixtures/anime_search.json");
+
+    fn search_document() -> KitsuDocument<Vec<KitsuAnime>> {
+        serde_json::from_str(ANIME_SEARCH).unwrap()
+    }
+
+    #[test]
+    fn test_maps_core_fields() {
+        let document = search_document();
+        let anime = document.data[0].clone();
+
+        let data = KitsuMapper::new()
+            .map_to_anime_data(anime, &document.included)
+            .unwrap();
+        let detailed = data.anime;
+
+        assert_eq!(detailed.title.main, "Cowboy Bebop");
+        assert_eq!(detailed.title.english.as_deref(), Some("Cowboy Bebop"));
+        assert_eq!(
+            detailed.title.japanese.as_deref(),
+            Some("カウボーイビバップ")
+        );
+        assert_eq!(detailed.episodes, Some(26));
+        assert_eq!(detailed.score, Some(8.25));
+        assert_eq!(detailed.status, AnimeStatus::Finished);
+        assert_eq!(detailed.anime_type, AnimeType::TV);
+        assert_eq!(
+            detailed.age_restriction,
+            Some(UnifiedAgeRestriction::ParentalGuidance17)
+        );
+        assert_eq!(detailed.duration.as_deref(), Some("25 min per ep"));
+        assert!(detailed.aired.from.is_some());
+        assert!(detailed.banner_image.is_some());
+        assert_eq!(
+            detailed.trailer_url.as_deref(),
+            Some("https://www.youtube.com/watch?v=qig4KOK2R2g")
+        );
+    }
+
+    #[test]
+    fn test_resolves_included_relationships() {
+        let document = search_document();
+        let anime = document.data[0].clone();
+
+        let detailed = KitsuMapper::new()
+            .map_to_anime_data(anime, &document.included)
+            .unwrap()
+            .anime;
+
+        let genres: Vec<_> = detailed.genres.iter().map(|g| g.name.as_str()).collect();
+        assert_eq!(genres, vec!["Space", "Science Fiction"]);
+
+        // Only studio productions count, licensors are skipped
+        assert_eq!(detailed.studios, vec!["Sunrise".to_string()]);
+    }
+
+    #[test]
+    fn test_collects_external_ids() {
+        let document = search_document();
+        let anime = document.data[0].clone();
+
+        let metadata = KitsuMapper::new()
+            .map_to_anime_data(anime, &document.included)
+            .unwrap()
+            .anime
+            .provider_metadata;
+
+        assert_eq!(metadata.primary_provider, AnimeProvider::Kitsu);
+        assert_eq!(
+            metadata.get_external_id(&AnimeProvider::Kitsu),
+            Some(&"1".to_string())
+        );
+        assert_eq!(
+            metadata.get_external_id(&AnimeProvider::Jikan),
+            Some(&"1".to_string())
+        );
+        assert_eq!(
+            metadata.get_external_id(&AnimeProvider::AniList),
+            Some(&"1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_sparse_entry() {
+        let document = search_document();
+        let anime = document.data[1].clone();
+
+        let data = KitsuMapper::new()
+            .map_to_anime_data(anime, &document.included)
+            .unwrap();
+        let detailed = &data.anime;
+
+        assert_eq!(detailed.title.main, "Attack on Titan");
+        assert!(detailed.title.english.is_none());
+        assert_eq!(detailed.title.romaji.as_deref(), Some("Shingeki no Kyojin"));
+        assert!(detailed.score.is_none());
+        assert!(detailed.genres.is_empty());
+        assert!(detailed.trailer_url.is_none());
+        assert_eq!(detailed.status, AnimeStatus::NotYetAired);
+        assert_eq!(detailed.anime_type, AnimeType::Movie);
+        assert!(data
+            .quality
+            .missing_fields
+            .contains(&"synopsis".to_string()));
+    }
+
+    #[test]
+    fn test_external_site_round_trip() {
+        for provider in [
+            AnimeProvider::Jikan,
+            AnimeProvider::AniList,
+            AnimeProvider::AniDB,
+        ] {
+            let site = KitsuMapper::external_site_for_provider(provider).unwrap();
+            assert_eq!(
+                KitsuMapper::provider_for_external_site(site),
+                Some(provider)
+            );
+        }
+
+        assert!(KitsuMapper::external_site_for_provider(AnimeProvider::TMDB).is_none());
+        assert!(KitsuMapper::provider_for_external_site("thetvdb/season").is_none());
+    }
+}
@@ -0,0 +1,5 @@
+pub mod adapter;
+pub mod mapper;
+pub mod models;
+
+pub use adapter::*;
@@ -0,0 +1,390 @@
+#![allow(unused)]
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// =============================================================================
+// JSON:API ENVELOPES
+// =============================================================================
+
+/// Top-level JSON:API document returned by every Kitsu endpoint
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KitsuDocument<T> {
+    pub data: T,
+    #[serde(default)]
+    pub included: Vec<KitsuIncluded>,
+    #[serde(default)]
+    pub meta: Option<KitsuMeta>,
+    #[serde(default)]
+    pub links: Option<KitsuLinks>,
+}
+
+impl<T> KitsuDocument<T> {
+    /// Whether the collection has a further page to fetch
+    pub fn has_next_page(&self) -> bool {
+        self.links
+            .as_ref()
+            .and_then(|links| links.next.as_ref())
+            .is_some()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KitsuMeta {
+    #[serde(default)]
+    pub count: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KitsuLinks {
+    #[serde(default)]
+    pub first: Option<String>,
+    #[serde(default)]
+    pub prev: Option<String>,
+    #[serde(default)]
+    pub next: Option<String>,
+    #[serde(default)]
+    pub last: Option<String>,
+}
+
+/// A primary resource object (`{ id, type, attributes, relationships }`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KitsuResource<A> {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub resource_type: String,
+    pub attributes: A,
+    #[serde(default)]
+    pub relationships: HashMap<String, KitsuRelationship>,
+}
+
+impl<A> KitsuResource<A> {
+    /// Identifiers linked under a relationship name, empty when not included
+    pub fn related_ids(&self, relationship: &str) -> Vec<&str> {
+        match self
+            .relationships
+            .get(relationship)
+            .and_then(|r| r.data.as_ref())
+        {
+            Some(KitsuRelationshipData::One(identifier)) => vec![identifier.id.as_str()],
+            Some(KitsuRelationshipData::Many(identifiers)) => {
+                identifiers.iter().map(|i| i.id.as_str()).collect()
+            }
+            None => Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KitsuRelationship {
+    #[serde(default)]
+    pub data: Option<KitsuRelationshipData>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum KitsuRelationshipData {
+    One(KitsuResourceIdentifier),
+    Many(Vec<KitsuResourceIdentifier>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KitsuResourceIdentifier {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub resource_type: String,
+}
+
+/// Side-loaded resources requested through `include=`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum KitsuIncluded {
+    #[serde(rename = "anime")]
+    Anime {
+        id: String,
+        attributes: Box<AnimeAttributes>,
+    },
+    #[serde(rename = "categories")]
+    Category {
+        id: String,
+        attributes: CategoryAttributes,
+    },
+    #[serde(rename = "genres")]
+    Genre {
+        id: String,
+        attributes: CategoryAttributes,
+    },
+    #[serde(rename = "mappings")]
+    Mapping {
+        id: String,
+        attributes: MappingAttributes,
+    },
+    #[serde(rename = "animeProductions")]
+    AnimeProduction {
+        id: String,
+        attributes: AnimeProductionAttributes,
+        #[serde(default)]
+        relationships: HashMap<String, KitsuRelationship>,
+    },
+    #[serde(rename = "producers")]
+    Producer {
+        id: String,
+        attributes: ProducerAttributes,
+    },
+    #[serde(other)]
+    Other,
+}
+
+pub type KitsuAnime = KitsuResource<AnimeAttributes>;
+pub type KitsuMapping = KitsuResource<MappingAttributes>;
+pub type KitsuEpisode = KitsuResource<EpisodeAttributes>;
+
+// =============================================================================
+// ANIME
+// =============================================================================
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnimeAttributes {
+    #[serde(default)]
+    pub slug: Option<String>,
+    #[serde(default)]
+    pub synopsis: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub titles: HashMap<String, Option<String>>,
+    #[serde(default)]
+    pub canonical_title: Option<String>,
+    #[serde(default)]
+    pub abbreviated_titles: Option<Vec<String>>,
+    /// Average rating as a percentage string, e.g. `"82.51"`
+    #[serde(default)]
+    pub average_rating: Option<String>,
+    #[serde(default)]
+    pub user_count: Option<u32>,
+    #[serde(default)]
+    pub favorites_count: Option<u32>,
+    #[serde(default)]
+    pub start_date: Option<String>,
+    #[serde(default)]
+    pub end_date: Option<String>,
+    #[serde(default)]
+    pub popularity_rank: Option<u32>,
+    #[serde(default)]
+    pub rating_rank: Option<u32>,
+    /// One of `G`, `PG`, `R`, `R18`
+    #[serde(default)]
+    pub age_rating: Option<String>,
+    #[serde(default)]
+    pub age_rating_guide: Option<String>,
+    /// One of `TV`, `special`, `OVA`, `ONA`, `movie`, `music`
+    #[serde(default)]
+    pub subtype: Option<String>,
+    /// One of `current`, `finished`, `tba`, `unreleased`, `upcoming`
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub poster_image: Option<KitsuImage>,
+    #[serde(default)]
+    pub cover_image: Option<KitsuImage>,
+    #[serde(default)]
+    pub episode_count: Option<u32>,
+    /// Episode length in minutes
+    #[serde(default)]
+    pub episode_length: Option<u32>,
+    #[serde(default)]
+    pub youtube_video_id: Option<String>,
+    #[serde(default)]
+    pub nsfw: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KitsuImage {
+    #[serde(default)]
+    pub tiny: Option<String>,
+    #[serde(default)]
+    pub small: Option<String>,
+    #[serde(default)]
+    pub medium: Option<String>,
+    #[serde(default)]
+    pub large: Option<String>,
+    #[serde(default)]
+    pub original: Option<String>,
+}
+
+// =============================================================================
+// SIDE-LOADED RESOURCES
+// =============================================================================
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CategoryAttributes {
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub slug: Option<String>,
+}
+
+impl CategoryAttributes {
+    /// Categories expose `title`, legacy genres expose `name`
+    pub fn display_name(&self) -> Option<&str> {
+        self.title.as_deref().or(self.name.as_deref())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MappingAttributes {
+    /// External site key, e.g. `myanimelist/anime` or `anilist/anime`
+    pub external_site: String,
+    pub external_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnimeProductionAttributes {
+    /// One of `producer`, `licensor`, `studio`, `serialization`
+    #[serde(default)]
+    pub role: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProducerAttributes {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub slug: Option<String>,
+}
+
+// =============================================================================
+// EPISODES
+// =============================================================================
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EpisodeAttributes {
+    #[serde(default)]
+    pub titles: HashMap<String, Option<String>>,
+    #[serde(default)]
+    pub canonical_title: Option<String>,
+    #[serde(default)]
+    pub season_number: Option<u32>,
+    #[serde(default)]
+    pub number: Option<u32>,
+    #[serde(default)]
+    pub relative_number: Option<u32>,
+    #[serde(default)]
+    pub synopsis: Option<String>,
+    #[serde(default)]
+    pub airdate: Option<String>,
+    /// Episode length in minutes
+    #[serde(default)]
+    pub length: Option<u32>,
+    #[serde(default)]
+    pub thumbnail: Option<KitsuImage>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANIME_SEARCH: &str = include_str!("fixtures/anime_search.json");
+    const MAPPING_LOOKUP: &str = include_str!("fixtures/mapping_lookup.json");
+    const EPISODES: &str = include_str!("fixtures/episodes.json");
+
+    #[test]
+    fn test_search_document_deserialization() {
+        let document: KitsuDocument<Vec<KitsuAnime>> = serde_json::from_str(ANIME_SEARCH).unwrap();
+
+        assert_eq!(document.data.len(), 2);
+        assert_eq!(document.meta.as_ref().unwrap().count, Some(2));
+        assert!(document.has_next_page());
+
+        let anime = &document.data[0];
+        assert_eq!(anime.id, "1");
+        assert_eq!(anime.resource_type, "anime");
+        assert_eq!(
+            anime.attributes.canonical_title.as_deref(),
+            Some("Cowboy Bebop")
+        );
+        assert_eq!(anime.attributes.average_rating.as_deref(), Some("82.51"));
+        assert_eq!(anime.attributes.episode_count, Some(26));
+    }
+
+    #[test]
+    fn test_optional_attributes() {
+        let document: KitsuDocument<Vec<KitsuAnime>> = serde_json::from_str(ANIME_SEARCH).unwrap();
+        let anime = &document.data[1];
+
+        assert!(anime.attributes.synopsis.is_none());
+        assert!(anime.attributes.average_rating.is_none());
+        assert!(anime.attributes.poster_image.is_none());
+        assert_eq!(anime.attributes.titles.get("en"), Some(&None));
+        assert!(anime.relationships.is_empty());
+    }
+
+    #[test]
+    fn test_relationship_identifiers() {
+        let document: KitsuDocument<Vec<KitsuAnime>> = serde_json::from_str(ANIME_SEARCH).unwrap();
+        let anime = &document.data[0];
+
+        assert_eq!(anime.related_ids("categories"), vec!["150", "157"]);
+        assert_eq!(anime.related_ids("mappings"), vec!["5686", "9001"]);
+        assert!(anime.related_ids("genres").is_empty());
+    }
+
+    #[test]
+    fn test_included_resources() {
+        let document: KitsuDocument<Vec<KitsuAnime>> = serde_json::from_str(ANIME_SEARCH).unwrap();
+
+        let categories = document
+            .included
+            .iter()
+            .filter(|r| matches!(r, KitsuIncluded::Category { .. }))
+            .count();
+        assert_eq!(categories, 2);
+
+        // Resource types the adapter does not model are kept as `Other`
+        assert!(document
+            .included
+            .iter()
+            .any(|r| matches!(r, KitsuIncluded::Other)));
+    }
+
+    #[test]
+    fn test_mapping_lookup_single_relationship() {
+        let document: KitsuDocument<Vec<KitsuMapping>> =
+            serde_json::from_str(MAPPING_LOOKUP).unwrap();
+        let mapping = &document.data[0];
+
+        assert_eq!(mapping.attributes.external_site, "myanimelist/anime");
+        assert_eq!(mapping.attributes.external_id, "1");
+        assert_eq!(mapping.related_ids("item"), vec!["1"]);
+        assert!(!document.has_next_page());
+    }
+
+    #[test]
+    fn test_episodes_deserialization() {
+        let document: KitsuDocument<Vec<KitsuEpisode>> = serde_json::from_str(EPISODES).unwrap();
+
+        assert_eq!(document.data.len(), 2);
+        assert_eq!(document.meta.as_ref().unwrap().count, Some(26));
+
+        let first = &document.data[0].attributes;
+        assert_eq!(first.number, Some(1));
+        assert_eq!(first.canonical_title.as_deref(), Some("Asteroid Blues"));
+        assert_eq!(first.airdate.as_deref(), Some("1998-10-24"));
+        assert_eq!(first.length, Some(24));
+
+        let second = &document.data[1].attributes;
+        assert!(second.canonical_title.is_none());
+        assert!(second.thumbnail.is_none());
+    }
+
+    #[test]
+    fn test_invalid_json() {
+        let invalid_json = r#"{"data": {"id": 1, "type": "anime"}}"#;
+        let result = serde_json::from_str::<KitsuDocument<KitsuAnime>>(invalid_json);
+        assert!(result.is_err());
+    }
+}
@@ -1,12 +1,16 @@
+pub mod anidb;
 pub mod anilist;
 pub mod cache_adapter;
 pub mod jikan;
+pub mod kitsu;
 pub mod provider_repository_adapter;
 pub mod tmdb;
 
 // Use specific imports to avoid conflicts
+pub use anidb::AniDbAdapter;
 pub use anilist::AniListAdapter;
 pub use cache_adapter::*;
 pub use jikan::JikanAdapter;
+pub use kitsu::KitsuAdapter;
 pub use provider_repository_adapter::*;
 pub use tmdb::TmdbAdapter;
@@ -1,5 +1,7 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use std::{
+    future::Future,
     sync::Arc,
     time::{Duration, Instant},
 };
@@ -8,28 +10,48 @@ use uuid::Uuid;
 
 use crate::{
     modules::{
+        character::domain::{ProviderCastMember, ProviderStaffMember},
+        discovery::domain::DiscoveryFeed,
+        links::domain::ProviderLink,
         media::domain::entities::{NewAnimeImage, NewAnimeVideo},
+        news::domain::ProviderNewsItem,
         provider::{
             domain::{
                 entities::AnimeData,
                 repositories::{
-                    AnimeProviderRepository, MediaProviderRepository,
-                    RelationshipProviderRepository,
+                    AiringPage, AnimeProviderRepository, CastProviderRepository, CatalogPage,
+                    CatalogProviderRepository, DiscoveryProviderRepository, EpisodePage,
+                    EpisodeProviderRepository, LinkProviderRepository, MediaProviderRepository,
+                    NewsProviderRepository, RecommendationProviderRepository,
+                    RelationshipProviderRepository, ScheduleProviderRepository,
+                    StatisticsProviderRepository, ThemeProviderRepository,
                 },
             },
             infrastructure::monitoring::health_monitor::{HealthMonitor, HealthMonitorConfig},
             AnimeProvider,
         },
+        recommendation::domain::ProviderRecommendation,
+        season::{BroadcastInfo, SeasonPeriod},
+        statistics::domain::ProviderStatistics,
+        themes::domain::ProviderThemeSong,
     },
     shared::errors::{AppError, AppResult},
 };
 
-use super::{AniListAdapter, JikanAdapter, TmdbAdapter};
+use super::{AniDbAdapter, AniListAdapter, JikanAdapter, KitsuAdapter, TmdbAdapter};
+
+/// Number of characters or staff requested from AniList in one call (its perPage cap)
+const CAST_PAGE_SIZE: usize = 50;
+
+/// Number of recommendations requested from AniList in one call
+const RECOMMENDATION_PAGE_SIZE: usize = 25;
 
 /// Concrete implementation for provider data access
 pub struct ProviderRepositoryAdapter {
+    anidb_adapter: AniDbAdapter,
     anilist_adapter: AniListAdapter,
     jikan_adapter: JikanAdapter,
+    kitsu_adapter: KitsuAdapter,
     tmdb_adapter: Option<TmdbAdapter>,
     health_monitor: Arc<HealthMonitor>,
 }
@@ -46,8 +68,10 @@ impl ProviderRepositoryAdapter {
         }
 
         Self {
+            anidb_adapter: AniDbAdapter::new(),
             anilist_adapter: AniListAdapter::new(),
             jikan_adapter: JikanAdapter::new(),
+            kitsu_adapter: KitsuAdapter::new(),
             tmdb_adapter,
             health_monitor: Arc::new(HealthMonitor::new(HealthMonitorConfig::default())),
         }
@@ -64,8 +88,10 @@ impl ProviderRepositoryAdapter {
         }
 
         Self {
+            anidb_adapter: AniDbAdapter::new(),
             anilist_adapter: AniListAdapter::new(),
             jikan_adapter: JikanAdapter::new(),
+            kitsu_adapter: KitsuAdapter::new(),
             tmdb_adapter,
             health_monitor,
         }
@@ -81,6 +107,7 @@ impl ProviderRepositoryAdapter {
         match provider {
             AnimeProvider::AniList => self.anilist_adapter.search_anime(query, limit).await,
             AnimeProvider::Jikan => self.jikan_adapter.search_anime(query, limit).await,
+            AnimeProvider::Kitsu => self.kitsu_adapter.search_anime(query, limit).await,
             AnimeProvider::TMDB => {
                 if let Some(ref tmdb) = self.tmdb_adapter {
                     tmdb.search_anime(query, limit).await
@@ -88,10 +115,7 @@ impl ProviderRepositoryAdapter {
                     Err(AppError::ApiError("TMDB adapter not available".to_string()))
                 }
             }
-            // For unsupported providers, default to Jikan
-            AnimeProvider::Kitsu | AnimeProvider::AniDB => {
-                self.jikan_adapter.search_anime(query, limit).await
-            }
+            AnimeProvider::AniDB => self.anidb_adapter.search_anime(query, limit).await,
         }
     }
 
@@ -104,6 +128,7 @@ impl ProviderRepositoryAdapter {
         match provider {
             AnimeProvider::AniList => self.anilist_adapter.get_anime_by_id(id).await,
             AnimeProvider::Jikan => self.jikan_adapter.get_anime_by_id(id).await,
+            AnimeProvider::Kitsu => self.kitsu_adapter.get_anime_by_id(id).await,
             AnimeProvider::TMDB => {
                 if let Some(ref tmdb) = self.tmdb_adapter {
                     tmdb.get_anime_by_id(id).await
@@ -111,9 +136,263 @@ impl ProviderRepositoryAdapter {
                     Err(AppError::ApiError("TMDB adapter not available".to_string()))
                 }
             }
-            // For unsupported providers, default to Jikan
-            AnimeProvider::Kitsu | AnimeProvider::AniDB => {
-                self.jikan_adapter.get_anime_by_id(id).await
+            AnimeProvider::AniDB => self.anidb_adapter.get_anime_by_id(id).await,
+        }
+    }
+
+    /// Helper to fetch a season page from specific adapter
+    async fn season_page_with_adapter(
+        &self,
+        provider: AnimeProvider,
+        period: SeasonPeriod,
+        page: u32,
+    ) -> AppResult<CatalogPage> {
+        match provider {
+            AnimeProvider::AniList => {
+                self.anilist_adapter
+                    .get_season_anime_data(period, page)
+                    .await
+            }
+            AnimeProvider::Jikan => self.jikan_adapter.get_season_anime_data(period, page).await,
+            _ => Err(AppError::InvalidInput(format!(
+                "Provider {} does not expose a seasonal catalog",
+                provider
+            ))),
+        }
+    }
+
+    /// Helper to fetch the cast from specific adapter
+    async fn cast_with_adapter(
+        &self,
+        provider: AnimeProvider,
+        provider_anime_id: u32,
+    ) -> AppResult<Vec<ProviderCastMember>> {
+        match provider {
+            AnimeProvider::AniList => {
+                self.anilist_adapter
+                    .get_anime_cast(provider_anime_id, CAST_PAGE_SIZE)
+                    .await
+            }
+            AnimeProvider::Jikan => self.jikan_adapter.get_anime_cast(provider_anime_id).await,
+            _ => Err(AppError::InvalidInput(format!(
+                "Provider {} does not expose character data",
+                provider
+            ))),
+        }
+    }
+
+    /// Helper to fetch the staff from specific adapter
+    async fn staff_with_adapter(
+        &self,
+        provider: AnimeProvider,
+        provider_anime_id: u32,
+    ) -> AppResult<Vec<ProviderStaffMember>> {
+        match provider {
+            AnimeProvider::AniList => {
+                self.anilist_adapter
+                    .get_anime_staff_members(provider_anime_id, CAST_PAGE_SIZE)
+                    .await
+            }
+            AnimeProvider::Jikan => {
+                self.jikan_adapter
+                    .get_anime_staff_members(provider_anime_id)
+                    .await
+            }
+            _ => Err(AppError::InvalidInput(format!(
+                "Provider {} does not expose staff data",
+                provider
+            ))),
+        }
+    }
+
+    /// Helper to fetch an episode page from specific adapter
+    async fn episodes_with_adapter(
+        &self,
+        provider: AnimeProvider,
+        provider_anime_id: u32,
+        page: u32,
+    ) -> AppResult<EpisodePage> {
+        match provider {
+            AnimeProvider::Jikan => {
+                self.jikan_adapter
+                    .get_anime_episode_page(provider_anime_id, page)
+                    .await
+            }
+            // AniList lists streaming episodes in one go
+            AnimeProvider::AniList if page > 1 => Ok(EpisodePage::default()),
+            AnimeProvider::AniList => Ok(EpisodePage {
+                items: self
+                    .anilist_adapter
+                    .get_anime_streaming_episodes(provider_anime_id)
+                    .await?,
+                has_next_page: false,
+            }),
+            _ => Err(AppError::InvalidInput(format!(
+                "Provider {} does not expose episode data",
+                provider
+            ))),
+        }
+    }
+
+    /// Helper to fetch recommendations from specific adapter
+    async fn recommendations_with_adapter(
+        &self,
+        provider: AnimeProvider,
+        provider_anime_id: u32,
+    ) -> AppResult<Vec<ProviderRecommendation>> {
+        match provider {
+            AnimeProvider::Jikan => {
+                self.jikan_adapter
+                    .get_recommended_anime(provider_anime_id)
+                    .await
+            }
+            AnimeProvider::AniList => {
+                self.anilist_adapter
+                    .get_recommended_anime(provider_anime_id, RECOMMENDATION_PAGE_SIZE)
+                    .await
+            }
+            _ => Err(AppError::InvalidInput(format!(
+                "Provider {} does not expose recommendations",
+                provider
+            ))),
+        }
+    }
+
+    /// Helper to fetch audience statistics from specific adapter
+    async fn statistics_with_adapter(
+        &self,
+        provider: AnimeProvider,
+        provider_anime_id: u32,
+    ) -> AppResult<ProviderStatistics> {
+        match provider {
+            AnimeProvider::Jikan => {
+                self.jikan_adapter
+                    .get_audience_statistics(provider_anime_id)
+                    .await
+            }
+            AnimeProvider::AniList => {
+                self.anilist_adapter
+                    .get_audience_statistics(provider_anime_id)
+                    .await
+            }
+            _ => Err(AppError::InvalidInput(format!(
+                "Provider {} does not expose audience statistics",
+                provider
+            ))),
+        }
+    }
+
+    /// Helper to fetch external links from specific adapter
+    async fn links_with_adapter(
+        &self,
+        provider: AnimeProvider,
+        provider_anime_id: u32,
+    ) -> AppResult<Vec<ProviderLink>> {
+        match provider {
+            AnimeProvider::Jikan => self.jikan_adapter.get_anime_links(provider_anime_id).await,
+            AnimeProvider::AniList => {
+                self.anilist_adapter
+                    .get_anime_links(provider_anime_id)
+                    .await
+            }
+            _ => Err(AppError::InvalidInput(format!(
+                "Provider {} does not list external links",
+                provider
+            ))),
+        }
+    }
+
+    /// Helper to fetch news articles from specific adapter
+    async fn news_with_adapter(
+        &self,
+        provider: AnimeProvider,
+        provider_anime_id: u32,
+    ) -> AppResult<Vec<ProviderNewsItem>> {
+        match provider {
+            AnimeProvider::Jikan => self.jikan_adapter.get_news(provider_anime_id).await,
+            _ => Err(AppError::InvalidInput(format!(
+                "Provider {} does not list news",
+                provider
+            ))),
+        }
+    }
+
+    /// Helper to fetch theme songs from specific adapter
+    async fn themes_with_adapter(
+        &self,
+        provider: AnimeProvider,
+        provider_anime_id: u32,
+    ) -> AppResult<Vec<ProviderThemeSong>> {
+        match provider {
+            AnimeProvider::Jikan => self.jikan_adapter.get_anime_themes(provider_anime_id).await,
+            _ => Err(AppError::InvalidInput(format!(
+                "Provider {} does not list theme songs",
+                provider
+            ))),
+        }
+    }
+
+    /// Helper to fetch a discovery feed from specific adapter
+    async fn discovery_with_adapter(
+        &self,
+        provider: AnimeProvider,
+        feed: DiscoveryFeed,
+        limit: usize,
+    ) -> AppResult<Vec<AnimeData>> {
+        match (provider, feed) {
+            (AnimeProvider::AniList, _) => {
+                self.anilist_adapter.get_discovery_feed(feed, limit).await
+            }
+            (AnimeProvider::Jikan, _) => self.jikan_adapter.get_discovery_feed(feed, limit).await,
+            (AnimeProvider::TMDB, DiscoveryFeed::Popular) => {
+                let tmdb_adapter = self.tmdb_adapter.as_ref().ok_or_else(|| {
+                    AppError::ApiError(
+                        "TMDB adapter not available (missing TMDB_API_KEY)".to_string(),
+                    )
+                })?;
+                tmdb_adapter.get_popular_anime(limit).await
+            }
+            _ => Err(AppError::InvalidInput(format!(
+                "Provider {} does not publish a {} feed",
+                provider,
+                feed.as_str()
+            ))),
+        }
+    }
+
+    /// Run one provider call under `timeout_duration` and report the outcome
+    /// to the health monitor
+    ///
+    /// `operation` names what was fetched and is only built for the timeout error.
+    async fn timed_call<T>(
+        &self,
+        provider: AnimeProvider,
+        timeout_duration: Duration,
+        operation: impl FnOnce() -> String,
+        call: impl Future<Output = AppResult<T>>,
+    ) -> AppResult<T> {
+        let start_time = Instant::now();
+
+        match timeout(timeout_duration, call).await {
+            Ok(Ok(value)) => {
+                self.health_monitor
+                    .record_success(provider, start_time.elapsed())
+                    .await;
+                Ok(value)
+            }
+            Ok(Err(e)) => {
+                self.health_monitor.record_failure(provider).await;
+                Err(e)
+            }
+            Err(_) => {
+                // Record timeout as failure
+                self.health_monitor.record_failure(provider).await;
+                Err(AppError::ApiError(format!(
+                    "Timeout fetching {} from provider {:?} after {:?}",
+                    operation(),
+                    provider,
+                    timeout_duration
+                )))
             }
         }
     }
@@ -386,3 +665,301 @@ impl RelationshipProviderRepository for ProviderRepositoryAdapter {
         true
     }
 }
+
+// =============================================================================
+// CATALOG PROVIDER REPOSITORY IMPLEMENTATION
+// =============================================================================
+
+#[async_trait]
+impl CatalogProviderRepository for ProviderRepositoryAdapter {
+    async fn fetch_season_page(
+        &self,
+        provider: AnimeProvider,
+        period: SeasonPeriod,
+        page: u32,
+    ) -> AppResult<CatalogPage> {
+        self.timed_call(
+            provider,
+            Duration::from_secs(15),
+            || format!("{} page {}", period.label(), page),
+            self.season_page_with_adapter(provider, period, page),
+        )
+        .await
+    }
+
+    fn season_providers(&self) -> Vec<AnimeProvider> {
+        // Only Jikan and AniList publish seasonal lineups
+        vec![AnimeProvider::Jikan, AnimeProvider::AniList]
+    }
+}
+
+// =============================================================================
+// CAST PROVIDER REPOSITORY IMPLEMENTATION
+// =============================================================================
+
+#[async_trait]
+impl CastProviderRepository for ProviderRepositoryAdapter {
+    async fn fetch_cast(
+        &self,
+        provider: AnimeProvider,
+        provider_anime_id: u32,
+    ) -> AppResult<Vec<ProviderCastMember>> {
+        self.timed_call(
+            provider,
+            Duration::from_secs(10),
+            || format!("cast for anime {}", provider_anime_id),
+            self.cast_with_adapter(provider, provider_anime_id),
+        )
+        .await
+    }
+
+    async fn fetch_staff(
+        &self,
+        provider: AnimeProvider,
+        provider_anime_id: u32,
+    ) -> AppResult<Vec<ProviderStaffMember>> {
+        self.timed_call(
+            provider,
+            Duration::from_secs(10),
+            || format!("staff for anime {}", provider_anime_id),
+            self.staff_with_adapter(provider, provider_anime_id),
+        )
+        .await
+    }
+
+    fn cast_providers(&self) -> Vec<AnimeProvider> {
+        // Only Jikan and AniList publish characters, voice actors and staff
+        vec![AnimeProvider::Jikan, AnimeProvider::AniList]
+    }
+}
+
+// =============================================================================
+// EPISODE PROVIDER REPOSITORY IMPLEMENTATION
+// =============================================================================
+
+#[async_trait]
+impl EpisodeProviderRepository for ProviderRepositoryAdapter {
+    async fn fetch_episode_page(
+        &self,
+        provider: AnimeProvider,
+        provider_anime_id: u32,
+        page: u32,
+    ) -> AppResult<EpisodePage> {
+        self.timed_call(
+            provider,
+            Duration::from_secs(10),
+            || format!("episode page {} for anime {}", page, provider_anime_id),
+            self.episodes_with_adapter(provider, provider_anime_id, page),
+        )
+        .await
+    }
+
+    fn episode_providers(&self) -> Vec<AnimeProvider> {
+        // MyAnimeList carries titles and filler/recap flags, AniList adds thumbnails
+        vec![AnimeProvider::Jikan, AnimeProvider::AniList]
+    }
+}
+
+// =============================================================================
+// RECOMMENDATION PROVIDER REPOSITORY IMPLEMENTATION
+// =============================================================================
+
+#[async_trait]
+impl RecommendationProviderRepository for ProviderRepositoryAdapter {
+    async fn fetch_recommendations(
+        &self,
+        provider: AnimeProvider,
+        provider_anime_id: u32,
+    ) -> AppResult<Vec<ProviderRecommendation>> {
+        self.timed_call(
+            provider,
+            Duration::from_secs(10),
+            || format!("recommendations for anime {}", provider_anime_id),
+            self.recommendations_with_adapter(provider, provider_anime_id),
+        )
+        .await
+    }
+
+    fn recommendation_providers(&self) -> Vec<AnimeProvider> {
+        // MyAnimeList has the larger vote counts, AniList adds genres and studios
+        vec![AnimeProvider::Jikan, AnimeProvider::AniList]
+    }
+}
+
+// =============================================================================
+// SCHEDULE PROVIDER REPOSITORY IMPLEMENTATION
+// =============================================================================
+
+#[async_trait]
+impl ScheduleProviderRepository for ProviderRepositoryAdapter {
+    async fn fetch_airing_page(
+        &self,
+        anilist_id: u32,
+        since: DateTime<Utc>,
+        page: u32,
+    ) -> AppResult<AiringPage> {
+        let (items, has_next_page) = self
+            .timed_call(
+                AnimeProvider::AniList,
+                Duration::from_secs(10),
+                || format!("airing schedule page {} for anime {}", page, anilist_id),
+                self.anilist_adapter
+                    .get_anime_airing_schedule(anilist_id, since, page),
+            )
+            .await?;
+
+        Ok(AiringPage {
+            items,
+            has_next_page,
+        })
+    }
+
+    async fn fetch_broadcast(&self, mal_id: u32) -> AppResult<Option<BroadcastInfo>> {
+        self.timed_call(
+            AnimeProvider::Jikan,
+            Duration::from_secs(10),
+            || format!("broadcast slot for anime {}", mal_id),
+            self.jikan_adapter.get_anime_broadcast(mal_id),
+        )
+        .await
+    }
+
+    fn schedule_providers(&self) -> Vec<AnimeProvider> {
+        // AniList has exact episode airing times, MyAnimeList the weekly slot
+        vec![AnimeProvider::AniList, AnimeProvider::Jikan]
+    }
+}
+
+// =============================================================================
+// STATISTICS PROVIDER REPOSITORY IMPLEMENTATION
+// =============================================================================
+
+#[async_trait]
+impl StatisticsProviderRepository for ProviderRepositoryAdapter {
+    async fn fetch_statistics(
+        &self,
+        provider: AnimeProvider,
+        provider_anime_id: u32,
+    ) -> AppResult<ProviderStatistics> {
+        self.timed_call(
+            provider,
+            Duration::from_secs(10),
+            || format!("statistics for anime {}", provider_anime_id),
+            self.statistics_with_adapter(provider, provider_anime_id),
+        )
+        .await
+    }
+
+    fn statistics_providers(&self) -> Vec<AnimeProvider> {
+        // Counts aren't comparable across providers, so each keeps its own series
+        vec![AnimeProvider::Jikan, AnimeProvider::AniList]
+    }
+}
+
+// =============================================================================
+// NEWS PROVIDER REPOSITORY IMPLEMENTATION
+// =============================================================================
+
+#[async_trait]
+impl NewsProviderRepository for ProviderRepositoryAdapter {
+    async fn fetch_news(
+        &self,
+        provider: AnimeProvider,
+        provider_anime_id: u32,
+    ) -> AppResult<Vec<ProviderNewsItem>> {
+        self.timed_call(
+            provider,
+            Duration::from_secs(10),
+            || format!("news for anime {}", provider_anime_id),
+            self.news_with_adapter(provider, provider_anime_id),
+        )
+        .await
+    }
+
+    fn news_providers(&self) -> Vec<AnimeProvider> {
+        // AniList has no news; MyAnimeList news is the only per-anime feed
+        vec![AnimeProvider::Jikan]
+    }
+}
+
+// =============================================================================
+// LINK PROVIDER REPOSITORY IMPLEMENTATION
+// =============================================================================
+
+#[async_trait]
+impl LinkProviderRepository for ProviderRepositoryAdapter {
+    async fn fetch_links(
+        &self,
+        provider: AnimeProvider,
+        provider_anime_id: u32,
+    ) -> AppResult<Vec<ProviderLink>> {
+        self.timed_call(
+            provider,
+            Duration::from_secs(10),
+            || format!("links for anime {}", provider_anime_id),
+            self.links_with_adapter(provider, provider_anime_id),
+        )
+        .await
+    }
+
+    fn link_providers(&self) -> Vec<AnimeProvider> {
+        // AniList first: it tags link types and regional languages
+        vec![AnimeProvider::AniList, AnimeProvider::Jikan]
+    }
+}
+
+// =============================================================================
+// THEME PROVIDER REPOSITORY IMPLEMENTATION
+// =============================================================================
+
+#[async_trait]
+impl ThemeProviderRepository for ProviderRepositoryAdapter {
+    async fn fetch_themes(
+        &self,
+        provider: AnimeProvider,
+        provider_anime_id: u32,
+    ) -> AppResult<Vec<ProviderThemeSong>> {
+        self.timed_call(
+            provider,
+            Duration::from_secs(10),
+            || format!("theme songs for anime {}", provider_anime_id),
+            self.themes_with_adapter(provider, provider_anime_id),
+        )
+        .await
+    }
+
+    fn theme_providers(&self) -> Vec<AnimeProvider> {
+        vec![AnimeProvider::Jikan]
+    }
+}
+
+// =============================================================================
+// DISCOVERY PROVIDER REPOSITORY IMPLEMENTATION
+// =============================================================================
+
+#[async_trait]
+impl DiscoveryProviderRepository for ProviderRepositoryAdapter {
+    async fn fetch_feed(
+        &self,
+        provider: AnimeProvider,
+        feed: DiscoveryFeed,
+        limit: usize,
+    ) -> AppResult<Vec<AnimeData>> {
+        self.timed_call(
+            provider,
+            Duration::from_secs(10),
+            || format!("{} feed", feed.as_str()),
+            self.discovery_with_adapter(provider, feed, limit),
+        )
+        .await
+    }
+
+    fn discovery_providers(&self, feed: DiscoveryFeed) -> Vec<AnimeProvider> {
+        // AniList first: it has a real trending list and the richest metadata
+        let mut providers = vec![AnimeProvider::AniList, AnimeProvider::Jikan];
+        if feed == DiscoveryFeed::Popular && self.tmdb_adapter.is_some() {
+            providers.push(AnimeProvider::TMDB);
+        }
+        providers
+    }
+}
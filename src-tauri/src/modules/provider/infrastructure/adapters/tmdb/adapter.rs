@@ -407,12 +407,14 @@ impl TmdbAdapter {
         Ok(tmdb_response)
     }
 
-    /// Get popular TV shows from Japan (likely anime)
+    /// Get popular animated TV shows from Japan
     pub async fn get_popular_japanese_shows(&self, limit: usize) -> AppResult<Vec<TvShow>> {
         let url = self.build_url_with_params(
             "/discover/tv",
             &[
                 ("with_origin_country".to_string(), "JP".to_string()),
+                // 16 is TMDB's Animation genre; without it live-action dramas dominate
+                ("with_genres".to_string(), "16".to_string()),
                 ("sort_by".to_string(), "popularity.desc".to_string()),
                 ("page".to_string(), "1".to_string()),
             ],
@@ -427,4 +429,14 @@ impl TmdbAdapter {
         log::info!("TMDB: Found {} popular Japanese shows", shows.len());
         Ok(shows)
     }
+
+    /// Get popular Japanese animation mapped to domain data
+    pub async fn get_popular_anime(&self, limit: usize) -> AppResult<Vec<AnimeData>> {
+        self.get_popular_japanese_shows(limit)
+            .await?
+            .into_iter()
+            .map(|show| self.mapper.map_to_anime_data(show))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| AppError::MappingError(format!("Failed to map TMDB data: {}", e)))
+    }
 }
@@ -49,6 +49,28 @@ impl RateLimitClient {
         )
     }
 
+    /// Create a new client for Kitsu API
+    pub fn for_kitsu() -> Self {
+        Self::new(
+            "Kitsu",
+            RetryPolicy::anilist(), // Kitsu has no published limit, retry like AniList
+            // Kitsu: undocumented limit, stay polite at 2 req/sec with small bursts
+            Self::create_rate_limiter(2.0, 5),
+            "miru/1.0 (https://github.com/your-repo/miru)".to_string(),
+        )
+    }
+
+    /// Create a new client for AniDB HTTP API
+    pub fn for_anidb() -> Self {
+        Self::new(
+            "AniDB",
+            RetryPolicy::jikan(), // AniDB bans aggressive clients, retry conservatively
+            // AniDB: at most one request every 2 seconds, no bursts
+            Self::create_rate_limiter(0.5, 1),
+            "miru/1.0 (https://github.com/your-repo/miru)".to_string(),
+        )
+    }
+
     /// Create a new client for TMDB API
     pub fn for_tmdb() -> Self {
         Self::new(
@@ -121,6 +143,18 @@ impl RateLimitClient {
             .await
     }
 
+    /// Make a GET request and return the raw body (for non-JSON APIs such as AniDB's XML)
+    pub async fn get_text(&self, url: &str) -> AppResult<String> {
+        let response = self.send_with_retries(Method::GET, url, None).await?;
+
+        response.text().await.map_err(|e| {
+            AppError::SerializationError(format!(
+                "Failed to read {} response: {}",
+                self.provider_name, e
+            ))
+        })
+    }
+
     /// Make a request with automatic retries and rate limiting
     async fn request_with_retries<T>(
         &self,
@@ -131,6 +165,17 @@ impl RateLimitClient {
     where
         T: serde::de::DeserializeOwned,
     {
+        let response = self.send_with_retries(method, url, body).await?;
+        self.parse_response(response).await
+    }
+
+    /// Send a request, retrying on rate limits and server errors, until a successful response
+    async fn send_with_retries(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<Value>,
+    ) -> AppResult<Response> {
         let mut last_error = None;
 
         for attempt in 0..=self.retry_policy.max_retries {
@@ -156,11 +201,14 @@ impl RateLimitClient {
                             sleep(delay).await;
                             continue;
                         } else {
-                            return Err(AppError::ApiError(format!(
-                                "{} API rate limit exceeded after {} attempts",
-                                self.provider_name,
-                                self.retry_policy.max_retries + 1
-                            )));
+                            return Err(AppError::rate_limited(
+                                format!(
+                                    "{} API rate limit exceeded after {} attempts",
+                                    self.provider_name,
+                                    self.retry_policy.max_retries + 1
+                                ),
+                                rate_limit_info.recommended_delay(),
+                            ));
                         }
                     }
 
@@ -199,8 +247,7 @@ impl RateLimitClient {
                         }
                     }
 
-                    // Parse successful response
-                    return self.parse_response(response).await;
+                    return Ok(response);
                 }
                 Err(e) => {
                     last_error = Some(AppError::ApiError(e.to_string()));
@@ -259,6 +306,14 @@ impl RateLimitClient {
             "Jikan" => {
                 // Jikan typically doesn't need special headers for GET requests
             }
+            "AniDB" => {
+                // AniDB serves gzip-compressed XML
+                request_builder = request_builder.header("Accept", "application/xml");
+            }
+            "Kitsu" => {
+                // Kitsu speaks JSON:API and expects its media type
+                request_builder = request_builder.header("Accept", "application/vnd.api+json");
+            }
             _ => {
                 // Default headers for unknown providers
                 request_builder = request_builder.header("Accept", "application/json");
@@ -333,6 +388,9 @@ mod tests {
 
         let anilist_client = RateLimitClient::for_anilist();
         assert_eq!(anilist_client.provider_name(), "AniList");
+
+        let kitsu_client = RateLimitClient::for_kitsu();
+        assert_eq!(kitsu_client.provider_name(), "Kitsu");
     }
 
     #[test]
@@ -0,0 +1,205 @@
+use super::super::domain::{
+    RatedAnime, Recommendation, RecommendationEdge, RecommendationEngine, RecommendationMerger,
+    RecommendationRepository,
+};
+use crate::modules::anime::AnimeRepository;
+use crate::modules::collection::{CollectionRepository, UserRatingRepository};
+use crate::modules::provider::application::service::ProviderService;
+use crate::shared::application::run_library_batch;
+use crate::shared::domain::value_objects::AnimeProvider;
+use crate::shared::errors::AppResult;
+use crate::{log_debug, log_info};
+use chrono::{Duration, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Stored recommendations younger than this are not fetched again
+const RESYNC_AFTER_DAYS: i64 = 7;
+
+/// Recommendations returned when the caller doesn't ask for a count
+const DEFAULT_LIMIT: u32 = 20;
+
+/// Outcome of refreshing provider recommendations for the rated anime
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct RecommendationSyncSummary {
+    pub anime_synced: u32,
+    pub edges_stored: u32,
+    pub failed: u32,
+}
+
+pub struct RecommendationService {
+    recommendation_repo: Arc<dyn RecommendationRepository>,
+    rating_repo: Arc<dyn UserRatingRepository>,
+    collection_repo: Arc<dyn CollectionRepository>,
+    anime_repo: Arc<dyn AnimeRepository>,
+    provider_service: Arc<ProviderService>,
+}
+
+impl RecommendationService {
+    pub fn new(
+        recommendation_repo: Arc<dyn RecommendationRepository>,
+        rating_repo: Arc<dyn UserRatingRepository>,
+        collection_repo: Arc<dyn CollectionRepository>,
+        anime_repo: Arc<dyn AnimeRepository>,
+        provider_service: Arc<ProviderService>,
+    ) -> Self {
+        Self {
+            recommendation_repo,
+            rating_repo,
+            collection_repo,
+            anime_repo,
+            provider_service,
+        }
+    }
+
+    /// Fetch "users also liked" lists for every rated anime not synced in the last week
+    ///
+    /// A sync replaces the anime's stored edges, so titles a provider stopped
+    /// recommending drop out.
+    pub async fn sync_recommendations(&self) -> AppResult<RecommendationSyncSummary> {
+        let rated_ids: Vec<Uuid> = self
+            .rating_repo
+            .find_all()
+            .await?
+            .into_iter()
+            .map(|rating| rating.anime_id)
+            .collect();
+        let fresh: HashSet<Uuid> = self
+            .recommendation_repo
+            .find_synced_sources(&rated_ids, Utc::now() - Duration::days(RESYNC_AFTER_DAYS))
+            .await?
+            .into_iter()
+            .collect();
+
+        let outcome = run_library_batch(
+            rated_ids.into_iter().filter(|id| !fresh.contains(id)),
+            |anime_id| format!("Failed to sync recommendations for {}", anime_id),
+            |anime_id| async move { self.sync_anime(&anime_id).await },
+        )
+        .await;
+
+        let summary = RecommendationSyncSummary {
+            anime_synced: outcome.completed.len() as u32,
+            edges_stored: outcome.completed.iter().sum::<usize>() as u32,
+            failed: outcome.failed,
+        };
+
+        log_info!(
+            "Synced recommendations for {} anime ({} edges, {} failed, {} up to date)",
+            summary.anime_synced,
+            summary.edges_stored,
+            summary.failed,
+            fresh.len()
+        );
+        Ok(summary)
+    }
+
+    /// Ranked recommendations for anime the user hasn't rated or collected yet
+    pub async fn get_recommendations(&self, limit: Option<u32>) -> AppResult<Vec<Recommendation>> {
+        let rated = self.rated_anime().await?;
+        if rated.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let rated_ids: Vec<Uuid> = rated.iter().map(|anime| anime.anime_id).collect();
+        let mut edges = self.recommendation_repo.find_edges_from(&rated_ids).await?;
+        self.link_library_anime(&mut edges).await?;
+
+        let mut excluded: HashSet<Uuid> = self
+            .collection_repo
+            .find_collected_anime_ids()
+            .await?
+            .into_iter()
+            .collect();
+        excluded.extend(rated_ids);
+
+        let engine = RecommendationEngine::new(&rated);
+        let recommendations =
+            engine.rank(&edges, &excluded, limit.unwrap_or(DEFAULT_LIMIT) as usize);
+
+        log_debug!(
+            "Ranked {} recommendations from {} edges and {} rated anime",
+            recommendations.len(),
+            edges.len(),
+            rated.len()
+        );
+        Ok(recommendations)
+    }
+
+    async fn sync_anime(&self, anime_id: &Uuid) -> AppResult<usize> {
+        let Some(anime) = self.anime_repo.find_by_id(anime_id).await? else {
+            return Ok(0);
+        };
+
+        let recommendations = self
+            .provider_service
+            .fetch_anime_recommendations(&anime.provider_metadata)
+            .await?;
+        let edges = RecommendationMerger::merge(anime.id, recommendations);
+
+        self.recommendation_repo
+            .replace_edges(&anime.id, &edges)
+            .await
+    }
+
+    /// Rated anime with the genres and studios the engine learns affinity from
+    async fn rated_anime(&self) -> AppResult<Vec<RatedAnime>> {
+        let mut rated = Vec::new();
+
+        for rating in self.rating_repo.find_all().await? {
+            let Some(anime) = self.anime_repo.find_by_id(&rating.anime_id).await? else {
+                continue;
+            };
+
+            rated.push(RatedAnime {
+                anime_id: anime.id,
+                title: anime.title.main,
+                genres: anime.genres.into_iter().map(|genre| genre.name).collect(),
+                studios: anime.studios,
+                rating,
+            });
+        }
+
+        Ok(rated)
+    }
+
+    /// Point edge targets that are already stored in the library at their anime
+    async fn link_library_anime(&self, edges: &mut [RecommendationEdge]) -> AppResult<()> {
+        let mal_ids: Vec<i32> = edges.iter().filter_map(|edge| edge.target.mal_id).collect();
+        let anilist_ids: Vec<i32> = edges
+            .iter()
+            .filter_map(|edge| edge.target.anilist_id)
+            .collect();
+
+        let by_mal: HashMap<i32, Uuid> = self
+            .recommendation_repo
+            .find_library_ids(AnimeProvider::Jikan, &mal_ids)
+            .await?
+            .into_iter()
+            .collect();
+        let by_anilist: HashMap<i32, Uuid> = self
+            .recommendation_repo
+            .find_library_ids(AnimeProvider::AniList, &anilist_ids)
+            .await?
+            .into_iter()
+            .collect();
+
+        for edge in edges.iter_mut() {
+            let target = &mut edge.target;
+            target.anime_id = target
+                .mal_id
+                .and_then(|id| by_mal.get(&id).copied())
+                .or_else(|| {
+                    target
+                        .anilist_id
+                        .and_then(|id| by_anilist.get(&id).copied())
+                });
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use super::application::service::{RecommendationService, RecommendationSyncSummary};
+use super::domain::Recommendation;
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::sync::Arc;
+use tauri::State;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct GetRecommendationsRequest {
+    /// Maximum number of recommendations; 20 when unset
+    pub limit: Option<u32>,
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_recommendations(
+    request: GetRecommendationsRequest,
+    recommendation_service: State<'_, Arc<RecommendationService>>,
+) -> Result<Vec<Recommendation>, String> {
+    recommendation_service
+        .get_recommendations(request.limit)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Refresh provider recommendations of rated anime not synced in the last week
+#[tauri::command]
+#[specta::specta]
+pub async fn sync_recommendations(
+    recommendation_service: State<'_, Arc<RecommendationService>>,
+) -> Result<RecommendationSyncSummary, String> {
+    recommendation_service
+        .sync_recommendations()
+        .await
+        .map_err(|e| e.to_string())
+}
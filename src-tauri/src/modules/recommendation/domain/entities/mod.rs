@@ -0,0 +1,5 @@
+pub mod provider_recommendation;
+pub mod recommendation;
+
+pub use provider_recommendation::ProviderRecommendation;
+pub use recommendation::{RatedAnime, Recommendation, RecommendationEdge, RecommendedAnime};
@@ -0,0 +1,16 @@
+use crate::shared::domain::value_objects::AnimeProvider;
+
+/// A "users also liked" entry as returned by one provider
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProviderRecommendation {
+    pub provider: AnimeProvider,
+    pub mal_id: Option<i32>,
+    pub anilist_id: Option<i32>,
+    pub title: String,
+    pub image_url: Option<String>,
+    /// Only AniList lists genres and studios of recommended anime
+    pub genres: Vec<String>,
+    pub studios: Vec<String>,
+    /// Users backing the recommendation on this provider
+    pub votes: i32,
+}
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use uuid::Uuid;
+
+use crate::modules::collection::UserRating;
+
+/// Anime on the receiving end of a recommendation edge
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecommendedAnime {
+    pub mal_id: Option<i32>,
+    pub anilist_id: Option<i32>,
+    pub title: String,
+    pub image_url: Option<String>,
+    pub genres: Vec<String>,
+    pub studios: Vec<String>,
+    /// Set when the anime is already stored in the library
+    pub anime_id: Option<Uuid>,
+}
+
+impl RecommendedAnime {
+    /// Whether both refer to the same anime on either provider
+    pub fn same_as(&self, other: &RecommendedAnime) -> bool {
+        (self.mal_id.is_some() && self.mal_id == other.mal_id)
+            || (self.anilist_id.is_some() && self.anilist_id == other.anilist_id)
+    }
+}
+
+/// "Users who liked the source also liked the target", merged across providers
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecommendationEdge {
+    pub source_anime_id: Uuid,
+    pub target: RecommendedAnime,
+    pub votes: i32,
+}
+
+/// A rated library anime feeding the recommendation engine
+#[derive(Debug, Clone)]
+pub struct RatedAnime {
+    pub anime_id: Uuid,
+    pub title: String,
+    pub genres: Vec<String>,
+    pub studios: Vec<String>,
+    pub rating: UserRating,
+}
+
+/// An anime suggested to the user, with the reasons behind it
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct Recommendation {
+    /// Library id when the anime is already stored
+    pub anime_id: Option<Uuid>,
+    pub mal_id: Option<i32>,
+    pub anilist_id: Option<i32>,
+    pub title: String,
+    pub image_url: Option<String>,
+    pub genres: Vec<String>,
+    pub score: f32,
+    /// Human readable explanations, strongest first, e.g. `Because you loved Cowboy Bebop`
+    pub reasons: Vec<String>,
+}
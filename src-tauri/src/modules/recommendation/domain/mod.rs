@@ -0,0 +1,10 @@
+pub mod entities;
+pub mod repositories;
+pub mod services;
+
+// Re-exports for easy access
+pub use entities::{
+    ProviderRecommendation, RatedAnime, Recommendation, RecommendationEdge, RecommendedAnime,
+};
+pub use repositories::RecommendationRepository;
+pub use services::{RecommendationEngine, RecommendationMerger};
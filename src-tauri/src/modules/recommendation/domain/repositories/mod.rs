@@ -0,0 +1,3 @@
+pub mod recommendation_repository;
+
+pub use recommendation_repository::RecommendationRepository;
@@ -0,0 +1,38 @@
+use super::super::entities::RecommendationEdge;
+use crate::shared::domain::value_objects::AnimeProvider;
+use crate::shared::errors::AppResult;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[async_trait]
+pub trait RecommendationRepository: Send + Sync {
+    /// Replace every stored edge leaving `source_anime_id` and mark it synced
+    ///
+    /// Returns the number of edges written, which may be none.
+    async fn replace_edges(
+        &self,
+        source_anime_id: &Uuid,
+        edges: &[RecommendationEdge],
+    ) -> AppResult<usize>;
+
+    /// Edges leaving any of the given anime
+    async fn find_edges_from(
+        &self,
+        source_anime_ids: &[Uuid],
+    ) -> AppResult<Vec<RecommendationEdge>>;
+
+    /// Anime among `source_anime_ids` synced after `since`, with or without edges
+    async fn find_synced_sources(
+        &self,
+        source_anime_ids: &[Uuid],
+        since: DateTime<Utc>,
+    ) -> AppResult<Vec<Uuid>>;
+
+    /// Library anime known to `provider` under any of `external_ids`
+    async fn find_library_ids(
+        &self,
+        provider: AnimeProvider,
+        external_ids: &[i32],
+    ) -> AppResult<Vec<(i32, Uuid)>>;
+}
@@ -0,0 +1,5 @@
+pub mod recommendation_engine;
+pub mod recommendation_merger;
+
+pub use recommendation_engine::RecommendationEngine;
+pub use recommendation_merger::RecommendationMerger;
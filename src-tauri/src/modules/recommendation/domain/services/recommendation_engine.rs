@@ -0,0 +1,371 @@
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::modules::collection::domain::entities::user_rating::{
+    OverallFeeling, RecommendationPreference,
+};
+use crate::modules::collection::UserRating;
+use crate::modules::recommendation::domain::entities::{
+    RatedAnime, Recommendation, RecommendationEdge, RecommendedAnime,
+};
+
+/// Votes at which a provider recommendation counts at full strength
+const FULL_STRENGTH_VOTES: f32 = 100.0;
+
+/// Strength of a recommendation nobody voted on yet
+const MIN_EDGE_STRENGTH: f32 = 0.1;
+
+/// How much genre and studio affinity adds on top of the graph score
+const AFFINITY_WEIGHT: f32 = 0.5;
+
+/// Ratings of a genre or studio needed before its affinity counts in full
+const AFFINITY_CONFIDENCE: f32 = 3.0;
+
+/// Rated anime named in the explanation of a single recommendation
+const MAX_SEED_REASONS: usize = 2;
+
+struct Seed {
+    weight: f32,
+    reason: String,
+}
+
+struct Candidate {
+    target: RecommendedAnime,
+    graph_score: f32,
+    contributions: Vec<(f32, String)>,
+}
+
+/// Ranks provider recommendation edges by the user's own ratings
+///
+/// Every rated anime is a seed: edges from liked anime pull their targets up,
+/// edges from disliked or avoided anime push them down. Genres and studios the
+/// user rates highly add a smaller bonus on top.
+pub struct RecommendationEngine {
+    seeds: HashMap<Uuid, Seed>,
+    genre_affinity: HashMap<String, (String, f32)>,
+    studio_affinity: HashMap<String, (String, f32)>,
+}
+
+impl RecommendationEngine {
+    pub fn new(rated: &[RatedAnime]) -> Self {
+        let mut seeds = HashMap::new();
+        let mut genres: HashMap<String, (String, f32, f32)> = HashMap::new();
+        let mut studios: HashMap<String, (String, f32, f32)> = HashMap::new();
+
+        for anime in rated {
+            let weight = Self::seed_weight(&anime.rating);
+            seeds.insert(
+                anime.anime_id,
+                Seed {
+                    weight,
+                    reason: Self::seed_reason(anime),
+                },
+            );
+
+            for (names, totals) in [(&anime.genres, &mut genres), (&anime.studios, &mut studios)] {
+                for name in names {
+                    let entry = totals
+                        .entry(name.to_lowercase())
+                        .or_insert_with(|| (name.clone(), 0.0, 0.0));
+                    entry.1 += weight;
+                    entry.2 += 1.0;
+                }
+            }
+        }
+
+        Self {
+            seeds,
+            genre_affinity: Self::affinities(genres),
+            studio_affinity: Self::affinities(studios),
+        }
+    }
+
+    /// How strongly a rating pulls recommendations: positive for liked anime,
+    /// negative for disliked or avoided ones
+    pub fn seed_weight(rating: &UserRating) -> f32 {
+        match rating.recommendation_preference {
+            Some(RecommendationPreference::Avoid) => -1.0,
+            Some(RecommendationPreference::Recommend) => rating.get_recommendation_impact(),
+            _ if rating.is_positive_rating() => rating.get_recommendation_impact(),
+            // Okay -0.6, Disliked -0.8, Hated -1.0
+            _ => rating.overall_feeling.to_score() / 10.0 - 1.0,
+        }
+    }
+
+    /// Best `limit` recommendations, skipping rated anime and anime in `excluded`
+    pub fn rank(
+        &self,
+        edges: &[RecommendationEdge],
+        excluded: &HashSet<Uuid>,
+        limit: usize,
+    ) -> Vec<Recommendation> {
+        let mut candidates: Vec<Candidate> = Vec::new();
+
+        for edge in edges {
+            let Some(seed) = self.seeds.get(&edge.source_anime_id) else {
+                continue;
+            };
+            if edge
+                .target
+                .anime_id
+                .is_some_and(|id| excluded.contains(&id) || self.seeds.contains_key(&id))
+            {
+                continue;
+            }
+
+            let contribution = seed.weight * Self::edge_strength(edge.votes);
+            let candidate = match candidates
+                .iter_mut()
+                .position(|candidate| candidate.target.same_as(&edge.target))
+            {
+                Some(index) => {
+                    let candidate = &mut candidates[index];
+                    let target = &mut candidate.target;
+                    target.mal_id = target.mal_id.or(edge.target.mal_id);
+                    target.anilist_id = target.anilist_id.or(edge.target.anilist_id);
+                    if target.genres.is_empty() {
+                        target.genres = edge.target.genres.clone();
+                    }
+                    if target.studios.is_empty() {
+                        target.studios = edge.target.studios.clone();
+                    }
+                    candidate
+                }
+                None => {
+                    candidates.push(Candidate {
+                        target: edge.target.clone(),
+                        graph_score: 0.0,
+                        contributions: Vec::new(),
+                    });
+                    candidates.last_mut().expect("just pushed")
+                }
+            };
+
+            candidate.graph_score += contribution;
+            if contribution > 0.0 {
+                candidate
+                    .contributions
+                    .push((contribution, seed.reason.clone()));
+            }
+        }
+
+        let mut recommendations: Vec<Recommendation> = candidates
+            .into_iter()
+            // Only anime reached from something the user liked
+            .filter(|candidate| !candidate.contributions.is_empty())
+            .filter_map(|candidate| self.score(candidate))
+            .collect();
+
+        recommendations.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.title.cmp(&b.title))
+        });
+        recommendations.truncate(limit);
+        recommendations
+    }
+
+    fn score(&self, mut candidate: Candidate) -> Option<Recommendation> {
+        let (genre_score, genre_matches) =
+            Self::affinity(&self.genre_affinity, &candidate.target.genres);
+        let (studio_score, studio_matches) =
+            Self::affinity(&self.studio_affinity, &candidate.target.studios);
+
+        let score = candidate.graph_score + AFFINITY_WEIGHT * (genre_score + studio_score);
+        if score <= 0.0 {
+            return None;
+        }
+
+        candidate
+            .contributions
+            .sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+        let mut reasons: Vec<String> = Vec::new();
+        for (_, reason) in candidate.contributions {
+            if reasons.len() == MAX_SEED_REASONS {
+                break;
+            }
+            if !reasons.contains(&reason) {
+                reasons.push(reason);
+            }
+        }
+        if !genre_matches.is_empty() {
+            reasons.push(format!(
+                "Matches genres you rate highly: {}",
+                genre_matches
+                    .iter()
+                    .take(2)
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+        if let Some(studio) = studio_matches.first() {
+            reasons.push(format!("Made by {}, a studio you rate highly", studio));
+        }
+
+        let target = candidate.target;
+        Some(Recommendation {
+            anime_id: target.anime_id,
+            mal_id: target.mal_id,
+            anilist_id: target.anilist_id,
+            title: target.title,
+            image_url: target.image_url,
+            genres: target.genres,
+            score,
+            reasons,
+        })
+    }
+
+    /// Mean affinity over `names`, with the positively rated ones strongest first
+    fn affinity(profile: &HashMap<String, (String, f32)>, names: &[String]) -> (f32, Vec<String>) {
+        if names.is_empty() {
+            return (0.0, Vec::new());
+        }
+
+        let mut matches: Vec<(String, f32)> = names
+            .iter()
+            .filter_map(|name| profile.get(&name.to_lowercase()).cloned())
+            .collect();
+        let score = matches.iter().map(|(_, affinity)| affinity).sum::<f32>() / names.len() as f32;
+
+        matches.retain(|(_, affinity)| *affinity > 0.0);
+        matches.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        (score, matches.into_iter().map(|(name, _)| name).collect())
+    }
+
+    /// Mean seed weight per name, damped until enough ratings back it
+    fn affinities(totals: HashMap<String, (String, f32, f32)>) -> HashMap<String, (String, f32)> {
+        totals
+            .into_iter()
+            .map(|(key, (name, sum, count))| {
+                let confidence = (count / AFFINITY_CONFIDENCE).min(1.0);
+                (key, (name, sum / count * confidence))
+            })
+            .collect()
+    }
+
+    fn edge_strength(votes: i32) -> f32 {
+        ((votes.max(0) as f32 + 1.0).ln() / (FULL_STRENGTH_VOTES + 1.0).ln())
+            .clamp(MIN_EDGE_STRENGTH, 1.0)
+    }
+
+    fn seed_reason(anime: &RatedAnime) -> String {
+        if anime.rating.recommendation_preference == Some(RecommendationPreference::Recommend) {
+            return format!("Because you want more like {}", anime.title);
+        }
+
+        let verb = match anime.rating.overall_feeling {
+            OverallFeeling::Loved => "loved",
+            OverallFeeling::Enjoyed => "enjoyed",
+            OverallFeeling::Liked => "liked",
+            _ => "rated",
+        };
+        format!("Because you {} {}", verb, anime.title)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::domain::LOCAL_USER_ID;
+
+    fn rated(title: &str, feeling: OverallFeeling, genres: &[&str]) -> RatedAnime {
+        let anime_id = Uuid::new_v4();
+        RatedAnime {
+            anime_id,
+            title: title.to_string(),
+            genres: genres.iter().map(|g| g.to_string()).collect(),
+            studios: Vec::new(),
+            rating: UserRating::new(anime_id, LOCAL_USER_ID.to_string(), feeling),
+        }
+    }
+
+    fn edge(source: &RatedAnime, mal_id: i32, votes: i32, genres: &[&str]) -> RecommendationEdge {
+        RecommendationEdge {
+            source_anime_id: source.anime_id,
+            target: RecommendedAnime {
+                mal_id: Some(mal_id),
+                anilist_id: None,
+                title: format!("Anime {}", mal_id),
+                image_url: None,
+                genres: genres.iter().map(|g| g.to_string()).collect(),
+                studios: Vec::new(),
+                anime_id: None,
+            },
+            votes,
+        }
+    }
+
+    #[test]
+    fn test_explains_recommendation_and_skips_excluded() {
+        let bebop = rated("Cowboy Bebop", OverallFeeling::Loved, &[]);
+        let engine = RecommendationEngine::new(std::slice::from_ref(&bebop));
+
+        let collected = Uuid::new_v4();
+        let mut in_collection = edge(&bebop, 2, 500, &[]);
+        in_collection.target.anime_id = Some(collected);
+
+        let recommendations = engine.rank(
+            &[edge(&bebop, 1, 50, &[]), in_collection],
+            &HashSet::from([collected]),
+            10,
+        );
+
+        assert_eq!(recommendations.len(), 1);
+        assert_eq!(recommendations[0].mal_id, Some(1));
+        assert_eq!(
+            recommendations[0].reasons,
+            vec!["Because you loved Cowboy Bebop".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_avoided_anime_pushes_shared_targets_down() {
+        let liked = rated("Trigun", OverallFeeling::Liked, &[]);
+        let mut avoided = rated("School Days", OverallFeeling::Okay, &[]);
+        avoided
+            .rating
+            .set_recommendation_preference(Some(RecommendationPreference::Avoid));
+        let engine = RecommendationEngine::new(&[liked.clone(), avoided.clone()]);
+
+        let recommendations = engine.rank(
+            &[
+                edge(&liked, 1, 10, &[]),
+                edge(&avoided, 1, 100, &[]),
+                edge(&liked, 2, 10, &[]),
+            ],
+            &HashSet::new(),
+            10,
+        );
+
+        let ids: Vec<Option<i32>> = recommendations.iter().map(|r| r.mal_id).collect();
+        assert_eq!(ids, vec![Some(2)]);
+    }
+
+    #[test]
+    fn test_genre_affinity_breaks_ties() {
+        let rated_anime = vec![
+            rated("Clannad", OverallFeeling::Loved, &["Drama", "Romance"]),
+            rated("Anohana", OverallFeeling::Enjoyed, &["Drama"]),
+            rated("Toradora", OverallFeeling::Loved, &["Romance", "Drama"]),
+        ];
+        let engine = RecommendationEngine::new(&rated_anime);
+
+        let recommendations = engine.rank(
+            &[
+                edge(&rated_anime[0], 1, 20, &["Mecha"]),
+                edge(&rated_anime[0], 2, 20, &["Drama"]),
+            ],
+            &HashSet::new(),
+            10,
+        );
+
+        assert_eq!(recommendations[0].mal_id, Some(2));
+        assert!(recommendations[0].score > recommendations[1].score);
+        assert_eq!(
+            recommendations[0].reasons.last().unwrap(),
+            "Matches genres you rate highly: Drama"
+        );
+    }
+}
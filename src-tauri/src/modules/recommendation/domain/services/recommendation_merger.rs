@@ -0,0 +1,123 @@
+use uuid::Uuid;
+
+use crate::modules::recommendation::domain::entities::{
+    ProviderRecommendation, RecommendationEdge, RecommendedAnime,
+};
+
+/// Merges MyAnimeList and AniList recommendations of one anime into one edge per target
+///
+/// AniList entries carry the MyAnimeList id too, so both providers' entries for
+/// the same anime link up. Votes are summed and AniList fills in genres and studios.
+pub struct RecommendationMerger;
+
+impl RecommendationMerger {
+    pub fn merge(
+        source_anime_id: Uuid,
+        recommendations: Vec<ProviderRecommendation>,
+    ) -> Vec<RecommendationEdge> {
+        let mut edges: Vec<RecommendationEdge> = Vec::new();
+
+        for recommendation in recommendations {
+            if recommendation.mal_id.is_none() && recommendation.anilist_id.is_none() {
+                continue;
+            }
+
+            let target = RecommendedAnime {
+                mal_id: recommendation.mal_id,
+                anilist_id: recommendation.anilist_id,
+                title: recommendation.title,
+                image_url: recommendation.image_url,
+                genres: recommendation.genres,
+                studios: recommendation.studios,
+                anime_id: None,
+            };
+            let votes = recommendation.votes.max(0);
+
+            match edges.iter_mut().find(|edge| edge.target.same_as(&target)) {
+                Some(edge) => {
+                    edge.votes += votes;
+                    let merged = &mut edge.target;
+                    merged.mal_id = merged.mal_id.or(target.mal_id);
+                    merged.anilist_id = merged.anilist_id.or(target.anilist_id);
+                    if merged.image_url.is_none() {
+                        merged.image_url = target.image_url;
+                    }
+                    if merged.genres.is_empty() {
+                        merged.genres = target.genres;
+                    }
+                    if merged.studios.is_empty() {
+                        merged.studios = target.studios;
+                    }
+                }
+                None => edges.push(RecommendationEdge {
+                    source_anime_id,
+                    target,
+                    votes,
+                }),
+            }
+        }
+
+        edges.sort_by_key(|edge| std::cmp::Reverse(edge.votes));
+        edges
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::domain::value_objects::AnimeProvider;
+
+    fn recommendation(
+        provider: AnimeProvider,
+        mal_id: Option<i32>,
+        anilist_id: Option<i32>,
+        votes: i32,
+    ) -> ProviderRecommendation {
+        ProviderRecommendation {
+            provider,
+            mal_id,
+            anilist_id,
+            title: "Samurai Champloo".to_string(),
+            image_url: None,
+            genres: match provider {
+                AnimeProvider::AniList => vec!["Action".to_string()],
+                _ => Vec::new(),
+            },
+            studios: Vec::new(),
+            votes,
+        }
+    }
+
+    #[test]
+    fn test_links_providers_by_mal_id_and_sums_votes() {
+        let source = Uuid::new_v4();
+        let edges = RecommendationMerger::merge(
+            source,
+            vec![
+                recommendation(AnimeProvider::Jikan, Some(205), None, 120),
+                recommendation(AnimeProvider::AniList, Some(205), Some(205), 30),
+            ],
+        );
+
+        assert_eq!(edges.len(), 1);
+        assert_eq!(edges[0].source_anime_id, source);
+        assert_eq!(edges[0].votes, 150);
+        assert_eq!(edges[0].target.anilist_id, Some(205));
+        assert_eq!(edges[0].target.genres, vec!["Action".to_string()]);
+    }
+
+    #[test]
+    fn test_keeps_unlinked_targets_apart_and_sorts_by_votes() {
+        let edges = RecommendationMerger::merge(
+            Uuid::new_v4(),
+            vec![
+                recommendation(AnimeProvider::Jikan, Some(1), None, 5),
+                recommendation(AnimeProvider::AniList, None, Some(2), 40),
+                recommendation(AnimeProvider::AniList, None, None, 99),
+            ],
+        );
+
+        let votes: Vec<i32> = edges.iter().map(|edge| edge.votes).collect();
+        assert_eq!(votes, vec![40, 5]);
+    }
+}
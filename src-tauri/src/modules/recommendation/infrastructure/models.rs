@@ -0,0 +1,69 @@
+use crate::modules::recommendation::domain::{RecommendationEdge, RecommendedAnime};
+use crate::schema::anime_recommendations;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use uuid::Uuid;
+
+// For reading from database
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone)]
+#[diesel(table_name = anime_recommendations)]
+pub struct RecommendationEdgeModel {
+    pub id: Uuid,
+    pub source_anime_id: Uuid,
+    pub target_mal_id: Option<i32>,
+    pub target_anilist_id: Option<i32>,
+    pub title: String,
+    pub image_url: Option<String>,
+    pub genres: serde_json::Value,
+    pub studios: serde_json::Value,
+    pub votes: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// For inserting new rows
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = anime_recommendations)]
+pub struct NewRecommendationEdgeModel {
+    pub source_anime_id: Uuid,
+    pub target_mal_id: Option<i32>,
+    pub target_anilist_id: Option<i32>,
+    pub title: String,
+    pub image_url: Option<String>,
+    pub genres: serde_json::Value,
+    pub studios: serde_json::Value,
+    pub votes: i32,
+}
+
+impl From<RecommendationEdgeModel> for RecommendationEdge {
+    fn from(model: RecommendationEdgeModel) -> Self {
+        Self {
+            source_anime_id: model.source_anime_id,
+            target: RecommendedAnime {
+                mal_id: model.target_mal_id,
+                anilist_id: model.target_anilist_id,
+                title: model.title,
+                image_url: model.image_url,
+                genres: serde_json::from_value(model.genres).unwrap_or_default(),
+                studios: serde_json::from_value(model.studios).unwrap_or_default(),
+                anime_id: None,
+            },
+            votes: model.votes,
+        }
+    }
+}
+
+impl From<&RecommendationEdge> for NewRecommendationEdgeModel {
+    fn from(edge: &RecommendationEdge) -> Self {
+        Self {
+            source_anime_id: edge.source_anime_id,
+            target_mal_id: edge.target.mal_id,
+            target_anilist_id: edge.target.anilist_id,
+            title: edge.target.title.clone(),
+            image_url: edge.target.image_url.clone(),
+            genres: serde_json::Value::from(edge.target.genres.clone()),
+            studios: serde_json::Value::from(edge.target.studios.clone()),
+            votes: edge.votes,
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod recommendation_repository_impl;
+
+pub use recommendation_repository_impl::RecommendationRepositoryImpl;
@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use tokio::task;
+use uuid::Uuid;
+
+use crate::modules::recommendation::domain::{RecommendationEdge, RecommendationRepository};
+use crate::modules::recommendation::infrastructure::models::{
+    NewRecommendationEdgeModel, RecommendationEdgeModel,
+};
+use crate::schema::{anime_external_ids, anime_recommendation_syncs, anime_recommendations};
+use crate::shared::domain::value_objects::AnimeProvider;
+use crate::shared::errors::AppResult;
+use crate::shared::Database;
+
+pub struct RecommendationRepositoryImpl {
+    db: Arc<Database>,
+}
+
+impl RecommendationRepositoryImpl {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl RecommendationRepository for RecommendationRepositoryImpl {
+    async fn replace_edges(
+        &self,
+        source_anime_id: &Uuid,
+        edges: &[RecommendationEdge],
+    ) -> AppResult<usize> {
+        let db = Arc::clone(&self.db);
+        let source_anime_id = *source_anime_id;
+        let rows: Vec<NewRecommendationEdgeModel> = edges.iter().map(Into::into).collect();
+
+        task::spawn_blocking(move || -> AppResult<usize> {
+            let mut conn = db.get_connection()?;
+            conn.transaction(|conn| {
+                diesel::delete(
+                    anime_recommendations::table
+                        .filter(anime_recommendations::source_anime_id.eq(source_anime_id)),
+                )
+                .execute(conn)?;
+
+                diesel::insert_into(anime_recommendation_syncs::table)
+                    .values(anime_recommendation_syncs::anime_id.eq(source_anime_id))
+                    .on_conflict(anime_recommendation_syncs::anime_id)
+                    .do_update()
+                    .set(anime_recommendation_syncs::synced_at.eq(diesel::dsl::now))
+                    .execute(conn)?;
+
+                if rows.is_empty() {
+                    return Ok(0);
+                }
+                let written = diesel::insert_into(anime_recommendations::table)
+                    .values(&rows)
+                    .execute(conn)?;
+                Ok(written)
+            })
+        })
+        .await?
+    }
+
+    async fn find_edges_from(
+        &self,
+        source_anime_ids: &[Uuid],
+    ) -> AppResult<Vec<RecommendationEdge>> {
+        let db = Arc::clone(&self.db);
+        let source_anime_ids = source_anime_ids.to_vec();
+
+        let models = task::spawn_blocking(move || -> AppResult<Vec<RecommendationEdgeModel>> {
+            let mut conn = db.get_connection()?;
+            let models = anime_recommendations::table
+                .filter(anime_recommendations::source_anime_id.eq_any(&source_anime_ids))
+                .order(anime_recommendations::votes.desc())
+                .select(RecommendationEdgeModel::as_select())
+                .load(&mut conn)?;
+            Ok(models)
+        })
+        .await??;
+
+        Ok(models.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_synced_sources(
+        &self,
+        source_anime_ids: &[Uuid],
+        since: DateTime<Utc>,
+    ) -> AppResult<Vec<Uuid>> {
+        let db = Arc::clone(&self.db);
+        let source_anime_ids = source_anime_ids.to_vec();
+
+        task::spawn_blocking(move || -> AppResult<Vec<Uuid>> {
+            let mut conn = db.get_connection()?;
+            let ids = anime_recommendation_syncs::table
+                .filter(anime_recommendation_syncs::anime_id.eq_any(&source_anime_ids))
+                .filter(anime_recommendation_syncs::synced_at.gt(since))
+                .select(anime_recommendation_syncs::anime_id)
+                .load(&mut conn)?;
+            Ok(ids)
+        })
+        .await?
+    }
+
+    async fn find_library_ids(
+        &self,
+        provider: AnimeProvider,
+        external_ids: &[i32],
+    ) -> AppResult<Vec<(i32, Uuid)>> {
+        if external_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let db = Arc::clone(&self.db);
+        let provider_code = provider.to_string();
+        let external_ids: Vec<String> = external_ids.iter().map(|id| id.to_string()).collect();
+
+        let rows = task::spawn_blocking(move || -> AppResult<Vec<(String, Uuid)>> {
+            let mut conn = db.get_connection()?;
+            let rows = anime_external_ids::table
+                .filter(anime_external_ids::provider_code.eq(&provider_code))
+                .filter(anime_external_ids::external_id.eq_any(&external_ids))
+                .select((
+                    anime_external_ids::external_id,
+                    anime_external_ids::anime_id,
+                ))
+                .load(&mut conn)?;
+            Ok(rows)
+        })
+        .await??;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(external_id, anime_id)| external_id.parse().ok().map(|id| (id, anime_id)))
+            .collect())
+    }
+}
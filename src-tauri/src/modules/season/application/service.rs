@@ -0,0 +1,227 @@
+use super::super::domain::{
+    AiringScheduleRepository, AiringTimeSource, NextEpisode, ScheduleBuilder, ScheduledAnime,
+    SeasonSchedule,
+};
+use crate::modules::anime::{airing_library_anime, AnimeDetailed, AnimeQueryRepository};
+use crate::modules::provider::application::service::ProviderService;
+use crate::shared::application::run_library_batch;
+use crate::shared::errors::{AppError, AppResult};
+use crate::{log_debug, log_info};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Airing times from the last week are refreshed too, so delayed episodes move
+const RESYNC_WINDOW_DAYS: i64 = 7;
+
+/// Outcome of refreshing airing times for the library's airing anime
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleSyncSummary {
+    pub anime_synced: u32,
+    pub episodes_stored: u32,
+    pub failed: u32,
+}
+
+pub struct ScheduleService {
+    schedule_repo: Arc<dyn AiringScheduleRepository>,
+    anime_query_repo: Arc<dyn AnimeQueryRepository>,
+    provider_service: Arc<ProviderService>,
+}
+
+impl ScheduleService {
+    pub fn new(
+        schedule_repo: Arc<dyn AiringScheduleRepository>,
+        anime_query_repo: Arc<dyn AnimeQueryRepository>,
+        provider_service: Arc<ProviderService>,
+    ) -> Self {
+        Self {
+            schedule_repo,
+            anime_query_repo,
+            provider_service,
+        }
+    }
+
+    /// Fetch airing times and broadcast slots for every airing or upcoming anime in the library
+    ///
+    /// Episodes that aired within the last week are fetched again, so a delayed
+    /// episode moves to its new slot.
+    pub async fn sync_airing_schedule(&self) -> AppResult<ScheduleSyncSummary> {
+        let outcome = run_library_batch(
+            self.airing_anime().await?,
+            |anime| format!("Failed to sync airing schedule for '{}'", anime.title.main),
+            |anime| async move { self.sync_anime(&anime).await },
+        )
+        .await;
+
+        let summary = ScheduleSyncSummary {
+            anime_synced: outcome.completed.len() as u32,
+            episodes_stored: outcome.completed.iter().sum::<usize>() as u32,
+            failed: outcome.failed,
+        };
+
+        log_info!(
+            "Synced airing schedule for {} anime ({} episodes, {} failed)",
+            summary.anime_synced,
+            summary.episodes_stored,
+            summary.failed
+        );
+        Ok(summary)
+    }
+
+    /// Airing calendar for the week containing `week_of` (today when unset) in `timezone`
+    ///
+    /// Anime without per-episode airing times are placed by their broadcast slot.
+    pub async fn get_weekly_schedule(
+        &self,
+        timezone: &str,
+        week_of: Option<NaiveDate>,
+    ) -> AppResult<SeasonSchedule> {
+        let timezone: Tz = timezone
+            .parse()
+            .map_err(|_| AppError::ValidationError(format!("Unknown timezone: {}", timezone)))?;
+        let week_of = week_of.unwrap_or_else(|| Utc::now().with_timezone(&timezone).date_naive());
+
+        let mut builder = ScheduleBuilder::new(timezone, week_of);
+        let (from, to) = builder.range();
+
+        let anime: HashMap<Uuid, ScheduledAnime> = self
+            .airing_anime()
+            .await?
+            .into_iter()
+            .map(|anime| {
+                (
+                    anime.id,
+                    ScheduledAnime {
+                        id: anime.id,
+                        title: anime.title.main,
+                        image_url: anime.image_url,
+                    },
+                )
+            })
+            .collect();
+        let anime_ids: Vec<Uuid> = anime.keys().copied().collect();
+
+        let scheduled: HashSet<Uuid> = self
+            .schedule_repo
+            .find_scheduled_anime(&anime_ids)
+            .await?
+            .into_iter()
+            .collect();
+
+        for (anime_id, episode) in self
+            .schedule_repo
+            .find_episodes_between(&anime_ids, from, to)
+            .await?
+        {
+            if let Some(anime) = anime.get(&anime_id) {
+                builder.add_episode(anime, &episode);
+            }
+        }
+
+        for (anime_id, broadcast) in self.schedule_repo.find_broadcasts(&anime_ids).await? {
+            if scheduled.contains(&anime_id) {
+                continue;
+            }
+            if let Some(anime) = anime.get(&anime_id) {
+                builder.add_broadcast(anime, &broadcast);
+            }
+        }
+
+        Ok(builder.build())
+    }
+
+    /// Next episode of each anime with a countdown, soonest first
+    ///
+    /// Defaults to every airing or upcoming anime in the library. Anime with no
+    /// known upcoming airing are left out.
+    pub async fn get_next_episodes(
+        &self,
+        anime_ids: Option<Vec<Uuid>>,
+    ) -> AppResult<Vec<NextEpisode>> {
+        let anime_ids = match anime_ids {
+            Some(anime_ids) => anime_ids,
+            None => self
+                .airing_anime()
+                .await?
+                .into_iter()
+                .map(|anime| anime.id)
+                .collect(),
+        };
+        let now = Utc::now();
+
+        let mut next: Vec<NextEpisode> = self
+            .schedule_repo
+            .find_next_episodes(&anime_ids, now)
+            .await?
+            .into_iter()
+            .map(|(anime_id, episode)| NextEpisode {
+                anime_id,
+                episode: Some(episode.episode),
+                airs_at: episode.airs_at,
+                airs_in_seconds: seconds_until(now, episode.airs_at),
+                source: AiringTimeSource::Schedule,
+            })
+            .collect();
+
+        // A stored schedule without upcoming episodes means the anime has finished
+        let scheduled: HashSet<Uuid> = self
+            .schedule_repo
+            .find_scheduled_anime(&anime_ids)
+            .await?
+            .into_iter()
+            .collect();
+
+        for (anime_id, broadcast) in self.schedule_repo.find_broadcasts(&anime_ids).await? {
+            if scheduled.contains(&anime_id) {
+                continue;
+            }
+            if let Some(airs_at) = broadcast.next_airing_after(now) {
+                next.push(NextEpisode {
+                    anime_id,
+                    episode: None,
+                    airs_at,
+                    airs_in_seconds: seconds_until(now, airs_at),
+                    source: AiringTimeSource::Broadcast,
+                });
+            }
+        }
+
+        next.sort_by_key(|episode| episode.airs_at);
+        Ok(next)
+    }
+
+    async fn sync_anime(&self, anime: &AnimeDetailed) -> AppResult<usize> {
+        let since = Utc::now() - Duration::days(RESYNC_WINDOW_DAYS);
+        let schedule = self
+            .provider_service
+            .fetch_airing_schedule(&anime.provider_metadata, since)
+            .await?;
+
+        if let Some(broadcast) = &schedule.broadcast {
+            self.schedule_repo
+                .save_broadcast(&anime.id, broadcast)
+                .await?;
+        }
+
+        let stored = self
+            .schedule_repo
+            .upsert_episodes(&anime.id, &schedule.episodes)
+            .await?;
+        log_debug!("Stored {} airing times for '{}'", stored, anime.title.main);
+
+        Ok(stored)
+    }
+
+    async fn airing_anime(&self) -> AppResult<Vec<AnimeDetailed>> {
+        airing_library_anime(self.anime_query_repo.as_ref()).await
+    }
+}
+
+fn seconds_until(now: DateTime<Utc>, airs_at: DateTime<Utc>) -> u32 {
+    u32::try_from((airs_at - now).num_seconds().max(0)).unwrap_or(u32::MAX)
+}
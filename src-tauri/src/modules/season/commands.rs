@@ -0,0 +1,59 @@
+use super::application::service::{ScheduleService, ScheduleSyncSummary};
+use super::domain::{NextEpisode, SeasonSchedule};
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::sync::Arc;
+use tauri::State;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct GetWeeklyScheduleRequest {
+    /// IANA timezone of the viewer, e.g. `Europe/Warsaw`
+    pub timezone: String,
+    /// Any day of the wanted week; the current week when unset
+    pub week_of: Option<NaiveDate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct GetNextEpisodesRequest {
+    /// Anime to look up; every airing anime in the library when unset
+    pub anime_ids: Option<Vec<Uuid>>,
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_weekly_schedule(
+    request: GetWeeklyScheduleRequest,
+    schedule_service: State<'_, Arc<ScheduleService>>,
+) -> Result<SeasonSchedule, String> {
+    schedule_service
+        .get_weekly_schedule(&request.timezone, request.week_of)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_next_episodes(
+    request: GetNextEpisodesRequest,
+    schedule_service: State<'_, Arc<ScheduleService>>,
+) -> Result<Vec<NextEpisode>, String> {
+    schedule_service
+        .get_next_episodes(request.anime_ids)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Refresh airing times of every airing or upcoming anime in the library
+#[tauri::command]
+#[specta::specta]
+pub async fn sync_airing_schedule(
+    schedule_service: State<'_, Arc<ScheduleService>>,
+) -> Result<ScheduleSyncSummary, String> {
+    schedule_service
+        .sync_airing_schedule()
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,22 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::modules::season::domain::value_objects::BroadcastInfo;
+
+/// When a single episode airs
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct AiringEpisode {
+    pub episode: i32,
+    pub airs_at: DateTime<Utc>,
+}
+
+/// Airing data of one anime gathered from the providers
+#[derive(Debug, Clone, Default)]
+pub struct ProviderAiringSchedule {
+    /// Per-episode airing times (AniList)
+    pub episodes: Vec<AiringEpisode>,
+    /// Weekly broadcast slot (MyAnimeList)
+    pub broadcast: Option<BroadcastInfo>,
+}
@@ -1 +1,7 @@
-// Placeholder for season entities (future: SeasonSchedule, etc.)
+pub mod airing_episode;
+pub mod season_schedule;
+
+pub use airing_episode::{AiringEpisode, ProviderAiringSchedule};
+pub use season_schedule::{
+    AiringTimeSource, NextEpisode, ScheduleDay, ScheduleEntry, SeasonSchedule,
+};
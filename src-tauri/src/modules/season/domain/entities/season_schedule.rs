@@ -0,0 +1,60 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use uuid::Uuid;
+
+/// Where an airing time comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum AiringTimeSource {
+    /// Per-episode airing schedule published by AniList
+    Schedule,
+    /// Projected from the weekly broadcast slot
+    Broadcast,
+}
+
+/// Next episode of an airing anime with a countdown
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct NextEpisode {
+    pub anime_id: Uuid,
+    /// Unknown when projected from the broadcast slot
+    pub episode: Option<i32>,
+    pub airs_at: DateTime<Utc>,
+    /// Seconds from now until the episode airs
+    pub airs_in_seconds: u32,
+    pub source: AiringTimeSource,
+}
+
+/// One airing on the weekly calendar
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleEntry {
+    pub anime_id: Uuid,
+    pub title: String,
+    pub image_url: Option<String>,
+    pub episode: Option<i32>,
+    pub airs_at: DateTime<Utc>,
+    /// `airs_at` in the calendar's timezone, e.g. `16:30`
+    pub local_time: String,
+    pub source: AiringTimeSource,
+}
+
+/// Airings of one calendar day, earliest first
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleDay {
+    pub date: NaiveDate,
+    pub weekday: String,
+    pub entries: Vec<ScheduleEntry>,
+}
+
+/// Monday-to-Sunday airing calendar in the viewer's timezone
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SeasonSchedule {
+    /// IANA timezone the days and local times are expressed in
+    pub timezone: String,
+    pub week_start: NaiveDate,
+    pub days: Vec<ScheduleDay>,
+}
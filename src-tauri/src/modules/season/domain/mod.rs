@@ -1,5 +1,13 @@
 pub mod entities;
+pub mod repositories;
+pub mod services;
 pub mod value_objects;
 
-// Re-exports for easy access (currently unused but kept for future features)
-// pub use value_objects::{broadcast_info::BroadcastInfo, season_enum::Season};
+// Re-exports for easy access
+pub use entities::{
+    AiringEpisode, AiringTimeSource, NextEpisode, ProviderAiringSchedule, ScheduleDay,
+    ScheduleEntry, SeasonSchedule,
+};
+pub use repositories::AiringScheduleRepository;
+pub use services::{ScheduleBuilder, ScheduledAnime};
+pub use value_objects::{BroadcastInfo, Season, SeasonPeriod};
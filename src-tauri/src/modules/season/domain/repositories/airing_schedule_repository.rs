@@ -0,0 +1,41 @@
+use super::super::entities::AiringEpisode;
+use super::super::value_objects::BroadcastInfo;
+use crate::shared::errors::AppResult;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[async_trait]
+pub trait AiringScheduleRepository: Send + Sync {
+    /// Insert airing times or move the stored ones for the same episode
+    ///
+    /// Returns the number of rows written.
+    async fn upsert_episodes(
+        &self,
+        anime_id: &Uuid,
+        episodes: &[AiringEpisode],
+    ) -> AppResult<usize>;
+
+    /// Store the weekly broadcast slot of an anime, replacing the previous one
+    async fn save_broadcast(&self, anime_id: &Uuid, broadcast: &BroadcastInfo) -> AppResult<()>;
+
+    /// Episodes of the given anime airing in `[from, to)`, earliest first
+    async fn find_episodes_between(
+        &self,
+        anime_ids: &[Uuid],
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> AppResult<Vec<(Uuid, AiringEpisode)>>;
+
+    /// First episode of each given anime airing after `after`
+    async fn find_next_episodes(
+        &self,
+        anime_ids: &[Uuid],
+        after: DateTime<Utc>,
+    ) -> AppResult<Vec<(Uuid, AiringEpisode)>>;
+
+    /// Anime among `anime_ids` that have any per-episode airing time stored
+    async fn find_scheduled_anime(&self, anime_ids: &[Uuid]) -> AppResult<Vec<Uuid>>;
+
+    async fn find_broadcasts(&self, anime_ids: &[Uuid]) -> AppResult<Vec<(Uuid, BroadcastInfo)>>;
+}
@@ -0,0 +1,3 @@
+pub mod airing_schedule_repository;
+
+pub use airing_schedule_repository::AiringScheduleRepository;
@@ -0,0 +1,3 @@
+pub mod schedule_builder;
+
+pub use schedule_builder::{ScheduleBuilder, ScheduledAnime};
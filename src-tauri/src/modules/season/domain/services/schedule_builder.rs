@@ -0,0 +1,215 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use uuid::Uuid;
+
+use crate::modules::season::domain::entities::{
+    AiringEpisode, AiringTimeSource, ScheduleDay, ScheduleEntry, SeasonSchedule,
+};
+use crate::modules::season::domain::value_objects::BroadcastInfo;
+
+/// Anime placed on the calendar
+#[derive(Debug, Clone)]
+pub struct ScheduledAnime {
+    pub id: Uuid,
+    pub title: String,
+    pub image_url: Option<String>,
+}
+
+/// Lays airings out on a Monday-to-Sunday week in the viewer's timezone
+///
+/// Airing times are kept in UTC and only converted when an entry is placed, so
+/// a late-night Japanese slot lands on whichever local day it really airs.
+pub struct ScheduleBuilder {
+    timezone: Tz,
+    week_start: NaiveDate,
+    entries: Vec<(NaiveDate, ScheduleEntry)>,
+}
+
+impl ScheduleBuilder {
+    /// Calendar for the week containing `day`
+    pub fn new(timezone: Tz, day: NaiveDate) -> Self {
+        let week_start = day - Duration::days(day.weekday().num_days_from_monday() as i64);
+
+        Self {
+            timezone,
+            week_start,
+            entries: Vec::new(),
+        }
+    }
+
+    /// UTC bounds of the week, end exclusive
+    pub fn range(&self) -> (DateTime<Utc>, DateTime<Utc>) {
+        (
+            self.start_of_day(self.week_start),
+            self.start_of_day(self.week_start + Duration::days(7)),
+        )
+    }
+
+    /// Place an episode with a known airing time; ignored outside the week
+    pub fn add_episode(&mut self, anime: &ScheduledAnime, episode: &AiringEpisode) {
+        self.push(
+            anime,
+            Some(episode.episode),
+            episode.airs_at,
+            AiringTimeSource::Schedule,
+        );
+    }
+
+    /// Place every airing of a weekly broadcast slot that falls in the week
+    pub fn add_broadcast(&mut self, anime: &ScheduledAnime, broadcast: &BroadcastInfo) {
+        let (start, end) = self.range();
+        for airs_at in broadcast.airings_between(start, end) {
+            self.push(anime, None, airs_at, AiringTimeSource::Broadcast);
+        }
+    }
+
+    pub fn build(self) -> SeasonSchedule {
+        let mut days: Vec<ScheduleDay> = (0..7)
+            .map(|offset| {
+                let date = self.week_start + Duration::days(offset);
+                ScheduleDay {
+                    date,
+                    weekday: date.format("%A").to_string(),
+                    entries: Vec::new(),
+                }
+            })
+            .collect();
+
+        for (date, entry) in self.entries {
+            let index = (date - self.week_start).num_days();
+            if let Some(day) = usize::try_from(index).ok().and_then(|i| days.get_mut(i)) {
+                day.entries.push(entry);
+            }
+        }
+
+        for day in &mut days {
+            day.entries.sort_by(|a, b| {
+                a.airs_at
+                    .cmp(&b.airs_at)
+                    .then_with(|| a.title.cmp(&b.title))
+            });
+        }
+
+        SeasonSchedule {
+            timezone: self.timezone.name().to_string(),
+            week_start: self.week_start,
+            days,
+        }
+    }
+
+    fn push(
+        &mut self,
+        anime: &ScheduledAnime,
+        episode: Option<i32>,
+        airs_at: DateTime<Utc>,
+        source: AiringTimeSource,
+    ) {
+        let (start, end) = self.range();
+        if airs_at < start || airs_at >= end {
+            return;
+        }
+
+        let local = airs_at.with_timezone(&self.timezone);
+        self.entries.push((
+            local.date_naive(),
+            ScheduleEntry {
+                anime_id: anime.id,
+                title: anime.title.clone(),
+                image_url: anime.image_url.clone(),
+                episode,
+                airs_at,
+                local_time: local.format("%H:%M").to_string(),
+                source,
+            },
+        ));
+    }
+
+    /// First instant of a local day; a few zones skip midnight when DST starts
+    fn start_of_day(&self, date: NaiveDate) -> DateTime<Utc> {
+        let midnight = date.and_time(NaiveTime::MIN);
+        self.timezone
+            .from_local_datetime(&midnight)
+            .earliest()
+            .or_else(|| {
+                self.timezone
+                    .from_local_datetime(&(midnight + Duration::hours(1)))
+                    .earliest()
+            })
+            .map(|start| start.with_timezone(&Utc))
+            .unwrap_or_else(|| Utc.from_utc_datetime(&midnight))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn anime(title: &str) -> ScheduledAnime {
+        ScheduledAnime {
+            id: Uuid::new_v4(),
+            title: title.to_string(),
+            image_url: None,
+        }
+    }
+
+    fn utc(value: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(value)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_late_night_japanese_slot_moves_to_previous_local_day() {
+        let warsaw: Tz = "Europe/Warsaw".parse().unwrap();
+        let mut builder =
+            ScheduleBuilder::new(warsaw, NaiveDate::from_ymd_opt(2025, 10, 8).unwrap());
+
+        builder.add_broadcast(
+            &anime("Frieren"),
+            &BroadcastInfo::from_jikan_string("Saturdays at 01:30 (JST)"),
+        );
+        let schedule = builder.build();
+
+        assert_eq!(
+            schedule.week_start,
+            NaiveDate::from_ymd_opt(2025, 10, 6).unwrap()
+        );
+        let friday = &schedule.days[4];
+        assert_eq!(friday.weekday, "Friday");
+        assert_eq!(friday.entries.len(), 1);
+        assert_eq!(friday.entries[0].local_time, "18:30");
+        assert_eq!(friday.entries[0].source, AiringTimeSource::Broadcast);
+    }
+
+    #[test]
+    fn test_episodes_outside_local_week_are_dropped() {
+        let warsaw: Tz = "Europe/Warsaw".parse().unwrap();
+        let mut builder =
+            ScheduleBuilder::new(warsaw, NaiveDate::from_ymd_opt(2025, 10, 6).unwrap());
+        let show = anime("Dandadan");
+
+        builder.add_episode(
+            &show,
+            &AiringEpisode {
+                episode: 2,
+                airs_at: utc("2025-10-12T21:30:00Z"),
+            },
+        );
+        // Sunday 23:00 UTC is already Monday in Warsaw
+        builder.add_episode(
+            &show,
+            &AiringEpisode {
+                episode: 3,
+                airs_at: utc("2025-10-12T23:00:00Z"),
+            },
+        );
+        let schedule = builder.build();
+
+        assert_eq!(schedule.days.len(), 7);
+        let entries: Vec<&ScheduleEntry> =
+            schedule.days.iter().flat_map(|day| &day.entries).collect();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].episode, Some(2));
+        assert_eq!(schedule.days[6].entries[0].local_time, "23:30");
+    }
+}
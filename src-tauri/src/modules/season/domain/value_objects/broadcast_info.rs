@@ -1,10 +1,13 @@
 //! Broadcast information with proper typing
 
-use chrono::{NaiveTime, Weekday};
+use chrono::{DateTime, Datelike, Duration, NaiveTime, TimeZone, Utc, Weekday};
 use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 use specta::Type;
 
+/// Timezone assumed for broadcast slots that don't name one (Japanese TV)
+pub const DEFAULT_BROADCAST_TIMEZONE: Tz = chrono_tz::Asia::Tokyo;
+
 /// Broadcast information with proper typing
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 pub struct BroadcastInfo {
@@ -153,6 +156,71 @@ impl BroadcastInfo {
     pub fn get_timezone(&self) -> Option<Tz> {
         self.timezone.as_ref().and_then(|tz| tz.parse().ok())
     }
+
+    /// Next broadcast strictly after `after`, `None` when day or time is unknown
+    ///
+    /// The slot is read in its own timezone (Japan when unset), so it stays put
+    /// when the viewer's timezone switches to or from daylight saving time.
+    pub fn next_airing_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let weekday = self.get_weekday()?;
+        let time = self.get_time()?;
+        let timezone = self.get_timezone().unwrap_or(DEFAULT_BROADCAST_TIMEZONE);
+
+        let mut date = after.with_timezone(&timezone).date_naive();
+        // Today's slot may already be over, so look one day past a full week
+        for _ in 0..8 {
+            if date.weekday() == weekday {
+                let airing = timezone
+                    .from_local_datetime(&date.and_time(time))
+                    .earliest()
+                    .map(|airing| airing.with_timezone(&Utc));
+                if let Some(airing) = airing.filter(|airing| *airing > after) {
+                    return Some(airing);
+                }
+            }
+            date = date.succ_opt()?;
+        }
+        None
+    }
+
+    /// Every broadcast in `[from, to)`
+    pub fn airings_between(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> Vec<DateTime<Utc>> {
+        let mut airings = Vec::new();
+        let mut cursor = from - Duration::seconds(1);
+
+        while let Some(airing) = self.next_airing_after(cursor).filter(|airing| *airing < to) {
+            airings.push(airing);
+            cursor = airing;
+        }
+        airings
+    }
+
+    /// The same weekly slot expressed in another timezone
+    ///
+    /// The offset between two timezones changes with daylight saving time, so the
+    /// slot is converted at its next airing after `reference`.
+    pub fn in_timezone(&self, timezone: Tz, reference: DateTime<Utc>) -> Option<Self> {
+        let airing = self.next_airing_after(reference)?.with_timezone(&timezone);
+
+        Some(Self::from_strings(
+            Some(weekday_name(airing.weekday()).to_string()),
+            Some(airing.format("%H:%M").to_string()),
+            Some(timezone.name().to_string()),
+        ))
+    }
+}
+
+/// Full English weekday name, the form `get_weekday` understands
+fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
 }
 
 impl Default for BroadcastInfo {
@@ -165,3 +233,66 @@ impl Default for BroadcastInfo {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(value: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(value)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_next_airing_reads_slot_in_japan_time() {
+        let broadcast = BroadcastInfo::from_jikan_string("Saturdays at 23:30 (JST)");
+
+        // Saturday 23:30 JST is Saturday 14:30 UTC
+        assert_eq!(
+            broadcast.next_airing_after(utc("2025-10-08T00:00:00Z")),
+            Some(utc("2025-10-11T14:30:00Z"))
+        );
+        // Right after an airing the next one is a week later
+        assert_eq!(
+            broadcast.next_airing_after(utc("2025-10-11T14:30:00Z")),
+            Some(utc("2025-10-18T14:30:00Z"))
+        );
+    }
+
+    #[test]
+    fn test_airings_between_covers_half_open_range() {
+        let broadcast = BroadcastInfo::from_strings(
+            Some("Saturday".to_string()),
+            Some("23:30".to_string()),
+            None,
+        );
+
+        let airings =
+            broadcast.airings_between(utc("2025-10-11T14:30:00Z"), utc("2025-10-25T14:30:00Z"));
+        assert_eq!(
+            airings,
+            vec![utc("2025-10-11T14:30:00Z"), utc("2025-10-18T14:30:00Z")]
+        );
+    }
+
+    #[test]
+    fn test_in_timezone_follows_daylight_saving_time() {
+        let broadcast = BroadcastInfo::from_jikan_string("Sundays at 01:00 (JST)");
+        let warsaw: Tz = "Europe/Warsaw".parse().unwrap();
+
+        // Summer time (UTC+2): Sunday 01:00 JST is Saturday 18:00 in Warsaw
+        let summer = broadcast
+            .in_timezone(warsaw, utc("2025-10-01T00:00:00Z"))
+            .unwrap();
+        assert_eq!(summer.day.as_deref(), Some("Saturday"));
+        assert_eq!(summer.time.as_deref(), Some("18:00"));
+
+        // Winter time (UTC+1): one hour earlier
+        let winter = broadcast
+            .in_timezone(warsaw, utc("2025-11-01T00:00:00Z"))
+            .unwrap();
+        assert_eq!(winter.time.as_deref(), Some("17:00"));
+        assert_eq!(winter.timezone.as_deref(), Some("Europe/Warsaw"));
+    }
+}
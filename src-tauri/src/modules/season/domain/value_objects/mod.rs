@@ -1,2 +1,8 @@
 pub mod broadcast_info;
 pub mod season_enum;
+pub mod season_period;
+
+// Re-exports for easy access
+pub use broadcast_info::BroadcastInfo;
+pub use season_enum::Season;
+pub use season_period::SeasonPeriod;
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Datelike, Utc};
 use serde::{Deserialize, Serialize};
 use specta::Type;
 
@@ -22,8 +23,17 @@ impl Season {
         }
     }
 
+    /// Lowercase season identifier as used by provider APIs
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Winter => "winter",
+            Self::Spring => "spring",
+            Self::Summer => "summer",
+            Self::Fall => "fall",
+        }
+    }
+
     /// Get season display name
-    #[allow(dead_code)]
     pub fn display_name(&self) -> &'static str {
         match self {
             Self::Winter => "Winter",
@@ -44,8 +54,50 @@ impl Season {
         }
     }
 
+    /// Season airing at `date`, together with its year
+    ///
+    /// Follows the broadcast cours providers group anime by: January to March
+    /// is winter, April to June spring, July to September summer and October
+    /// to December fall, all within the same calendar year.
+    pub fn airing_at(date: DateTime<Utc>) -> (i32, Self) {
+        let season = match date.month() {
+            1..=3 => Self::Winter,
+            4..=6 => Self::Spring,
+            7..=9 => Self::Summer,
+            _ => Self::Fall,
+        };
+        (date.year(), season)
+    }
+
     /// Get all seasons in chronological order
     pub fn all() -> [Self; 4] {
         [Self::Winter, Self::Spring, Self::Summer, Self::Fall]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_airing_at_follows_broadcast_cours() {
+        assert_eq!(Season::airing_at(at(2025, 1, 1)), (2025, Season::Winter));
+        assert_eq!(Season::airing_at(at(2025, 3, 31)), (2025, Season::Winter));
+        assert_eq!(Season::airing_at(at(2025, 4, 1)), (2025, Season::Spring));
+        assert_eq!(Season::airing_at(at(2025, 6, 30)), (2025, Season::Spring));
+        assert_eq!(Season::airing_at(at(2025, 7, 1)), (2025, Season::Summer));
+        assert_eq!(Season::airing_at(at(2025, 10, 1)), (2025, Season::Fall));
+    }
+
+    #[test]
+    fn test_airing_at_keeps_december_in_its_own_year() {
+        // Unlike `from_month`, December belongs to fall of the same year
+        assert_eq!(Season::airing_at(at(2024, 12, 31)), (2024, Season::Fall));
+        assert_eq!(Season::from_month(12), Some(Season::Winter));
+    }
+}
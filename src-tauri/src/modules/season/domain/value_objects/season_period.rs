@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use super::season_enum::Season;
+
+/// Which season lineup to fetch from the providers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SeasonPeriod {
+    /// A specific season of a specific year
+    Specific { year: u32, season: Season },
+    /// The season that is currently airing
+    Current,
+    /// Titles announced for upcoming seasons
+    Upcoming,
+}
+
+impl SeasonPeriod {
+    /// Human readable label used in logs
+    pub fn label(&self) -> String {
+        match self {
+            Self::Specific { year, season } => format!("{} {}", season.display_name(), year),
+            Self::Current => "current season".to_string(),
+            Self::Upcoming => "upcoming season".to_string(),
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod models;
+pub mod persistence;
+
+pub use persistence::AiringScheduleRepositoryImpl;
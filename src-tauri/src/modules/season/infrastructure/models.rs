@@ -0,0 +1,75 @@
+use crate::modules::season::domain::{AiringEpisode, BroadcastInfo};
+use crate::schema::{anime_airing_schedule, anime_broadcasts};
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use uuid::Uuid;
+
+// For reading from database
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone)]
+#[diesel(table_name = anime_airing_schedule)]
+pub struct AiringScheduleModel {
+    pub id: Uuid,
+    pub anime_id: Uuid,
+    pub episode: i32,
+    pub airs_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// For inserting new rows
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = anime_airing_schedule)]
+pub struct NewAiringScheduleModel {
+    pub anime_id: Uuid,
+    pub episode: i32,
+    pub airs_at: DateTime<Utc>,
+}
+
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone)]
+#[diesel(table_name = anime_broadcasts)]
+#[diesel(primary_key(anime_id))]
+pub struct BroadcastModel {
+    pub anime_id: Uuid,
+    pub day: String,
+    pub start_time: String,
+    pub timezone: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Insertable, AsChangeset, Debug, Clone)]
+#[diesel(table_name = anime_broadcasts)]
+pub struct NewBroadcastModel {
+    pub anime_id: Uuid,
+    pub day: String,
+    pub start_time: String,
+    pub timezone: String,
+}
+
+impl From<AiringScheduleModel> for AiringEpisode {
+    fn from(model: AiringScheduleModel) -> Self {
+        Self {
+            episode: model.episode,
+            airs_at: model.airs_at,
+        }
+    }
+}
+
+impl From<BroadcastModel> for BroadcastInfo {
+    fn from(model: BroadcastModel) -> Self {
+        Self::from_strings(
+            Some(model.day),
+            Some(model.start_time),
+            Some(model.timezone),
+        )
+    }
+}
+
+impl NewAiringScheduleModel {
+    pub fn new(anime_id: Uuid, episode: &AiringEpisode) -> Self {
+        Self {
+            anime_id,
+            episode: episode.episode,
+            airs_at: episode.airs_at,
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel::upsert::excluded;
+use tokio::task;
+use uuid::Uuid;
+
+use crate::modules::season::domain::value_objects::broadcast_info::DEFAULT_BROADCAST_TIMEZONE;
+use crate::modules::season::domain::{AiringEpisode, AiringScheduleRepository, BroadcastInfo};
+use crate::modules::season::infrastructure::models::{
+    AiringScheduleModel, BroadcastModel, NewAiringScheduleModel, NewBroadcastModel,
+};
+use crate::schema::{anime_airing_schedule, anime_broadcasts};
+use crate::shared::errors::{AppError, AppResult};
+use crate::shared::Database;
+
+pub struct AiringScheduleRepositoryImpl {
+    db: Arc<Database>,
+}
+
+impl AiringScheduleRepositoryImpl {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl AiringScheduleRepository for AiringScheduleRepositoryImpl {
+    async fn upsert_episodes(
+        &self,
+        anime_id: &Uuid,
+        episodes: &[AiringEpisode],
+    ) -> AppResult<usize> {
+        if episodes.is_empty() {
+            return Ok(0);
+        }
+
+        let db = Arc::clone(&self.db);
+        let rows: Vec<NewAiringScheduleModel> = episodes
+            .iter()
+            .map(|episode| NewAiringScheduleModel::new(*anime_id, episode))
+            .collect();
+
+        task::spawn_blocking(move || -> AppResult<usize> {
+            let mut conn = db.get_connection()?;
+            let written = diesel::insert_into(anime_airing_schedule::table)
+                .values(&rows)
+                .on_conflict((
+                    anime_airing_schedule::anime_id,
+                    anime_airing_schedule::episode,
+                ))
+                .do_update()
+                .set(anime_airing_schedule::airs_at.eq(excluded(anime_airing_schedule::airs_at)))
+                .execute(&mut conn)?;
+            Ok(written)
+        })
+        .await?
+    }
+
+    async fn save_broadcast(&self, anime_id: &Uuid, broadcast: &BroadcastInfo) -> AppResult<()> {
+        let (Some(day), Some(time)) = (
+            broadcast
+                .day
+                .clone()
+                .filter(|_| broadcast.get_weekday().is_some()),
+            broadcast.get_time(),
+        ) else {
+            return Err(AppError::ValidationError(
+                "Broadcast slot needs both a day and a time".to_string(),
+            ));
+        };
+
+        let db = Arc::clone(&self.db);
+        let row = NewBroadcastModel {
+            anime_id: *anime_id,
+            day,
+            start_time: time.format("%H:%M").to_string(),
+            timezone: broadcast
+                .get_timezone()
+                .unwrap_or(DEFAULT_BROADCAST_TIMEZONE)
+                .name()
+                .to_string(),
+        };
+
+        task::spawn_blocking(move || -> AppResult<()> {
+            let mut conn = db.get_connection()?;
+            diesel::insert_into(anime_broadcasts::table)
+                .values(&row)
+                .on_conflict(anime_broadcasts::anime_id)
+                .do_update()
+                .set(&row)
+                .execute(&mut conn)?;
+            Ok(())
+        })
+        .await?
+    }
+
+    async fn find_episodes_between(
+        &self,
+        anime_ids: &[Uuid],
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> AppResult<Vec<(Uuid, AiringEpisode)>> {
+        let db = Arc::clone(&self.db);
+        let anime_ids = anime_ids.to_vec();
+
+        let models = task::spawn_blocking(move || -> AppResult<Vec<AiringScheduleModel>> {
+            let mut conn = db.get_connection()?;
+            let models = anime_airing_schedule::table
+                .filter(anime_airing_schedule::anime_id.eq_any(&anime_ids))
+                .filter(anime_airing_schedule::airs_at.ge(from))
+                .filter(anime_airing_schedule::airs_at.lt(to))
+                .order(anime_airing_schedule::airs_at.asc())
+                .select(AiringScheduleModel::as_select())
+                .load(&mut conn)?;
+            Ok(models)
+        })
+        .await??;
+
+        Ok(models
+            .into_iter()
+            .map(|model| (model.anime_id, model.into()))
+            .collect())
+    }
+
+    async fn find_next_episodes(
+        &self,
+        anime_ids: &[Uuid],
+        after: DateTime<Utc>,
+    ) -> AppResult<Vec<(Uuid, AiringEpisode)>> {
+        let db = Arc::clone(&self.db);
+        let anime_ids = anime_ids.to_vec();
+
+        let models = task::spawn_blocking(move || -> AppResult<Vec<AiringScheduleModel>> {
+            let mut conn = db.get_connection()?;
+            let models = anime_airing_schedule::table
+                .filter(anime_airing_schedule::anime_id.eq_any(&anime_ids))
+                .filter(anime_airing_schedule::airs_at.gt(after))
+                .distinct_on(anime_airing_schedule::anime_id)
+                .order((
+                    anime_airing_schedule::anime_id,
+                    anime_airing_schedule::airs_at.asc(),
+                ))
+                .select(AiringScheduleModel::as_select())
+                .load(&mut conn)?;
+            Ok(models)
+        })
+        .await??;
+
+        Ok(models
+            .into_iter()
+            .map(|model| (model.anime_id, model.into()))
+            .collect())
+    }
+
+    async fn find_scheduled_anime(&self, anime_ids: &[Uuid]) -> AppResult<Vec<Uuid>> {
+        let db = Arc::clone(&self.db);
+        let anime_ids = anime_ids.to_vec();
+
+        task::spawn_blocking(move || -> AppResult<Vec<Uuid>> {
+            let mut conn = db.get_connection()?;
+            let ids = anime_airing_schedule::table
+                .filter(anime_airing_schedule::anime_id.eq_any(&anime_ids))
+                .select(anime_airing_schedule::anime_id)
+                .distinct()
+                .load(&mut conn)?;
+            Ok(ids)
+        })
+        .await?
+    }
+
+    async fn find_broadcasts(&self, anime_ids: &[Uuid]) -> AppResult<Vec<(Uuid, BroadcastInfo)>> {
+        let db = Arc::clone(&self.db);
+        let anime_ids = anime_ids.to_vec();
+
+        let models = task::spawn_blocking(move || -> AppResult<Vec<BroadcastModel>> {
+            let mut conn = db.get_connection()?;
+            let models = anime_broadcasts::table
+                .filter(anime_broadcasts::anime_id.eq_any(&anime_ids))
+                .select(BroadcastModel::as_select())
+                .load(&mut conn)?;
+            Ok(models)
+        })
+        .await??;
+
+        Ok(models
+            .into_iter()
+            .map(|model| (model.anime_id, model.into()))
+            .collect())
+    }
+}
@@ -0,0 +1,3 @@
+pub mod airing_schedule_repository_impl;
+
+pub use airing_schedule_repository_impl::AiringScheduleRepositoryImpl;
@@ -1,4 +1,11 @@
+pub mod application;
+pub mod commands;
 pub mod domain;
+pub mod infrastructure;
 
-// Re-exports for easy external access (currently unused but kept for future features)
-// pub use domain::{BroadcastInfo, Season};
+// Re-exports for easy external access
+pub use application::service::ScheduleService;
+pub use domain::{
+    AiringEpisode, AiringScheduleRepository, BroadcastInfo, NextEpisode, ProviderAiringSchedule,
+    Season, SeasonPeriod, SeasonSchedule,
+};
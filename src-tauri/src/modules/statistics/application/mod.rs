@@ -0,0 +1,4 @@
+pub mod momentum_source;
+pub mod service;
+
+pub use momentum_source::SnapshotMomentumSource;
@@ -0,0 +1,56 @@
+use super::super::domain::{StatisticsRepository, StatisticsSnapshot, TrendCalculator};
+use crate::modules::anime::domain::value_objects::AudienceMomentum;
+use crate::modules::anime::MomentumSource;
+use crate::shared::domain::value_objects::AnimeProvider;
+use crate::shared::errors::AppResult;
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Period the momentum fed to the score calculator is measured over
+const MOMENTUM_WINDOW_DAYS: i64 = 14;
+
+/// Providers whose series drives momentum, largest audience first
+pub(crate) const MOMENTUM_PROVIDERS: [AnimeProvider; 2] =
+    [AnimeProvider::Jikan, AnimeProvider::AniList];
+
+/// Measures audience momentum from the snapshots of the last two weeks
+///
+/// The first provider in `MOMENTUM_PROVIDERS` with a usable series wins, so
+/// the anime's score doesn't jump between providers from one sync to the next.
+pub struct SnapshotMomentumSource {
+    statistics_repo: Arc<dyn StatisticsRepository>,
+}
+
+impl SnapshotMomentumSource {
+    pub fn new(statistics_repo: Arc<dyn StatisticsRepository>) -> Self {
+        Self { statistics_repo }
+    }
+}
+
+#[async_trait]
+impl MomentumSource for SnapshotMomentumSource {
+    async fn measured_momentum(&self, anime_id: &Uuid) -> AppResult<Option<AudienceMomentum>> {
+        let snapshots = self
+            .statistics_repo
+            .find_snapshots(anime_id, Utc::now() - Duration::days(MOMENTUM_WINDOW_DAYS))
+            .await?;
+
+        let mut by_provider: HashMap<AnimeProvider, Vec<StatisticsSnapshot>> = HashMap::new();
+        for snapshot in snapshots {
+            by_provider
+                .entry(snapshot.provider)
+                .or_default()
+                .push(snapshot);
+        }
+
+        Ok(MOMENTUM_PROVIDERS.iter().find_map(|provider| {
+            let snapshots = by_provider.remove(provider)?;
+            TrendCalculator::provider_trend(*provider, snapshots)
+                .as_ref()
+                .and_then(TrendCalculator::momentum)
+        }))
+    }
+}
@@ -0,0 +1,200 @@
+use super::super::domain::{
+    AnimeStatisticsTrend, StatisticsRepository, StatisticsSnapshot, TrendCalculator,
+};
+use super::momentum_source::{SnapshotMomentumSource, MOMENTUM_PROVIDERS};
+use crate::modules::anime::domain::services::score_calculator::ScoreCalculator;
+use crate::modules::anime::{
+    airing_library_anime, AnimeDetailed, AnimeQueryRepository, AnimeRepository, MomentumSource,
+};
+use crate::modules::provider::application::service::ProviderService;
+use crate::shared::application::run_library_batch;
+use crate::shared::domain::value_objects::AnimeProvider;
+use crate::shared::errors::{AppError, AppResult};
+use crate::{log_debug, log_info};
+use chrono::{Duration, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Anime snapshotted more recently than this are skipped, so syncs stay roughly daily
+const SNAPSHOT_INTERVAL_HOURS: i64 = 20;
+
+/// History returned when the caller doesn't ask for a period
+const DEFAULT_TREND_DAYS: u32 = 90;
+
+/// Outcome of snapshotting provider statistics
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct StatisticsSyncSummary {
+    pub anime_synced: u32,
+    pub snapshots_stored: u32,
+    /// Anime whose composite score now uses measured momentum
+    pub rescored: u32,
+    pub failed: u32,
+}
+
+pub struct StatisticsService {
+    statistics_repo: Arc<dyn StatisticsRepository>,
+    anime_repo: Arc<dyn AnimeRepository>,
+    anime_query_repo: Arc<dyn AnimeQueryRepository>,
+    provider_service: Arc<ProviderService>,
+    momentum_source: SnapshotMomentumSource,
+    score_calculator: ScoreCalculator,
+}
+
+impl StatisticsService {
+    pub fn new(
+        statistics_repo: Arc<dyn StatisticsRepository>,
+        anime_repo: Arc<dyn AnimeRepository>,
+        anime_query_repo: Arc<dyn AnimeQueryRepository>,
+        provider_service: Arc<ProviderService>,
+    ) -> Self {
+        Self {
+            momentum_source: SnapshotMomentumSource::new(Arc::clone(&statistics_repo)),
+            statistics_repo,
+            anime_repo,
+            anime_query_repo,
+            provider_service,
+            score_calculator: ScoreCalculator::new(),
+        }
+    }
+
+    /// Snapshot provider statistics for `anime_ids`, or every airing or upcoming anime in the library
+    ///
+    /// Anime snapshotted within the last day are skipped. Once an anime has two
+    /// snapshots far enough apart, its composite score is recalculated with the
+    /// measured momentum.
+    pub async fn sync_statistics(
+        &self,
+        anime_ids: Option<Vec<Uuid>>,
+    ) -> AppResult<StatisticsSyncSummary> {
+        let anime = match anime_ids {
+            Some(ids) => {
+                let mut anime = Vec::with_capacity(ids.len());
+                for id in ids {
+                    let found = self.anime_repo.find_by_id(&id).await?.ok_or_else(|| {
+                        AppError::NotFound(format!("Anime with ID {} not found", id))
+                    })?;
+                    anime.push(found);
+                }
+                anime
+            }
+            None => airing_library_anime(self.anime_query_repo.as_ref()).await?,
+        };
+
+        let anime_ids: Vec<Uuid> = anime.iter().map(|anime| anime.id).collect();
+        let recent: HashSet<Uuid> = self
+            .statistics_repo
+            .find_captured_since(
+                &anime_ids,
+                Utc::now() - Duration::hours(SNAPSHOT_INTERVAL_HOURS),
+            )
+            .await?
+            .into_iter()
+            .collect();
+
+        let outcome = run_library_batch(
+            anime
+                .into_iter()
+                .filter(|anime| !recent.contains(&anime.id)),
+            |anime| format!("Failed to snapshot statistics for '{}'", anime.title.main),
+            |anime| self.sync_anime(anime),
+        )
+        .await;
+
+        let summary = StatisticsSyncSummary {
+            anime_synced: outcome.completed.len() as u32,
+            snapshots_stored: outcome
+                .completed
+                .iter()
+                .map(|(stored, _)| *stored as u32)
+                .sum(),
+            rescored: outcome
+                .completed
+                .iter()
+                .filter(|(_, rescored)| *rescored)
+                .count() as u32,
+            failed: outcome.failed,
+        };
+
+        log_info!(
+            "Snapshotted statistics for {} anime ({} snapshots, {} rescored, {} failed, {} up to date)",
+            summary.anime_synced,
+            summary.snapshots_stored,
+            summary.rescored,
+            summary.failed,
+            recent.len()
+        );
+        Ok(summary)
+    }
+
+    /// Statistics history of an anime over the last `days` (90 when unset), one trend per provider
+    pub async fn get_statistics_trend(
+        &self,
+        anime_id: &Uuid,
+        days: Option<u32>,
+    ) -> AppResult<AnimeStatisticsTrend> {
+        let days = days.unwrap_or(DEFAULT_TREND_DAYS) as i64;
+        let snapshots = self
+            .statistics_repo
+            .find_snapshots(anime_id, Utc::now() - Duration::days(days))
+            .await?;
+
+        let mut by_provider: HashMap<AnimeProvider, Vec<StatisticsSnapshot>> = HashMap::new();
+        for snapshot in snapshots {
+            by_provider
+                .entry(snapshot.provider)
+                .or_default()
+                .push(snapshot);
+        }
+
+        let mut providers: Vec<_> = by_provider
+            .into_iter()
+            .filter_map(|(provider, snapshots)| {
+                TrendCalculator::provider_trend(provider, snapshots)
+            })
+            .collect();
+        providers.sort_by_key(|trend| {
+            MOMENTUM_PROVIDERS
+                .iter()
+                .position(|provider| *provider == trend.provider)
+                .unwrap_or(MOMENTUM_PROVIDERS.len())
+        });
+
+        Ok(AnimeStatisticsTrend {
+            anime_id: *anime_id,
+            providers,
+        })
+    }
+
+    /// Store one snapshot per provider, then rescore from the momentum window
+    async fn sync_anime(&self, mut anime: AnimeDetailed) -> AppResult<(usize, bool)> {
+        let statistics = self
+            .provider_service
+            .fetch_anime_statistics(&anime.provider_metadata)
+            .await?;
+
+        for provider_statistics in &statistics {
+            self.statistics_repo
+                .save_snapshot(&anime.id, provider_statistics)
+                .await?;
+        }
+
+        let Some(momentum) = self.momentum_source.measured_momentum(&anime.id).await? else {
+            return Ok((statistics.len(), false));
+        };
+
+        log_debug!(
+            "Measured momentum for '{}': {:.0} members/day, {:.1}% dropped",
+            anime.title.main,
+            momentum.members_per_day,
+            momentum.drop_rate * 100.0
+        );
+        anime.update_scores_with_momentum(&self.score_calculator, momentum);
+        self.anime_repo.update(&anime).await?;
+
+        Ok((statistics.len(), true))
+    }
+}
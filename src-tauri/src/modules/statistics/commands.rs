@@ -0,0 +1,46 @@
+use super::application::service::{StatisticsService, StatisticsSyncSummary};
+use super::domain::AnimeStatisticsTrend;
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::sync::Arc;
+use tauri::State;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct GetAnimeStatisticsTrendRequest {
+    pub anime_id: Uuid,
+    /// Days of history to include; 90 when unset
+    pub days: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SyncAnimeStatisticsRequest {
+    /// Anime to snapshot; every airing or upcoming anime in the library when unset
+    pub anime_ids: Option<Vec<Uuid>>,
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_anime_statistics_trend(
+    request: GetAnimeStatisticsTrendRequest,
+    statistics_service: State<'_, Arc<StatisticsService>>,
+) -> Result<AnimeStatisticsTrend, String> {
+    statistics_service
+        .get_statistics_trend(&request.anime_id, request.days)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Snapshot provider statistics and rescore anime with measured momentum
+#[tauri::command]
+#[specta::specta]
+pub async fn sync_anime_statistics(
+    request: SyncAnimeStatisticsRequest,
+    statistics_service: State<'_, Arc<StatisticsService>>,
+) -> Result<StatisticsSyncSummary, String> {
+    statistics_service
+        .sync_statistics(request.anime_ids)
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,7 @@
+pub mod provider_statistics;
+pub mod statistics_snapshot;
+pub mod statistics_trend;
+
+pub use provider_statistics::{ProviderStatistics, ScoreBucket};
+pub use statistics_snapshot::StatisticsSnapshot;
+pub use statistics_trend::{AnimeStatisticsTrend, ProviderTrend, TrendDirection};
@@ -0,0 +1,46 @@
+use crate::shared::domain::value_objects::AnimeProvider;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Votes for one score of a provider's user score distribution, on a 1-10 scale
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+pub struct ScoreBucket {
+    pub score: i32,
+    pub votes: i32,
+}
+
+/// List status counts and score distribution of an anime as reported by a single provider
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProviderStatistics {
+    pub provider: AnimeProvider,
+    pub watching: i32,
+    pub completed: i32,
+    pub on_hold: i32,
+    pub dropped: i32,
+    pub plan_to_watch: i32,
+    /// Users with the anime on their list in any status
+    pub total: i32,
+    pub favorites: Option<i32>,
+    pub score_distribution: Vec<ScoreBucket>,
+}
+
+impl ProviderStatistics {
+    /// Vote-weighted mean of the score distribution
+    pub fn mean_score(&self) -> Option<f32> {
+        let votes: i64 = self
+            .score_distribution
+            .iter()
+            .map(|bucket| bucket.votes as i64)
+            .sum();
+        if votes <= 0 {
+            return None;
+        }
+
+        let weighted: i64 = self
+            .score_distribution
+            .iter()
+            .map(|bucket| bucket.score as i64 * bucket.votes as i64)
+            .sum();
+        Some(weighted as f32 / votes as f32)
+    }
+}
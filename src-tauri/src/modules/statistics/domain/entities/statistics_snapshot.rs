@@ -0,0 +1,25 @@
+use super::provider_statistics::ScoreBucket;
+use crate::shared::domain::value_objects::AnimeProvider;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use uuid::Uuid;
+
+/// Provider statistics of an anime at one point in time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct StatisticsSnapshot {
+    pub id: Uuid,
+    pub anime_id: Uuid,
+    pub provider: AnimeProvider,
+    pub watching: i32,
+    pub completed: i32,
+    pub on_hold: i32,
+    pub dropped: i32,
+    pub plan_to_watch: i32,
+    pub total: i32,
+    pub favorites: Option<i32>,
+    pub score_distribution: Vec<ScoreBucket>,
+    pub mean_score: Option<f32>,
+    pub captured_at: DateTime<Utc>,
+}
@@ -0,0 +1,40 @@
+use super::statistics_snapshot::StatisticsSnapshot;
+use crate::shared::domain::value_objects::AnimeProvider;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use uuid::Uuid;
+
+/// Whether the watching audience grew or shrank over the period
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub enum TrendDirection {
+    Gaining,
+    Steady,
+    Losing,
+}
+
+/// Change of one provider's statistics between the first and last snapshot of a period
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderTrend {
+    pub provider: AnimeProvider,
+    /// Oldest first
+    pub snapshots: Vec<StatisticsSnapshot>,
+    pub total_change: i32,
+    pub watching_change: i32,
+    pub completed_change: i32,
+    pub dropped_change: i32,
+    /// Net list entries added per day
+    pub members_per_day: f32,
+    /// Share of the starting watching audience that dropped the show, 0.0-1.0
+    pub drop_rate: f32,
+    pub mean_score_change: Option<f32>,
+    pub direction: TrendDirection,
+}
+
+/// Statistics history of an anime, one trend per provider
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct AnimeStatisticsTrend {
+    pub anime_id: Uuid,
+    pub providers: Vec<ProviderTrend>,
+}
@@ -0,0 +1,11 @@
+pub mod entities;
+pub mod repositories;
+pub mod services;
+
+// Re-exports for easy access
+pub use entities::{
+    AnimeStatisticsTrend, ProviderStatistics, ProviderTrend, ScoreBucket, StatisticsSnapshot,
+    TrendDirection,
+};
+pub use repositories::StatisticsRepository;
+pub use services::TrendCalculator;
@@ -0,0 +1,3 @@
+pub mod statistics_repository;
+
+pub use statistics_repository::StatisticsRepository;
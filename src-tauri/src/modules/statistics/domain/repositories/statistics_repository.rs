@@ -0,0 +1,29 @@
+use super::super::entities::{ProviderStatistics, StatisticsSnapshot};
+use crate::shared::errors::AppResult;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[async_trait]
+pub trait StatisticsRepository: Send + Sync {
+    /// Store the provider's current statistics as a new snapshot of the anime
+    async fn save_snapshot(
+        &self,
+        anime_id: &Uuid,
+        statistics: &ProviderStatistics,
+    ) -> AppResult<StatisticsSnapshot>;
+
+    /// Snapshots of an anime captured after `since`, oldest first
+    async fn find_snapshots(
+        &self,
+        anime_id: &Uuid,
+        since: DateTime<Utc>,
+    ) -> AppResult<Vec<StatisticsSnapshot>>;
+
+    /// Anime among `anime_ids` with a snapshot captured after `since`
+    async fn find_captured_since(
+        &self,
+        anime_ids: &[Uuid],
+        since: DateTime<Utc>,
+    ) -> AppResult<Vec<Uuid>>;
+}
@@ -0,0 +1,3 @@
+pub mod trend_calculator;
+
+pub use trend_calculator::TrendCalculator;
@@ -0,0 +1,173 @@
+use crate::modules::anime::domain::value_objects::AudienceMomentum;
+use crate::modules::statistics::domain::entities::{
+    ProviderTrend, StatisticsSnapshot, TrendDirection,
+};
+use crate::shared::domain::value_objects::AnimeProvider;
+
+/// Relative change of the watching audience still considered steady
+const STEADY_BAND: f32 = 0.01;
+
+/// Shortest period between snapshots that yields a usable momentum
+const MIN_MOMENTUM_HOURS: i64 = 12;
+
+/// Turns a provider's snapshot history into deltas and audience momentum
+pub struct TrendCalculator;
+
+impl TrendCalculator {
+    /// Trend between the first and last of `snapshots`; `None` without snapshots
+    pub fn provider_trend(
+        provider: AnimeProvider,
+        mut snapshots: Vec<StatisticsSnapshot>,
+    ) -> Option<ProviderTrend> {
+        snapshots.sort_by_key(|snapshot| snapshot.captured_at);
+        let first = snapshots.first()?;
+        let last = snapshots.last()?;
+
+        let days = (last.captured_at - first.captured_at).num_seconds() as f32 / 86_400.0;
+        let total_change = last.total - first.total;
+        let watching_change = last.watching - first.watching;
+        let dropped_change = last.dropped - first.dropped;
+
+        let members_per_day = if days > 0.0 {
+            total_change as f32 / days
+        } else {
+            0.0
+        };
+        let drop_rate = if first.watching > 0 {
+            (dropped_change.max(0) as f32 / first.watching as f32).min(1.0)
+        } else {
+            0.0
+        };
+        let mean_score_change = match (first.mean_score, last.mean_score) {
+            (Some(first), Some(last)) => Some(last - first),
+            _ => None,
+        };
+
+        let direction = Self::direction(first, watching_change, total_change);
+
+        Some(ProviderTrend {
+            provider,
+            total_change,
+            watching_change,
+            completed_change: last.completed - first.completed,
+            dropped_change,
+            members_per_day,
+            drop_rate,
+            mean_score_change,
+            direction,
+            snapshots,
+        })
+    }
+
+    /// Momentum for `ScoreCalculator`; `None` until snapshots span a usable period
+    pub fn momentum(trend: &ProviderTrend) -> Option<AudienceMomentum> {
+        let first = trend.snapshots.first()?;
+        let last = trend.snapshots.last()?;
+        if (last.captured_at - first.captured_at).num_hours() < MIN_MOMENTUM_HOURS {
+            return None;
+        }
+
+        Some(AudienceMomentum {
+            members_per_day: trend.members_per_day,
+            drop_rate: trend.drop_rate,
+            score_change: trend.mean_score_change.unwrap_or(0.0),
+        })
+    }
+
+    /// Watching audience decides; before anyone watches, list growth does
+    fn direction(
+        first: &StatisticsSnapshot,
+        watching_change: i32,
+        total_change: i32,
+    ) -> TrendDirection {
+        let (change, base) = if first.watching > 0 {
+            (watching_change, first.watching)
+        } else {
+            (total_change, first.total)
+        };
+        let band = (base as f32 * STEADY_BAND).max(1.0);
+
+        if change as f32 > band {
+            TrendDirection::Gaining
+        } else if (change as f32) < -band {
+            TrendDirection::Losing
+        } else {
+            TrendDirection::Steady
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+    use uuid::Uuid;
+
+    fn snapshot(days_ago: i64, watching: i32, dropped: i32, total: i32) -> StatisticsSnapshot {
+        StatisticsSnapshot {
+            id: Uuid::new_v4(),
+            anime_id: Uuid::nil(),
+            provider: AnimeProvider::Jikan,
+            watching,
+            completed: 0,
+            on_hold: 0,
+            dropped,
+            plan_to_watch: total - watching - dropped,
+            total,
+            favorites: None,
+            score_distribution: Vec::new(),
+            mean_score: None,
+            captured_at: Utc::now() - Duration::days(days_ago),
+        }
+    }
+
+    #[test]
+    fn test_detects_show_bleeding_viewers() {
+        let trend = TrendCalculator::provider_trend(
+            AnimeProvider::Jikan,
+            vec![
+                snapshot(0, 90_000, 15_000, 200_000),
+                snapshot(10, 100_000, 5_000, 190_000),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(trend.direction, TrendDirection::Losing);
+        assert_eq!(trend.watching_change, -10_000);
+        assert_eq!(trend.dropped_change, 10_000);
+        assert!((trend.members_per_day - 1_000.0).abs() < 1.0);
+        assert!((trend.drop_rate - 0.1).abs() < 0.001);
+        // Sorted oldest first whatever order they came in
+        assert_eq!(trend.snapshots[0].watching, 100_000);
+    }
+
+    #[test]
+    fn test_single_snapshot_is_steady_without_momentum() {
+        let trend = TrendCalculator::provider_trend(
+            AnimeProvider::Jikan,
+            vec![snapshot(0, 1_000, 10, 5_000)],
+        )
+        .unwrap();
+
+        assert_eq!(trend.direction, TrendDirection::Steady);
+        assert_eq!(trend.members_per_day, 0.0);
+        assert!(TrendCalculator::momentum(&trend).is_none());
+    }
+
+    #[test]
+    fn test_momentum_from_growing_show() {
+        let trend = TrendCalculator::provider_trend(
+            AnimeProvider::Jikan,
+            vec![
+                snapshot(7, 40_000, 100, 80_000),
+                snapshot(0, 55_000, 200, 101_000),
+            ],
+        )
+        .unwrap();
+        let momentum = TrendCalculator::momentum(&trend).unwrap();
+
+        assert_eq!(trend.direction, TrendDirection::Gaining);
+        assert!((momentum.members_per_day - 3_000.0).abs() < 1.0);
+        assert!(momentum.drop_rate < 0.01);
+    }
+}
@@ -0,0 +1,82 @@
+use crate::modules::statistics::domain::{ProviderStatistics, StatisticsSnapshot};
+use crate::schema::anime_statistics_snapshots;
+use crate::shared::domain::value_objects::AnimeProvider;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use uuid::Uuid;
+
+// For reading from database
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone)]
+#[diesel(table_name = anime_statistics_snapshots)]
+pub struct StatisticsSnapshotModel {
+    pub id: Uuid,
+    pub anime_id: Uuid,
+    pub provider: AnimeProvider,
+    pub watching: i32,
+    pub completed: i32,
+    pub on_hold: i32,
+    pub dropped: i32,
+    pub plan_to_watch: i32,
+    pub total: i32,
+    pub favorites: Option<i32>,
+    pub score_distribution: serde_json::Value,
+    pub mean_score: Option<f32>,
+    pub captured_at: DateTime<Utc>,
+}
+
+// For inserting new rows
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = anime_statistics_snapshots)]
+pub struct NewStatisticsSnapshotModel {
+    pub anime_id: Uuid,
+    pub provider: AnimeProvider,
+    pub watching: i32,
+    pub completed: i32,
+    pub on_hold: i32,
+    pub dropped: i32,
+    pub plan_to_watch: i32,
+    pub total: i32,
+    pub favorites: Option<i32>,
+    pub score_distribution: serde_json::Value,
+    pub mean_score: Option<f32>,
+}
+
+impl From<StatisticsSnapshotModel> for StatisticsSnapshot {
+    fn from(model: StatisticsSnapshotModel) -> Self {
+        Self {
+            id: model.id,
+            anime_id: model.anime_id,
+            provider: model.provider,
+            watching: model.watching,
+            completed: model.completed,
+            on_hold: model.on_hold,
+            dropped: model.dropped,
+            plan_to_watch: model.plan_to_watch,
+            total: model.total,
+            favorites: model.favorites,
+            score_distribution: serde_json::from_value(model.score_distribution)
+                .unwrap_or_default(),
+            mean_score: model.mean_score,
+            captured_at: model.captured_at,
+        }
+    }
+}
+
+impl NewStatisticsSnapshotModel {
+    pub fn new(anime_id: Uuid, statistics: &ProviderStatistics) -> Self {
+        Self {
+            anime_id,
+            provider: statistics.provider,
+            watching: statistics.watching,
+            completed: statistics.completed,
+            on_hold: statistics.on_hold,
+            dropped: statistics.dropped,
+            plan_to_watch: statistics.plan_to_watch,
+            total: statistics.total,
+            favorites: statistics.favorites,
+            score_distribution: serde_json::to_value(&statistics.score_distribution)
+                .unwrap_or_default(),
+            mean_score: statistics.mean_score(),
+        }
+    }
+}
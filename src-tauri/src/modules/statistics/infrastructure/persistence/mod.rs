@@ -0,0 +1,3 @@
+pub mod statistics_repository_impl;
+
+pub use statistics_repository_impl::StatisticsRepositoryImpl;
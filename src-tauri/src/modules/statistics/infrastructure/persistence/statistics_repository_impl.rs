@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use tokio::task;
+use uuid::Uuid;
+
+use crate::modules::statistics::domain::{
+    ProviderStatistics, StatisticsRepository, StatisticsSnapshot,
+};
+use crate::modules::statistics::infrastructure::models::{
+    NewStatisticsSnapshotModel, StatisticsSnapshotModel,
+};
+use crate::schema::anime_statistics_snapshots;
+use crate::shared::errors::AppResult;
+use crate::shared::Database;
+
+pub struct StatisticsRepositoryImpl {
+    db: Arc<Database>,
+}
+
+impl StatisticsRepositoryImpl {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl StatisticsRepository for StatisticsRepositoryImpl {
+    async fn save_snapshot(
+        &self,
+        anime_id: &Uuid,
+        statistics: &ProviderStatistics,
+    ) -> AppResult<StatisticsSnapshot> {
+        let db = Arc::clone(&self.db);
+        let row = NewStatisticsSnapshotModel::new(*anime_id, statistics);
+
+        let model = task::spawn_blocking(move || -> AppResult<StatisticsSnapshotModel> {
+            let mut conn = db.get_connection()?;
+            let model = diesel::insert_into(anime_statistics_snapshots::table)
+                .values(&row)
+                .returning(StatisticsSnapshotModel::as_returning())
+                .get_result(&mut conn)?;
+            Ok(model)
+        })
+        .await??;
+
+        Ok(model.into())
+    }
+
+    async fn find_snapshots(
+        &self,
+        anime_id: &Uuid,
+        since: DateTime<Utc>,
+    ) -> AppResult<Vec<StatisticsSnapshot>> {
+        let db = Arc::clone(&self.db);
+        let anime_id = *anime_id;
+
+        let models = task::spawn_blocking(move || -> AppResult<Vec<StatisticsSnapshotModel>> {
+            let mut conn = db.get_connection()?;
+            let models = anime_statistics_snapshots::table
+                .filter(anime_statistics_snapshots::anime_id.eq(anime_id))
+                .filter(anime_statistics_snapshots::captured_at.gt(since))
+                .order(anime_statistics_snapshots::captured_at.asc())
+                .select(StatisticsSnapshotModel::as_select())
+                .load(&mut conn)?;
+            Ok(models)
+        })
+        .await??;
+
+        Ok(models.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_captured_since(
+        &self,
+        anime_ids: &[Uuid],
+        since: DateTime<Utc>,
+    ) -> AppResult<Vec<Uuid>> {
+        let db = Arc::clone(&self.db);
+        let anime_ids = anime_ids.to_vec();
+
+        task::spawn_blocking(move || -> AppResult<Vec<Uuid>> {
+            let mut conn = db.get_connection()?;
+            let ids = anime_statistics_snapshots::table
+                .filter(anime_statistics_snapshots::anime_id.eq_any(&anime_ids))
+                .filter(anime_statistics_snapshots::captured_at.gt(since))
+                .select(anime_statistics_snapshots::anime_id)
+                .distinct()
+                .load(&mut conn)?;
+            Ok(ids)
+        })
+        .await?
+    }
+}
@@ -0,0 +1,12 @@
+pub mod application;
+pub mod commands;
+pub mod domain;
+pub mod infrastructure;
+
+// Re-exports for easy external access
+pub use application::service::StatisticsService;
+pub use application::SnapshotMomentumSource;
+pub use domain::{
+    AnimeStatisticsTrend, ProviderStatistics, StatisticsRepository, StatisticsSnapshot,
+    TrendDirection,
+};
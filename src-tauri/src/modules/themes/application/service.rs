@@ -0,0 +1,112 @@
+use super::super::domain::{
+    AnimeTheme, ArtistTheme, ThemeArtist, ThemeParser, ThemeRepository, ThemeVideoMatcher,
+};
+use crate::modules::anime::{AnimeDetailed, AnimeRepository};
+use crate::modules::media::domain::{AnimeVideoRepository, VideoType};
+use crate::modules::provider::application::service::ProviderService;
+use crate::shared::errors::{AppError, AppResult};
+use crate::{log_debug, log_info};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Default number of artists returned by an artist search
+const DEFAULT_ARTIST_LIMIT: i64 = 20;
+
+pub struct ThemeService {
+    theme_repo: Arc<dyn ThemeRepository>,
+    video_repo: Arc<dyn AnimeVideoRepository>,
+    anime_repo: Arc<dyn AnimeRepository>,
+    provider_service: Arc<ProviderService>,
+}
+
+impl ThemeService {
+    pub fn new(
+        theme_repo: Arc<dyn ThemeRepository>,
+        video_repo: Arc<dyn AnimeVideoRepository>,
+        anime_repo: Arc<dyn AnimeRepository>,
+        provider_service: Arc<ProviderService>,
+    ) -> Self {
+        Self {
+            theme_repo,
+            video_repo,
+            anime_repo,
+            provider_service,
+        }
+    }
+
+    /// Stored openings and endings of an anime
+    pub async fn get_themes(&self, anime_id: &Uuid) -> AppResult<Vec<AnimeTheme>> {
+        self.theme_repo.find_by_anime(anime_id).await
+    }
+
+    /// Fetch theme songs from providers, link them to videos and replace the stored ones
+    pub async fn sync_themes(&self, anime_id: &Uuid) -> AppResult<Vec<AnimeTheme>> {
+        let anime = self.require_anime(anime_id).await?;
+
+        let songs = self
+            .provider_service
+            .fetch_anime_themes(&anime.provider_metadata)
+            .await?;
+
+        let mut themes = ThemeParser::parse_all(&songs);
+        if themes.is_empty() {
+            // Keep whatever we had rather than wiping it on an empty answer
+            log_debug!(
+                "No theme songs returned by providers for '{}'",
+                anime.title.main
+            );
+            return self.theme_repo.find_by_anime(anime_id).await;
+        }
+
+        let mut videos = Vec::new();
+        for video_type in [VideoType::Opening, VideoType::Ending] {
+            videos.extend(
+                self.video_repo
+                    .find_by_anime_and_type(*anime_id, video_type)
+                    .map_err(AppError::DatabaseError)?,
+            );
+        }
+        ThemeVideoMatcher::link(&mut themes, &videos);
+
+        let stored = self.theme_repo.replace_themes(anime_id, &themes).await?;
+        log_info!(
+            "Stored {} theme songs for '{}' ({} linked to videos)",
+            stored,
+            anime.title.main,
+            themes
+                .iter()
+                .filter(|theme| theme.video_id.is_some())
+                .count()
+        );
+
+        self.theme_repo.find_by_anime(anime_id).await
+    }
+
+    /// Artists credited on stored themes; an empty query lists the most prolific
+    pub async fn search_artists(
+        &self,
+        query: &str,
+        limit: Option<u32>,
+    ) -> AppResult<Vec<ThemeArtist>> {
+        let limit = limit.map(i64::from).unwrap_or(DEFAULT_ARTIST_LIMIT);
+        self.theme_repo.search_artists(query, limit).await
+    }
+
+    /// Every stored theme credited to an artist
+    pub async fn get_themes_by_artist(&self, artist: &str) -> AppResult<Vec<ArtistTheme>> {
+        if artist.trim().is_empty() {
+            return Err(AppError::ValidationError(
+                "Artist name cannot be empty".to_string(),
+            ));
+        }
+
+        self.theme_repo.find_by_artist(artist).await
+    }
+
+    async fn require_anime(&self, anime_id: &Uuid) -> AppResult<AnimeDetailed> {
+        self.anime_repo
+            .find_by_id(anime_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Anime with ID {} not found", anime_id)))
+    }
+}
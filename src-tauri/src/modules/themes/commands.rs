@@ -0,0 +1,79 @@
+use super::application::service::ThemeService;
+use super::domain::{AnimeTheme, ArtistTheme, ThemeArtist};
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::sync::Arc;
+use tauri::State;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct GetAnimeThemesRequest {
+    pub anime_id: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SyncAnimeThemesRequest {
+    pub anime_id: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SearchThemeArtistsRequest {
+    #[serde(default)]
+    pub query: String,
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct GetThemesByArtistRequest {
+    pub artist: String,
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_anime_themes(
+    request: GetAnimeThemesRequest,
+    theme_service: State<'_, Arc<ThemeService>>,
+) -> Result<Vec<AnimeTheme>, String> {
+    theme_service
+        .get_themes(&request.anime_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn sync_anime_themes(
+    request: SyncAnimeThemesRequest,
+    theme_service: State<'_, Arc<ThemeService>>,
+) -> Result<Vec<AnimeTheme>, String> {
+    theme_service
+        .sync_themes(&request.anime_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn search_theme_artists(
+    request: SearchThemeArtistsRequest,
+    theme_service: State<'_, Arc<ThemeService>>,
+) -> Result<Vec<ThemeArtist>, String> {
+    theme_service
+        .search_artists(&request.query, request.limit)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Every theme song of an artist across the library
+#[tauri::command]
+#[specta::specta]
+pub async fn get_themes_by_artist(
+    request: GetThemesByArtistRequest,
+    theme_service: State<'_, Arc<ThemeService>>,
+) -> Result<Vec<ArtistTheme>, String> {
+    theme_service
+        .get_themes_by_artist(&request.artist)
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,59 @@
+use super::super::value_objects::ThemeKind;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use uuid::Uuid;
+
+/// A parsed theme song ready to be stored
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewAnimeTheme {
+    pub kind: ThemeKind,
+    pub sequence: i32,
+    pub title: String,
+    pub title_native: Option<String>,
+    pub artist: Option<String>,
+    pub episodes: Option<String>,
+    pub episode_start: Option<i32>,
+    pub episode_end: Option<i32>,
+    pub raw_text: String,
+    /// Opening or ending video of the anime showing this song
+    pub video_id: Option<Uuid>,
+}
+
+/// A stored opening or ending song of an anime
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct AnimeTheme {
+    pub id: Uuid,
+    pub anime_id: Uuid,
+    pub kind: ThemeKind,
+    /// `OP2` is sequence 2 of kind opening
+    pub sequence: i32,
+    pub title: String,
+    pub title_native: Option<String>,
+    pub artist: Option<String>,
+    /// Episodes as listed by the provider, e.g. `1-11, 13-24`
+    pub episodes: Option<String>,
+    pub episode_start: Option<i32>,
+    pub episode_end: Option<i32>,
+    pub video_id: Option<Uuid>,
+    pub video_url: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A theme song together with the anime it belongs to
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtistTheme {
+    pub anime_title: String,
+    pub theme: AnimeTheme,
+}
+
+/// An artist credited on theme songs in the library
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ThemeArtist {
+    pub name: String,
+    pub theme_count: u32,
+    pub anime_count: u32,
+}
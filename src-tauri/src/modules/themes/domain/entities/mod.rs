@@ -0,0 +1,5 @@
+pub mod anime_theme;
+pub mod provider_theme_song;
+
+pub use anime_theme::{AnimeTheme, ArtistTheme, NewAnimeTheme, ThemeArtist};
+pub use provider_theme_song::ProviderThemeSong;
@@ -0,0 +1,10 @@
+use super::super::value_objects::ThemeKind;
+use crate::shared::domain::value_objects::AnimeProvider;
+
+/// A theme song line as listed by a provider, e.g. `1: "Tank!" by The Seatbelts (eps 1-25)`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProviderThemeSong {
+    pub provider: AnimeProvider,
+    pub kind: ThemeKind,
+    pub text: String,
+}
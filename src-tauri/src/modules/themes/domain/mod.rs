@@ -0,0 +1,10 @@
+pub mod entities;
+pub mod repositories;
+pub mod services;
+pub mod value_objects;
+
+// Re-exports for easy access
+pub use entities::{AnimeTheme, ArtistTheme, NewAnimeTheme, ProviderThemeSong, ThemeArtist};
+pub use repositories::ThemeRepository;
+pub use services::{ParsedTheme, ThemeParser, ThemeVideoMatcher};
+pub use value_objects::ThemeKind;
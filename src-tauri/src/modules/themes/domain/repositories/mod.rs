@@ -0,0 +1,3 @@
+pub mod theme_repository;
+
+pub use theme_repository::ThemeRepository;
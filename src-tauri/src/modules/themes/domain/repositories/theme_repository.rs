@@ -0,0 +1,19 @@
+use super::super::entities::{AnimeTheme, ArtistTheme, NewAnimeTheme, ThemeArtist};
+use crate::shared::errors::AppResult;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait ThemeRepository: Send + Sync {
+    /// Replace every stored theme of an anime
+    async fn replace_themes(&self, anime_id: &Uuid, themes: &[NewAnimeTheme]) -> AppResult<usize>;
+
+    /// Themes of an anime, openings first, in sequence order
+    async fn find_by_anime(&self, anime_id: &Uuid) -> AppResult<Vec<AnimeTheme>>;
+
+    /// Themes credited to an artist, matched case-insensitively
+    async fn find_by_artist(&self, artist: &str) -> AppResult<Vec<ArtistTheme>>;
+
+    /// Artists whose name contains the query, most prolific first
+    async fn search_artists(&self, query: &str, limit: i64) -> AppResult<Vec<ThemeArtist>>;
+}
@@ -0,0 +1,5 @@
+pub mod theme_parser;
+pub mod theme_video_matcher;
+
+pub use theme_parser::{ParsedTheme, ThemeParser};
+pub use theme_video_matcher::ThemeVideoMatcher;
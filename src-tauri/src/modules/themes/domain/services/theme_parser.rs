@@ -0,0 +1,275 @@
+use super::super::entities::{NewAnimeTheme, ProviderThemeSong};
+use std::collections::{HashMap, HashSet};
+
+/// Structured fields of a single theme song line
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedTheme {
+    pub sequence: Option<i32>,
+    pub title: String,
+    pub title_native: Option<String>,
+    pub artist: Option<String>,
+    pub episodes: Option<String>,
+    pub episode_start: Option<i32>,
+    pub episode_end: Option<i32>,
+}
+
+/// Parses MyAnimeList theme song lines
+///
+/// Lines look like `1: "Tank!" by The Seatbelts (eps 1-25)`. The sequence
+/// number and episode range are optional, and the native title is usually
+/// appended in parentheses inside the quotes.
+pub struct ThemeParser;
+
+impl ThemeParser {
+    pub fn parse(text: &str) -> Option<ParsedTheme> {
+        let (sequence, rest) = Self::split_sequence(text.trim());
+        let (rest, episodes) = Self::split_episodes(rest);
+        let (title, artist) = Self::split_title_and_artist(rest)?;
+        let (title, title_native) = Self::split_native_title(&title);
+
+        if title.is_empty() {
+            return None;
+        }
+
+        let episode_numbers: Vec<i32> = episodes
+            .as_deref()
+            .map(|episodes| {
+                episodes
+                    .split(|c: char| !c.is_ascii_digit())
+                    .filter_map(|number| number.parse().ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Some(ParsedTheme {
+            sequence,
+            title: title.to_string(),
+            title_native,
+            artist: artist
+                .map(str::trim)
+                .filter(|artist| !artist.is_empty())
+                .map(str::to_string),
+            episodes,
+            episode_start: episode_numbers.first().copied(),
+            episode_end: episode_numbers.last().copied(),
+        })
+    }
+
+    /// Parses every line of an anime, numbering lines that lack a sequence
+    ///
+    /// A line without a number follows the previous line of the same kind.
+    /// Lines repeating an already used kind and number are dropped.
+    pub fn parse_all(songs: &[ProviderThemeSong]) -> Vec<NewAnimeTheme> {
+        let mut last_sequence = HashMap::new();
+        let mut seen = HashSet::new();
+        let mut themes = Vec::new();
+
+        for song in songs {
+            let Some(parsed) = Self::parse(&song.text) else {
+                continue;
+            };
+
+            let last = last_sequence.entry(song.kind).or_insert(0);
+            let sequence = parsed.sequence.unwrap_or(*last + 1);
+            *last = sequence;
+
+            if !seen.insert((song.kind, sequence)) {
+                continue;
+            }
+
+            themes.push(NewAnimeTheme {
+                kind: song.kind,
+                sequence,
+                title: parsed.title,
+                title_native: parsed.title_native,
+                artist: parsed.artist,
+                episodes: parsed.episodes,
+                episode_start: parsed.episode_start,
+                episode_end: parsed.episode_end,
+                raw_text: song.text.trim().to_string(),
+                video_id: None,
+            });
+        }
+
+        themes
+    }
+
+    /// `#2: "..."` and `2: "..."` both yield sequence 2
+    fn split_sequence(text: &str) -> (Option<i32>, &str) {
+        let unprefixed = text.strip_prefix('#').unwrap_or(text);
+        let digits = unprefixed
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(unprefixed.len());
+
+        if digits == 0 {
+            return (None, text);
+        }
+
+        match unprefixed[digits..].strip_prefix(':') {
+            Some(rest) => (unprefixed[..digits].parse().ok(), rest.trim()),
+            None => (None, text),
+        }
+    }
+
+    /// Splits off a trailing `(eps 1-11, 13-24)` or `(ep 12)`
+    fn split_episodes(text: &str) -> (&str, Option<String>) {
+        let Some(inner_end) = text.strip_suffix(')') else {
+            return (text, None);
+        };
+        let Some(open) = inner_end.rfind('(') else {
+            return (text, None);
+        };
+
+        let inner = inner_end[open + 1..].trim();
+        if !inner.to_lowercase().starts_with("ep") {
+            return (text, None);
+        }
+
+        let episodes = inner
+            .trim_start_matches(|c: char| c.is_alphabetic() || c == '.')
+            .trim();
+        let episodes = (!episodes.is_empty()).then(|| episodes.to_string());
+        (text[..open].trim(), episodes)
+    }
+
+    fn split_title_and_artist(text: &str) -> Option<(String, Option<&str>)> {
+        if let Some(quoted) = text.strip_prefix('"') {
+            // Artists can be quoted too, so prefer the quote that precedes "by"
+            let close = quoted
+                .find("\" by ")
+                .or_else(|| quoted.rfind('"'))
+                .unwrap_or(quoted.len());
+            let mut title = quoted[..close].trim().to_string();
+            let mut after = quoted.get(close + 1..).unwrap_or("").trim();
+
+            // `"Hikari" (ひかり) by ...` puts the native title outside the quotes
+            if let Some(paren_end) = after.starts_with('(').then(|| after.find(')')).flatten() {
+                title = format!("{} {}", title, &after[..=paren_end]);
+                after = after[paren_end + 1..].trim();
+            }
+
+            let artist = after.strip_prefix("by ");
+            return Some((title, artist));
+        }
+
+        let (title, artist) = text.split_once(" by ")?;
+        Some((title.trim().trim_matches('"').to_string(), Some(artist)))
+    }
+
+    /// `Guren no Yumiya (紅蓮の弓矢)` keeps the non-Latin part apart
+    fn split_native_title(title: &str) -> (&str, Option<String>) {
+        let Some(inner_end) = title.strip_suffix(')') else {
+            return (title, None);
+        };
+        let Some(open) = inner_end.rfind('(') else {
+            return (title, None);
+        };
+
+        let native = inner_end[open + 1..].trim();
+        if native.is_ascii() || native.is_empty() {
+            return (title, None);
+        }
+
+        (title[..open].trim(), Some(native.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::themes::domain::ThemeKind;
+    use crate::shared::domain::value_objects::AnimeProvider;
+
+    fn song(kind: ThemeKind, text: &str) -> ProviderThemeSong {
+        ProviderThemeSong {
+            provider: AnimeProvider::Jikan,
+            kind,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parses_numbered_line_with_episodes() {
+        let parsed = ThemeParser::parse("1: \"Tank!\" by The Seatbelts (eps 1-25)").unwrap();
+
+        assert_eq!(parsed.sequence, Some(1));
+        assert_eq!(parsed.title, "Tank!");
+        assert_eq!(parsed.artist.as_deref(), Some("The Seatbelts"));
+        assert_eq!(parsed.episodes.as_deref(), Some("1-25"));
+        assert_eq!(
+            (parsed.episode_start, parsed.episode_end),
+            (Some(1), Some(25))
+        );
+    }
+
+    #[test]
+    fn test_parses_native_title_and_split_episode_ranges() {
+        let parsed = ThemeParser::parse(
+            "\"Guren no Yumiya (紅蓮の弓矢)\" by Linked Horizon (eps 1-11, 13-24)",
+        )
+        .unwrap();
+
+        assert_eq!(parsed.sequence, None);
+        assert_eq!(parsed.title, "Guren no Yumiya");
+        assert_eq!(parsed.title_native.as_deref(), Some("紅蓮の弓矢"));
+        assert_eq!(parsed.artist.as_deref(), Some("Linked Horizon"));
+        assert_eq!(parsed.episodes.as_deref(), Some("1-11, 13-24"));
+        assert_eq!(
+            (parsed.episode_start, parsed.episode_end),
+            (Some(1), Some(24))
+        );
+
+        let parsed = ThemeParser::parse("\"Hikari\" (ひかり) by Utada Hikaru").unwrap();
+        assert_eq!(parsed.title, "Hikari");
+        assert_eq!(parsed.title_native.as_deref(), Some("ひかり"));
+        assert_eq!(parsed.artist.as_deref(), Some("Utada Hikaru"));
+    }
+
+    #[test]
+    fn test_parses_lines_without_episodes_or_artist() {
+        let parsed = ThemeParser::parse("#2: \"The Real Folk Blues\" by Mai Yamane").unwrap();
+        assert_eq!(parsed.sequence, Some(2));
+        assert_eq!(parsed.episodes, None);
+        assert_eq!(parsed.episode_start, None);
+
+        let parsed = ThemeParser::parse("\"Blue (Ver. 2)\" (ep 26)").unwrap();
+        assert_eq!(parsed.title, "Blue (Ver. 2)");
+        assert_eq!(parsed.title_native, None);
+        assert_eq!(parsed.artist, None);
+        assert_eq!(
+            (parsed.episode_start, parsed.episode_end),
+            (Some(26), Some(26))
+        );
+
+        assert_eq!(
+            ThemeParser::parse("No opening themes have been added"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_all_numbers_lines_per_kind() {
+        let themes = ThemeParser::parse_all(&[
+            song(
+                ThemeKind::Opening,
+                "\"unravel\" by TK from Ling tosite sigure",
+            ),
+            song(
+                ThemeKind::Opening,
+                "\"Kyouki no Sakura\" by Ling tosite sigure",
+            ),
+            song(ThemeKind::Ending, "\"Saints\" by People In The Box"),
+            song(ThemeKind::Ending, "1: \"Saints\" by People In The Box"),
+        ]);
+
+        let keys: Vec<(ThemeKind, i32)> = themes.iter().map(|t| (t.kind, t.sequence)).collect();
+        assert_eq!(
+            keys,
+            vec![
+                (ThemeKind::Opening, 1),
+                (ThemeKind::Opening, 2),
+                (ThemeKind::Ending, 1),
+            ]
+        );
+    }
+}
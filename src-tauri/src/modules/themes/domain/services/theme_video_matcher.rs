@@ -0,0 +1,176 @@
+use super::super::entities::NewAnimeTheme;
+use super::super::value_objects::ThemeKind;
+use crate::modules::media::domain::AnimeVideo;
+use std::collections::HashSet;
+
+/// Links parsed theme songs to the anime's opening and ending videos
+///
+/// A video is matched by the song title appearing in its name, then by a
+/// label such as `OP 2` or `NCED1` carrying the theme's sequence. When an
+/// anime has a single song and a single video of a kind they are paired.
+/// Each video is linked to at most one theme.
+pub struct ThemeVideoMatcher;
+
+impl ThemeVideoMatcher {
+    pub fn link(themes: &mut [NewAnimeTheme], videos: &[AnimeVideo]) {
+        for kind in [ThemeKind::Opening, ThemeKind::Ending] {
+            let candidates: Vec<&AnimeVideo> = videos
+                .iter()
+                .filter(|video| video.video_type == kind.video_type())
+                .collect();
+            if candidates.is_empty() {
+                continue;
+            }
+
+            let mut used = HashSet::new();
+            let indices: Vec<usize> = themes
+                .iter()
+                .enumerate()
+                .filter(|(_, theme)| theme.kind == kind)
+                .map(|(index, _)| index)
+                .collect();
+
+            for &index in &indices {
+                let title = themes[index].title.to_lowercase();
+                let found = candidates.iter().find(|video| {
+                    !used.contains(&video.id) && video.name.to_lowercase().contains(&title)
+                });
+                if let Some(video) = found {
+                    used.insert(video.id);
+                    themes[index].video_id = Some(video.id);
+                }
+            }
+
+            for &index in &indices {
+                if themes[index].video_id.is_some() {
+                    continue;
+                }
+                let sequence = themes[index].sequence;
+                let found = candidates.iter().find(|video| {
+                    !used.contains(&video.id)
+                        && Self::labelled_sequence(&video.name, kind) == Some(sequence)
+                });
+                if let Some(video) = found {
+                    used.insert(video.id);
+                    themes[index].video_id = Some(video.id);
+                }
+            }
+
+            if let ([index], [video]) = (indices.as_slice(), candidates.as_slice()) {
+                if themes[*index].video_id.is_none() && !used.contains(&video.id) {
+                    themes[*index].video_id = Some(video.id);
+                }
+            }
+        }
+    }
+
+    /// Number following a kind label, e.g. 2 for `Opening 2` or `NCOP2`
+    fn labelled_sequence(name: &str, kind: ThemeKind) -> Option<i32> {
+        let mut tokens = Vec::new();
+        for word in name
+            .to_lowercase()
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+        {
+            let split = word
+                .find(|c: char| c.is_ascii_digit())
+                .unwrap_or(word.len());
+            let (letters, digits) = word.split_at(split);
+            if !letters.is_empty() {
+                tokens.push(letters.to_string());
+            }
+            if !digits.is_empty() {
+                tokens.push(digits.to_string());
+            }
+        }
+
+        tokens.windows(2).find_map(|pair| {
+            kind.video_labels()
+                .contains(&pair[0].as_str())
+                .then(|| pair[1].parse().ok())
+                .flatten()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::media::domain::VideoType;
+    use crate::shared::domain::value_objects::AnimeProvider;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    fn theme(kind: ThemeKind, sequence: i32, title: &str) -> NewAnimeTheme {
+        NewAnimeTheme {
+            kind,
+            sequence,
+            title: title.to_string(),
+            title_native: None,
+            artist: None,
+            episodes: None,
+            episode_start: None,
+            episode_end: None,
+            raw_text: title.to_string(),
+            video_id: None,
+        }
+    }
+
+    fn video(video_type: VideoType, name: &str) -> AnimeVideo {
+        AnimeVideo {
+            id: Uuid::new_v4(),
+            anime_id: Uuid::new_v4(),
+            provider: AnimeProvider::Jikan,
+            provider_video_id: None,
+            video_type,
+            is_official: true,
+            name: name.to_string(),
+            site: "YouTube".to_string(),
+            key: name.to_string(),
+            url: format!("https://www.youtube.com/watch?v={}", name),
+            resolution: None,
+            duration_seconds: None,
+            language: None,
+            published_at: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            synced_at: None,
+        }
+    }
+
+    #[test]
+    fn test_links_by_title_then_label() {
+        let mut themes = vec![
+            theme(ThemeKind::Opening, 1, "unravel"),
+            theme(ThemeKind::Opening, 2, "Asphyxia"),
+            theme(ThemeKind::Ending, 1, "Saints"),
+        ];
+        let videos = vec![
+            video(VideoType::Opening, "NCOP2"),
+            video(VideoType::Opening, "Opening 1 | Unravel"),
+            video(VideoType::Ending, "Ending 3"),
+        ];
+
+        ThemeVideoMatcher::link(&mut themes, &videos);
+
+        assert_eq!(themes[0].video_id, Some(videos[1].id));
+        assert_eq!(themes[1].video_id, Some(videos[0].id));
+        assert_eq!(themes[2].video_id, Some(videos[2].id));
+    }
+
+    #[test]
+    fn test_leaves_ambiguous_themes_unlinked() {
+        let mut themes = vec![
+            theme(ThemeKind::Ending, 1, "Saints"),
+            theme(ThemeKind::Ending, 2, "Hikari"),
+        ];
+        let videos = vec![
+            video(VideoType::Ending, "Ending"),
+            video(VideoType::Opening, "Opening 2"),
+        ];
+
+        ThemeVideoMatcher::link(&mut themes, &videos);
+
+        assert!(themes.iter().all(|theme| theme.video_id.is_none()));
+    }
+}
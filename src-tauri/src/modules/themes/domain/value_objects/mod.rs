@@ -0,0 +1,3 @@
+pub mod theme_kind;
+
+pub use theme_kind::ThemeKind;
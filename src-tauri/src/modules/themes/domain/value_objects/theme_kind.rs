@@ -0,0 +1,31 @@
+use crate::modules::media::domain::value_objects::VideoType;
+use diesel_derive_enum::DbEnum;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Whether a theme song opens or closes the episode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, DbEnum, Type)]
+#[ExistingTypePath = "crate::schema::sql_types::ThemeKind"]
+#[serde(rename_all = "snake_case")]
+pub enum ThemeKind {
+    Opening,
+    Ending,
+}
+
+impl ThemeKind {
+    /// Video type of the credit sequences that play this kind of song
+    pub fn video_type(&self) -> VideoType {
+        match self {
+            ThemeKind::Opening => VideoType::Opening,
+            ThemeKind::Ending => VideoType::Ending,
+        }
+    }
+
+    /// Words video titles use for this kind, e.g. `OP 2` or `NCED1`
+    pub fn video_labels(&self) -> &'static [&'static str] {
+        match self {
+            ThemeKind::Opening => &["opening", "op", "ncop"],
+            ThemeKind::Ending => &["ending", "ed", "nced"],
+        }
+    }
+}
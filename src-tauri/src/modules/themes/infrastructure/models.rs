@@ -0,0 +1,79 @@
+use crate::modules::themes::domain::{AnimeTheme, NewAnimeTheme, ThemeKind};
+use crate::schema::anime_themes;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use uuid::Uuid;
+
+// For reading from database
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone)]
+#[diesel(table_name = anime_themes)]
+pub struct AnimeThemeModel {
+    pub id: Uuid,
+    pub anime_id: Uuid,
+    pub video_id: Option<Uuid>,
+    pub kind: ThemeKind,
+    pub sequence: i32,
+    pub title: String,
+    pub title_native: Option<String>,
+    pub artist: Option<String>,
+    pub episodes: Option<String>,
+    pub episode_start: Option<i32>,
+    pub episode_end: Option<i32>,
+    pub raw_text: String,
+    pub created_at: DateTime<Utc>,
+}
+
+// For inserting new rows
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = anime_themes)]
+pub struct NewAnimeThemeModel {
+    pub anime_id: Uuid,
+    pub video_id: Option<Uuid>,
+    pub kind: ThemeKind,
+    pub sequence: i32,
+    pub title: String,
+    pub title_native: Option<String>,
+    pub artist: Option<String>,
+    pub episodes: Option<String>,
+    pub episode_start: Option<i32>,
+    pub episode_end: Option<i32>,
+    pub raw_text: String,
+}
+
+impl AnimeThemeModel {
+    pub fn into_theme(self, video_url: Option<String>) -> AnimeTheme {
+        AnimeTheme {
+            id: self.id,
+            anime_id: self.anime_id,
+            kind: self.kind,
+            sequence: self.sequence,
+            title: self.title,
+            title_native: self.title_native,
+            artist: self.artist,
+            episodes: self.episodes,
+            episode_start: self.episode_start,
+            episode_end: self.episode_end,
+            video_id: self.video_id,
+            video_url,
+            created_at: self.created_at,
+        }
+    }
+}
+
+impl NewAnimeThemeModel {
+    pub fn new(anime_id: Uuid, theme: &NewAnimeTheme) -> Self {
+        Self {
+            anime_id,
+            video_id: theme.video_id,
+            kind: theme.kind,
+            sequence: theme.sequence,
+            title: theme.title.clone(),
+            title_native: theme.title_native.clone(),
+            artist: theme.artist.clone(),
+            episodes: theme.episodes.clone(),
+            episode_start: theme.episode_start,
+            episode_end: theme.episode_end,
+            raw_text: theme.raw_text.clone(),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod theme_repository_impl;
+
+pub use theme_repository_impl::ThemeRepositoryImpl;
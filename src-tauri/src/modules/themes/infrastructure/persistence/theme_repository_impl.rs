@@ -0,0 +1,147 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use diesel::dsl::{count_distinct, count_star};
+use diesel::prelude::*;
+use tokio::task;
+use uuid::Uuid;
+
+use crate::modules::themes::domain::{
+    AnimeTheme, ArtistTheme, NewAnimeTheme, ThemeArtist, ThemeRepository,
+};
+use crate::modules::themes::infrastructure::models::{AnimeThemeModel, NewAnimeThemeModel};
+use crate::schema::{anime, anime_themes, anime_videos};
+use crate::shared::errors::AppResult;
+use crate::shared::infrastructure::escape_like;
+use crate::shared::Database;
+
+pub struct ThemeRepositoryImpl {
+    db: Arc<Database>,
+}
+
+impl ThemeRepositoryImpl {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl ThemeRepository for ThemeRepositoryImpl {
+    async fn replace_themes(&self, anime_id: &Uuid, themes: &[NewAnimeTheme]) -> AppResult<usize> {
+        let db = Arc::clone(&self.db);
+        let anime_id = *anime_id;
+        let rows: Vec<NewAnimeThemeModel> = themes
+            .iter()
+            .map(|theme| NewAnimeThemeModel::new(anime_id, theme))
+            .collect();
+
+        task::spawn_blocking(move || -> AppResult<usize> {
+            let mut conn = db.get_connection()?;
+
+            conn.transaction(|conn| {
+                diesel::delete(anime_themes::table.filter(anime_themes::anime_id.eq(anime_id)))
+                    .execute(conn)?;
+
+                let inserted = diesel::insert_into(anime_themes::table)
+                    .values(&rows)
+                    .execute(conn)?;
+                Ok(inserted)
+            })
+        })
+        .await?
+    }
+
+    async fn find_by_anime(&self, anime_id: &Uuid) -> AppResult<Vec<AnimeTheme>> {
+        let db = Arc::clone(&self.db);
+        let anime_id = *anime_id;
+
+        let rows = task::spawn_blocking(
+            move || -> AppResult<Vec<(AnimeThemeModel, Option<String>)>> {
+                let mut conn = db.get_connection()?;
+                let rows = anime_themes::table
+                    .left_join(anime_videos::table)
+                    .filter(anime_themes::anime_id.eq(anime_id))
+                    .order((anime_themes::kind.asc(), anime_themes::sequence.asc()))
+                    .select((AnimeThemeModel::as_select(), anime_videos::url.nullable()))
+                    .load(&mut conn)?;
+                Ok(rows)
+            },
+        )
+        .await??;
+
+        Ok(rows
+            .into_iter()
+            .map(|(model, video_url)| model.into_theme(video_url))
+            .collect())
+    }
+
+    async fn find_by_artist(&self, artist: &str) -> AppResult<Vec<ArtistTheme>> {
+        let db = Arc::clone(&self.db);
+        let pattern = escape_like(artist);
+
+        let rows = task::spawn_blocking(
+            move || -> AppResult<Vec<(AnimeThemeModel, Option<String>, String)>> {
+                let mut conn = db.get_connection()?;
+                let rows = anime_themes::table
+                    .inner_join(anime::table)
+                    .left_join(anime_videos::table)
+                    .filter(anime_themes::artist.ilike(&pattern))
+                    .order((
+                        anime::title_main.asc(),
+                        anime_themes::kind.asc(),
+                        anime_themes::sequence.asc(),
+                    ))
+                    .select((
+                        AnimeThemeModel::as_select(),
+                        anime_videos::url.nullable(),
+                        anime::title_main,
+                    ))
+                    .load(&mut conn)?;
+                Ok(rows)
+            },
+        )
+        .await??;
+
+        Ok(rows
+            .into_iter()
+            .map(|(model, video_url, anime_title)| ArtistTheme {
+                anime_title,
+                theme: model.into_theme(video_url),
+            })
+            .collect())
+    }
+
+    async fn search_artists(&self, query: &str, limit: i64) -> AppResult<Vec<ThemeArtist>> {
+        let db = Arc::clone(&self.db);
+        let pattern = format!("%{}%", escape_like(query));
+
+        let rows = task::spawn_blocking(move || -> AppResult<Vec<(Option<String>, i64, i64)>> {
+            let mut conn = db.get_connection()?;
+            let rows = anime_themes::table
+                .filter(anime_themes::artist.is_not_null())
+                .filter(anime_themes::artist.ilike(&pattern))
+                .group_by(anime_themes::artist)
+                .select((
+                    anime_themes::artist,
+                    count_star(),
+                    count_distinct(anime_themes::anime_id),
+                ))
+                .order((count_star().desc(), anime_themes::artist.asc()))
+                .limit(limit)
+                .load(&mut conn)?;
+            Ok(rows)
+        })
+        .await??;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(name, theme_count, anime_count)| {
+                Some(ThemeArtist {
+                    name: name?,
+                    theme_count: theme_count as u32,
+                    anime_count: anime_count as u32,
+                })
+            })
+            .collect())
+    }
+}
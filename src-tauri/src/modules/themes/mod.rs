@@ -0,0 +1,10 @@
+pub mod application;
+pub mod commands;
+pub mod domain;
+pub mod infrastructure;
+
+// Re-exports for easy external access
+pub use application::service::ThemeService;
+pub use domain::{
+    AnimeTheme, ArtistTheme, ProviderThemeSong, ThemeArtist, ThemeKind, ThemeRepository,
+};
@@ -0,0 +1,188 @@
+use super::super::domain::{WatchEntry, WatchEntryRepository, WatchingStatus};
+use crate::modules::anime::domain::{
+    entities::anime_detailed::AnimeDetailed, repositories::anime_repository::AnimeRepository,
+};
+use crate::shared::errors::{AppError, AppResult};
+use crate::{log_debug, log_info};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Watch-list entry together with the anime it tracks
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchListItem {
+    pub entry: WatchEntry,
+    pub anime: AnimeDetailed,
+}
+
+/// Number of tracked anime per status
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchListSummary {
+    pub plan_to_watch: u32,
+    pub watching: u32,
+    pub completed: u32,
+    pub on_hold: u32,
+    pub dropped: u32,
+    pub rewatching: u32,
+    pub total: u32,
+}
+
+/// Optional field updates applied to a watch entry
+#[derive(Debug, Clone, Default)]
+pub struct WatchEntryUpdate {
+    pub personal_rating: Option<Option<f32>>,
+    pub is_favorite: Option<bool>,
+    pub notes: Option<Option<String>>,
+    pub tags: Option<Vec<String>>,
+}
+
+pub struct WatchTrackingService {
+    watch_repo: Arc<dyn WatchEntryRepository>,
+    anime_repo: Arc<dyn AnimeRepository>,
+}
+
+impl WatchTrackingService {
+    pub fn new(
+        watch_repo: Arc<dyn WatchEntryRepository>,
+        anime_repo: Arc<dyn AnimeRepository>,
+    ) -> Self {
+        Self {
+            watch_repo,
+            anime_repo,
+        }
+    }
+
+    pub async fn get_entry(&self, anime_id: &Uuid) -> AppResult<Option<WatchEntry>> {
+        self.watch_repo.find_by_anime(anime_id).await
+    }
+
+    pub async fn get_watch_list(
+        &self,
+        status: Option<WatchingStatus>,
+    ) -> AppResult<Vec<WatchListItem>> {
+        let entries = self.watch_repo.find_all(status).await?;
+
+        let mut items = Vec::with_capacity(entries.len());
+        for entry in entries {
+            match self.anime_repo.find_by_id(&entry.anime_id).await? {
+                Some(anime) => items.push(WatchListItem { entry, anime }),
+                None => log_debug!("Skipping watch entry for missing anime {}", entry.anime_id),
+            }
+        }
+
+        Ok(items)
+    }
+
+    pub async fn set_status(
+        &self,
+        anime_id: &Uuid,
+        status: WatchingStatus,
+    ) -> AppResult<WatchEntry> {
+        let anime = self.require_anime(anime_id).await?;
+        let mut entry = self.load_or_new(anime_id).await?;
+
+        entry.set_status(status, anime.episodes.map(u32::from));
+
+        let saved = self.watch_repo.save(&entry).await?;
+        log_info!("Set watch status of '{}' to {}", anime.title.main, status);
+        Ok(saved)
+    }
+
+    /// Record episode progress, auto-completing when the last episode is reached
+    pub async fn update_progress(
+        &self,
+        anime_id: &Uuid,
+        episodes_watched: u32,
+    ) -> AppResult<WatchEntry> {
+        let anime = self.require_anime(anime_id).await?;
+        let mut entry = self.load_or_new(anime_id).await?;
+
+        entry.record_progress(episodes_watched, anime.episodes.map(u32::from))?;
+
+        let saved = self.watch_repo.save(&entry).await?;
+        log_info!(
+            "Recorded {} episodes watched for '{}' ({:?})",
+            saved.episodes_watched,
+            anime.title.main,
+            saved.status
+        );
+        Ok(saved)
+    }
+
+    /// Mark one more episode as watched
+    pub async fn increment_progress(&self, anime_id: &Uuid) -> AppResult<WatchEntry> {
+        let current = self
+            .watch_repo
+            .find_by_anime(anime_id)
+            .await?
+            .map(|entry| entry.episodes_watched)
+            .unwrap_or(0);
+
+        self.update_progress(anime_id, current + 1).await
+    }
+
+    pub async fn update_entry(
+        &self,
+        anime_id: &Uuid,
+        update: WatchEntryUpdate,
+    ) -> AppResult<WatchEntry> {
+        self.require_anime(anime_id).await?;
+        let mut entry = self.load_or_new(anime_id).await?;
+
+        if let Some(rating) = update.personal_rating {
+            entry.set_rating(rating)?;
+        }
+        if let Some(is_favorite) = update.is_favorite {
+            entry.set_favorite(is_favorite);
+        }
+        if let Some(notes) = update.notes {
+            entry.update_notes(notes);
+        }
+        if let Some(tags) = update.tags {
+            entry.set_tags(tags);
+        }
+
+        self.watch_repo.save(&entry).await
+    }
+
+    pub async fn remove_entry(&self, anime_id: &Uuid) -> AppResult<()> {
+        self.watch_repo.delete(anime_id).await
+    }
+
+    pub async fn get_summary(&self) -> AppResult<WatchListSummary> {
+        let mut summary = WatchListSummary::default();
+
+        for (status, count) in self.watch_repo.count_by_status().await? {
+            let count = count as u32;
+            match status {
+                WatchingStatus::PlanToWatch => summary.plan_to_watch = count,
+                WatchingStatus::Watching => summary.watching = count,
+                WatchingStatus::Completed => summary.completed = count,
+                WatchingStatus::OnHold => summary.on_hold = count,
+                WatchingStatus::Dropped => summary.dropped = count,
+                WatchingStatus::Rewatching => summary.rewatching = count,
+            }
+            summary.total += count;
+        }
+
+        Ok(summary)
+    }
+
+    async fn require_anime(&self, anime_id: &Uuid) -> AppResult<AnimeDetailed> {
+        self.anime_repo
+            .find_by_id(anime_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Anime with ID {} not found", anime_id)))
+    }
+
+    async fn load_or_new(&self, anime_id: &Uuid) -> AppResult<WatchEntry> {
+        Ok(self
+            .watch_repo
+            .find_by_anime(anime_id)
+            .await?
+            .unwrap_or_else(|| WatchEntry::new(*anime_id)))
+    }
+}
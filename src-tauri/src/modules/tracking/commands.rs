@@ -0,0 +1,164 @@
+use super::application::service::{
+    WatchEntryUpdate, WatchListItem, WatchListSummary, WatchTrackingService,
+};
+use super::domain::{WatchEntry, WatchingStatus};
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::sync::Arc;
+use tauri::State;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct GetWatchEntryRequest {
+    pub anime_id: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct GetWatchListRequest {
+    pub status: Option<WatchingStatus>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SetWatchStatusRequest {
+    pub anime_id: Uuid,
+    pub status: WatchingStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct UpdateWatchProgressRequest {
+    pub anime_id: Uuid,
+    pub episodes_watched: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct IncrementWatchProgressRequest {
+    pub anime_id: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct UpdateWatchEntryRequest {
+    pub anime_id: Uuid,
+    /// Omit to keep the current rating; `clear_rating` removes it
+    pub personal_rating: Option<f32>,
+    #[serde(default)]
+    pub clear_rating: bool,
+    pub is_favorite: Option<bool>,
+    pub notes: Option<String>,
+    pub tags: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct RemoveFromWatchListRequest {
+    pub anime_id: Uuid,
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_watch_entry(
+    request: GetWatchEntryRequest,
+    tracking_service: State<'_, Arc<WatchTrackingService>>,
+) -> Result<Option<WatchEntry>, String> {
+    tracking_service
+        .get_entry(&request.anime_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_watch_list(
+    request: GetWatchListRequest,
+    tracking_service: State<'_, Arc<WatchTrackingService>>,
+) -> Result<Vec<WatchListItem>, String> {
+    tracking_service
+        .get_watch_list(request.status)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_watch_list_summary(
+    tracking_service: State<'_, Arc<WatchTrackingService>>,
+) -> Result<WatchListSummary, String> {
+    tracking_service
+        .get_summary()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn set_watch_status(
+    request: SetWatchStatusRequest,
+    tracking_service: State<'_, Arc<WatchTrackingService>>,
+) -> Result<WatchEntry, String> {
+    tracking_service
+        .set_status(&request.anime_id, request.status)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn update_watch_progress(
+    request: UpdateWatchProgressRequest,
+    tracking_service: State<'_, Arc<WatchTrackingService>>,
+) -> Result<WatchEntry, String> {
+    tracking_service
+        .update_progress(&request.anime_id, request.episodes_watched)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn increment_watch_progress(
+    request: IncrementWatchProgressRequest,
+    tracking_service: State<'_, Arc<WatchTrackingService>>,
+) -> Result<WatchEntry, String> {
+    tracking_service
+        .increment_progress(&request.anime_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn update_watch_entry(
+    request: UpdateWatchEntryRequest,
+    tracking_service: State<'_, Arc<WatchTrackingService>>,
+) -> Result<WatchEntry, String> {
+    let personal_rating = if request.clear_rating {
+        Some(None)
+    } else {
+        request.personal_rating.map(Some)
+    };
+
+    let update = WatchEntryUpdate {
+        personal_rating,
+        is_favorite: request.is_favorite,
+        notes: request
+            .notes
+            .map(|n| Some(n).filter(|n| !n.trim().is_empty())),
+        tags: request.tags,
+    };
+
+    tracking_service
+        .update_entry(&request.anime_id, update)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn remove_from_watch_list(
+    request: RemoveFromWatchListRequest,
+    tracking_service: State<'_, Arc<WatchTrackingService>>,
+) -> Result<(), String> {
+    tracking_service
+        .remove_entry(&request.anime_id)
+        .await
+        .map_err(|e| e.to_string())
+}
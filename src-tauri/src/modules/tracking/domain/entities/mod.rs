@@ -0,0 +1,3 @@
+pub mod watch_entry;
+
+pub use watch_entry::WatchEntry;
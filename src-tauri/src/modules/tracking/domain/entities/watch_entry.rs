@@ -0,0 +1,211 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use uuid::Uuid;
+
+use super::super::value_objects::WatchingStatus;
+use crate::shared::errors::{AppError, AppResult};
+
+/// Personal watch-tracking state for one anime
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchEntry {
+    pub anime_id: Uuid,
+    pub status: Option<WatchingStatus>,
+    pub personal_rating: Option<f32>,
+    pub episodes_watched: u32,
+    pub rewatched_count: u32,
+    pub is_favorite: bool,
+    pub notes: Option<String>,
+    pub tags: Vec<String>,
+    pub start_date: Option<DateTime<Utc>>,
+    pub finish_date: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl WatchEntry {
+    pub fn new(anime_id: Uuid) -> Self {
+        let now = Utc::now();
+        Self {
+            anime_id,
+            status: None,
+            personal_rating: None,
+            episodes_watched: 0,
+            rewatched_count: 0,
+            is_favorite: false,
+            notes: None,
+            tags: Vec::new(),
+            start_date: None,
+            finish_date: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Change the watching status, keeping dates and progress consistent
+    ///
+    /// `total_episodes` is the episode count of the anime when known; marking
+    /// an entry as completed fills progress up to it.
+    pub fn set_status(&mut self, status: WatchingStatus, total_episodes: Option<u32>) {
+        let now = Utc::now();
+
+        match status {
+            WatchingStatus::Watching => {
+                self.start_date.get_or_insert(now);
+            }
+            WatchingStatus::Rewatching => {
+                // A rewatch starts from the first episode again
+                if self.status != Some(WatchingStatus::Rewatching) {
+                    self.episodes_watched = 0;
+                }
+                self.start_date.get_or_insert(now);
+            }
+            WatchingStatus::Completed => {
+                if self.status == Some(WatchingStatus::Rewatching) {
+                    self.rewatched_count += 1;
+                }
+                if let Some(total) = total_episodes {
+                    self.episodes_watched = total;
+                }
+                self.start_date.get_or_insert(now);
+                self.finish_date = Some(now);
+            }
+            WatchingStatus::PlanToWatch | WatchingStatus::OnHold | WatchingStatus::Dropped => {}
+        }
+
+        self.status = Some(status);
+        self.updated_at = now;
+    }
+
+    /// Record how many episodes have been watched
+    ///
+    /// Starting progress on an untracked or planned entry moves it to
+    /// watching; reaching `total_episodes` completes it.
+    pub fn record_progress(
+        &mut self,
+        episodes_watched: u32,
+        total_episodes: Option<u32>,
+    ) -> AppResult<()> {
+        if let Some(total) = total_episodes {
+            if total > 0 && episodes_watched > total {
+                return Err(AppError::InvalidInput(format!(
+                    "Episodes watched ({}) exceeds the episode count ({})",
+                    episodes_watched, total
+                )));
+            }
+        }
+
+        self.episodes_watched = episodes_watched;
+        self.updated_at = Utc::now();
+
+        let reached_end =
+            matches!(total_episodes, Some(total) if total > 0 && episodes_watched == total);
+        if reached_end {
+            if self.status != Some(WatchingStatus::Completed) {
+                self.set_status(WatchingStatus::Completed, total_episodes);
+            }
+        } else if episodes_watched > 0 && !self.status.is_some_and(|s| s.is_in_progress()) {
+            self.set_status(WatchingStatus::Watching, total_episodes);
+        }
+
+        Ok(())
+    }
+
+    pub fn set_rating(&mut self, rating: Option<f32>) -> AppResult<()> {
+        if let Some(value) = rating {
+            if !(0.0..=10.0).contains(&value) {
+                return Err(AppError::InvalidInput(format!(
+                    "Personal rating must be between 0 and 10, got {}",
+                    value
+                )));
+            }
+        }
+
+        self.personal_rating = rating;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    pub fn set_favorite(&mut self, is_favorite: bool) {
+        self.is_favorite = is_favorite;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn update_notes(&mut self, notes: Option<String>) {
+        self.notes = notes;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn set_tags(&mut self, tags: Vec<String>) {
+        let mut seen = std::collections::HashSet::new();
+        self.tags = tags
+            .into_iter()
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty() && seen.insert(t.to_lowercase()))
+            .collect();
+        self.updated_at = Utc::now();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_starts_watching() {
+        let mut entry = WatchEntry::new(Uuid::new_v4());
+        entry.record_progress(3, Some(12)).unwrap();
+
+        assert_eq!(entry.status, Some(WatchingStatus::Watching));
+        assert_eq!(entry.episodes_watched, 3);
+        assert!(entry.start_date.is_some());
+        assert!(entry.finish_date.is_none());
+    }
+
+    #[test]
+    fn test_progress_auto_completes_at_last_episode() {
+        let mut entry = WatchEntry::new(Uuid::new_v4());
+        entry.set_status(WatchingStatus::Watching, Some(12));
+        entry.record_progress(12, Some(12)).unwrap();
+
+        assert_eq!(entry.status, Some(WatchingStatus::Completed));
+        assert!(entry.finish_date.is_some());
+    }
+
+    #[test]
+    fn test_progress_beyond_episode_count_is_rejected() {
+        let mut entry = WatchEntry::new(Uuid::new_v4());
+        assert!(entry.record_progress(13, Some(12)).is_err());
+        assert_eq!(entry.episodes_watched, 0);
+    }
+
+    #[test]
+    fn test_progress_with_unknown_episode_count_never_completes() {
+        let mut entry = WatchEntry::new(Uuid::new_v4());
+        entry.record_progress(500, None).unwrap();
+
+        assert_eq!(entry.status, Some(WatchingStatus::Watching));
+    }
+
+    #[test]
+    fn test_completing_rewatch_increments_count() {
+        let mut entry = WatchEntry::new(Uuid::new_v4());
+        entry.set_status(WatchingStatus::Completed, Some(12));
+        entry.set_status(WatchingStatus::Rewatching, Some(12));
+        assert_eq!(entry.episodes_watched, 0);
+
+        entry.record_progress(12, Some(12)).unwrap();
+
+        assert_eq!(entry.status, Some(WatchingStatus::Completed));
+        assert_eq!(entry.rewatched_count, 1);
+    }
+
+    #[test]
+    fn test_rating_out_of_range_is_rejected() {
+        let mut entry = WatchEntry::new(Uuid::new_v4());
+        assert!(entry.set_rating(Some(11.0)).is_err());
+        assert!(entry.set_rating(Some(8.5)).is_ok());
+        assert_eq!(entry.personal_rating, Some(8.5));
+    }
+}
@@ -0,0 +1,8 @@
+pub mod entities;
+pub mod repositories;
+pub mod value_objects;
+
+// Re-exports for easy access
+pub use entities::WatchEntry;
+pub use repositories::WatchEntryRepository;
+pub use value_objects::WatchingStatus;
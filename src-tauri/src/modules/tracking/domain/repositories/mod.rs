@@ -0,0 +1,3 @@
+pub mod watch_entry_repository;
+
+pub use watch_entry_repository::WatchEntryRepository;
@@ -0,0 +1,20 @@
+use super::super::{entities::WatchEntry, value_objects::WatchingStatus};
+use crate::shared::errors::AppResult;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait WatchEntryRepository: Send + Sync {
+    async fn find_by_anime(&self, anime_id: &Uuid) -> AppResult<Option<WatchEntry>>;
+
+    /// List tracked entries, optionally restricted to one status
+    async fn find_all(&self, status: Option<WatchingStatus>) -> AppResult<Vec<WatchEntry>>;
+
+    /// Insert or update the entry for its anime
+    async fn save(&self, entry: &WatchEntry) -> AppResult<WatchEntry>;
+
+    async fn delete(&self, anime_id: &Uuid) -> AppResult<()>;
+
+    /// Number of tracked entries per status
+    async fn count_by_status(&self) -> AppResult<Vec<(WatchingStatus, i64)>>;
+}
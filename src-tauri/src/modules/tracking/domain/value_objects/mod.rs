@@ -0,0 +1,3 @@
+pub mod watching_status;
+
+pub use watching_status::WatchingStatus;
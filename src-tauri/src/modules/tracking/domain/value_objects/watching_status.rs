@@ -0,0 +1,39 @@
+use diesel_derive_enum::DbEnum;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, DbEnum, Type)]
+#[ExistingTypePath = "crate::schema::sql_types::WatchingStatus"]
+#[serde(rename_all = "snake_case")]
+pub enum WatchingStatus {
+    PlanToWatch,
+    Watching,
+    Completed,
+    OnHold,
+    Dropped,
+    Rewatching,
+}
+
+impl WatchingStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WatchingStatus::PlanToWatch => "plan_to_watch",
+            WatchingStatus::Watching => "watching",
+            WatchingStatus::Completed => "completed",
+            WatchingStatus::OnHold => "on_hold",
+            WatchingStatus::Dropped => "dropped",
+            WatchingStatus::Rewatching => "rewatching",
+        }
+    }
+
+    /// Returns whether the user is actively progressing through episodes
+    pub fn is_in_progress(&self) -> bool {
+        matches!(self, WatchingStatus::Watching | WatchingStatus::Rewatching)
+    }
+}
+
+impl std::fmt::Display for WatchingStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
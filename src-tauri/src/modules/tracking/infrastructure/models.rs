@@ -0,0 +1,58 @@
+use crate::modules::tracking::domain::WatchingStatus;
+use crate::schema::user_anime_data;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use uuid::Uuid;
+
+// For reading from database
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone)]
+#[diesel(table_name = user_anime_data)]
+#[diesel(primary_key(anime_id, user_id))]
+pub struct UserAnimeDataModel {
+    pub anime_id: Uuid,
+    pub user_id: String,
+    pub status: Option<WatchingStatus>,
+    pub personal_rating: Option<f32>,
+    pub episodes_watched: Option<i32>,
+    pub rewatched_count: Option<i32>,
+    pub is_favorite: Option<bool>,
+    pub notes: Option<String>,
+    pub tags: Option<serde_json::Value>,
+    pub start_date: Option<DateTime<Utc>>,
+    pub finish_date: Option<DateTime<Utc>>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+// For inserting new rows
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = user_anime_data)]
+pub struct NewUserAnimeData {
+    pub anime_id: Uuid,
+    pub user_id: String,
+    pub status: Option<WatchingStatus>,
+    pub personal_rating: Option<f32>,
+    pub episodes_watched: Option<i32>,
+    pub rewatched_count: Option<i32>,
+    pub is_favorite: Option<bool>,
+    pub notes: Option<String>,
+    pub tags: Option<serde_json::Value>,
+    pub start_date: Option<DateTime<Utc>>,
+    pub finish_date: Option<DateTime<Utc>>,
+}
+
+// For updating existing rows (excludes keys and created_at)
+#[derive(AsChangeset, Debug, Clone)]
+#[diesel(table_name = user_anime_data)]
+#[diesel(treat_none_as_null = true)]
+pub struct UserAnimeDataChangeset {
+    pub status: Option<WatchingStatus>,
+    pub personal_rating: Option<f32>,
+    pub episodes_watched: Option<i32>,
+    pub rewatched_count: Option<i32>,
+    pub is_favorite: Option<bool>,
+    pub notes: Option<String>,
+    pub tags: Option<serde_json::Value>,
+    pub start_date: Option<DateTime<Utc>>,
+    pub finish_date: Option<DateTime<Utc>>,
+}
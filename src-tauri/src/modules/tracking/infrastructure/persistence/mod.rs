@@ -0,0 +1,3 @@
+pub mod watch_entry_repository_impl;
+
+pub use watch_entry_repository_impl::WatchEntryRepositoryImpl;
@@ -0,0 +1,192 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+use tokio::task;
+use uuid::Uuid;
+
+use crate::modules::tracking::domain::{WatchEntry, WatchEntryRepository, WatchingStatus};
+use crate::modules::tracking::infrastructure::models::{
+    NewUserAnimeData, UserAnimeDataChangeset, UserAnimeDataModel,
+};
+use crate::schema::user_anime_data;
+use crate::shared::domain::LOCAL_USER_ID;
+use crate::shared::errors::{AppError, AppResult};
+use crate::shared::Database;
+
+pub struct WatchEntryRepositoryImpl {
+    db: Arc<Database>,
+}
+
+impl WatchEntryRepositoryImpl {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    fn model_to_entity(model: UserAnimeDataModel) -> WatchEntry {
+        let updated_at = model.updated_at.unwrap_or_else(chrono::Utc::now);
+
+        WatchEntry {
+            anime_id: model.anime_id,
+            status: model.status,
+            personal_rating: model.personal_rating,
+            episodes_watched: model.episodes_watched.unwrap_or(0).max(0) as u32,
+            rewatched_count: model.rewatched_count.unwrap_or(0).max(0) as u32,
+            is_favorite: model.is_favorite.unwrap_or(false),
+            notes: model.notes,
+            tags: model
+                .tags
+                .and_then(|v| serde_json::from_value::<Vec<String>>(v).ok())
+                .unwrap_or_default(),
+            start_date: model.start_date,
+            finish_date: model.finish_date,
+            created_at: model.created_at.unwrap_or(updated_at),
+            updated_at,
+        }
+    }
+
+    fn entity_to_new_model(entry: &WatchEntry) -> NewUserAnimeData {
+        NewUserAnimeData {
+            anime_id: entry.anime_id,
+            user_id: LOCAL_USER_ID.to_string(),
+            status: entry.status,
+            personal_rating: entry.personal_rating,
+            episodes_watched: Some(entry.episodes_watched as i32),
+            rewatched_count: Some(entry.rewatched_count as i32),
+            is_favorite: Some(entry.is_favorite),
+            notes: entry.notes.clone(),
+            tags: Some(serde_json::json!(entry.tags)),
+            start_date: entry.start_date,
+            finish_date: entry.finish_date,
+        }
+    }
+
+    fn entity_to_changeset(entry: &WatchEntry) -> UserAnimeDataChangeset {
+        UserAnimeDataChangeset {
+            status: entry.status,
+            personal_rating: entry.personal_rating,
+            episodes_watched: Some(entry.episodes_watched as i32),
+            rewatched_count: Some(entry.rewatched_count as i32),
+            is_favorite: Some(entry.is_favorite),
+            notes: entry.notes.clone(),
+            tags: Some(serde_json::json!(entry.tags)),
+            start_date: entry.start_date,
+            finish_date: entry.finish_date,
+        }
+    }
+}
+
+#[async_trait]
+impl WatchEntryRepository for WatchEntryRepositoryImpl {
+    async fn find_by_anime(&self, anime_id: &Uuid) -> AppResult<Option<WatchEntry>> {
+        let db = Arc::clone(&self.db);
+        let anime_id = *anime_id;
+
+        let model = task::spawn_blocking(move || -> AppResult<Option<UserAnimeDataModel>> {
+            let mut conn = db.get_connection()?;
+            let m = user_anime_data::table
+                .filter(user_anime_data::anime_id.eq(anime_id))
+                .filter(user_anime_data::user_id.eq(LOCAL_USER_ID))
+                .select(UserAnimeDataModel::as_select())
+                .first(&mut conn)
+                .optional()?;
+            Ok(m)
+        })
+        .await??;
+
+        Ok(model.map(Self::model_to_entity))
+    }
+
+    async fn find_all(&self, status: Option<WatchingStatus>) -> AppResult<Vec<WatchEntry>> {
+        let db = Arc::clone(&self.db);
+
+        let models = task::spawn_blocking(move || -> AppResult<Vec<UserAnimeDataModel>> {
+            let mut conn = db.get_connection()?;
+            let mut query = user_anime_data::table
+                .filter(user_anime_data::user_id.eq(LOCAL_USER_ID))
+                .filter(user_anime_data::status.is_not_null())
+                .into_boxed();
+
+            if let Some(status) = status {
+                query = query.filter(user_anime_data::status.eq(status));
+            }
+
+            let rows = query
+                .order(user_anime_data::updated_at.desc())
+                .select(UserAnimeDataModel::as_select())
+                .load(&mut conn)?;
+            Ok(rows)
+        })
+        .await??;
+
+        Ok(models.into_iter().map(Self::model_to_entity).collect())
+    }
+
+    async fn save(&self, entry: &WatchEntry) -> AppResult<WatchEntry> {
+        let db = Arc::clone(&self.db);
+        let new_row = Self::entity_to_new_model(entry);
+        let changeset = Self::entity_to_changeset(entry);
+
+        let model = task::spawn_blocking(move || -> AppResult<UserAnimeDataModel> {
+            let mut conn = db.get_connection()?;
+            let m = diesel::insert_into(user_anime_data::table)
+                .values(&new_row)
+                .on_conflict((user_anime_data::anime_id, user_anime_data::user_id))
+                .do_update()
+                .set(&changeset)
+                .returning(UserAnimeDataModel::as_returning())
+                .get_result(&mut conn)?;
+            Ok(m)
+        })
+        .await??;
+
+        Ok(Self::model_to_entity(model))
+    }
+
+    async fn delete(&self, anime_id: &Uuid) -> AppResult<()> {
+        let db = Arc::clone(&self.db);
+        let anime_id = *anime_id;
+
+        let deleted = task::spawn_blocking(move || -> AppResult<usize> {
+            let mut conn = db.get_connection()?;
+            let n = diesel::delete(
+                user_anime_data::table
+                    .filter(user_anime_data::anime_id.eq(anime_id))
+                    .filter(user_anime_data::user_id.eq(LOCAL_USER_ID)),
+            )
+            .execute(&mut conn)?;
+            Ok(n)
+        })
+        .await??;
+
+        if deleted == 0 {
+            return Err(AppError::NotFound(format!(
+                "Anime {} is not on the watch list",
+                anime_id
+            )));
+        }
+        Ok(())
+    }
+
+    async fn count_by_status(&self) -> AppResult<Vec<(WatchingStatus, i64)>> {
+        use diesel::dsl::count_star;
+
+        let db = Arc::clone(&self.db);
+
+        task::spawn_blocking(move || -> AppResult<Vec<(WatchingStatus, i64)>> {
+            let mut conn = db.get_connection()?;
+            let rows = user_anime_data::table
+                .filter(user_anime_data::user_id.eq(LOCAL_USER_ID))
+                .filter(user_anime_data::status.is_not_null())
+                .group_by(user_anime_data::status)
+                .select((user_anime_data::status, count_star()))
+                .load::<(Option<WatchingStatus>, i64)>(&mut conn)?;
+
+            Ok(rows
+                .into_iter()
+                .filter_map(|(status, count)| status.map(|s| (s, count)))
+                .collect())
+        })
+        .await?
+    }
+}
@@ -17,6 +17,10 @@ pub mod sql_types {
     #[diesel(postgres_type(name = "anime_type"))]
     pub struct AnimeType;
 
+    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "character_role"))]
+    pub struct CharacterRole;
+
     #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
     #[diesel(postgres_type(name = "image_type"))]
     pub struct ImageType;
@@ -25,10 +29,30 @@ pub mod sql_types {
     #[diesel(postgres_type(name = "job_status"))]
     pub struct JobStatus;
 
+    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "link_category"))]
+    pub struct LinkCategory;
+
     #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
     #[diesel(postgres_type(name = "media_provider"))]
     pub struct MediaProvider;
 
+    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "overall_feeling"))]
+    pub struct OverallFeeling;
+
+    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "recommendation_preference"))]
+    pub struct RecommendationPreference;
+
+    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "staff_role"))]
+    pub struct StaffRole;
+
+    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "theme_kind"))]
+    pub struct ThemeKind;
+
     #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
     #[diesel(postgres_type(name = "unified_age_restriction"))]
     pub struct UnifiedAgeRestriction;
@@ -87,6 +111,68 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    anime_airing_schedule (id) {
+        id -> Uuid,
+        anime_id -> Uuid,
+        episode -> Int4,
+        airs_at -> Timestamptz,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    anime_broadcasts (anime_id) {
+        anime_id -> Uuid,
+        #[max_length = 10]
+        day -> Varchar,
+        #[max_length = 5]
+        start_time -> Varchar,
+        #[max_length = 64]
+        timezone -> Varchar,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::CharacterRole;
+
+    anime_characters (id) {
+        id -> Uuid,
+        anime_id -> Uuid,
+        character_id -> Uuid,
+        voice_actor_id -> Nullable<Uuid>,
+        role -> CharacterRole,
+        #[max_length = 50]
+        language -> Nullable<Varchar>,
+        position -> Int4,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    anime_episodes (id) {
+        id -> Uuid,
+        anime_id -> Uuid,
+        number -> Int4,
+        #[max_length = 500]
+        title -> Nullable<Varchar>,
+        #[max_length = 500]
+        title_japanese -> Nullable<Varchar>,
+        #[max_length = 500]
+        title_romaji -> Nullable<Varchar>,
+        aired_at -> Nullable<Timestamptz>,
+        score -> Nullable<Float4>,
+        is_filler -> Bool,
+        is_recap -> Bool,
+        thumbnail_url -> Nullable<Text>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     anime_external_ids (anime_id, provider_code) {
         anime_id -> Uuid,
@@ -134,6 +220,60 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::LinkCategory;
+    use super::sql_types::MediaProvider;
+
+    anime_links (id) {
+        id -> Uuid,
+        anime_id -> Uuid,
+        category -> LinkCategory,
+        #[max_length = 255]
+        site -> Varchar,
+        #[max_length = 255]
+        host -> Varchar,
+        url -> Text,
+        #[max_length = 50]
+        language -> Nullable<Varchar>,
+        provider -> MediaProvider,
+        position -> Int4,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    anime_news (anime_id, news_item_id) {
+        anime_id -> Uuid,
+        news_item_id -> Uuid,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    anime_recommendation_syncs (anime_id) {
+        anime_id -> Uuid,
+        synced_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    anime_recommendations (id) {
+        id -> Uuid,
+        source_anime_id -> Uuid,
+        target_mal_id -> Nullable<Int4>,
+        target_anilist_id -> Nullable<Int4>,
+        #[max_length = 500]
+        title -> Varchar,
+        image_url -> Nullable<Text>,
+        genres -> Jsonb,
+        studios -> Jsonb,
+        votes -> Int4,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::AnimeRelationType;
@@ -148,6 +288,43 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::StaffRole;
+
+    anime_staff (id) {
+        id -> Uuid,
+        anime_id -> Uuid,
+        person_id -> Uuid,
+        role -> StaffRole,
+        #[max_length = 255]
+        role_label -> Varchar,
+        position -> Int4,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::MediaProvider;
+
+    anime_statistics_snapshots (id) {
+        id -> Uuid,
+        anime_id -> Uuid,
+        provider -> MediaProvider,
+        watching -> Int4,
+        completed -> Int4,
+        on_hold -> Int4,
+        dropped -> Int4,
+        plan_to_watch -> Int4,
+        total -> Int4,
+        favorites -> Nullable<Int4>,
+        score_distribution -> Jsonb,
+        mean_score -> Nullable<Float4>,
+        captured_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     anime_studios (anime_id, studio_id) {
         anime_id -> Uuid,
@@ -155,6 +332,31 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ThemeKind;
+
+    anime_themes (id) {
+        id -> Uuid,
+        anime_id -> Uuid,
+        video_id -> Nullable<Uuid>,
+        kind -> ThemeKind,
+        sequence -> Int4,
+        #[max_length = 500]
+        title -> Varchar,
+        #[max_length = 500]
+        title_native -> Nullable<Varchar>,
+        #[max_length = 500]
+        artist -> Nullable<Varchar>,
+        #[max_length = 255]
+        episodes -> Nullable<Varchar>,
+        episode_start -> Nullable<Int4>,
+        episode_end -> Nullable<Int4>,
+        raw_text -> Text,
+        created_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::MediaProvider;
@@ -200,6 +402,23 @@ diesel::table! {
         started_at -> Nullable<Timestamptz>,
         completed_at -> Nullable<Timestamptz>,
         error -> Nullable<Text>,
+        scheduled_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    characters (id) {
+        id -> Uuid,
+        mal_id -> Nullable<Int4>,
+        anilist_id -> Nullable<Int4>,
+        #[max_length = 255]
+        name -> Varchar,
+        #[max_length = 255]
+        name_native -> Nullable<Varchar>,
+        image_url -> Nullable<Text>,
+        description -> Nullable<Text>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
     }
 }
 
@@ -235,6 +454,43 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::MediaProvider;
+
+    news_items (id) {
+        id -> Uuid,
+        provider -> MediaProvider,
+        url -> Text,
+        #[max_length = 500]
+        title -> Varchar,
+        excerpt -> Nullable<Text>,
+        image_url -> Nullable<Text>,
+        #[max_length = 255]
+        author -> Nullable<Varchar>,
+        forum_url -> Nullable<Text>,
+        comments -> Int4,
+        published_at -> Nullable<Timestamptz>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    people (id) {
+        id -> Uuid,
+        mal_id -> Nullable<Int4>,
+        anilist_id -> Nullable<Int4>,
+        #[max_length = 255]
+        name -> Varchar,
+        #[max_length = 255]
+        name_native -> Nullable<Varchar>,
+        image_url -> Nullable<Text>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     providers (code) {
         #[max_length = 20]
@@ -289,33 +545,88 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::OverallFeeling;
+    use super::sql_types::RecommendationPreference;
+
+    user_ratings (id) {
+        id -> Uuid,
+        anime_id -> Uuid,
+        #[max_length = 255]
+        user_id -> Varchar,
+        overall_feeling -> OverallFeeling,
+        recommendation_preference -> Nullable<RecommendationPreference>,
+        rating_criteria -> Jsonb,
+        emotional_tags -> Jsonb,
+        standout_aspects -> Jsonb,
+        personal_notes -> Nullable<Text>,
+        would_rewatch -> Nullable<Bool>,
+        composite_score -> Float4,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::joinable!(anime_airing_schedule -> anime (anime_id));
+diesel::joinable!(anime_broadcasts -> anime (anime_id));
 diesel::joinable!(anime_external_ids -> anime (anime_id));
+diesel::joinable!(anime_episodes -> anime (anime_id));
+diesel::joinable!(anime_characters -> anime (anime_id));
+diesel::joinable!(anime_characters -> characters (character_id));
+diesel::joinable!(anime_characters -> people (voice_actor_id));
 diesel::joinable!(anime_external_ids -> providers (provider_code));
 diesel::joinable!(anime_genres -> anime (anime_id));
 diesel::joinable!(anime_genres -> genres (genre_id));
 diesel::joinable!(anime_images -> anime (anime_id));
+diesel::joinable!(anime_links -> anime (anime_id));
+diesel::joinable!(anime_news -> anime (anime_id));
+diesel::joinable!(anime_news -> news_items (news_item_id));
+diesel::joinable!(anime_recommendation_syncs -> anime (anime_id));
+diesel::joinable!(anime_recommendations -> anime (source_anime_id));
+diesel::joinable!(anime_staff -> anime (anime_id));
+diesel::joinable!(anime_staff -> people (person_id));
+diesel::joinable!(anime_statistics_snapshots -> anime (anime_id));
 diesel::joinable!(anime_studios -> anime (anime_id));
 diesel::joinable!(anime_studios -> studios (studio_id));
+diesel::joinable!(anime_themes -> anime (anime_id));
+diesel::joinable!(anime_themes -> anime_videos (video_id));
 diesel::joinable!(anime_videos -> anime (anime_id));
 diesel::joinable!(collection_anime -> anime (anime_id));
 diesel::joinable!(collection_anime -> collections (collection_id));
 diesel::joinable!(quality_metrics -> anime (anime_id));
 diesel::joinable!(user_anime_data -> anime (anime_id));
+diesel::joinable!(user_ratings -> anime (anime_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     anime,
+    anime_airing_schedule,
+    anime_broadcasts,
+    anime_characters,
+    anime_episodes,
     anime_external_ids,
     anime_genres,
     anime_images,
+    anime_links,
+    anime_news,
+    anime_recommendation_syncs,
+    anime_recommendations,
     anime_relations,
+    anime_staff,
+    anime_statistics_snapshots,
     anime_studios,
+    anime_themes,
     anime_videos,
     background_jobs,
+    characters,
     collection_anime,
     collections,
     genres,
+    news_items,
+    people,
     providers,
     quality_metrics,
     studios,
     user_anime_data,
+    user_ratings,
 );
@@ -0,0 +1,73 @@
+//! Per-anime refreshes over the library
+//!
+//! Syncs that fetch something from providers for each anime in turn share this
+//! loop, so a single failing anime is logged and counted instead of aborting
+//! the whole refresh.
+
+use crate::log_warn;
+use crate::shared::errors::AppResult;
+use std::future::Future;
+
+/// What a library batch produced
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchOutcome<T> {
+    /// Results of the anime that went through, in input order
+    pub completed: Vec<T>,
+    pub failed: u32,
+}
+
+/// Run `step` for each item one after another
+///
+/// Items run sequentially so provider rate limits hold. When a step fails,
+/// the warning logged for it starts with `failure`'s message.
+pub async fn run_library_batch<I, T, Fut>(
+    items: impl IntoIterator<Item = I>,
+    failure: impl Fn(&I) -> String,
+    step: impl Fn(I) -> Fut,
+) -> BatchOutcome<T>
+where
+    Fut: Future<Output = AppResult<T>>,
+{
+    let mut outcome = BatchOutcome {
+        completed: Vec::new(),
+        failed: 0,
+    };
+
+    for item in items {
+        let message = failure(&item);
+        match step(item).await {
+            Ok(result) => outcome.completed.push(result),
+            Err(e) => {
+                log_warn!("{}: {}", message, e);
+                outcome.failed += 1;
+            }
+        }
+    }
+
+    outcome
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::errors::AppError;
+
+    #[tokio::test]
+    async fn test_failed_items_are_counted_and_skipped() {
+        let outcome = run_library_batch(
+            vec![1, 2, 3, 4],
+            |n| format!("Failed to sync {}", n),
+            |n| async move {
+                if n % 2 == 0 {
+                    Err(AppError::ApiError(format!("{} is even", n)))
+                } else {
+                    Ok(n * 10)
+                }
+            },
+        )
+        .await;
+
+        assert_eq!(outcome.completed, vec![10, 30]);
+        assert_eq!(outcome.failed, 2);
+    }
+}
@@ -2,8 +2,10 @@
 ///
 /// This module contains application-level abstractions used across
 /// multiple bounded contexts.
+pub mod library_batch;
 pub mod pagination;
 pub mod use_case;
 
+pub use library_batch::{run_library_batch, BatchOutcome};
 pub use pagination::*;
 pub use use_case::UseCase;
@@ -1,6 +1,7 @@
 /// Pagination support for queries
 ///
 /// Standard pagination model used across all bounded contexts
+use crate::shared::errors::{AppError, AppResult};
 use serde::{Deserialize, Serialize};
 use specta::Type;
 
@@ -25,6 +26,16 @@ impl PaginationParams {
         Self { page, page_size }
     }
 
+    /// Reject page numbers below 1, which have no offset
+    pub fn validate(&self) -> AppResult<()> {
+        if self.page == 0 {
+            return Err(AppError::ValidationError(
+                "Page numbers start at 1".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
     /// Calculate offset for database queries
     pub fn offset(&self) -> i64 {
         ((self.page - 1) * self.page_size) as i64
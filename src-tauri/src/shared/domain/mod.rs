@@ -1 +1,4 @@
 pub mod value_objects;
+
+/// Owner of all per-user rows in this single-user desktop app
+pub const LOCAL_USER_ID: &str = "local";
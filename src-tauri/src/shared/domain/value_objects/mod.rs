@@ -1,7 +1,9 @@
 mod anime_provider;
 mod provider_metadata;
+mod staff_role;
 mod unified_age_restriction;
 
 pub use anime_provider::AnimeProvider;
 pub use provider_metadata::ProviderMetadata;
+pub use staff_role::StaffRole;
 pub use unified_age_restriction::UnifiedAgeRestriction;
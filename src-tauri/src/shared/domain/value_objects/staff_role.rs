@@ -0,0 +1,137 @@
+use diesel_derive_enum::DbEnum;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Staff position normalised across providers
+///
+/// MAL and AniList spell positions differently and AniList appends episode
+/// notes (`Storyboard (eps 1, 3)`); both collapse to the same role here.
+/// Positions we don't track individually are stored as `Other` with their label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, DbEnum, Type)]
+#[ExistingTypePath = "crate::schema::sql_types::StaffRole"]
+#[serde(rename_all = "snake_case")]
+pub enum StaffRole {
+    Director,
+    SeriesComposition,
+    Script,
+    OriginalCreator,
+    CharacterDesign,
+    ChiefAnimationDirector,
+    ArtDirector,
+    SoundDirector,
+    Music,
+    EpisodeDirector,
+    Storyboard,
+    AnimationDirector,
+    Producer,
+    Other,
+}
+
+impl StaffRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StaffRole::Director => "director",
+            StaffRole::SeriesComposition => "series_composition",
+            StaffRole::Script => "script",
+            StaffRole::OriginalCreator => "original_creator",
+            StaffRole::CharacterDesign => "character_design",
+            StaffRole::ChiefAnimationDirector => "chief_animation_director",
+            StaffRole::ArtDirector => "art_director",
+            StaffRole::SoundDirector => "sound_director",
+            StaffRole::Music => "music",
+            StaffRole::EpisodeDirector => "episode_director",
+            StaffRole::Storyboard => "storyboard",
+            StaffRole::AnimationDirector => "animation_director",
+            StaffRole::Producer => "producer",
+            StaffRole::Other => "other",
+        }
+    }
+
+    /// Parse a provider position label, ignoring case and episode notes
+    pub fn from_provider(label: &str) -> Self {
+        match Self::clean_label(label).to_lowercase().as_str() {
+            "director" | "chief director" | "series director" => StaffRole::Director,
+            "series composition" => StaffRole::SeriesComposition,
+            "script" | "screenplay" => StaffRole::Script,
+            "original creator" | "original story" | "original work" => StaffRole::OriginalCreator,
+            "character design" | "original character design" | "anime character design" => {
+                StaffRole::CharacterDesign
+            }
+            "chief animation director" => StaffRole::ChiefAnimationDirector,
+            "art director" | "art direction" => StaffRole::ArtDirector,
+            "sound director" | "sound direction" => StaffRole::SoundDirector,
+            "music" | "music composition" => StaffRole::Music,
+            "episode director" => StaffRole::EpisodeDirector,
+            "storyboard" => StaffRole::Storyboard,
+            "animation director" => StaffRole::AnimationDirector,
+            "producer" => StaffRole::Producer,
+            _ => StaffRole::Other,
+        }
+    }
+
+    /// Label without the trailing episode notes AniList adds in parentheses
+    pub fn clean_label(label: &str) -> &str {
+        label.split('(').next().unwrap_or(label).trim()
+    }
+
+    /// Sort key: the creative leads first
+    pub fn rank(&self) -> u8 {
+        match self {
+            StaffRole::Director => 0,
+            StaffRole::SeriesComposition => 1,
+            StaffRole::Script => 2,
+            StaffRole::OriginalCreator => 3,
+            StaffRole::CharacterDesign => 4,
+            StaffRole::ChiefAnimationDirector => 5,
+            StaffRole::ArtDirector => 6,
+            StaffRole::SoundDirector => 7,
+            StaffRole::Music => 8,
+            StaffRole::EpisodeDirector => 9,
+            StaffRole::Storyboard => 10,
+            StaffRole::AnimationDirector => 11,
+            StaffRole::Producer => 12,
+            StaffRole::Other => 13,
+        }
+    }
+}
+
+impl std::fmt::Display for StaffRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalises_provider_labels() {
+        assert_eq!(StaffRole::from_provider("Director"), StaffRole::Director);
+        assert_eq!(
+            StaffRole::from_provider("Series Composition"),
+            StaffRole::SeriesComposition
+        );
+        assert_eq!(
+            StaffRole::from_provider("Storyboard (eps 1, 3)"),
+            StaffRole::Storyboard
+        );
+        assert_eq!(
+            StaffRole::from_provider("Animation Director (OP)"),
+            StaffRole::AnimationDirector
+        );
+        assert_eq!(
+            StaffRole::from_provider("Assistant Director"),
+            StaffRole::Other
+        );
+    }
+
+    #[test]
+    fn test_clean_label_strips_episode_notes() {
+        assert_eq!(
+            StaffRole::clean_label("Key Animation (ep 5)"),
+            "Key Animation"
+        );
+        assert_eq!(StaffRole::clean_label("Music"), "Music");
+    }
+}
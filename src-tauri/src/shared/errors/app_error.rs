@@ -1,4 +1,5 @@
 use serde::Serialize;
+use std::time::Duration;
 use thiserror::Error;
 
 #[derive(Error, Debug, Clone, Serialize)]
@@ -22,8 +23,12 @@ pub enum AppError {
     #[error("Internal error: {0}")]
     InternalError(String),
 
-    #[error("Rate limit exceeded: {0}")]
-    RateLimitError(String),
+    /// `retry_after_secs` carries the provider's `Retry-After` hint when it sent one
+    #[error("Rate limit exceeded: {message}")]
+    RateLimitError {
+        message: String,
+        retry_after_secs: Option<u64>,
+    },
 
     #[error("Serialization error: {0}")]
     SerializationError(String),
@@ -92,7 +97,7 @@ impl From<reqwest::Error> for AppError {
             AppError::ExternalServiceError("Failed to connect to external service".to_string())
         } else if let Some(status) = err.status() {
             match status.as_u16() {
-                429 => AppError::RateLimitError("Too many requests".to_string()),
+                429 => AppError::rate_limited("Too many requests", None),
                 404 => AppError::NotFound("External resource not found".to_string()),
                 401 | 403 => {
                     AppError::Unauthorized("Not authorized to access external service".to_string())
@@ -149,6 +154,43 @@ impl From<String> for AppError {
 
 // Convert AppError to a format suitable for Tauri commands
 impl AppError {
+    /// Rate limit error, optionally with how long the provider asked us to back off
+    pub fn rate_limited(message: impl Into<String>, retry_after: Option<Duration>) -> Self {
+        AppError::RateLimitError {
+            message: message.into(),
+            retry_after_secs: retry_after.map(|delay| delay.as_secs()),
+        }
+    }
+
+    /// Delay requested by the provider for rate limit errors
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            AppError::RateLimitError {
+                retry_after_secs, ..
+            } => retry_after_secs.map(Duration::from_secs),
+            _ => None,
+        }
+    }
+
+    /// Whether repeating the operation later can succeed
+    ///
+    /// Missing records, bad input and mapping bugs fail the same way every time,
+    /// so background jobs hitting them are not worth retrying.
+    pub fn is_retryable(&self) -> bool {
+        !matches!(
+            self,
+            AppError::NotFound(_)
+                | AppError::InvalidInput(_)
+                | AppError::Unauthorized(_)
+                | AppError::SerializationError(_)
+                | AppError::ValidationError(_)
+                | AppError::NotImplemented(_)
+                | AppError::InvalidOperation(_)
+                | AppError::Duplicate(_)
+                | AppError::MappingError(_)
+        )
+    }
+
     // Note: Use the Display implementation instead (format!("{}", self))
     // pub fn to_string(&self) -> String {
     //     format!("{}", self)
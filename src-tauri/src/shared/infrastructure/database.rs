@@ -147,3 +147,14 @@ struct PoolConfig {
     max_size: u32,
     min_idle: u32,
 }
+
+/// Escape `LIKE` wildcards so user input is matched literally
+///
+/// Postgres uses `\` as the default escape character for `LIKE` and `ILIKE`.
+pub fn escape_like(value: &str) -> String {
+    value
+        .trim()
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
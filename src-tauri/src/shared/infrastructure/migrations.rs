@@ -0,0 +1,4 @@
+use diesel_migrations::{embed_migrations, EmbeddedMigrations};
+
+/// Schema migrations compiled into the binary, run at startup and before a restore
+pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
@@ -4,7 +4,9 @@
 /// multiple bounded contexts (modules).
 pub mod database;
 pub mod database_state;
+pub mod migrations;
 
 // Re-exports for convenience
-pub use database::Database;
+pub use database::{escape_like, Database};
 pub use database_state::DatabaseState;
+pub use migrations::MIGRATIONS;
@@ -6,11 +6,15 @@
 /// Tests cover:
 /// - Job processing and completion
 /// - Priority-based job ordering
-/// - Retry logic (3 attempts then fail)
-/// - Worker lifecycle (start/stop)
+/// - Retry logic (3 attempts then dead letter, rate-limit rescheduling)
+/// - Worker lifecycle (start/stop, pause/resume)
+/// - Queue controls (cancel, retry, filtering)
 mod utils;
 
-use miru_lib::modules::jobs::domain::{entities::Job, repository::JobRepository};
+use miru_lib::modules::jobs::domain::{
+    entities::{Job, JobStatus, JobType},
+    repository::{JobFilter, JobRepository},
+};
 use utils::{factories::AnimeFactory, helpers};
 
 // ================================================================================================
@@ -219,8 +223,8 @@ async fn failed_job_retries_up_to_3_times() {
         .unwrap()
         .unwrap();
     assert_eq!(
-        final_job.status, "failed",
-        "Should be permanently failed after 3 attempts"
+        final_job.status, "dead_letter",
+        "Should be dead-lettered after 3 attempts"
     );
     assert_eq!(final_job.attempts, 3);
 
@@ -229,6 +233,55 @@ async fn failed_job_retries_up_to_3_times() {
     assert!(next.is_none(), "Failed job should not be dequeueable");
 }
 
+#[tokio::test]
+async fn rate_limited_job_waits_for_retry_after() {
+    let services = helpers::build_test_services();
+
+    let job = Job::enrichment(uuid::Uuid::new_v4(), 5);
+    let enqueued = services.job_repository.enqueue(job).await.unwrap();
+
+    let running = services.job_repository.dequeue().await.unwrap().unwrap();
+    services
+        .job_repository
+        .mark_rate_limited(
+            running.id,
+            "Rate limit exceeded",
+            std::time::Duration::from_secs(60),
+        )
+        .await
+        .unwrap();
+
+    let rescheduled = services
+        .job_repository
+        .get_by_id(enqueued.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(rescheduled.status, "pending");
+    assert!(
+        rescheduled.scheduled_at >= chrono::Utc::now() + chrono::Duration::seconds(55),
+        "Job should honour the provider's Retry-After"
+    );
+
+    // Not due yet
+    assert!(services.job_repository.dequeue().await.unwrap().is_none());
+
+    // Manual retry makes it due immediately
+    services
+        .job_repository
+        .mark_failed_permanently(enqueued.id, "Anime not found")
+        .await
+        .unwrap();
+    let retried = services
+        .job_repository
+        .retry_job(enqueued.id)
+        .await
+        .unwrap()
+        .expect("Failed job should be retryable");
+    assert!(retried.scheduled_at <= chrono::Utc::now());
+    assert!(services.job_repository.dequeue().await.unwrap().is_some());
+}
+
 // ================================================================================================
 // RELATIONS DISCOVERY JOB TEST
 // ================================================================================================
@@ -363,3 +416,170 @@ async fn job_statistics_accurate() {
     assert_eq!(stats_after.pending_count, 2);
     assert_eq!(stats_after.completed_count, 1);
 }
+
+// ================================================================================================
+// QUEUE CONTROL TESTS
+// ================================================================================================
+
+#[tokio::test]
+async fn cancelled_job_is_not_processed_and_can_be_retried() {
+    let services = helpers::build_test_services();
+
+    let anime = AnimeFactory::minimal().with_anilist_id(50001).build();
+    services.anime_service.create_anime(&anime).await.unwrap();
+    let job_record = services
+        .job_repository
+        .enqueue(Job::enrichment(anime.id, 5))
+        .await
+        .unwrap();
+
+    let cancelled = services
+        .job_repository
+        .cancel_job(job_record.id)
+        .await
+        .unwrap()
+        .expect("Pending job should be cancellable");
+    assert_eq!(cancelled.status, "cancelled");
+
+    // Worker must not pick up cancelled jobs
+    assert!(services.job_repository.dequeue().await.unwrap().is_none());
+
+    // Cancelling twice is a no-op
+    assert!(services
+        .job_repository
+        .cancel_job(job_record.id)
+        .await
+        .unwrap()
+        .is_none());
+
+    let retried = services
+        .job_repository
+        .retry_job(job_record.id)
+        .await
+        .unwrap()
+        .expect("Cancelled job should be retryable");
+    assert_eq!(retried.status, "pending");
+    assert_eq!(retried.attempts, 0);
+
+    let stats = services.job_repository.get_statistics().await.unwrap();
+    assert_eq!(stats.pending_count, 1);
+    assert_eq!(stats.cancelled_count, 0);
+}
+
+#[tokio::test]
+async fn list_jobs_filters_by_status_and_anime() {
+    let services = helpers::build_test_services();
+
+    let anime1 = AnimeFactory::minimal().with_anilist_id(50002).build();
+    let anime2 = AnimeFactory::minimal().with_anilist_id(50003).build();
+    services.anime_service.create_anime(&anime1).await.unwrap();
+    services.anime_service.create_anime(&anime2).await.unwrap();
+
+    services
+        .job_repository
+        .enqueue(Job::enrichment(anime1.id, 5))
+        .await
+        .unwrap();
+    let job2 = services
+        .job_repository
+        .enqueue(Job::relations_discovery(anime2.id, 5))
+        .await
+        .unwrap();
+    services.job_repository.cancel_job(job2.id).await.unwrap();
+
+    let cancelled = services
+        .job_repository
+        .list_jobs(JobFilter {
+            status: Some(JobStatus::Cancelled),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(cancelled.len(), 1);
+    assert_eq!(cancelled[0].id, job2.id);
+
+    let for_anime1 = services
+        .job_repository
+        .list_jobs(JobFilter {
+            anime_id: Some(anime1.id),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(for_anime1.len(), 1);
+    assert_eq!(for_anime1[0].job_type, "enrichment");
+}
+
+#[tokio::test]
+async fn paused_worker_leaves_queue_untouched() {
+    let services = helpers::build_test_services();
+
+    let anime = AnimeFactory::minimal().with_anilist_id(50004).build();
+    services.anime_service.create_anime(&anime).await.unwrap();
+    services
+        .job_repository
+        .enqueue(Job::enrichment(anime.id, 5))
+        .await
+        .unwrap();
+
+    services.background_worker.pause().await;
+    helpers::run_worker_for_duration(services.background_worker.clone(), 2).await;
+
+    let pending = services
+        .job_repository
+        .get_pending_jobs()
+        .await
+        .unwrap()
+        .len();
+    assert_eq!(pending, 1, "Paused worker should not process jobs");
+
+    let stats = services.background_worker.get_statistics().await.unwrap();
+    assert!(stats.is_paused);
+}
+
+#[tokio::test]
+async fn dequeue_matching_respects_job_types() {
+    let services = helpers::build_test_services();
+
+    let anime = AnimeFactory::minimal().with_anilist_id(50005).build();
+    services.anime_service.create_anime(&anime).await.unwrap();
+
+    // Enrichment has the higher priority, but only relations discovery has a free slot
+    services
+        .job_repository
+        .enqueue(Job::enrichment(anime.id, 1))
+        .await
+        .unwrap();
+    let relations_job = services
+        .job_repository
+        .enqueue(Job::relations_discovery(anime.id, 5))
+        .await
+        .unwrap();
+
+    let job = services
+        .job_repository
+        .dequeue_matching(&[JobType::RelationsDiscovery])
+        .await
+        .unwrap()
+        .expect("Relations job should be dequeued");
+    assert_eq!(job.id, relations_job.id);
+
+    assert!(services
+        .job_repository
+        .dequeue_matching(&[JobType::RelationsDiscovery])
+        .await
+        .unwrap()
+        .is_none());
+
+    // Interrupted jobs go back to pending without spending an attempt
+    let requeued = services.job_repository.requeue_interrupted().await.unwrap();
+    assert_eq!(requeued, 1);
+    let job = services
+        .job_repository
+        .get_by_id(relations_job.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(job.status, "pending");
+    assert_eq!(job.attempts, 0);
+}
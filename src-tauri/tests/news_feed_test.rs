@@ -0,0 +1,245 @@
+#![allow(dead_code)]
+
+/// Tests for the news feed against a real database
+///
+/// Tests cover:
+/// - Syncing an article listed under several anime stores it once
+/// - Re-syncing counts only articles not seen before
+/// - Feed filters (anime, collection), ordering and paging
+///
+/// Provider news comes from a stub so the tests run offline.
+mod utils;
+
+use async_trait::async_trait;
+use chrono::{TimeZone, Utc};
+use futures::future::BoxFuture;
+use miru_lib::modules::{
+    anime::{
+        domain::entities::anime_detailed::AnimeDetailed,
+        infrastructure::persistence::AnimeRepositoryImpl, AnimeRepository,
+    },
+    collection::{
+        domain::entities::collection::Collection,
+        infrastructure::persistence::CollectionRepositoryImpl, CollectionRepository,
+    },
+    news::{
+        infrastructure::NewsRepositoryImpl, NewsFilter, NewsRepository, NewsService,
+        ProviderNewsItem,
+    },
+    provider::{
+        application::service::ProviderService, domain::repositories::NewsProviderRepository,
+        infrastructure::adapters::ProviderRepositoryAdapter, AnimeProvider,
+    },
+};
+use miru_lib::shared::errors::AppResult;
+use miru_lib::shared::infrastructure::database::Database;
+use std::collections::HashMap;
+use std::sync::Arc;
+use utils::{factories::AnimeFactory, test_db::TestDb};
+
+/// News per MyAnimeList id, served without touching the network
+struct StubNewsProvider {
+    articles: HashMap<u32, Vec<ProviderNewsItem>>,
+}
+
+#[async_trait]
+impl NewsProviderRepository for StubNewsProvider {
+    async fn fetch_news(
+        &self,
+        _provider: AnimeProvider,
+        provider_anime_id: u32,
+    ) -> AppResult<Vec<ProviderNewsItem>> {
+        Ok(self
+            .articles
+            .get(&provider_anime_id)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    fn news_providers(&self) -> Vec<AnimeProvider> {
+        vec![AnimeProvider::Jikan]
+    }
+}
+
+fn article(url: &str, day: u32) -> ProviderNewsItem {
+    ProviderNewsItem {
+        provider: AnimeProvider::Jikan,
+        url: url.to_string(),
+        title: format!("Article {}", url),
+        excerpt: None,
+        image_url: None,
+        author: None,
+        forum_url: None,
+        comments: 0,
+        published_at: Some(Utc.with_ymd_and_hms(2025, 10, day, 12, 0, 0).unwrap()),
+    }
+}
+
+fn news_service(db: Arc<Database>, articles: HashMap<u32, Vec<ProviderNewsItem>>) -> NewsService {
+    let adapter = Arc::new(ProviderRepositoryAdapter::new());
+    let provider_service = Arc::new(ProviderService::new(
+        adapter.clone(),
+        adapter.clone(),
+        adapter.clone(),
+        adapter.clone(),
+        adapter.clone(),
+        adapter.clone(),
+        adapter.clone(),
+        adapter.clone(),
+        adapter.clone(),
+        Arc::new(StubNewsProvider { articles }),
+        adapter.clone(),
+        adapter.clone(),
+        adapter,
+    ));
+
+    NewsService::new(
+        Arc::new(NewsRepositoryImpl::new(db.clone())),
+        Arc::new(AnimeRepositoryImpl::new(db)),
+        provider_service,
+    )
+}
+
+async fn save_anime(db: &Arc<Database>, title: &str, mal_id: u32) -> AnimeDetailed {
+    let anime = AnimeFactory::minimal()
+        .with_title(title)
+        .with_provider(AnimeProvider::Jikan, &mal_id.to_string())
+        .build();
+    AnimeRepositoryImpl::new(db.clone())
+        .save(&anime)
+        .await
+        .unwrap()
+}
+
+async fn collect(db: &Arc<Database>, name: &str, anime: &[&AnimeDetailed]) -> Collection {
+    let repo = CollectionRepositoryImpl::new(db.clone());
+    let collection = repo.save(&Collection::new(name.to_string())).await.unwrap();
+    for anime in anime {
+        repo.add_anime_to_collection(&collection.id, &anime.id, None, None)
+            .await
+            .unwrap();
+    }
+    collection
+}
+
+fn urls(feed: &[miru_lib::modules::news::NewsFeedItem]) -> Vec<&str> {
+    feed.iter().map(|item| item.url.as_str()).collect()
+}
+
+#[tokio::test]
+async fn sync_news_stores_shared_articles_once() {
+    let test_db = TestDb::new();
+
+    test_db
+        .run_test(|pool| -> BoxFuture<'static, ()> {
+            Box::pin(async move {
+                let db = Arc::new(Database::from_pool(pool));
+                let frieren = save_anime(&db, "Frieren", 52991).await;
+                let apothecary = save_anime(&db, "The Apothecary Diaries", 54492).await;
+                collect(&db, "Watching", &[&frieren, &apothecary]).await;
+
+                let service = news_service(
+                    db.clone(),
+                    HashMap::from([
+                        (
+                            52991,
+                            vec![article("a/fall-lineup", 3), article("a/frieren", 2)],
+                        ),
+                        (54492, vec![article("a/fall-lineup", 3)]),
+                    ]),
+                );
+
+                let summary = service.sync_news().await.unwrap();
+                assert_eq!(summary.anime_synced, 2);
+                assert_eq!(summary.items_added, 2);
+                assert_eq!(summary.failed, 0);
+
+                let feed = service.get_news_feed(&NewsFilter::default()).await.unwrap();
+                assert_eq!(urls(&feed), vec!["a/fall-lineup", "a/frieren"]);
+
+                let titles: Vec<&str> = feed[0]
+                    .anime
+                    .iter()
+                    .map(|anime| anime.title.as_str())
+                    .collect();
+                assert_eq!(titles, vec!["Frieren", "The Apothecary Diaries"]);
+
+                // Nothing new on the second run, and no duplicate rows either
+                let summary = service.sync_news().await.unwrap();
+                assert_eq!(summary.items_added, 0);
+                let feed = service.get_news_feed(&NewsFilter::default()).await.unwrap();
+                assert_eq!(feed.len(), 2);
+            })
+        })
+        .await;
+}
+
+#[tokio::test]
+async fn find_feed_filters_by_anime_and_collection() {
+    let test_db = TestDb::new();
+
+    test_db
+        .run_test(|pool| -> BoxFuture<'static, ()> {
+            Box::pin(async move {
+                let db = Arc::new(Database::from_pool(pool));
+                let repo = NewsRepositoryImpl::new(db.clone());
+
+                let frieren = save_anime(&db, "Frieren", 52991).await;
+                let apothecary = save_anime(&db, "The Apothecary Diaries", 54492).await;
+                let dandadan = save_anime(&db, "Dandadan", 57334).await;
+                let collection = collect(&db, "Favorites", &[&frieren, &apothecary]).await;
+
+                repo.save_items(&frieren.id, &[article("a/frieren", 1)])
+                    .await
+                    .unwrap();
+                repo.save_items(&apothecary.id, &[article("a/apothecary", 2)])
+                    .await
+                    .unwrap();
+                repo.save_items(&dandadan.id, &[article("a/dandadan", 3)])
+                    .await
+                    .unwrap();
+
+                let all = repo.find_feed(&NewsFilter::default()).await.unwrap();
+                assert_eq!(urls(&all), vec!["a/dandadan", "a/apothecary", "a/frieren"]);
+
+                let by_anime = repo
+                    .find_feed(&NewsFilter {
+                        anime_id: Some(apothecary.id),
+                        ..NewsFilter::default()
+                    })
+                    .await
+                    .unwrap();
+                assert_eq!(urls(&by_anime), vec!["a/apothecary"]);
+
+                let by_collection = repo
+                    .find_feed(&NewsFilter {
+                        collection_id: Some(collection.id),
+                        ..NewsFilter::default()
+                    })
+                    .await
+                    .unwrap();
+                assert_eq!(urls(&by_collection), vec!["a/apothecary", "a/frieren"]);
+
+                let both = repo
+                    .find_feed(&NewsFilter {
+                        collection_id: Some(collection.id),
+                        anime_id: Some(dandadan.id),
+                        ..NewsFilter::default()
+                    })
+                    .await
+                    .unwrap();
+                assert!(both.is_empty());
+
+                let second_page = repo
+                    .find_feed(&NewsFilter {
+                        limit: Some(1),
+                        offset: Some(1),
+                        ..NewsFilter::default()
+                    })
+                    .await
+                    .unwrap();
+                assert_eq!(urls(&second_page), vec!["a/apothecary"]);
+            })
+        })
+        .await;
+}
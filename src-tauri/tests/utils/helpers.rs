@@ -5,17 +5,23 @@ use miru_lib::modules::{
     anime::{
         application::{ingestion_service::AnimeIngestionService, service::AnimeService},
         domain::services::anime_relations_service::{AnimeRelationsService, RelationsCache},
-        infrastructure::persistence::AnimeRepositoryImpl,
-        AnimeRepository,
+        infrastructure::persistence::{AnimeQueryRepositoryImpl, AnimeRepositoryImpl},
+        AnimeRepository, InProcessEventBus,
     },
     data_import::domain::services::import_components::{
         data_enhancement_service::DataEnhancementService, validation_service::ValidationService,
     },
-    jobs::{infrastructure::JobRepositoryImpl, worker::BackgroundWorker},
+    episode::{infrastructure::EpisodeRepositoryImpl, EpisodeService},
+    jobs::{infrastructure::JobRepositoryImpl, worker::BackgroundWorker, RetryBackoff},
+    news::{infrastructure::NewsRepositoryImpl, NewsService},
     provider::{
         application::service::ProviderService,
         infrastructure::adapters::{CacheAdapter, ProviderRepositoryAdapter},
     },
+    statistics::{
+        infrastructure::StatisticsRepositoryImpl, SnapshotMomentumSource, StatisticsRepository,
+        StatisticsService,
+    },
 };
 use miru_lib::shared::infrastructure::database::Database;
 use std::sync::Arc;
@@ -41,21 +47,41 @@ pub fn build_test_services_with_pool(pool: super::test_db::TestPool) -> TestServ
     let db = Arc::new(Database::from_pool(pool.clone()));
 
     let anime_repo: Arc<dyn AnimeRepository> = Arc::new(AnimeRepositoryImpl::new(db.clone()));
-    let job_repo = Arc::new(JobRepositoryImpl::new(pool.clone()));
+    // Retry right away so tests can walk a job through every attempt
+    let job_repo = Arc::new(JobRepositoryImpl::with_backoff(
+        pool.clone(),
+        RetryBackoff::none(),
+    ));
 
     let provider_repo = Arc::new(ProviderRepositoryAdapter::new());
     let _cache_repo = Arc::new(CacheAdapter::new());
 
-    // ProviderRepositoryAdapter implements all three repository traits
+    // ProviderRepositoryAdapter implements all four repository traits
     let provider_service = Arc::new(ProviderService::new(
+        provider_repo.clone(),
+        provider_repo.clone(),
+        provider_repo.clone(),
+        provider_repo.clone(),
+        provider_repo.clone(),
+        provider_repo.clone(),
+        provider_repo.clone(),
+        provider_repo.clone(),
+        provider_repo.clone(),
+        provider_repo.clone(),
         provider_repo.clone(),
         provider_repo.clone(),
         provider_repo,
     ));
 
+    let event_bus = Arc::new(InProcessEventBus::new());
+    let statistics_repo: Arc<dyn StatisticsRepository> =
+        Arc::new(StatisticsRepositoryImpl::new(db.clone()));
+
     let anime_service = Arc::new(AnimeService::new(
         anime_repo.clone(),
         provider_service.clone(),
+        event_bus.clone(),
+        Arc::new(SnapshotMomentumSource::new(statistics_repo.clone())),
     ));
 
     let validation_service = Arc::new(ValidationService::new(
@@ -90,11 +116,37 @@ pub fn build_test_services_with_pool(pool: super::test_db::TestPool) -> TestServ
         ingestion_service.clone(),
     ));
 
+    let episode_service = Arc::new(EpisodeService::new(
+        Arc::new(EpisodeRepositoryImpl::new(db.clone())),
+        anime_repo.clone(),
+        provider_service.clone(),
+    ));
+
+    let news_service = Arc::new(NewsService::new(
+        Arc::new(NewsRepositoryImpl::new(db.clone())),
+        anime_repo.clone(),
+        provider_service.clone(),
+    ));
+
+    let statistics_service = Arc::new(StatisticsService::new(
+        statistics_repo,
+        anime_repo.clone(),
+        Arc::new(AnimeQueryRepositoryImpl::new(
+            db.clone(),
+            anime_repo_impl.clone(),
+        )),
+        provider_service.clone(),
+    ));
+
     let background_worker = Arc::new(BackgroundWorker::new(
         job_repo.clone(),
         anime_service.clone(),
         provider_service.clone(),
         relations_service.clone(),
+        episode_service,
+        news_service,
+        statistics_service,
+        event_bus,
     ));
 
     TestServices {